@@ -0,0 +1,51 @@
+//! Filtering a package index with [VersionReq::matches_slice] against calling
+//! [VersionReq::matches] in a loop.
+//!
+//! Run with `cargo bench --bench matches_slice`, add `--features nightly` on a nightly toolchain
+//! for the SIMD version. Baseline x86-64 has no 64 bit vector comparisons, the SIMD version only
+//! pays off with them, e.g. with `RUSTFLAGS="-C target-cpu=native"`.
+
+use fast_version::{Version, VersionReq};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const COUNT: usize = 100_000;
+const ROUNDS: usize = 100;
+
+fn main() {
+    let mut state = 0x5eed_u64;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state % 20
+    };
+    let versions: Vec<Version> = (0..COUNT)
+        .map(|_| Version::new(next(), next(), next()))
+        .collect();
+    let req = VersionReq::parse(">=3.4.5, <12.0.0").unwrap();
+    let mut out = vec![false; COUNT];
+
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        for (version, out) in black_box(&versions).iter().zip(out.iter_mut()) {
+            *out = req.matches(version);
+        }
+        black_box(&out);
+    }
+    report("matches", start.elapsed());
+
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        req.matches_slice(black_box(&versions), &mut out);
+        black_box(&out);
+    }
+    report("matches_slice", start.elapsed());
+}
+
+fn report(name: &str, elapsed: Duration) {
+    println!(
+        "{name:>13}: {:>8.2?} per {COUNT} versions",
+        elapsed / ROUNDS as u32
+    );
+}
@@ -0,0 +1,49 @@
+//! Sorting version strings in place with [VersionStr] against parsing them into [Version]s first.
+//!
+//! Run with `cargo bench --bench version_str`.
+
+use fast_version::{Version, VersionStr};
+use std::hint::black_box;
+use std::mem::size_of;
+use std::time::Instant;
+
+const COUNT: usize = 1_000_000;
+
+fn main() {
+    let mut state = 0x5eed_u64;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state % 100
+    };
+    let arena: Vec<String> = (0..COUNT)
+        .map(|_| format!("{}.{}.{}", next(), next(), next()))
+        .collect();
+
+    let start = Instant::now();
+    let mut lazy: Vec<VersionStr> = arena
+        .iter()
+        .map(|version| VersionStr::try_new(version).unwrap())
+        .collect();
+    lazy.sort_unstable();
+    black_box(&lazy);
+    report("VersionStr", size_of::<VersionStr>(), start);
+
+    let start = Instant::now();
+    let mut parsed: Vec<Version> = arena
+        .iter()
+        .map(|version| version.parse().unwrap())
+        .collect();
+    parsed.sort_unstable();
+    black_box(&parsed);
+    report("Version", size_of::<Version>(), start);
+}
+
+fn report(name: &str, size: usize, start: Instant) {
+    println!(
+        "{name:>10}: {:>8.1?} to validate and sort, {:>5.1} MiB besides the strings",
+        start.elapsed(),
+        (size * COUNT) as f64 / (1024.0 * 1024.0),
+    );
+}
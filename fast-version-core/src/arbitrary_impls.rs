@@ -0,0 +1,156 @@
+//! [Arbitrary] implementations for fuzzing, behind the `arbitrary` feature.
+//!
+//! [Version] and the bound enums derive it, the requirement variants are written by hand so that
+//! they never lower to [VersionReq::EMPTY].
+
+use crate::version::Version;
+use crate::version_req::{
+    VersionReq, VersionReqVariant, VersionReqVariantLowerBound, VersionReqVariantUpperBound,
+};
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+/// Every variant, a [Compound](VersionReqVariant::Compound) has its lower bound below its upper
+/// bound. Variants no version could match, like `<0`, are replaced by a
+/// [Strict](VersionReqVariant::Strict) one.
+impl<'a> Arbitrary<'a> for VersionReqVariant {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        use VersionReqVariant::*;
+        let variant = match u.int_in_range(0..=15u8)? {
+            0 => Strict(u.arbitrary()?),
+            1 => return compound(u),
+            2 => MajorGreater {
+                major: u.arbitrary()?,
+            },
+            3 => MinorGreater {
+                major: u.arbitrary()?,
+                minor: u.arbitrary()?,
+            },
+            4 => PatchGreater {
+                major: u.arbitrary()?,
+                minor: u.arbitrary()?,
+                patch: u.arbitrary()?,
+            },
+            5 => MajorGreaterEqual {
+                major: u.arbitrary()?,
+            },
+            6 => MinorGreaterEqual {
+                major: u.arbitrary()?,
+                minor: u.arbitrary()?,
+            },
+            7 => PatchGreaterEqual {
+                major: u.arbitrary()?,
+                minor: u.arbitrary()?,
+                patch: u.arbitrary()?,
+            },
+            8 => MajorLess {
+                major: u.arbitrary()?,
+            },
+            9 => MinorLess {
+                major: u.arbitrary()?,
+                minor: u.arbitrary()?,
+            },
+            10 => PatchLess {
+                major: u.arbitrary()?,
+                minor: u.arbitrary()?,
+                patch: u.arbitrary()?,
+            },
+            11 => MajorLessEqual {
+                major: u.arbitrary()?,
+            },
+            12 => MinorLessEqual {
+                major: u.arbitrary()?,
+                minor: u.arbitrary()?,
+            },
+            13 => PatchLessEqual {
+                major: u.arbitrary()?,
+                minor: u.arbitrary()?,
+                patch: u.arbitrary()?,
+            },
+            14 => Caret(u.arbitrary()?),
+            _ => Tilde(u.arbitrary()?),
+        };
+        if VersionReq::new(&variant).is_empty() {
+            return Ok(Strict(u.arbitrary()?));
+        }
+        Ok(variant)
+    }
+}
+
+/// Lowers an arbitrary [VersionReqVariant], so the requirement is never empty.
+impl<'a> Arbitrary<'a> for VersionReq {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(VersionReq::new(&u.arbitrary()?))
+    }
+}
+
+/// Bounds of arbitrary kinds taken from two ordered versions. Exclusive or truncated bounds can
+/// still leave nothing in between, like `>1.2.3, <1.2.4`, those fall back to both versions as
+/// inclusive bounds.
+fn compound(u: &mut Unstructured<'_>) -> Result<VersionReqVariant> {
+    let first: Version = u.arbitrary()?;
+    let second: Version = u.arbitrary()?;
+    let (low, high) = (
+        Version::const_min(first, second),
+        Version::const_max(first, second),
+    );
+    let lower = lower_bound(u.int_in_range(0..=5)?, low);
+    let upper = upper_bound(u.int_in_range(0..=5)?, high);
+    let variant = VersionReqVariant::Compound(lower, upper);
+    if VersionReq::new(&variant).is_empty() {
+        return Ok(VersionReqVariant::Compound(
+            lower_bound(5, low),
+            upper_bound(5, high),
+        ));
+    }
+    Ok(variant)
+}
+
+fn lower_bound(kind: u8, version: Version) -> VersionReqVariantLowerBound {
+    use VersionReqVariantLowerBound::*;
+    let Version {
+        major,
+        minor,
+        patch,
+    } = version;
+    match kind {
+        0 => MajorGreater { major },
+        1 => MinorGreater { major, minor },
+        2 => PatchGreater {
+            major,
+            minor,
+            patch,
+        },
+        3 => MajorGreaterEqual { major },
+        4 => MinorGreaterEqual { major, minor },
+        _ => PatchGreaterEqual {
+            major,
+            minor,
+            patch,
+        },
+    }
+}
+
+fn upper_bound(kind: u8, version: Version) -> VersionReqVariantUpperBound {
+    use VersionReqVariantUpperBound::*;
+    let Version {
+        major,
+        minor,
+        patch,
+    } = version;
+    match kind {
+        0 => MajorLess { major },
+        1 => MinorLess { major, minor },
+        2 => PatchLess {
+            major,
+            minor,
+            patch,
+        },
+        3 => MajorLessEqual { major },
+        4 => MinorLessEqual { major, minor },
+        _ => PatchLessEqual {
+            major,
+            minor,
+            patch,
+        },
+    }
+}
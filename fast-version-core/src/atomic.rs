@@ -0,0 +1,135 @@
+//! A [Version] cell that can be shared between threads without locking.
+//!
+//! The version is stored packed into 64 bits like [Version::to_packed_u64] does, so only versions
+//! with a major below 2^16 and minor and patch below 2^24 can be stored. Targets without 64 bit
+//! atomics are supported through the `portable-atomic` feature.
+
+use crate::packed::PackedError;
+use crate::version::Version;
+use crate::version_req::VersionReq;
+use core::fmt;
+#[cfg(not(feature = "portable-atomic"))]
+use core::sync::atomic::AtomicU64;
+use core::sync::atomic::Ordering;
+#[cfg(feature = "portable-atomic")]
+use portable_atomic::AtomicU64;
+
+/// Lock-free cell holding a [Version], like a "minimum supported version" that is swapped at
+/// runtime.
+/// ## Example:
+/// ```
+/// # use fast_version_core::{atomic::AtomicVersion, version::Version, version_req::VersionReq};
+/// use std::sync::atomic::Ordering;
+///
+/// static MIN_SUPPORTED: AtomicVersion = AtomicVersion::new(Version::new(1, 2, 0));
+///
+/// MIN_SUPPORTED.store(Version::new(1, 4, 0), Ordering::Release).unwrap();
+/// assert_eq!(MIN_SUPPORTED.load(Ordering::Acquire), Version::new(1, 4, 0));
+///
+/// let req = VersionReq::parse("^1.3").unwrap();
+/// assert!(MIN_SUPPORTED.matches_req(&req, Ordering::Acquire));
+/// ```
+pub struct AtomicVersion {
+    packed: AtomicU64,
+}
+
+impl AtomicVersion {
+    /// Create a new cell holding the version.
+    ///
+    /// ## Panics
+    /// Panics, or fails to compile in const evaluation, if the version doesn't fit into 64 bits.
+    /// Use [AtomicVersion::try_new] for versions that aren't known to fit.
+    pub const fn new(version: Version) -> Self {
+        match version.to_packed_u64() {
+            Ok(packed) => Self::from_packed(packed),
+            Err(_) => panic!("version doesn't fit into the 64 bits of an AtomicVersion"),
+        }
+    }
+
+    /// Create a new cell holding the version, fails if it doesn't fit into 64 bits.
+    pub const fn try_new(version: Version) -> Result<Self, PackedError> {
+        match version.to_packed_u64() {
+            Ok(packed) => Ok(Self::from_packed(packed)),
+            Err(error) => Err(error),
+        }
+    }
+
+    const fn from_packed(packed: u64) -> Self {
+        Self {
+            packed: AtomicU64::new(packed),
+        }
+    }
+
+    /// Loads the version, see [AtomicU64::load] for the orderings.
+    #[inline]
+    pub fn load(&self, order: Ordering) -> Version {
+        Version::from_packed_u64(self.packed.load(order))
+    }
+
+    /// Stores the version, see [AtomicU64::store] for the orderings. Fails without touching the
+    /// cell if the version doesn't fit into 64 bits.
+    #[inline]
+    pub fn store(&self, version: Version, order: Ordering) -> Result<(), PackedError> {
+        self.packed.store(version.to_packed_u64()?, order);
+        Ok(())
+    }
+
+    /// Stores `new` if the cell holds `current`, see [AtomicU64::compare_exchange].
+    ///
+    /// The inner result is the one of [AtomicU64::compare_exchange]: the previous version, which
+    /// is `current` on success. The outer one fails without touching the cell if `new` doesn't fit
+    /// into 64 bits. A `current` that doesn't fit is never held, so the exchange just fails.
+    /// ```
+    /// # use fast_version_core::{atomic::AtomicVersion, version::Version};
+    /// use std::sync::atomic::Ordering::SeqCst;
+    ///
+    /// let cell = AtomicVersion::new(Version::new(1, 0, 0));
+    /// let (old, new) = (Version::new(1, 0, 0), Version::new(2, 0, 0));
+    ///
+    /// assert_eq!(cell.compare_exchange(old, new, SeqCst, SeqCst).unwrap(), Ok(old));
+    /// assert_eq!(cell.compare_exchange(old, new, SeqCst, SeqCst).unwrap(), Err(new));
+    /// assert!(cell.compare_exchange(new, Version::new(1 << 16, 0, 0), SeqCst, SeqCst).is_err());
+    /// ```
+    pub fn compare_exchange(
+        &self,
+        current: Version,
+        new: Version,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<Result<Version, Version>, PackedError> {
+        let new = new.to_packed_u64()?;
+        let Ok(current) = current.to_packed_u64() else {
+            return Ok(Err(self.load(failure)));
+        };
+        Ok(self
+            .packed
+            .compare_exchange(current, new, success, failure)
+            .map(Version::from_packed_u64)
+            .map_err(Version::from_packed_u64))
+    }
+
+    /// Checks wether the currently held version matches the requirement.
+    #[inline]
+    pub fn matches_req(&self, req: &VersionReq, order: Ordering) -> bool {
+        req.matches(&self.load(order))
+    }
+
+    /// Consumes the cell and returns the version.
+    pub fn into_inner(self) -> Version {
+        Version::from_packed_u64(self.packed.into_inner())
+    }
+}
+
+/// Holds `0.0.0`.
+impl Default for AtomicVersion {
+    fn default() -> Self {
+        Self::from_packed(0)
+    }
+}
+
+/// Prints the currently held version, loaded with [Ordering::Relaxed].
+impl fmt::Debug for AtomicVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.load(Ordering::Relaxed), f)
+    }
+}
@@ -0,0 +1,51 @@
+//! Compile time byte strings containing a [Version](crate::version::Version).
+
+/// Builds a `&'static [u8; N]` of a prefix, the textual version and a suffix at compile time.
+///
+/// The version has to be a constant expression, like [Version::new](crate::version::Version::new)
+/// or the `const_version!` macro of the facade crate.
+/// ```
+/// # use fast_version_core::{version::Version, version_banner};
+/// const BANNER: &[u8] = version_banner!(b"myproto/", Version::new(1, 2, 3), b"\n");
+///
+/// assert_eq!(BANNER, b"myproto/1.2.3\n");
+/// ```
+#[macro_export]
+macro_rules! version_banner {
+    ($prefix:expr, $version:expr, $suffix:expr $(,)?) => {{
+        const PREFIX: &[u8] = $prefix;
+        const SUFFIX: &[u8] = $suffix;
+        const ASCII: ([u8; $crate::version::MAX_STR_LEN], usize) =
+            $crate::version::Version::to_ascii_array($version);
+        const LEN: usize = PREFIX.len() + ASCII.1 + SUFFIX.len();
+        const BANNER: [u8; LEN] = $crate::banner::concat(PREFIX, ASCII, SUFFIX);
+        &BANNER
+    }};
+}
+
+#[doc(hidden)]
+pub const fn concat<const N: usize>(
+    prefix: &[u8],
+    (version, version_len): ([u8; crate::version::MAX_STR_LEN], usize),
+    suffix: &[u8],
+) -> [u8; N] {
+    let mut bytes = [0; N];
+    let mut index = 0;
+    while index < prefix.len() {
+        bytes[index] = prefix[index];
+        index += 1;
+    }
+    let mut offset = 0;
+    while offset < version_len {
+        bytes[index] = version[offset];
+        index += 1;
+        offset += 1;
+    }
+    offset = 0;
+    while offset < suffix.len() {
+        bytes[index] = suffix[offset];
+        index += 1;
+        offset += 1;
+    }
+    bytes
+}
@@ -0,0 +1,159 @@
+//! Matching a requirement against many versions at once.
+
+use crate::version::Version;
+use crate::version_req::VersionReq;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::ops::Range;
+#[cfg(feature = "nightly")]
+use core::simd::{
+    cmp::{SimdPartialEq, SimdPartialOrd},
+    mask64x4, u64x4,
+};
+
+/// The number of versions [VersionReq::matches_slice] compares at once with the `nightly` feature.
+#[cfg(feature = "nightly")]
+const LANES: usize = 4;
+
+impl VersionReq {
+    /// The indices of the matching versions in ascending order.
+    ///
+    /// If `versions` is sorted the matches form a contiguous block that is found by binary
+    /// search and allocated exactly, otherwise every version is checked.
+    /// ```
+    /// # use fast_version_core::{version::Version, version_req::VersionReq};
+    /// let req = VersionReq::parse("^1.2").unwrap();
+    /// let versions = [
+    ///     Version::new(1, 3, 0),
+    ///     Version::new(2, 0, 0),
+    ///     Version::new(1, 2, 0),
+    /// ];
+    ///
+    /// assert_eq!(req.matching_indices(&versions), [0, 2]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn matching_indices(&self, versions: &[Version]) -> Vec<usize> {
+        if versions.is_sorted() {
+            return self.sorted_matching_range(versions).collect();
+        }
+        versions
+            .iter()
+            .enumerate()
+            .filter(|(_, version)| self.matches(version))
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Writes whether each version matches into the same index of `out`, without allocating.
+    ///
+    /// With the `nightly` feature the versions are compared four at a time with SIMD, the results
+    /// are the same as [VersionReq::matches].
+    /// ```
+    /// # use fast_version_core::{version::Version, version_req::VersionReq};
+    /// let req = VersionReq::parse("^1.2").unwrap();
+    /// let versions = [Version::new(1, 3, 0), Version::new(2, 0, 0), Version::new(1, 2, 0)];
+    /// let mut out = [false; 3];
+    ///
+    /// req.matches_slice(&versions, &mut out);
+    /// assert_eq!(out, [true, false, true]);
+    /// ```
+    ///
+    /// # Panics
+    /// If `out` doesn't have the same length as `versions`.
+    pub fn matches_slice(&self, versions: &[Version], out: &mut [bool]) {
+        assert_eq!(
+            versions.len(),
+            out.len(),
+            "versions and out have different lengths"
+        );
+        #[cfg(feature = "nightly")]
+        let (versions, out) = {
+            let chunks = versions.chunks_exact(LANES);
+            let rest = chunks.remainder();
+            let mut out_chunks = out.chunks_exact_mut(LANES);
+            for (chunk, out) in chunks.zip(&mut out_chunks) {
+                out.copy_from_slice(&self.matches_lanes(chunk).to_array());
+            }
+            (rest, out_chunks.into_remainder())
+        };
+        for (version, out) in versions.iter().zip(out) {
+            *out = self.matches(version);
+        }
+    }
+
+    /// [VersionReq::matches] for exactly [LANES] versions, one component per vector.
+    #[cfg(feature = "nightly")]
+    #[inline]
+    fn matches_lanes(&self, versions: &[Version]) -> mask64x4 {
+        let component = |get: fn(&Version) -> u64| {
+            u64x4::from_array(core::array::from_fn(|lane| get(&versions[lane])))
+        };
+        let versions = [
+            component(|version| version.major),
+            component(|version| version.minor),
+            component(|version| version.patch),
+        ];
+        let splat = |version: &Version| {
+            [
+                u64x4::splat(version.major),
+                u64x4::splat(version.minor),
+                u64x4::splat(version.patch),
+            ]
+        };
+        lanes_le(splat(&self.lower), versions) & lanes_le(versions, splat(&self.upper))
+    }
+
+    /// The number of matching versions.
+    /// ```
+    /// # use fast_version_core::{version::Version, version_req::VersionReq};
+    /// let req = VersionReq::parse(">=2").unwrap();
+    /// let versions = [Version::new(1, 0, 0), Version::new(2, 0, 0), Version::new(3, 1, 0)];
+    ///
+    /// assert_eq!(req.count_matching(&versions), 2);
+    /// ```
+    pub fn count_matching(&self, versions: &[Version]) -> usize {
+        versions
+            .iter()
+            .filter(|version| self.matches(version))
+            .count()
+    }
+
+    /// Checks wether at least `n` of the versions match, stopping at the `n`th match.
+    /// ```
+    /// # use fast_version_core::{version::Version, version_req::VersionReq};
+    /// let req = VersionReq::parse(">=2").unwrap();
+    /// let versions = [Version::new(1, 0, 0), Version::new(2, 0, 0), Version::new(3, 1, 0)];
+    ///
+    /// assert!(req.has_at_least(&versions, 2));
+    /// assert!(!req.has_at_least(&versions, 3));
+    /// ```
+    pub fn has_at_least(&self, versions: &[Version], n: usize) -> bool {
+        let Some(skip) = n.checked_sub(1) else {
+            return true;
+        };
+        versions
+            .iter()
+            .filter(|version| self.matches(version))
+            .nth(skip)
+            .is_some()
+    }
+
+    /// The block of matching versions in a sorted slice.
+    #[cfg(feature = "alloc")]
+    fn sorted_matching_range(&self, versions: &[Version]) -> Range<usize> {
+        let start = versions.partition_point(|version| *version < self.lower);
+        let len = versions[start..].partition_point(|version| *version <= self.upper);
+        start..start + len
+    }
+}
+
+/// Lexicographic `lhs <= rhs` of the versions in each lane, given as major, minor and patch
+/// vectors.
+#[cfg(feature = "nightly")]
+#[inline]
+fn lanes_le(lhs: [u64x4; 3], rhs: [u64x4; 3]) -> mask64x4 {
+    let patch = lhs[2].simd_le(rhs[2]);
+    let minor = lhs[1].simd_lt(rhs[1]) | (lhs[1].simd_eq(rhs[1]) & patch);
+    lhs[0].simd_lt(rhs[0]) | (lhs[0].simd_eq(rhs[0]) & minor)
+}
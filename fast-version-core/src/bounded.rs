@@ -0,0 +1,100 @@
+//! Versions whose range is enforced by the type, for fields of protocol structs.
+//!
+//! The bounds are const generics holding versions packed by [Version::to_packed_u128], which
+//! orders like the versions. `const_version_u128!` of the fast-version crate packs them from
+//! string literals.
+
+use crate::version::Version;
+use core::fmt;
+use thiserror::Error;
+
+/// A [Version] that is known to lie within `MIN..=MAX`, both packed by [Version::to_packed_u128].
+/// ## Example:
+/// ```
+/// # use fast_version_core::{bounded::VersionBounded, version::Version};
+/// const fn packed(version: Version) -> u128 {
+///     match version.to_packed_u128() {
+///         Ok(packed) => packed,
+///         Err(_) => panic!(),
+///     }
+/// }
+/// type ProtocolVersion =
+///     VersionBounded<{ packed(Version::new(1, 0, 0)) }, { packed(Version::new(1, 99, 0)) }>;
+///
+/// const CURRENT: ProtocolVersion = ProtocolVersion::new_const(Version::new(1, 4, 0));
+///
+/// assert_eq!(CURRENT.get(), Version::new(1, 4, 0));
+/// assert!(ProtocolVersion::new(Version::new(2, 0, 0)).is_err());
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
+pub struct VersionBounded<const MIN: u128, const MAX: u128>(Version);
+
+/// Returned when a version lies outside the bounds of a [VersionBounded].
+#[derive(Error, Debug, PartialEq, Eq, Clone, Copy)]
+#[error("The version {version} is not within {min} and {max}")]
+pub struct VersionOutOfBounds {
+    pub version: Version,
+    pub min: Version,
+    pub max: Version,
+}
+
+impl<const MIN: u128, const MAX: u128> VersionBounded<MIN, MAX> {
+    /// The smallest allowed version.
+    pub const MIN: Version = Version::from_packed_u128(MIN);
+    /// The largest allowed version.
+    pub const MAX: Version = Version::from_packed_u128(MAX);
+
+    /// Wraps the version, fails if it is outside the bounds.
+    pub const fn new(version: Version) -> Result<Self, VersionOutOfBounds> {
+        if version.const_cmp(&Self::MIN).is_lt() || version.const_cmp(&Self::MAX).is_gt() {
+            return Err(VersionOutOfBounds {
+                version,
+                min: Self::MIN,
+                max: Self::MAX,
+            });
+        }
+        Ok(Self(version))
+    }
+
+    /// Wraps the version, for constants.
+    ///
+    /// ## Panics
+    /// Panics, or fails to compile in const evaluation, if the version is outside the bounds.
+    pub const fn new_const(version: Version) -> Self {
+        match Self::new(version) {
+            Ok(bounded) => bounded,
+            Err(_) => panic!("version outside the bounds of the VersionBounded"),
+        }
+    }
+
+    /// The wrapped version.
+    #[inline]
+    pub const fn get(self) -> Version {
+        self.0
+    }
+}
+
+impl<const MIN: u128, const MAX: u128> From<VersionBounded<MIN, MAX>> for Version {
+    fn from(bounded: VersionBounded<MIN, MAX>) -> Self {
+        bounded.0
+    }
+}
+
+impl<const MIN: u128, const MAX: u128> TryFrom<Version> for VersionBounded<MIN, MAX> {
+    type Error = VersionOutOfBounds;
+    fn try_from(version: Version) -> Result<Self, Self::Error> {
+        Self::new(version)
+    }
+}
+
+impl<const MIN: u128, const MAX: u128> AsRef<Version> for VersionBounded<MIN, MAX> {
+    fn as_ref(&self) -> &Version {
+        &self.0
+    }
+}
+
+impl<const MIN: u128, const MAX: u128> fmt::Display for VersionBounded<MIN, MAX> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
@@ -0,0 +1,237 @@
+//! Bumping a [Version] by one release step.
+
+use crate::version::Version;
+use core::fmt;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Kind of release step between two versions, ordered by significance.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BumpKind {
+    Patch,
+    Minor,
+    Major,
+}
+
+impl fmt::Display for BumpKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            BumpKind::Patch => "patch",
+            BumpKind::Minor => "minor",
+            BumpKind::Major => "major",
+        })
+    }
+}
+
+/// The most significant component in which two versions differ, see [Version::diff].
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum VersionDiff {
+    Equal,
+    Patch,
+    Minor,
+    Major,
+}
+
+impl fmt::Display for VersionDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            VersionDiff::Equal => "equal",
+            VersionDiff::Patch => "patch",
+            VersionDiff::Minor => "minor",
+            VersionDiff::Major => "major",
+        })
+    }
+}
+
+impl Version {
+    /// The most significant component in which the versions differ, no matter which one is
+    /// larger.
+    /// ```
+    /// # use fast_version_core::{bump::VersionDiff, version::Version};
+    /// let version = Version::new(1, 2, 3);
+    ///
+    /// assert_eq!(version.diff(&Version::new(1, 4, 0)), VersionDiff::Minor);
+    /// assert_eq!(version.diff(&Version::new(0, 2, 3)), VersionDiff::Major);
+    /// assert_eq!(version.diff(&version), VersionDiff::Equal);
+    /// ```
+    pub const fn diff(&self, other: &Version) -> VersionDiff {
+        if self.major != other.major {
+            VersionDiff::Major
+        } else if self.minor != other.minor {
+            VersionDiff::Minor
+        } else if self.patch != other.patch {
+            VersionDiff::Patch
+        } else {
+            VersionDiff::Equal
+        }
+    }
+
+    /// Increments the component of the bump and resets all lower components to 0.
+    /// ```
+    /// # use fast_version_core::{bump::BumpKind, version::Version};
+    /// const NEXT: Version = Version::new(1, 2, 3).apply_bump(BumpKind::Minor);
+    ///
+    /// assert_eq!(NEXT, Version::new(1, 3, 0));
+    /// ```
+    ///
+    /// ## Panics
+    /// Panics if the bumped component is already `u64::MAX`.
+    pub const fn apply_bump(self, kind: BumpKind) -> Version {
+        match checked_bump(self, kind) {
+            Some(version) => version,
+            None => panic!("bumped component overflowed"),
+        }
+    }
+
+    /// [Version::apply_bump], or None if the bumped component is already `u64::MAX`.
+    /// ```
+    /// # use fast_version_core::{bump::BumpKind, version::Version};
+    /// let version = Version::new(1, u64::MAX, 3);
+    ///
+    /// assert_eq!(version.checked_apply_bump(BumpKind::Major), Some(Version::new(2, 0, 0)));
+    /// assert_eq!(version.checked_apply_bump(BumpKind::Minor), None);
+    /// ```
+    pub const fn checked_apply_bump(self, kind: BumpKind) -> Option<Version> {
+        checked_bump(self, kind)
+    }
+
+    /// [Version::apply_bump], clamped to the largest version below the overflowing one if the
+    /// bumped component is already `u64::MAX`. The result is never smaller than the version.
+    /// ```
+    /// # use fast_version_core::{bump::BumpKind, version::Version};
+    /// let version = Version::new(1, u64::MAX, 3);
+    ///
+    /// assert_eq!(
+    ///     version.saturating_apply_bump(BumpKind::Minor),
+    ///     Version::new(1, u64::MAX, u64::MAX)
+    /// );
+    /// assert_eq!(version.saturating_apply_bump(BumpKind::Patch), Version::new(1, u64::MAX, 4));
+    /// ```
+    pub const fn saturating_apply_bump(self, kind: BumpKind) -> Version {
+        match checked_bump(self, kind) {
+            Some(version) => version,
+            None => match kind {
+                BumpKind::Major => Version::MAX,
+                BumpKind::Minor => Version::new(self.major, u64::MAX, u64::MAX),
+                BumpKind::Patch => self,
+            },
+        }
+    }
+
+    /// Increments the major and resets minor and patch to 0, like `1.4.9` to `2.0.0`.
+    ///
+    /// ## Panics
+    /// Panics if the major is already `u64::MAX`.
+    #[inline]
+    pub const fn bump_major(self) -> Version {
+        self.apply_bump(BumpKind::Major)
+    }
+
+    /// Increments the minor and resets the patch to 0, like `1.4.9` to `1.5.0`.
+    /// ```
+    /// # use fast_version_core::version::Version;
+    /// const NEXT: Version = Version::new(1, 4, 9).bump_minor();
+    ///
+    /// assert_eq!(NEXT, Version::new(1, 5, 0));
+    /// ```
+    ///
+    /// ## Panics
+    /// Panics if the minor is already `u64::MAX`.
+    #[inline]
+    pub const fn bump_minor(self) -> Version {
+        self.apply_bump(BumpKind::Minor)
+    }
+
+    /// Increments the patch, like `1.4.9` to `1.4.10`.
+    ///
+    /// ## Panics
+    /// Panics if the patch is already `u64::MAX`.
+    #[inline]
+    pub const fn bump_patch(self) -> Version {
+        self.apply_bump(BumpKind::Patch)
+    }
+
+    /// [Version::bump_major], or None if the major is already `u64::MAX`.
+    #[inline]
+    pub const fn checked_bump_major(self) -> Option<Version> {
+        checked_bump(self, BumpKind::Major)
+    }
+
+    /// [Version::bump_minor], or None if the minor is already `u64::MAX`.
+    #[inline]
+    pub const fn checked_bump_minor(self) -> Option<Version> {
+        checked_bump(self, BumpKind::Minor)
+    }
+
+    /// [Version::bump_patch], or None if the patch is already `u64::MAX`.
+    #[inline]
+    pub const fn checked_bump_patch(self) -> Option<Version> {
+        checked_bump(self, BumpKind::Patch)
+    }
+
+    /// [Version::bump_major], [Version::MAX] if the major is already `u64::MAX`.
+    #[inline]
+    pub const fn saturating_bump_major(self) -> Version {
+        self.saturating_apply_bump(BumpKind::Major)
+    }
+
+    /// [Version::bump_minor], the last version of the major if the minor is already `u64::MAX`.
+    #[inline]
+    pub const fn saturating_bump_minor(self) -> Version {
+        self.saturating_apply_bump(BumpKind::Minor)
+    }
+
+    /// [Version::bump_patch], the version itself if the patch is already `u64::MAX`.
+    #[inline]
+    pub const fn saturating_bump_patch(self) -> Version {
+        self.saturating_apply_bump(BumpKind::Patch)
+    }
+
+    /// The kind of bump that turns `old` into `new`, or None if `new` isn't exactly one clean
+    /// bump of `old`.
+    ///
+    /// Skipping a release (`1.2.3` to `1.4.0`), not resetting the lower components (`1.2.3` to
+    /// `1.3.3`), going backwards and staying the same are all rejected.
+    /// ```
+    /// # use fast_version_core::{bump::BumpKind, version::Version};
+    /// let old = Version::new(1, 2, 3);
+    ///
+    /// assert_eq!(Version::bumped_between(old, Version::new(1, 3, 0)), Some(BumpKind::Minor));
+    /// assert_eq!(Version::bumped_between(old, Version::new(1, 4, 0)), None);
+    /// ```
+    pub const fn bumped_between(old: Version, new: Version) -> Option<BumpKind> {
+        let kinds = [BumpKind::Patch, BumpKind::Minor, BumpKind::Major];
+        let mut index = 0;
+        while index < kinds.len() {
+            if let Some(bumped) = checked_bump(old, kinds[index]) {
+                if bumped.major == new.major
+                    && bumped.minor == new.minor
+                    && bumped.patch == new.patch
+                {
+                    return Some(kinds[index]);
+                }
+            }
+            index += 1;
+        }
+        None
+    }
+}
+
+const fn checked_bump(version: Version, kind: BumpKind) -> Option<Version> {
+    match kind {
+        BumpKind::Major => match version.major.checked_add(1) {
+            Some(major) => Some(Version::new(major, 0, 0)),
+            None => None,
+        },
+        BumpKind::Minor => match version.minor.checked_add(1) {
+            Some(minor) => Some(Version::new(version.major, minor, 0)),
+            None => None,
+        },
+        BumpKind::Patch => match version.patch.checked_add(1) {
+            Some(patch) => Some(Version::new(version.major, version.minor, patch)),
+            None => None,
+        },
+    }
+}
@@ -0,0 +1,96 @@
+//! Release channels like alpha, beta and rc, derived from the prerelease identifier.
+
+use crate::full_version::FullVersion;
+use crate::version_req::VersionReq;
+use core::fmt;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The release channel of a version, ordered from least to most stable.
+///
+/// | prerelease starts with | channel             |
+/// |------------------------|---------------------|
+/// | nothing                | [Channel::Stable]   |
+/// | `rc`                   | [Channel::Rc]       |
+/// | `beta`                 | [Channel::Beta]     |
+/// | `alpha`                | [Channel::Alpha]    |
+/// | `nightly`              | [Channel::Nightly]  |
+/// | anything else          | [Channel::Other]    |
+///
+/// The prefixes are matched case-insensitively against the first dot separated identifier, so
+/// `RC1`, `rc.2` and `beta-3` are recognized as well.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Channel {
+    /// An unknown prerelease tag like `dev` or `snapshot`. Treated as the least stable channel,
+    /// so it never passes a gate meant for a named one.
+    Other,
+    Nightly,
+    Alpha,
+    Beta,
+    Rc,
+    /// No prerelease at all.
+    Stable,
+}
+
+impl Channel {
+    /// The channel of a prerelease identifier like `beta.2`, [Channel::Stable] if it's empty.
+    /// ```
+    /// # use fast_version_core::channel::Channel;
+    /// assert_eq!(Channel::from_prerelease("rc.1"), Channel::Rc);
+    /// assert_eq!(Channel::from_prerelease("Alpha3"), Channel::Alpha);
+    /// assert_eq!(Channel::from_prerelease("SNAPSHOT"), Channel::Other);
+    /// assert_eq!(Channel::from_prerelease(""), Channel::Stable);
+    /// ```
+    pub fn from_prerelease(prerelease: &str) -> Channel {
+        if prerelease.is_empty() {
+            return Channel::Stable;
+        }
+        let first = prerelease.split('.').next().unwrap_or_default();
+        [
+            ("nightly", Channel::Nightly),
+            ("alpha", Channel::Alpha),
+            ("beta", Channel::Beta),
+            ("rc", Channel::Rc),
+        ]
+        .into_iter()
+        .find(|(prefix, _)| {
+            first
+                .get(..prefix.len())
+                .is_some_and(|start| start.eq_ignore_ascii_case(prefix))
+        })
+        .map_or(Channel::Other, |(_, channel)| channel)
+    }
+}
+
+impl fmt::Display for Channel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Channel::Other => "other",
+            Channel::Nightly => "nightly",
+            Channel::Alpha => "alpha",
+            Channel::Beta => "beta",
+            Channel::Rc => "rc",
+            Channel::Stable => "stable",
+        })
+    }
+}
+
+impl VersionReq {
+    /// Checks wether the version matches with [VersionReq::matches_full] and its
+    /// [channel](FullVersion::channel) is at least as stable as `allowed`, so [Channel::Beta]
+    /// accepts beta, rc and stable versions.
+    /// ```
+    /// # use fast_version_core::{channel::Channel, full_version::FullVersion, version_req::VersionReq};
+    /// let req = VersionReq::parse(">=1.2.0-alpha").unwrap();
+    /// let beta: FullVersion = "1.2.0-beta.1".parse().unwrap();
+    /// let alpha: FullVersion = "1.2.0-alpha.3".parse().unwrap();
+    ///
+    /// assert!(req.matches_in_channel(&beta, Channel::Beta));
+    /// assert!(!req.matches_in_channel(&alpha, Channel::Beta));
+    /// assert!(req.matches_in_channel(&alpha, Channel::Alpha));
+    /// ```
+    pub fn matches_in_channel(&self, version: &FullVersion, allowed: Channel) -> bool {
+        version.channel() >= allowed && self.matches_full(version)
+    }
+}
@@ -0,0 +1,75 @@
+//! Querying ordered collections of versions with a requirement, behind the `alloc` feature.
+//!
+//! Built on the [RangeBounds](core::ops::RangeBounds) implementation of [VersionReq], so every
+//! query walks only the matching part of the tree.
+//! ```
+//! # use fast_version_core::{collections::VersionCollectionExt, version::Version, version_req::VersionReq};
+//! use std::collections::BTreeMap;
+//!
+//! let mut cache = BTreeMap::new();
+//! cache.insert(Version::new(1, 2, 0), "sha-a");
+//! cache.insert(Version::new(1, 9, 1), "sha-b");
+//! cache.insert(Version::new(2, 0, 0), "sha-c");
+//!
+//! let req = VersionReq::parse("^1").unwrap();
+//! assert_eq!(cache.latest_matching(&req), Some((&Version::new(1, 9, 1), &"sha-b")));
+//! assert_eq!(cache.count_matching(&req), 2);
+//! ```
+
+use crate::version::Version;
+use crate::version_req::VersionReq;
+use alloc::collections::{btree_map, btree_set, BTreeMap, BTreeSet};
+
+/// Requirement queries for [BTreeSet]s and [BTreeMap]s keyed by [Version].
+pub trait VersionCollectionExt {
+    /// A matching entry, `&Version` for sets and `(&Version, &V)` for maps.
+    type Item<'a>
+    where
+        Self: 'a;
+
+    /// Iterator over the matching entries.
+    type Matching<'a>: DoubleEndedIterator<Item = Self::Item<'a>>
+    where
+        Self: 'a;
+
+    /// The matching entries in ascending order.
+    fn matching(&self, req: &VersionReq) -> Self::Matching<'_>;
+
+    /// The entry with the largest matching version, found in `O(log n)`.
+    #[inline]
+    fn latest_matching(&self, req: &VersionReq) -> Option<Self::Item<'_>> {
+        self.matching(req).next_back()
+    }
+
+    /// The number of matching entries.
+    #[inline]
+    fn count_matching(&self, req: &VersionReq) -> usize {
+        self.matching(req).count()
+    }
+}
+
+impl VersionCollectionExt for BTreeSet<Version> {
+    type Item<'a> = &'a Version;
+    type Matching<'a> = btree_set::Range<'a, Version>;
+
+    #[inline]
+    fn matching(&self, req: &VersionReq) -> Self::Matching<'_> {
+        self.range(*req)
+    }
+}
+
+impl<V> VersionCollectionExt for BTreeMap<Version, V> {
+    type Item<'a>
+        = (&'a Version, &'a V)
+    where
+        V: 'a;
+    type Matching<'a>
+        = btree_map::Range<'a, Version, V>
+    where
+        V: 'a;
+
+    #[inline]
+    fn matching(&self, req: &VersionReq) -> Self::Matching<'_> {
+        self.range(*req)
+    }
+}
@@ -0,0 +1,107 @@
+//! Signed per-component differences between versions, for recording and replaying upgrade steps.
+
+use crate::version::Version;
+use core::fmt;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The difference `to - from` of every component, see [Version::delta].
+///
+/// Unlike [VersionDiff](crate::bump::VersionDiff) it keeps the amounts, so a step can be
+/// replayed with [VersionDelta::apply] and reversed with [VersionDelta::invert]. Displayed with
+/// explicit signs, like `+1.-2.+0`.
+/// ```
+/// # use fast_version_core::version::Version;
+/// let from = Version::new(1, 4, 2);
+/// let to = Version::new(2, 0, 0);
+/// let delta = Version::delta(from, to);
+///
+/// assert_eq!(delta.to_string(), "+1.-4.-2");
+/// assert_eq!(delta.apply(from), Some(to));
+/// assert_eq!(delta.invert().apply(to), Some(from));
+/// ```
+#[derive(PartialEq, Eq, Hash, Debug, Default, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VersionDelta {
+    pub major: i128,
+    pub minor: i128,
+    pub patch: i128,
+}
+
+impl VersionDelta {
+    /// The delta that changes nothing.
+    pub const ZERO: Self = Self::new(0, 0, 0);
+
+    /// Create a new delta from the differences of the components.
+    #[inline]
+    pub const fn new(major: i128, minor: i128, patch: i128) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// Adds the delta to every component of `base`, None if a component would leave the range of
+    /// `u64`.
+    /// ```
+    /// # use fast_version_core::{delta::VersionDelta, version::Version};
+    /// let delta = VersionDelta::new(0, -1, 0);
+    ///
+    /// assert_eq!(delta.apply(Version::new(1, 2, 3)), Some(Version::new(1, 1, 3)));
+    /// assert_eq!(delta.apply(Version::new(1, 0, 3)), None);
+    /// ```
+    pub const fn apply(&self, base: Version) -> Option<Version> {
+        match (
+            apply_component(base.major, self.major),
+            apply_component(base.minor, self.minor),
+            apply_component(base.patch, self.patch),
+        ) {
+            (Some(major), Some(minor), Some(patch)) => Some(Version::new(major, minor, patch)),
+            _ => None,
+        }
+    }
+
+    /// The delta undoing this one. A component of `i128::MIN`, which no [Version::delta] returns
+    /// and no [VersionDelta::apply] accepts, becomes `i128::MAX`.
+    #[inline]
+    pub const fn invert(self) -> Self {
+        Self::new(
+            self.major.saturating_neg(),
+            self.minor.saturating_neg(),
+            self.patch.saturating_neg(),
+        )
+    }
+
+    /// Returns true if the delta changes nothing.
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.major == 0 && self.minor == 0 && self.patch == 0
+    }
+}
+
+impl Version {
+    /// The signed difference of every component from `from` to `to`, which
+    /// [VersionDelta::apply] turns `from` back into `to` with.
+    pub const fn delta(from: Version, to: Version) -> VersionDelta {
+        VersionDelta::new(
+            to.major as i128 - from.major as i128,
+            to.minor as i128 - from.minor as i128,
+            to.patch as i128 - from.patch as i128,
+        )
+    }
+}
+
+const fn apply_component(component: u64, delta: i128) -> Option<u64> {
+    match (component as i128).checked_add(delta) {
+        Some(sum) if sum >= 0 && sum <= u64::MAX as i128 => Some(sum as u64),
+        _ => None,
+    }
+}
+
+/// Every component with its sign, like `+1.-2.+0`.
+impl fmt::Display for VersionDelta {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:+}.{:+}.{:+}", self.major, self.minor, self.patch)
+    }
+}
@@ -0,0 +1,86 @@
+//! Classification of docker image tags into version tags and named tags.
+//!
+//! ```
+//! # use fast_version_core::docker::{classify_tag, TagKind};
+//! # use fast_version_core::version::Version;
+//! assert_eq!(
+//!     classify_tag("1.25.3-alpine"),
+//!     TagKind::Version {
+//!         version: Version::new(1, 25, 3),
+//!         suffix: Some("alpine")
+//!     }
+//! );
+//! assert_eq!(classify_tag("latest"), TagKind::Named("latest"));
+//! ```
+
+use crate::matcher::VersionMatcher;
+use crate::version::{parse_short_version, Version};
+
+/// Kind of a docker image tag, as returned by [classify_tag].
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone)]
+pub enum TagKind<'a> {
+    /// Tag starting with a version, like `1.2.3`, `v1.2` or `1.2.3-alpine`.
+    Version {
+        version: Version,
+        /// Variant suffix after the first `-`, like `alpine` or `slim-bookworm`.
+        suffix: Option<&'a str>,
+    },
+    /// Any other tag, like `latest`, `stable` or `bullseye`.
+    Named(&'a str),
+}
+
+/// Classifies a docker image tag.
+///
+/// A tag is a version tag if it consists of an optional `v` followed by one to three dot separated
+/// numbers and an optional non empty suffix separated by `-`. Missing minor and patch components
+/// are filled with 0, so `v1.2` becomes `1.2.0`. Everything else is a named tag.
+pub fn classify_tag(tag: &str) -> TagKind<'_> {
+    let without_prefix = tag.strip_prefix('v').unwrap_or(tag);
+    let (version_str, suffix) = match without_prefix.split_once('-') {
+        Some((_, "")) => return TagKind::Named(tag),
+        Some((version_str, suffix)) => (version_str, Some(suffix)),
+        None => (without_prefix, None),
+    };
+    match parse_short_version(version_str) {
+        Some(version) => TagKind::Version { version, suffix },
+        None => TagKind::Named(tag),
+    }
+}
+
+/// Picks the tag with the newest version accepted by the matcher.
+///
+/// Named tags are ignored. If several tags carry the same version, a tag without suffix is
+/// preferred over one with suffix, otherwise the first one wins.
+/// ```
+/// # use fast_version_core::docker::best_version_tag;
+/// # use fast_version_core::version_req::VersionReq;
+/// let tags = ["latest", "1.24.0", "1.25.3-alpine", "1.25.3", "stable"];
+///
+/// assert_eq!(best_version_tag(&tags, &VersionReq::STAR), Some("1.25.3"));
+/// ```
+pub fn best_version_tag<'a, M: VersionMatcher + ?Sized>(
+    tags: &[&'a str],
+    matcher: &M,
+) -> Option<&'a str> {
+    let mut best: Option<(Version, bool, &'a str)> = None;
+    for tag in tags {
+        let (version, has_suffix) = match classify_tag(tag) {
+            TagKind::Version { version, suffix } => (version, suffix.is_some()),
+            TagKind::Named(_) => continue,
+        };
+        if !matcher.matches(&version) {
+            continue;
+        }
+        let better = match best {
+            None => true,
+            Some((best_version, best_has_suffix, _)) => {
+                version > best_version
+                    || (version == best_version && best_has_suffix && !has_suffix)
+            }
+        };
+        if better {
+            best = Some((version, has_suffix, tag));
+        }
+    }
+    best.map(|(_, _, tag)| tag)
+}
@@ -0,0 +1,227 @@
+use crate::version::Version;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
+use thiserror::Error;
+
+const INLINE_CAPACITY: usize = 5;
+
+/// Version with an arbitrary number of numeric components, like `2.3.4.5.6` or `1.2`.
+///
+/// Up to five components are stored inline, longer versions need the `alloc` feature.
+///
+/// Missing trailing components are treated as 0 when comparing, so `1.2 == 1.2.0`. The
+/// components are displayed as they were given though.
+///
+/// ## Example:
+/// ```
+/// # use fast_version_core::dyn_version::DynVersion;
+/// use core::str::FromStr;
+///
+/// let short = DynVersion::from_str("1.2").unwrap();
+/// let long = DynVersion::from_str("1.2.0.0.1").unwrap();
+///
+/// assert_eq!(short, DynVersion::from_str("1.2.0").unwrap());
+/// assert!(short < long);
+/// assert_eq!(short.to_string(), "1.2");
+/// ```
+#[derive(Debug, Clone)]
+pub struct DynVersion {
+    storage: Storage,
+}
+
+#[derive(Debug, Clone)]
+enum Storage {
+    Inline {
+        len: u8,
+        components: [u64; INLINE_CAPACITY],
+    },
+    #[cfg(feature = "alloc")]
+    Heap(Vec<u64>),
+}
+
+impl DynVersion {
+    /// Create a new version from its components.
+    ///
+    /// ## Panics
+    /// Panics if there are more than five components and the `alloc` feature is disabled.
+    pub fn new(components: &[u64]) -> Self {
+        Self::try_new(components).expect("too many components without the alloc feature")
+    }
+
+    fn try_new(input: &[u64]) -> Option<Self> {
+        if input.len() <= INLINE_CAPACITY {
+            let mut components = [0; INLINE_CAPACITY];
+            components[..input.len()].copy_from_slice(input);
+            let len = input.len() as u8;
+            return Some(Self {
+                storage: Storage::Inline { len, components },
+            });
+        }
+        #[cfg(feature = "alloc")]
+        return Some(Self {
+            storage: Storage::Heap(input.to_vec()),
+        });
+        #[cfg(not(feature = "alloc"))]
+        None
+    }
+
+    pub fn new_from_str(input: &str) -> Result<Self, DynVersionParseError> {
+        let mut components = [0; INLINE_CAPACITY];
+        #[cfg(feature = "alloc")]
+        let mut overflow = Vec::new();
+        let mut len = 0;
+        for (index, split) in input.split('.').enumerate() {
+            let component = u64::from_str(split)
+                .map_err(|_| DynVersionParseError::ComponentParseError { index })?;
+            len = index + 1;
+            if index < INLINE_CAPACITY {
+                components[index] = component;
+                continue;
+            }
+            #[cfg(feature = "alloc")]
+            {
+                if overflow.is_empty() {
+                    overflow.extend_from_slice(&components);
+                }
+                overflow.push(component);
+            }
+            #[cfg(not(feature = "alloc"))]
+            return Err(DynVersionParseError::TooManyComponents);
+        }
+        #[cfg(feature = "alloc")]
+        if !overflow.is_empty() {
+            return Ok(Self {
+                storage: Storage::Heap(overflow),
+            });
+        }
+        Ok(Self {
+            storage: Storage::Inline {
+                len: len as u8,
+                components,
+            },
+        })
+    }
+
+    /// The components as they were given.
+    pub fn components(&self) -> &[u64] {
+        match &self.storage {
+            Storage::Inline { len, components } => &components[..*len as usize],
+            #[cfg(feature = "alloc")]
+            Storage::Heap(components) => components,
+        }
+    }
+
+    /// Number of components as they were given.
+    pub fn len(&self) -> usize {
+        self.components().len()
+    }
+
+    /// Whether there are no components, which only happens for a version created from an empty
+    /// slice.
+    pub fn is_empty(&self) -> bool {
+        self.components().is_empty()
+    }
+
+    /// The component at the given index, or 0 if there are less components.
+    pub fn get(&self, index: usize) -> u64 {
+        self.components().get(index).copied().unwrap_or(0)
+    }
+
+    /// The components without trailing zeros, which is what equality and hashing are based on.
+    fn significant_components(&self) -> &[u64] {
+        let components = self.components();
+        let len = components
+            .iter()
+            .rposition(|component| *component != 0)
+            .map_or(0, |index| index + 1);
+        &components[..len]
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum DynVersionParseError {
+    #[error("Parse error in component {index}")]
+    ComponentParseError { index: usize },
+    #[error("More than five components require the alloc feature")]
+    TooManyComponents,
+}
+
+/// Returned when converting a [DynVersion] with more than three components into a [Version].
+#[derive(Error, Debug)]
+#[error("Version has {found} components, at most 3 are supported")]
+pub struct TooManyComponents {
+    pub found: usize,
+}
+
+impl PartialEq for DynVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.significant_components() == other.significant_components()
+    }
+}
+
+impl Eq for DynVersion {}
+
+impl Hash for DynVersion {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.significant_components().hash(state);
+    }
+}
+
+impl PartialOrd for DynVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DynVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let len = self.len().max(other.len());
+        (0..len)
+            .map(|index| self.get(index).cmp(&other.get(index)))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+impl FromStr for DynVersion {
+    type Err = DynVersionParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new_from_str(s)
+    }
+}
+
+impl fmt::Display for DynVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut components = self.components().iter();
+        if let Some(first) = components.next() {
+            write!(f, "{}", first)?;
+        }
+        for component in components {
+            write!(f, ".{}", component)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<Version> for DynVersion {
+    fn from(version: Version) -> Self {
+        Self::new(&[version.major, version.minor, version.patch])
+    }
+}
+
+/// Missing minor and patch components are filled with 0.
+impl TryFrom<DynVersion> for Version {
+    type Error = TooManyComponents;
+    fn try_from(version: DynVersion) -> Result<Self, Self::Error> {
+        if version.len() > 3 {
+            return Err(TooManyComponents {
+                found: version.len(),
+            });
+        }
+        Ok(Version::new(version.get(0), version.get(1), version.get(2)))
+    }
+}
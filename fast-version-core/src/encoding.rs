@@ -0,0 +1,260 @@
+//! A binary encoding of [VersionReq] that stays readable when the struct changes.
+//!
+//! Every encoding starts with a byte naming its format, followed by the inclusive bounds, lower
+//! major to upper patch. The empty requirement is stored as [VersionReq::EMPTY].
+//!
+//! | format | byte | bounds                                          |
+//! |--------|------|-------------------------------------------------|
+//! | 1      | `01` | six `u64`, little endian, 48 bytes              |
+//! | 2      | `02` | six unsigned LEB128 varints, 1 to 10 bytes each |
+//! | 3      | `03` | like `02`, then the bounds on full versions     |
+//!
+//! [VersionReq::encode] writes format 2, or format 3 for a requirement that
+//! [matches prereleases](VersionReq#prereleases). [VersionReq::decode] reads all of them. The
+//! bounds of format 1 are what bincode wrote for the struct before there was an encoding, so
+//! such bytes are read with [VersionReq::decode_legacy].
+//!
+//! Format 3 appends the lower and the upper bound on full versions. Each starts with a byte,
+//! `00` if there is no bound, `01` for an exclusive and `02` for an inclusive one, which is
+//! followed by the three components as varints, the length of the prerelease in one byte and its
+//! ASCII.
+//! ```
+//! # use fast_version_core::version_req::VersionReq;
+//! let req = VersionReq::parse("^1.2").unwrap();
+//! let mut bytes = Vec::new();
+//! req.encode(&mut bytes);
+//!
+//! assert_eq!(VersionReq::decode(&bytes), Ok((req, bytes.len())));
+//! ```
+
+use crate::full_version::Prerelease;
+use crate::version::Version;
+use crate::version_req::{FullBound, PrereleaseBounds, VersionReq};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use thiserror::Error;
+
+/// Format byte of six little endian `u64`.
+pub const FORMAT_FIXED: u8 = 1;
+/// Format byte of six LEB128 varints, written by [VersionReq::encode].
+pub const FORMAT_VARINT: u8 = 2;
+/// Format byte of six LEB128 varints followed by the bounds on full versions, written by
+/// [VersionReq::encode] for requirements matching prereleases.
+pub const FORMAT_PRERELEASE: u8 = 3;
+
+const FIXED_LEN: usize = 6 * 8;
+/// The longest varint, of a `u64`.
+const VARINT_MAX_LEN: usize = 10;
+/// The longest encoding, format 3 with both bounds on full versions naming the longest
+/// prerelease.
+pub const MAX_LEN: usize =
+    1 + 6 * VARINT_MAX_LEN + 2 * (1 + 3 * VARINT_MAX_LEN + 1 + Prerelease::CAPACITY);
+
+/// Returned for bytes that aren't an encoded [VersionReq].
+#[derive(Error, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum DecodeError {
+    #[error("The encoding ended early")]
+    UnexpectedEnd,
+    #[error("Unknown format {0}")]
+    UnknownFormat(u8),
+    #[error("A varint at byte {position} doesn't fit into a u64")]
+    VarintOverflow { position: usize },
+    #[error("Invalid bound on full versions at byte {position}")]
+    InvalidPrerelease { position: usize },
+}
+
+impl VersionReq {
+    /// Appends the requirement in the newest format, currently [FORMAT_VARINT], or
+    /// [FORMAT_PRERELEASE] if it matches prereleases.
+    #[cfg(feature = "alloc")]
+    pub fn encode(&self, out: &mut Vec<u8>) {
+        let mut buffer = [0; MAX_LEN];
+        let len = self.encode_into(&mut buffer);
+        out.extend_from_slice(&buffer[..len]);
+    }
+
+    /// Writes the encoding of [encode](VersionReq::encode) to the start of `buffer`, returning its
+    /// length. Doesn't need `alloc`.
+    /// ```
+    /// # use fast_version_core::encoding::MAX_LEN;
+    /// # use fast_version_core::version_req::VersionReq;
+    /// let req = VersionReq::parse(">=1.0.0-rc.1, <2").unwrap();
+    /// let mut buffer = [0; MAX_LEN];
+    /// let len = req.encode_into(&mut buffer);
+    ///
+    /// assert_eq!(VersionReq::decode(&buffer[..len]), Ok((req, len)));
+    /// ```
+    pub fn encode_into(&self, buffer: &mut [u8; MAX_LEN]) -> usize {
+        let mut out = Writer { buffer, len: 0 };
+        out.push(match self.pre {
+            Some(_) => FORMAT_PRERELEASE,
+            None => FORMAT_VARINT,
+        });
+        for component in self.lower.into_iter().chain(self.upper) {
+            out.varint(component);
+        }
+        if let Some(bounds) = &self.pre {
+            for bound in [bounds.lower, bounds.upper] {
+                let Some(bound) = bound else {
+                    out.push(0);
+                    continue;
+                };
+                out.push(1 + bound.inclusive as u8);
+                for component in bound.version {
+                    out.varint(component);
+                }
+                let pre = bound.pre.as_bytes();
+                out.push(pre.len() as u8);
+                out.extend(pre);
+            }
+        }
+        out.len
+    }
+
+    /// Reads a requirement in any format from the start of `bytes`, returning it with the number
+    /// of bytes read.
+    /// ```
+    /// # use fast_version_core::{encoding::DecodeError, version_req::VersionReq};
+    /// // `>=1.2.0, <=1.2.18446744073709551615` followed by unrelated data.
+    /// let bytes = [2, 1, 2, 0, 1, 2, 255, 255, 255, 255, 255, 255, 255, 255, 255, 1, 42];
+    /// let req = VersionReq::parse(">=1.2, <1.3").unwrap();
+    ///
+    /// assert_eq!(VersionReq::decode(&bytes), Ok((req, 16)));
+    ///
+    /// assert_eq!(VersionReq::decode(&[9]), Err(DecodeError::UnknownFormat(9)));
+    /// ```
+    pub fn decode(bytes: &[u8]) -> Result<(VersionReq, usize), DecodeError> {
+        let (&format, rest) = bytes.split_first().ok_or(DecodeError::UnexpectedEnd)?;
+        let (req, len) = match format {
+            FORMAT_FIXED => Self::decode_legacy(rest)?,
+            FORMAT_VARINT => decode_varints(rest)?,
+            FORMAT_PRERELEASE => decode_prerelease(rest)?,
+            format => return Err(DecodeError::UnknownFormat(format)),
+        };
+        Ok((req, len + 1))
+    }
+
+    /// Reads the bounds of [FORMAT_FIXED] without the format byte, as bincode wrote them for the
+    /// struct with its fields `major_lower` to `patch_higher`.
+    pub fn decode_legacy(bytes: &[u8]) -> Result<(VersionReq, usize), DecodeError> {
+        let bytes = bytes.get(..FIXED_LEN).ok_or(DecodeError::UnexpectedEnd)?;
+        let mut bounds = [0; 6];
+        for (bound, chunk) in bounds.iter_mut().zip(bytes.chunks_exact(8)) {
+            let mut le_bytes = [0; 8];
+            le_bytes.copy_from_slice(chunk);
+            *bound = u64::from_le_bytes(le_bytes);
+        }
+        Ok((from_bounds(bounds), FIXED_LEN))
+    }
+}
+
+/// Appends to a buffer that fits every encoding.
+struct Writer<'a> {
+    buffer: &'a mut [u8; MAX_LEN],
+    len: usize,
+}
+
+impl Writer<'_> {
+    fn push(&mut self, byte: u8) {
+        self.buffer[self.len] = byte;
+        self.len += 1;
+    }
+
+    fn extend(&mut self, bytes: &[u8]) {
+        self.buffer[self.len..self.len + bytes.len()].copy_from_slice(bytes);
+        self.len += bytes.len();
+    }
+
+    fn varint(&mut self, mut value: u64) {
+        while value >= 0x80 {
+            self.push(value as u8 | 0x80);
+            value >>= 7;
+        }
+        self.push(value as u8);
+    }
+}
+
+fn decode_varints(bytes: &[u8]) -> Result<(VersionReq, usize), DecodeError> {
+    let mut bounds = [0; 6];
+    let mut position = 0;
+    for bound in &mut bounds {
+        *bound = decode_varint(bytes, &mut position)?;
+    }
+    Ok((from_bounds(bounds), position))
+}
+
+/// Reads the varint at `position` and moves past it.
+fn decode_varint(bytes: &[u8], position: &mut usize) -> Result<u64, DecodeError> {
+    let start = *position;
+    let mut value = 0;
+    let mut shift = 0;
+    loop {
+        let byte = *bytes.get(*position).ok_or(DecodeError::UnexpectedEnd)?;
+        *position += 1;
+        let bits = u64::from(byte & 0x7f);
+        if (shift == 63 && bits > 1) || shift > 63 {
+            // The format byte comes before `bytes`.
+            return Err(DecodeError::VarintOverflow {
+                position: start + 1,
+            });
+        }
+        value |= bits << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+        shift += 7;
+    }
+}
+
+fn decode_prerelease(bytes: &[u8]) -> Result<(VersionReq, usize), DecodeError> {
+    let (req, mut position) = decode_varints(bytes)?;
+    let lower = decode_bound(bytes, &mut position)?;
+    let upper = decode_bound(bytes, &mut position)?;
+    Ok((
+        req.with_prerelease_bounds(PrereleaseBounds { lower, upper }),
+        position,
+    ))
+}
+
+/// Reads a bound on full versions at `position` and moves past it.
+fn decode_bound(bytes: &[u8], position: &mut usize) -> Result<Option<FullBound>, DecodeError> {
+    // The format byte comes before `bytes`.
+    let invalid = DecodeError::InvalidPrerelease {
+        position: *position + 1,
+    };
+    let tag = *bytes.get(*position).ok_or(DecodeError::UnexpectedEnd)?;
+    *position += 1;
+    let inclusive = match tag {
+        0 => return Ok(None),
+        1 => false,
+        2 => true,
+        _ => return Err(invalid),
+    };
+    let major = decode_varint(bytes, position)?;
+    let minor = decode_varint(bytes, position)?;
+    let patch = decode_varint(bytes, position)?;
+    let len = usize::from(*bytes.get(*position).ok_or(DecodeError::UnexpectedEnd)?);
+    *position += 1;
+    let pre = bytes
+        .get(*position..*position + len)
+        .ok_or(DecodeError::UnexpectedEnd)?;
+    *position += len;
+    let pre = core::str::from_utf8(pre)
+        .ok()
+        .and_then(|pre| Prerelease::new(pre).ok())
+        .ok_or(invalid)?;
+    Ok(Some(FullBound {
+        version: Version::new(major, minor, patch),
+        pre,
+        inclusive,
+    }))
+}
+
+fn from_bounds(
+    [major_lower, minor_lower, patch_lower, major_upper, minor_upper, patch_upper]: [u64; 6],
+) -> VersionReq {
+    VersionReq::new_bounded(
+        Some(Version::new(major_lower, minor_lower, patch_lower)),
+        Some(Version::new(major_upper, minor_upper, patch_upper)),
+    )
+}
@@ -0,0 +1,105 @@
+use crate::version::{Version, VersionParseError};
+use crate::version_req::VersionReq;
+use core::fmt;
+use core::str::FromStr;
+use thiserror::Error;
+
+/// Version with an epoch prefix as used by RPM and deb packages (`2:1.4.0`).
+///
+/// The epoch dominates the comparison, so `2:1.0.0` is newer than `1:9.9.9`. A missing epoch is
+/// treated as 0 and a zero epoch is omitted when displaying the version.
+///
+/// ## Example:
+/// ```
+/// # use fast_version_core::{epoch_version::EpochVersion, version::Version};
+/// use core::str::FromStr;
+///
+/// let version = EpochVersion::from_str("2:1.4.0").unwrap();
+/// assert_eq!(version.epoch, 2);
+/// assert_eq!(version.version, Version::new(1, 4, 0));
+///
+/// assert!(version > EpochVersion::from_str("1:9.9.9").unwrap());
+/// assert_eq!(EpochVersion::from_str("1.4.0").unwrap().to_string(), "1.4.0");
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Copy, Clone)]
+pub struct EpochVersion {
+    pub epoch: u32,
+    pub version: Version,
+}
+
+impl EpochVersion {
+    /// Create a new version from an epoch and the version it prefixes.
+    #[inline]
+    pub const fn new(epoch: u32, version: Version) -> Self {
+        EpochVersion { epoch, version }
+    }
+
+    pub fn new_from_str(input: &str) -> Result<Self, EpochVersionParseError> {
+        let (epoch, version_str) = match input.split_once(':') {
+            Some((epoch_str, version_str)) => {
+                let epoch = u32::from_str(epoch_str)
+                    .map_err(|_| EpochVersionParseError::EpochParseError)?;
+                (epoch, version_str)
+            }
+            None => (0, input),
+        };
+        let version = Version::new_from_str(version_str)?;
+        Ok(Self::new(epoch, version))
+    }
+
+    /// Checks the version against a requirement that lives in epoch 0.
+    ///
+    /// Equivalent to [matches_in_epoch](EpochVersion::matches_in_epoch) with an epoch of 0.
+    #[inline]
+    pub const fn matches(&self, req: &VersionReq) -> bool {
+        self.matches_in_epoch(0, req)
+    }
+
+    /// Checks the version against a requirement that lives in the given epoch.
+    ///
+    /// Versions of a different epoch never match, regardless of the requirement. Otherwise the
+    /// inner version has to match the requirement.
+    /// ```
+    /// # use fast_version_core::{epoch_version::EpochVersion, version::Version};
+    /// # use fast_version_core::version_req::VersionReq;
+    /// let version = EpochVersion::new(1, Version::new(1, 2, 3));
+    ///
+    /// assert!(version.matches_in_epoch(1, &VersionReq::STAR));
+    /// assert!(!version.matches_in_epoch(0, &VersionReq::STAR));
+    /// assert!(!version.matches(&VersionReq::STAR));
+    /// ```
+    #[inline]
+    pub const fn matches_in_epoch(&self, epoch: u32, req: &VersionReq) -> bool {
+        self.epoch == epoch && req.matches(&self.version)
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum EpochVersionParseError {
+    #[error("Epoch Parse Error")]
+    EpochParseError,
+    #[error(transparent)]
+    VersionParseError(#[from] VersionParseError),
+}
+
+impl FromStr for EpochVersion {
+    type Err = EpochVersionParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new_from_str(s)
+    }
+}
+
+impl fmt::Display for EpochVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.epoch != 0 {
+            write!(f, "{}:", self.epoch)?;
+        }
+        write!(f, "{}", self.version)
+    }
+}
+
+impl From<Version> for EpochVersion {
+    fn from(version: Version) -> Self {
+        Self::new(0, version)
+    }
+}
@@ -0,0 +1,73 @@
+//! Why a version doesn't match a requirement, for hints like "upgrade to at least 2.0.0".
+
+use crate::delta::VersionDelta;
+use crate::version::Version;
+use crate::version_req::{successor, version_le, VersionReq};
+use core::fmt;
+use thiserror::Error;
+
+/// The bound of a [VersionReq] a version violates, returned by [VersionReq::explain]. Bounds are
+/// inclusive, like [VersionReq::range], and `by` is the [delta](Version::delta) from the version
+/// to the bound, the smallest step that makes it match.
+#[derive(Error, PartialEq, Eq, Hash, Debug, Copy, Clone)]
+pub enum MatchFailure {
+    #[error("The version is too old, upgrade to at least {bound}")]
+    BelowLower { bound: Version, by: VersionDelta },
+    #[error("The version is too new, downgrade to {}", UpperLimit(*bound))]
+    AboveUpper { bound: Version, by: VersionDelta },
+    #[error("The requirement doesn't allow any version")]
+    EmptyRequirement,
+}
+
+/// Formats an inclusive upper bound the way [VersionReq]'s `Display` does, `<2.0.0` instead of
+/// `<=1.18446744073709551615.18446744073709551615`.
+struct UpperLimit(Version);
+
+impl fmt::Display for UpperLimit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match successor(&self.0) {
+            Some(next) if self.0.patch == u64::MAX => write!(f, "a version below {next}"),
+            _ => write!(f, "at most {}", self.0),
+        }
+    }
+}
+
+impl VersionReq {
+    /// Like [VersionReq::matches], but says which bound a version violates.
+    /// ```
+    /// # use fast_version_core::{delta::VersionDelta, explain::MatchFailure, version::Version, version_req::VersionReq};
+    /// let req = VersionReq::parse(">=2.0, <3.0").unwrap();
+    ///
+    /// let failure = req.explain(&Version::new(1, 5, 0)).unwrap_err();
+    /// assert_eq!(
+    ///     failure,
+    ///     MatchFailure::BelowLower {
+    ///         bound: Version::new(2, 0, 0),
+    ///         by: VersionDelta::new(1, -5, 0),
+    ///     }
+    /// );
+    /// assert_eq!(failure.to_string(), "The version is too old, upgrade to at least 2.0.0");
+    ///
+    /// let failure = req.explain(&Version::new(3, 1, 0)).unwrap_err();
+    /// assert_eq!(failure.to_string(), "The version is too new, downgrade to a version below 3.0.0");
+    ///
+    /// assert_eq!(req.explain(&Version::new(2, 4, 0)), Ok(()));
+    /// ```
+    pub const fn explain(&self, version: &Version) -> Result<(), MatchFailure> {
+        if !self.has_releases() {
+            Err(MatchFailure::EmptyRequirement)
+        } else if !version_le(&self.lower, version) {
+            Err(MatchFailure::BelowLower {
+                bound: self.lower,
+                by: Version::delta(*version, self.lower),
+            })
+        } else if !version_le(version, &self.upper) {
+            Err(MatchFailure::AboveUpper {
+                bound: self.upper,
+                by: Version::delta(*version, self.upper),
+            })
+        } else {
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,549 @@
+//! SemVer versions with prerelease and build metadata, like `1.2.3-alpha.1+build.5`.
+//!
+//! [Version] stays three plain numbers, so it can keep its fixed layout, its packed forms and
+//! const comparisons. [FullVersion] adds the identifiers next to it, stored inline with a fixed
+//! capacity so it doesn't need an allocator either.
+//! ```
+//! # use fast_version_core::{channel::Channel, full_version::FullVersion, version::Version};
+//! use core::str::FromStr;
+//!
+//! let version = FullVersion::from_str("1.2.3-beta.2+build.5").unwrap();
+//! assert_eq!(version.version, Version::new(1, 2, 3));
+//! assert_eq!(version.pre.as_str(), "beta.2");
+//! assert_eq!(version.build.as_str(), "build.5");
+//! assert_eq!(version.channel(), Channel::Beta);
+//!
+//! assert!(version < FullVersion::from(Version::new(1, 2, 3)));
+//! ```
+
+use crate::channel::Channel;
+use crate::version::{Version, VersionParseError};
+use crate::version_req::VersionReq;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
+use thiserror::Error;
+
+/// The dot separated identifiers of a prerelease or build metadata, at most
+/// [Prerelease::CAPACITY] bytes of ASCII.
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize))]
+struct Identifiers {
+    bytes: [u8; CAPACITY],
+    len: u8,
+}
+
+const CAPACITY: usize = 32;
+
+impl Identifiers {
+    const EMPTY: Self = Self {
+        bytes: [0; CAPACITY],
+        len: 0,
+    };
+
+    /// Checks the SemVer grammar: non-empty identifiers of ASCII alphanumerics and hyphens.
+    /// Numeric identifiers of a prerelease must not have leading zeros.
+    fn new(input: &str, numeric_leading_zeros: bool) -> Result<Self, IdentifierError> {
+        if input.len() > CAPACITY {
+            return Err(IdentifierError::TooLong { len: input.len() });
+        }
+        let mut start = 0;
+        for identifier in input.split('.') {
+            if identifier.is_empty() {
+                return Err(IdentifierError::EmptyIdentifier { position: start });
+            }
+            if let Some(offset) = identifier
+                .bytes()
+                .position(|byte| !byte.is_ascii_alphanumeric() && byte != b'-')
+            {
+                return Err(IdentifierError::InvalidCharacter {
+                    position: start + offset,
+                });
+            }
+            if !numeric_leading_zeros
+                && identifier.len() > 1
+                && identifier.starts_with('0')
+                && identifier.bytes().all(|byte| byte.is_ascii_digit())
+            {
+                return Err(IdentifierError::LeadingZero { position: start });
+            }
+            start += identifier.len() + 1;
+        }
+        let mut identifiers = Self::EMPTY;
+        identifiers.bytes[..input.len()].copy_from_slice(input.as_bytes());
+        identifiers.len = input.len() as u8;
+        Ok(identifiers)
+    }
+
+    const fn as_bytes(&self) -> &[u8] {
+        self.bytes.split_at(self.len as usize).0
+    }
+
+    fn as_str(&self) -> &str {
+        // Only ASCII has been stored.
+        core::str::from_utf8(self.as_bytes()).unwrap_or_default()
+    }
+}
+
+/// Compares dot separated identifiers by SemVer precedence: numeric identifiers numerically and
+/// below alphanumeric ones, alphanumeric ones in ASCII order, and a prefix below the longer list.
+/// Both lists are non-empty.
+const fn cmp_identifiers(lhs: &[u8], rhs: &[u8]) -> Ordering {
+    let (mut lhs_start, mut rhs_start) = (0, 0);
+    loop {
+        match (lhs_start > lhs.len(), rhs_start > rhs.len()) {
+            (true, true) => return Ordering::Equal,
+            (true, false) => return Ordering::Less,
+            (false, true) => return Ordering::Greater,
+            (false, false) => {}
+        }
+        let lhs_identifier = identifier_at(lhs, lhs_start);
+        let rhs_identifier = identifier_at(rhs, rhs_start);
+        let ordering = match (is_numeric(lhs_identifier), is_numeric(rhs_identifier)) {
+            (true, true) => {
+                // Leading zeros only occur in build metadata, where `01` sorts after `1`.
+                let lhs_digits = trim_zeros(lhs_identifier);
+                let rhs_digits = trim_zeros(rhs_identifier);
+                match cmp_usize(lhs_digits.len(), rhs_digits.len()) {
+                    Ordering::Equal => match cmp_bytes(lhs_digits, rhs_digits) {
+                        Ordering::Equal => cmp_usize(lhs_identifier.len(), rhs_identifier.len()),
+                        ordering => ordering,
+                    },
+                    ordering => ordering,
+                }
+            }
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => cmp_bytes(lhs_identifier, rhs_identifier),
+        };
+        if !matches!(ordering, Ordering::Equal) {
+            return ordering;
+        }
+        lhs_start += lhs_identifier.len() + 1;
+        rhs_start += rhs_identifier.len() + 1;
+    }
+}
+
+/// The identifier starting at `start`, up to the next dot.
+const fn identifier_at(bytes: &[u8], start: usize) -> &[u8] {
+    let rest = bytes.split_at(start).1;
+    let mut len = 0;
+    while len < rest.len() && rest[len] != b'.' {
+        len += 1;
+    }
+    rest.split_at(len).0
+}
+
+const fn is_numeric(identifier: &[u8]) -> bool {
+    let mut index = 0;
+    while index < identifier.len() {
+        if !identifier[index].is_ascii_digit() {
+            return false;
+        }
+        index += 1;
+    }
+    true
+}
+
+const fn trim_zeros(digits: &[u8]) -> &[u8] {
+    let mut start = 0;
+    while start < digits.len() && digits[start] == b'0' {
+        start += 1;
+    }
+    digits.split_at(start).1
+}
+
+/// Const equivalent of [Ord] for byte slices.
+const fn cmp_bytes(lhs: &[u8], rhs: &[u8]) -> Ordering {
+    let mut index = 0;
+    while index < lhs.len() && index < rhs.len() {
+        if lhs[index] != rhs[index] {
+            return if lhs[index] < rhs[index] {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            };
+        }
+        index += 1;
+    }
+    cmp_usize(lhs.len(), rhs.len())
+}
+
+const fn cmp_usize(lhs: usize, rhs: usize) -> Ordering {
+    if lhs < rhs {
+        Ordering::Less
+    } else if lhs > rhs {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    }
+}
+
+/// The prerelease of a [FullVersion], like `alpha.1` in `1.2.3-alpha.1`.
+///
+/// Ordered by SemVer precedence, with the empty prerelease of a release above every other one.
+/// ```
+/// # use fast_version_core::full_version::Prerelease;
+/// let alpha = Prerelease::new("alpha.1").unwrap();
+/// let beta = Prerelease::new("beta").unwrap();
+///
+/// assert!(alpha < beta);
+/// assert!(beta < Prerelease::EMPTY);
+/// assert!(Prerelease::new("alpha.01").is_err());
+/// ```
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize))]
+pub struct Prerelease(Identifiers);
+
+/// The build metadata of a [FullVersion], like `build.5` in `1.2.3+build.5`.
+///
+/// Build metadata doesn't affect precedence, its order only keeps [Ord] consistent with [Eq]:
+/// identifiers are compared like a [Prerelease]'s, and the empty one is the smallest.
+#[derive(Copy, Clone)]
+pub struct BuildMetadata(Identifiers);
+
+/// Returned when a [Prerelease] or [BuildMetadata] doesn't follow SemVer. Positions are byte
+/// offsets into the identifiers.
+#[derive(Error, PartialEq, Eq, Hash, Debug, Copy, Clone)]
+pub enum IdentifierError {
+    #[error(
+        "Identifiers with {len} bytes are longer than the capacity of {}",
+        CAPACITY
+    )]
+    TooLong { len: usize },
+    #[error("Empty identifier at position {position}")]
+    EmptyIdentifier { position: usize },
+    #[error("Invalid character at position {position}, expected ASCII alphanumerics or `-`")]
+    InvalidCharacter { position: usize },
+    #[error("Numeric identifier at position {position} has a leading zero")]
+    LeadingZero { position: usize },
+}
+
+impl Prerelease {
+    /// No prerelease, the one of a release.
+    pub const EMPTY: Self = Self(Identifiers::EMPTY);
+    /// The maximum length in bytes.
+    pub const CAPACITY: usize = CAPACITY;
+
+    /// Validates the identifiers, an empty string is [Prerelease::EMPTY].
+    pub fn new(input: &str) -> Result<Self, IdentifierError> {
+        if input.is_empty() {
+            return Ok(Self::EMPTY);
+        }
+        Identifiers::new(input, false).map(Self)
+    }
+
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.0.len == 0
+    }
+
+    #[inline]
+    pub(crate) const fn as_bytes(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+
+    /// Const equivalent of [Ord::cmp].
+    pub const fn const_cmp(&self, other: &Prerelease) -> Ordering {
+        match (self.is_empty(), other.is_empty()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) => cmp_identifiers(self.0.as_bytes(), other.0.as_bytes()),
+        }
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl ArchivedPrerelease {
+    /// The prerelease in native representation, None if the archive holds an invalid one.
+    pub(crate) fn to_native(&self) -> Option<Prerelease> {
+        let bytes = self.0.bytes.get(..usize::from(self.0.len))?;
+        Prerelease::new(core::str::from_utf8(bytes).ok()?).ok()
+    }
+}
+
+impl BuildMetadata {
+    /// No build metadata.
+    pub const EMPTY: Self = Self(Identifiers::EMPTY);
+    /// The maximum length in bytes.
+    pub const CAPACITY: usize = CAPACITY;
+
+    /// Validates the identifiers, an empty string is [BuildMetadata::EMPTY]. Unlike in a
+    /// prerelease, numeric identifiers may have leading zeros.
+    pub fn new(input: &str) -> Result<Self, IdentifierError> {
+        if input.is_empty() {
+            return Ok(Self::EMPTY);
+        }
+        Identifiers::new(input, true).map(Self)
+    }
+
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.0.len == 0
+    }
+}
+
+impl PartialEq for Prerelease {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for Prerelease {}
+
+impl Hash for Prerelease {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl PartialOrd for Prerelease {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Prerelease {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.const_cmp(other)
+    }
+}
+
+impl PartialEq for BuildMetadata {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for BuildMetadata {}
+
+impl Hash for BuildMetadata {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl PartialOrd for BuildMetadata {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BuildMetadata {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.is_empty(), other.is_empty()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => cmp_identifiers(self.0.as_bytes(), other.0.as_bytes()),
+        }
+    }
+}
+
+impl Default for Prerelease {
+    fn default() -> Self {
+        Self::EMPTY
+    }
+}
+
+impl Default for BuildMetadata {
+    fn default() -> Self {
+        Self::EMPTY
+    }
+}
+
+impl fmt::Debug for Prerelease {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Prerelease({:?})", self.as_str())
+    }
+}
+
+impl fmt::Debug for BuildMetadata {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "BuildMetadata({:?})", self.as_str())
+    }
+}
+
+impl fmt::Display for Prerelease {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+impl fmt::Display for BuildMetadata {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+/// A [Version] with an optional [Prerelease] and [BuildMetadata].
+///
+/// ## Precedence
+/// A prerelease sorts before its release, so `1.2.3-alpha < 1.2.3-alpha.1 < 1.2.3-beta < 1.2.3`.
+/// Like for [Version], [Ord] and [Eq] consider the full value and only use the build metadata to
+/// tell otherwise equal versions apart, while [FullVersion::cmp_precedence] ignores it.
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone)]
+pub struct FullVersion {
+    pub version: Version,
+    pub pre: Prerelease,
+    pub build: BuildMetadata,
+}
+
+/// Returned when parsing a [FullVersion] fails, naming the part that is invalid.
+#[derive(Error, Debug)]
+pub enum FullVersionParseError {
+    #[error("Invalid version: {0}")]
+    Version(#[source] VersionParseError),
+    #[error("Invalid prerelease: {0}")]
+    Prerelease(#[source] IdentifierError),
+    #[error("Invalid build metadata: {0}")]
+    BuildMetadata(#[source] IdentifierError),
+}
+
+impl FullVersion {
+    #[inline]
+    pub const fn new(version: Version, pre: Prerelease, build: BuildMetadata) -> Self {
+        Self {
+            version,
+            pre,
+            build,
+        }
+    }
+
+    /// Returns true if the version has a prerelease.
+    #[inline]
+    pub const fn is_prerelease(&self) -> bool {
+        !self.pre.is_empty()
+    }
+
+    /// The release channel, taken from the prerelease with [Channel::from_prerelease].
+    pub fn channel(&self) -> Channel {
+        Channel::from_prerelease(self.pre.as_str())
+    }
+
+    /// Compares the versions by precedence, ignoring build metadata.
+    /// ```
+    /// # use fast_version_core::full_version::FullVersion;
+    /// let first: FullVersion = "1.0.0+build.1".parse().unwrap();
+    /// let second: FullVersion = "1.0.0+build.2".parse().unwrap();
+    ///
+    /// assert!(first < second);
+    /// assert!(first.eq_precedence(&second));
+    /// ```
+    pub fn cmp_precedence(&self, other: &FullVersion) -> Ordering {
+        self.version
+            .cmp(&other.version)
+            .then_with(|| self.pre.cmp(&other.pre))
+    }
+
+    /// Checks wether both versions have the same precedence, so they are the same release.
+    #[inline]
+    pub fn eq_precedence(&self, other: &FullVersion) -> bool {
+        self.cmp_precedence(other).is_eq()
+    }
+
+    /// The version without build metadata.
+    #[inline]
+    pub const fn strip_metadata(self) -> FullVersion {
+        FullVersion::new(self.version, self.pre, BuildMetadata::EMPTY)
+    }
+}
+
+impl VersionReq {
+    /// Checks wether the version matches, ignoring build metadata. Like in Cargo a prerelease
+    /// only matches if the requirement names a prerelease of the same `major.minor.patch`, see
+    /// [prereleases](VersionReq#prereleases).
+    /// ```
+    /// # use fast_version_core::{full_version::FullVersion, version_req::VersionReq};
+    /// let req = VersionReq::parse(">=1.0.0").unwrap();
+    ///
+    /// assert!(req.matches_full(&"1.2.0+build.5".parse().unwrap()));
+    /// assert!(!req.matches_full(&"1.2.0-rc.1".parse().unwrap()));
+    ///
+    /// let req = VersionReq::parse(">=1.2.0-rc.1").unwrap();
+    /// assert!(req.matches_full(&"1.2.0-rc.2".parse().unwrap()));
+    /// assert!(!req.matches_full(&"1.3.0-rc.1".parse().unwrap()));
+    /// ```
+    pub const fn matches_full(&self, version: &FullVersion) -> bool {
+        if !version.is_prerelease() {
+            return self.matches(&version.version);
+        }
+        match &self.pre {
+            Some(bounds) => bounds.matches(&version.version, &version.pre),
+            None => false,
+        }
+    }
+}
+
+impl From<Version> for FullVersion {
+    #[inline]
+    fn from(version: Version) -> Self {
+        Self::new(version, Prerelease::EMPTY, BuildMetadata::EMPTY)
+    }
+}
+
+impl PartialOrd for FullVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for FullVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cmp_precedence(other)
+            .then_with(|| self.build.cmp(&other.build))
+    }
+}
+
+/// A `-` or `+` without identifiers after it.
+const EMPTY_AFTER_SEPARATOR: IdentifierError = IdentifierError::EmptyIdentifier { position: 0 };
+
+/// Parses `major.minor.patch`, optionally followed by `-prerelease` and `+build`.
+impl FromStr for FullVersion {
+    type Err = FullVersionParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (rest, build) = match s.split_once('+') {
+            Some((_, "")) => {
+                return Err(FullVersionParseError::BuildMetadata(EMPTY_AFTER_SEPARATOR))
+            }
+            Some((rest, build)) => (rest, build),
+            None => (s, ""),
+        };
+        let (version, pre) = match rest.split_once('-') {
+            Some((_, "")) => return Err(FullVersionParseError::Prerelease(EMPTY_AFTER_SEPARATOR)),
+            Some((version, pre)) => (version, pre),
+            None => (rest, ""),
+        };
+        Ok(Self::new(
+            Version::from_str(version).map_err(FullVersionParseError::Version)?,
+            Prerelease::new(pre).map_err(FullVersionParseError::Prerelease)?,
+            BuildMetadata::new(build).map_err(FullVersionParseError::BuildMetadata)?,
+        ))
+    }
+}
+
+/// Prints `1.2.3-alpha.1+build.5`, leaving out the empty parts.
+impl fmt::Display for FullVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.version)?;
+        if !self.pre.is_empty() {
+            write!(f, "-{}", self.pre)?;
+        }
+        if !self.build.is_empty() {
+            write!(f, "+{}", self.build)?;
+        }
+        Ok(())
+    }
+}
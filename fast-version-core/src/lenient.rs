@@ -0,0 +1,121 @@
+//! Lenient extraction of versions from messy real world strings.
+
+use crate::version::{parse_component, split_digits, ComponentKind, Version, VersionParseError};
+
+/// Version extracted from a messy string by [LenientVersion::parse], together with what had to
+/// be assumed or stripped to get it.
+///
+/// ```
+/// # use fast_version_core::lenient::{LenientInfo, LenientVersion};
+/// # use fast_version_core::version::Version;
+/// let lenient = LenientVersion::parse("v1.2 (build 456)").unwrap();
+///
+/// assert_eq!(lenient.version, Version::new(1, 2, 0));
+/// assert_eq!(
+///     lenient.info,
+///     LenientInfo {
+///         had_prefix: true,
+///         trailing: Some("(build 456)"),
+///         assumed_components: 1,
+///     }
+/// );
+/// ```
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone)]
+pub struct LenientVersion<'a> {
+    /// The normalized version.
+    pub version: Version,
+    pub info: LenientInfo<'a>,
+}
+
+/// What [LenientVersion::parse] stripped or assumed.
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone)]
+pub struct LenientInfo<'a> {
+    /// Wether anything came before the version, like a `v` or a program name.
+    pub had_prefix: bool,
+    /// Everything after the version with surrounding whitespace trimmed, None if that's empty.
+    pub trailing: Option<&'a str>,
+    /// How many of minor and patch were missing and assumed to be 0.
+    pub assumed_components: u8,
+}
+
+impl<'a> LenientVersion<'a> {
+    /// Extracts the first version from the input.
+    ///
+    /// The version starts at the first digit that begins a word, optionally preceded by a `v`, so
+    /// `v1.2.3`, `curl/8.4.0` and `rustc 1.75.0 (82e1608df 2023-12-21)` work, but the `3` in
+    /// `python3` isn't taken as a version. It consists of one to three dot separated numbers,
+    /// missing components are assumed to be 0.
+    ///
+    /// So inputs like `1.2`, which [Version::parse_checked] rejects with
+    /// [VersionParseError::TooFewComponents], are accepted, while a fourth component isn't part
+    /// of the version and ends up in [LenientInfo::trailing].
+    /// ```
+    /// # use fast_version_core::{lenient::LenientVersion, version::Version};
+    /// let lenient = LenientVersion::parse("1.2.3.4").unwrap();
+    ///
+    /// assert_eq!(lenient.version, Version::new(1, 2, 3));
+    /// assert_eq!(lenient.info.trailing, Some(".4"));
+    /// ```
+    ///
+    /// Errors with [VersionParseError::MajorNotFound] if there is no version at all and with
+    /// [VersionParseError::ComponentOverflow] if a component is too large.
+    pub fn parse(input: &'a str) -> Result<Self, VersionParseError> {
+        let start = find_version_start(input).ok_or(VersionParseError::MajorNotFound)?;
+        let had_prefix = !input[..start].trim().is_empty();
+
+        let kinds = [
+            ComponentKind::Major,
+            ComponentKind::Minor,
+            ComponentKind::Patch,
+        ];
+        let mut components = [0; 3];
+        let mut count = 0;
+        let mut rest = &input[start..];
+        for (index, kind) in kinds.into_iter().enumerate() {
+            let candidate = if index == 0 {
+                rest
+            } else {
+                match rest.strip_prefix('.') {
+                    Some(candidate) => candidate,
+                    None => break,
+                }
+            };
+            let (digits, after) = split_digits(candidate);
+            if digits.is_empty() {
+                break;
+            }
+            components[index] = parse_component(digits, kind)?;
+            count = index + 1;
+            rest = after;
+        }
+
+        let trailing = Some(rest.trim()).filter(|trailing| !trailing.is_empty());
+        let [major, minor, patch] = components;
+        Ok(Self {
+            version: Version::new(major, minor, patch),
+            info: LenientInfo {
+                had_prefix,
+                trailing,
+                assumed_components: 3 - count as u8,
+            },
+        })
+    }
+}
+
+impl From<LenientVersion<'_>> for Version {
+    fn from(lenient: LenientVersion<'_>) -> Self {
+        lenient.version
+    }
+}
+
+/// Index of the first digit at the start of a word, a `v` directly in front of it counts as part
+/// of the word.
+fn find_version_start(input: &str) -> Option<usize> {
+    let bytes = input.as_bytes();
+    let starts_word = |index: usize| index == 0 || !bytes[index - 1].is_ascii_alphanumeric();
+    (0..bytes.len()).find(|&index| {
+        bytes[index].is_ascii_digit()
+            && (starts_word(index)
+                || (matches!(bytes[index - 1], b'v' | b'V') && starts_word(index - 1)))
+    })
+}
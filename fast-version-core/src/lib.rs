@@ -2,7 +2,70 @@
 //!
 //! Refer to the [fast-version](https://crates.io/crates/fast-version) for usage and documentation.
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(feature = "nightly", feature(portable_simd))]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impls;
+#[cfg(any(feature = "portable-atomic", target_has_atomic = "64"))]
+pub mod atomic;
+pub mod banner;
+mod batch;
+pub mod bounded;
+pub mod bump;
+pub mod channel;
+#[cfg(feature = "alloc")]
+pub mod collections;
+pub mod delta;
+pub mod docker;
+pub mod dyn_version;
+pub mod encoding;
+pub mod epoch_version;
+pub mod explain;
+pub mod full_version;
+pub mod lenient;
+#[cfg(feature = "alloc")]
+pub mod list_diff;
+pub mod matcher;
+pub mod oneshot;
+pub mod packed;
+#[cfg(feature = "rand")]
+mod random;
+pub mod release_range;
+pub mod release_stage;
+#[cfg(feature = "fancy-errors")]
+mod render;
+#[cfg(feature = "rkyv")]
+mod rkyv_impls;
+#[cfg(feature = "std")]
+pub mod runtime_req;
+#[cfg(feature = "schemars")]
+mod schema_impls;
+#[cfg(feature = "semver-interop")]
+pub mod semver_interop;
+pub mod sequence;
+#[cfg(feature = "serde")]
+pub mod serde_helpers;
+#[cfg(feature = "serde")]
+mod serde_impls;
+pub mod slice;
+#[cfg(feature = "alloc")]
+pub mod spec;
+#[cfg(feature = "proptest")]
+pub mod strategies;
+pub mod support;
+pub mod tags;
+#[cfg(feature = "test-util")]
+pub mod test_util;
+pub mod update;
 pub mod version;
+pub mod version4;
+pub mod version_or_req;
+pub mod version_pattern;
 pub mod version_req;
+#[cfg(feature = "alloc")]
+pub mod version_req_set;
+pub mod version_str;
@@ -0,0 +1,132 @@
+//! Comparing two lists of named versions, like the packages installed before and after a deploy.
+//! Behind the `alloc` feature.
+
+use crate::bump::{BumpKind, VersionDiff};
+use crate::version::Version;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// What happened to a single name between the old and the new list.
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ListChange {
+    /// Only in the new list.
+    Added(Version),
+    /// Only in the old list.
+    Removed(Version),
+    /// `kind` is the most significant component that changed.
+    Upgraded {
+        from: Version,
+        to: Version,
+        kind: BumpKind,
+    },
+    /// `kind` is the most significant component that changed.
+    Downgraded {
+        from: Version,
+        to: Version,
+        kind: BumpKind,
+    },
+    Unchanged(Version),
+}
+
+/// Result of [diff_lists], one entry per name sorted by name.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ListDiff<N> {
+    pub entries: Vec<(N, ListChange)>,
+}
+
+impl<N> ListDiff<N> {
+    /// The entries that aren't [ListChange::Unchanged].
+    pub fn changed(&self) -> impl DoubleEndedIterator<Item = &(N, ListChange)> {
+        self.entries
+            .iter()
+            .filter(|(_, change)| !matches!(change, ListChange::Unchanged(_)))
+    }
+
+    /// Wether every name kept its version.
+    pub fn is_unchanged(&self) -> bool {
+        self.changed().next().is_none()
+    }
+}
+
+impl fmt::Display for ListChange {
+    /// Formats the change like `upgraded 1.2.3 -> 1.3.0 (minor)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ListChange::Added(version) => write!(f, "added {version}"),
+            ListChange::Removed(version) => write!(f, "removed {version}"),
+            ListChange::Upgraded { from, to, kind } => {
+                write!(f, "upgraded {from} -> {to} ({kind})")
+            }
+            ListChange::Downgraded { from, to, kind } => {
+                write!(f, "downgraded {from} -> {to} ({kind})")
+            }
+            ListChange::Unchanged(version) => write!(f, "unchanged {version}"),
+        }
+    }
+}
+
+/// Classifies every name of both lists. If a name appears more than once in a list, its last
+/// version counts.
+/// ```
+/// # use fast_version_core::{bump::BumpKind, list_diff::{diff_lists, ListChange}, version::Version};
+/// let old = [("serde", Version::new(1, 0, 100)), ("rand", Version::new(0, 8, 5))];
+/// let new = [("serde", Version::new(1, 0, 101)), ("csv", Version::new(1, 3, 0))];
+///
+/// let diff = diff_lists(&old, &new);
+/// assert_eq!(
+///     diff.entries,
+///     [
+///         ("csv", ListChange::Added(Version::new(1, 3, 0))),
+///         ("rand", ListChange::Removed(Version::new(0, 8, 5))),
+///         (
+///             "serde",
+///             ListChange::Upgraded {
+///                 from: Version::new(1, 0, 100),
+///                 to: Version::new(1, 0, 101),
+///                 kind: BumpKind::Patch,
+///             }
+///         ),
+///     ]
+/// );
+/// ```
+pub fn diff_lists<N: Ord + Clone>(old: &[(N, Version)], new: &[(N, Version)]) -> ListDiff<N> {
+    let mut merged: BTreeMap<&N, (Option<Version>, Option<Version>)> = BTreeMap::new();
+    for (name, version) in old {
+        merged.entry(name).or_default().0 = Some(*version);
+    }
+    for (name, version) in new {
+        merged.entry(name).or_default().1 = Some(*version);
+    }
+    let entries = merged
+        .into_iter()
+        .map(|(name, versions)| {
+            let change = match versions {
+                (Some(from), Some(to)) => change_between(from, to),
+                (None, Some(to)) => ListChange::Added(to),
+                (Some(from), None) => ListChange::Removed(from),
+                (None, None) => unreachable!("every name comes with a version"),
+            };
+            (name.clone(), change)
+        })
+        .collect();
+    ListDiff { entries }
+}
+
+fn change_between(from: Version, to: Version) -> ListChange {
+    let kind = match from.diff(&to) {
+        VersionDiff::Equal => return ListChange::Unchanged(to),
+        VersionDiff::Patch => BumpKind::Patch,
+        VersionDiff::Minor => BumpKind::Minor,
+        VersionDiff::Major => BumpKind::Major,
+    };
+    if from < to {
+        ListChange::Upgraded { from, to, kind }
+    } else {
+        ListChange::Downgraded { from, to, kind }
+    }
+}
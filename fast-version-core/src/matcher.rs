@@ -0,0 +1,114 @@
+//! Matching versions generically, no matter what decides about the match.
+//!
+//! [VersionMatcher] is implemented by [VersionReq], [VersionReqSet], [VersionPattern] and every
+//! closure taking a `&Version` and returning a `bool`. The helpers in this module accept any of
+//! them.
+//! ```
+//! # use fast_version_core::matcher::max_matching;
+//! # use fast_version_core::version::Version;
+//! # use fast_version_core::version_req::VersionReq;
+//! let versions = [Version::new(1, 2, 0), Version::new(1, 9, 1), Version::new(2, 0, 0)];
+//!
+//! let req = VersionReq::parse("^1").unwrap();
+//! assert_eq!(max_matching(&versions, &req), Some(&Version::new(1, 9, 1)));
+//!
+//! let even_minor = |version: &Version| version.minor % 2 == 0;
+//! assert_eq!(max_matching(&versions, &even_minor), Some(&Version::new(2, 0, 0)));
+//! ```
+
+use crate::version::Version;
+use crate::version_pattern::VersionPattern;
+use crate::version_req::VersionReq;
+#[cfg(feature = "alloc")]
+use crate::version_req_set::VersionReqSet;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Anything that decides wether a version matches.
+pub trait VersionMatcher {
+    /// Checks wether the version matches.
+    fn matches(&self, version: &Version) -> bool;
+}
+
+impl VersionMatcher for VersionReq {
+    #[inline]
+    fn matches(&self, version: &Version) -> bool {
+        VersionReq::matches(self, version)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl VersionMatcher for VersionReqSet {
+    #[inline]
+    fn matches(&self, version: &Version) -> bool {
+        VersionReqSet::matches(self, version)
+    }
+}
+
+impl VersionMatcher for VersionPattern {
+    #[inline]
+    fn matches(&self, version: &Version) -> bool {
+        VersionPattern::matches(self, version)
+    }
+}
+
+/// Closures need their argument annotated, like `|version: &Version| version.major == 1`.
+impl<F: Fn(&Version) -> bool> VersionMatcher for F {
+    #[inline]
+    fn matches(&self, version: &Version) -> bool {
+        self(version)
+    }
+}
+
+/// The matching versions, in the order of the slice.
+pub fn filter<'a, M: VersionMatcher + ?Sized>(
+    versions: &'a [Version],
+    matcher: &'a M,
+) -> impl Iterator<Item = &'a Version> + 'a {
+    versions
+        .iter()
+        .filter(move |version| matcher.matches(version))
+}
+
+/// The newest matching version.
+pub fn max_matching<'a, M: VersionMatcher + ?Sized>(
+    versions: &'a [Version],
+    matcher: &M,
+) -> Option<&'a Version> {
+    versions
+        .iter()
+        .filter(|version| matcher.matches(version))
+        .max()
+}
+
+/// The oldest matching version.
+pub fn min_matching<'a, M: VersionMatcher + ?Sized>(
+    versions: &'a [Version],
+    matcher: &M,
+) -> Option<&'a Version> {
+    versions
+        .iter()
+        .filter(|version| matcher.matches(version))
+        .min()
+}
+
+/// The indices of the matching versions in ascending order.
+///
+/// Unlike [VersionReq::matching_indices] this always checks every version.
+#[cfg(feature = "alloc")]
+pub fn matching_indices<M: VersionMatcher + ?Sized>(
+    versions: &[Version],
+    matcher: &M,
+) -> Vec<usize> {
+    versions
+        .iter()
+        .enumerate()
+        .filter(|(_, version)| matcher.matches(version))
+        .map(|(index, _)| index)
+        .collect()
+}
+
+/// The number of matching versions.
+pub fn count_matching<M: VersionMatcher + ?Sized>(versions: &[Version], matcher: &M) -> usize {
+    filter(versions, matcher).count()
+}
@@ -0,0 +1,51 @@
+//! Parsing and comparing in a single call, for scripts and FFI shims.
+//!
+//! ```
+//! # use fast_version_core::oneshot;
+//! assert!(oneshot::matches(">=1.2, <2", "1.5.0").unwrap());
+//! assert!(oneshot::compare("1.2.3", "1.10.0").unwrap().is_lt());
+//! ```
+
+use crate::version::{Version, VersionParseError};
+use crate::version_req::{VersionReq, VersionReqParseError};
+use core::cmp::Ordering;
+use core::str::FromStr;
+use thiserror::Error;
+
+/// Returned by [matches()], naming the argument that failed to parse.
+#[derive(Error, Debug)]
+pub enum MatchStrError {
+    #[error("Invalid version requirement: {0}")]
+    Req(#[source] VersionReqParseError),
+    #[error("Invalid version: {0}")]
+    Version(#[source] VersionParseError),
+}
+
+/// Returned by [compare], naming the argument that failed to parse.
+#[derive(Error, Debug)]
+pub enum CompareStrError {
+    #[error("Invalid first version: {0}")]
+    First(#[source] VersionParseError),
+    #[error("Invalid second version: {0}")]
+    Second(#[source] VersionParseError),
+}
+
+/// Parses both arguments and checks wether the version matches the requirement. Surrounding
+/// whitespace is ignored.
+/// ```
+/// # use fast_version_core::oneshot::{matches, MatchStrError};
+/// assert!(!matches("^1", "2.0.0").unwrap());
+/// assert!(matches!(matches("^1", "2.0"), Err(MatchStrError::Version(_))));
+/// ```
+pub fn matches(req: &str, version: &str) -> Result<bool, MatchStrError> {
+    let req = VersionReq::parse(req).map_err(MatchStrError::Req)?;
+    let version = Version::from_str(version.trim()).map_err(MatchStrError::Version)?;
+    Ok(req.matches(&version))
+}
+
+/// Parses both versions and compares them. Surrounding whitespace is ignored.
+pub fn compare(first: &str, second: &str) -> Result<Ordering, CompareStrError> {
+    let first = Version::from_str(first.trim()).map_err(CompareStrError::First)?;
+    let second = Version::from_str(second.trim()).map_err(CompareStrError::Second)?;
+    Ok(first.cmp(&second))
+}
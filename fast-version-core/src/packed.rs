@@ -0,0 +1,200 @@
+//! Packing of a [Version] into a single integer.
+//!
+//! | integer | major   | minor   | patch   |
+//! |---------|---------|---------|---------|
+//! | `u64`   | 16 bits | 24 bits | 24 bits |
+//! | `u128`  | 32 bits | 48 bits | 48 bits |
+//!
+//! The major occupies the most significant bits and the patch the least significant ones, so
+//! comparing packed integers gives the same ordering as comparing the versions.
+//!
+//! [PackedKey] wraps a packed `u128` to look it up in maps keyed by [Version] without unpacking it
+//! first, with the `hashbrown` feature.
+//!
+//! [Version::sort_key] is the lossless alternative: the three components as 24 big-endian bytes,
+//! ordered bytewise like the versions.
+
+use crate::version::{hash_components, ComponentKind, Version};
+use core::hash::{Hash, Hasher};
+use thiserror::Error;
+
+const U64_WIDTHS: [u32; 3] = [16, 24, 24];
+const U128_WIDTHS: [u32; 3] = [32, 48, 48];
+
+/// Returned when a component doesn't fit into its bits of the packed integer.
+#[derive(Error, Debug, PartialEq, Eq, Clone, Copy)]
+#[error("The {component} {value} doesn't fit into {bits} bits")]
+pub struct PackedError {
+    pub component: ComponentKind,
+    pub value: u64,
+    pub bits: u32,
+}
+
+/// A version packed by [Version::to_packed_u128] that hashes exactly like the unpacked
+/// [Version], so it can stand in for one as a map key.
+///
+/// With the `hashbrown` feature it implements `hashbrown::Equivalent` in both directions: a map
+/// keyed by [Version] can be queried with a [PackedKey] and a map keyed by [PackedKey] with a
+/// [Version].
+/// ```
+/// # use fast_version_core::{packed::PackedKey, version::Version};
+/// use std::hash::BuildHasher;
+///
+/// let version = Version::new(1, 2, 3);
+/// let key = PackedKey(version.to_packed_u128().unwrap());
+/// let state = std::collections::hash_map::RandomState::new();
+///
+/// assert_eq!(key.version(), version);
+/// assert_eq!(state.hash_one(key), state.hash_one(version));
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Copy, Clone)]
+pub struct PackedKey(pub u128);
+
+impl PackedKey {
+    /// The unpacked version, see [Version::from_packed_u128].
+    #[inline]
+    pub const fn version(self) -> Version {
+        Version::from_packed_u128(self.0)
+    }
+}
+
+impl Hash for PackedKey {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let version = self.version();
+        hash_components(version.major, version.minor, version.patch, state);
+    }
+}
+
+#[cfg(feature = "hashbrown")]
+impl hashbrown::Equivalent<Version> for PackedKey {
+    #[inline]
+    fn equivalent(&self, key: &Version) -> bool {
+        self.version() == *key
+    }
+}
+
+/// Versions with a component too large for the packed form are equivalent to no key.
+#[cfg(feature = "hashbrown")]
+impl hashbrown::Equivalent<PackedKey> for Version {
+    #[inline]
+    fn equivalent(&self, key: &PackedKey) -> bool {
+        self.to_packed_u128() == Ok(key.0)
+    }
+}
+
+impl Version {
+    /// Packs the version into a `u64` with 16 bits for the major and 24 bits each for minor and
+    /// patch.
+    /// ```
+    /// # use fast_version_core::version::Version;
+    /// const PACKED: u64 = match Version::new(1, 2, 3).to_packed_u64() {
+    ///     Ok(packed) => packed,
+    ///     Err(_) => panic!(),
+    /// };
+    ///
+    /// assert_eq!(PACKED, 1 << 48 | 2 << 24 | 3);
+    /// assert!(Version::new(1 << 16, 0, 0).to_packed_u64().is_err());
+    /// ```
+    pub const fn to_packed_u64(&self) -> Result<u64, PackedError> {
+        match check_widths(self, U64_WIDTHS) {
+            Some(error) => Err(error),
+            None => Ok(self.major << 48 | self.minor << 24 | self.patch),
+        }
+    }
+
+    /// Unpacks a version packed by [Version::to_packed_u64]. Every `u64` is a valid packed
+    /// version, so this can't fail.
+    pub const fn from_packed_u64(packed: u64) -> Version {
+        const MASK: u64 = (1 << 24) - 1;
+        Version::new(packed >> 48, packed >> 24 & MASK, packed & MASK)
+    }
+
+    /// Packs the version into a `u128` with 32 bits for the major and 48 bits each for minor and
+    /// patch.
+    pub const fn to_packed_u128(&self) -> Result<u128, PackedError> {
+        match check_widths(self, U128_WIDTHS) {
+            Some(error) => Err(error),
+            None => {
+                Ok((self.major as u128) << 96 | (self.minor as u128) << 48 | self.patch as u128)
+            }
+        }
+    }
+
+    /// Unpacks a version packed by [Version::to_packed_u128]. Every `u128` is a valid packed
+    /// version, so this can't fail.
+    pub const fn from_packed_u128(packed: u128) -> Version {
+        const MASK: u128 = (1 << 48) - 1;
+        Version::new(
+            (packed >> 96) as u64,
+            (packed >> 48 & MASK) as u64,
+            (packed & MASK) as u64,
+        )
+    }
+}
+
+impl Version {
+    /// Big-endian concatenation of major, minor and patch. Comparing keys bytewise, like
+    /// `sort` or `memcmp` do, gives the same ordering as comparing the versions.
+    /// ```
+    /// # use fast_version_core::version::Version;
+    /// let older = Version::new(1, 255, 0).sort_key();
+    /// let newer = Version::new(1, 256, 0).sort_key();
+    ///
+    /// assert!(older < newer);
+    /// assert_eq!(Version::from_sort_key(newer), Version::new(1, 256, 0));
+    /// ```
+    pub const fn sort_key(self) -> [u8; 24] {
+        let mut key = [0; 24];
+        let components = [
+            self.major.to_be_bytes(),
+            self.minor.to_be_bytes(),
+            self.patch.to_be_bytes(),
+        ];
+        let mut index = 0;
+        while index < key.len() {
+            key[index] = components[index / 8][index % 8];
+            index += 1;
+        }
+        key
+    }
+
+    /// Reads a key created by [Version::sort_key]. Every key is a valid version, so this can't
+    /// fail.
+    pub const fn from_sort_key(key: [u8; 24]) -> Version {
+        let mut components = [[0; 8]; 3];
+        let mut index = 0;
+        while index < key.len() {
+            components[index / 8][index % 8] = key[index];
+            index += 1;
+        }
+        Version::new(
+            u64::from_be_bytes(components[0]),
+            u64::from_be_bytes(components[1]),
+            u64::from_be_bytes(components[2]),
+        )
+    }
+}
+
+/// The first component exceeding its width, if any.
+const fn check_widths(version: &Version, widths: [u32; 3]) -> Option<PackedError> {
+    let components = [
+        (ComponentKind::Major, version.major),
+        (ComponentKind::Minor, version.minor),
+        (ComponentKind::Patch, version.patch),
+    ];
+    let mut index = 0;
+    while index < components.len() {
+        let (component, value) = components[index];
+        let bits = widths[index];
+        if value >> bits != 0 {
+            return Some(PackedError {
+                component,
+                value,
+                bits,
+            });
+        }
+        index += 1;
+    }
+    None
+}
@@ -0,0 +1,116 @@
+//! Uniform sampling of versions matching a requirement, behind the `rand` feature.
+//!
+//! Versions are ordered like 192 bit integers with the major as most significant 64 bits, so a
+//! range of versions is a range of such integers. Sampling is uniform over these integers: each
+//! matching version is equally likely, so a range covering more versions gets proportionally more
+//! samples. Note that this makes small majors rare, `>=1.0.0, <3.0.0` almost never yields a
+//! version with a small minor, as there are 2^64 minors in each major.
+
+use crate::release_range::ReleaseRange;
+use crate::version::Version;
+use crate::version_req::VersionReq;
+#[cfg(feature = "alloc")]
+use crate::version_req_set::VersionReqSet;
+use rand::Rng;
+
+impl VersionReq {
+    /// A uniformly sampled version matching the requirement, None if it is empty.
+    /// ```
+    /// # use fast_version_core::{version::Version, version_req::VersionReq};
+    /// let req = VersionReq::parse(">=1.2.0, <=1.2.9").unwrap();
+    /// let version = req.random_matching(&mut rand::rng()).unwrap();
+    ///
+    /// assert!(req.matches(&version));
+    /// ```
+    pub fn random_matching<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<Version> {
+        let range = ReleaseRange::of(self)?;
+        let offset = random_up_to(span(&range), rng);
+        Some(from_limbs(add(to_limbs(range.lower), offset)))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl VersionReqSet {
+    /// A version sampled uniformly from the union of the ranges, None if the set is empty.
+    ///
+    /// Each range is weighted by the number of versions it contains, so every matching version is
+    /// equally likely.
+    pub fn random_matching<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<Version> {
+        let ranges = self.ranges();
+        if ranges.is_empty() {
+            return None;
+        }
+        // The number of matching versions minus one always fits, even for the whole domain.
+        let mut last_offset = [0, 0, ranges.len() as u64 - 1];
+        for range in ranges {
+            last_offset = add(last_offset, span(range));
+        }
+        let mut offset = random_up_to(last_offset, rng);
+        for range in ranges {
+            let span = span(range);
+            if offset <= span {
+                return Some(from_limbs(add(to_limbs(range.lower), offset)));
+            }
+            offset = sub(sub(offset, span), [0, 0, 1]);
+        }
+        unreachable!("the offset is smaller than the number of matching versions")
+    }
+}
+
+/// Big-endian 192 bit integer, compared lexicographically like the version.
+type Limbs = [u64; 3];
+
+const fn to_limbs(version: Version) -> Limbs {
+    [version.major, version.minor, version.patch]
+}
+
+const fn from_limbs([major, minor, patch]: Limbs) -> Version {
+    Version::new(major, minor, patch)
+}
+
+/// Number of versions in the non-empty range minus one.
+fn span(range: &ReleaseRange) -> Limbs {
+    sub(to_limbs(range.upper), to_limbs(range.lower))
+}
+
+fn add(lhs: Limbs, rhs: Limbs) -> Limbs {
+    let mut sum = [0; 3];
+    let mut carry = false;
+    for index in (0..3).rev() {
+        let (partial, first) = lhs[index].overflowing_add(rhs[index]);
+        let (partial, second) = partial.overflowing_add(carry as u64);
+        sum[index] = partial;
+        carry = first || second;
+    }
+    sum
+}
+
+fn sub(lhs: Limbs, rhs: Limbs) -> Limbs {
+    let mut difference = [0; 3];
+    let mut borrow = false;
+    for index in (0..3).rev() {
+        let (partial, first) = lhs[index].overflowing_sub(rhs[index]);
+        let (partial, second) = partial.overflowing_sub(borrow as u64);
+        difference[index] = partial;
+        borrow = first || second;
+    }
+    difference
+}
+
+/// Uniform integer in `0..=max` by rejection sampling below the next power of two.
+fn random_up_to<R: Rng + ?Sized>(max: Limbs, rng: &mut R) -> Limbs {
+    let Some(first) = max.iter().position(|limb| *limb != 0) else {
+        return [0; 3];
+    };
+    let mask = u64::MAX >> max[first].leading_zeros();
+    loop {
+        let mut candidate = [0; 3];
+        for limb in &mut candidate[first..] {
+            *limb = rng.next_u64();
+        }
+        candidate[first] &= mask;
+        if candidate <= max {
+            return candidate;
+        }
+    }
+}
@@ -0,0 +1,104 @@
+//! Non-empty ranges of releases, the part of a [VersionReq] that [Version]s can match.
+
+use crate::version::Version;
+use crate::version_req::{version_le, VersionReq};
+use core::fmt;
+
+/// The releases between two inclusive bounds, at least one of them.
+///
+/// Unlike a [VersionReq] it has no room for [bounds on prereleases](VersionReq#prereleases), so
+/// it is what a [VersionReqSet](crate::version_req_set::VersionReqSet) stores. It compares equal
+/// to the requirement matching the same versions and is displayed like it.
+/// ```
+/// # use fast_version_core::{release_range::ReleaseRange, version::Version, version_req::VersionReq};
+/// let req = VersionReq::parse(">=1.0.0-alpha, <2").unwrap();
+/// let range = ReleaseRange::of(&req).unwrap();
+///
+/// assert_eq!(range.minimal(), Version::new(1, 0, 0));
+/// assert_eq!(range, VersionReq::parse("^1").unwrap());
+/// assert_eq!(range.to_string(), ">=1.0.0, <2.0.0");
+/// assert_eq!(ReleaseRange::of(&VersionReq::parse("=1.0.0-alpha").unwrap()), None);
+/// ```
+#[derive(PartialEq, PartialOrd, Eq, Ord, Hash, Clone, Copy)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize))]
+pub struct ReleaseRange {
+    pub(crate) lower: Version,
+    pub(crate) upper: Version,
+}
+
+impl ReleaseRange {
+    /// The releases matching the requirement, None if it only matches prereleases or nothing.
+    pub const fn of(req: &VersionReq) -> Option<Self> {
+        if req.has_releases() {
+            Some(Self {
+                lower: req.lower,
+                upper: req.upper,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// The smallest release in the range.
+    #[inline]
+    pub const fn minimal(&self) -> Version {
+        self.lower
+    }
+
+    /// The largest release in the range.
+    #[inline]
+    pub const fn maximal(&self) -> Version {
+        self.upper
+    }
+
+    /// Checks wether the version lies in the range.
+    #[inline]
+    pub const fn matches(&self, version: &Version) -> bool {
+        version_le(&self.lower, version) && version_le(version, &self.upper)
+    }
+
+    /// The requirement matching exactly the releases of the range.
+    #[inline]
+    pub const fn to_req(&self) -> VersionReq {
+        VersionReq::new_bounded(Some(self.lower), Some(self.upper))
+    }
+}
+
+impl From<ReleaseRange> for VersionReq {
+    fn from(range: ReleaseRange) -> Self {
+        range.to_req()
+    }
+}
+
+impl PartialEq<VersionReq> for ReleaseRange {
+    fn eq(&self, other: &VersionReq) -> bool {
+        self.to_req() == *other
+    }
+}
+
+impl PartialEq<ReleaseRange> for VersionReq {
+    fn eq(&self, other: &ReleaseRange) -> bool {
+        *self == other.to_req()
+    }
+}
+
+impl fmt::Display for ReleaseRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_req(), f)
+    }
+}
+
+/// Prints the range in comparator notation, like `ReleaseRange(">=1.2.0, <2.0.0")`. The
+/// alternate flag (`{:#?}`) prints the inclusive bounds.
+impl fmt::Debug for ReleaseRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.debug_struct("ReleaseRange")
+                .field("lower", &self.lower)
+                .field("upper", &self.upper)
+                .finish()
+        } else {
+            write!(f, "ReleaseRange(\"{}\")", self)
+        }
+    }
+}
@@ -0,0 +1,52 @@
+//! Stability classification of a [Version] following the SemVer conventions for `0.x` versions.
+
+use crate::version::Version;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Stability expectation of a version.
+///
+/// The stages line up with how caret requirements (`^x.y.z`, as used by Cargo) decide which
+/// component is breaking: the leftmost non-zero one.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ReleaseStage {
+    /// `0.0.x`, every release may be breaking, so `^0.0.3` only allows `0.0.3`.
+    Experimental,
+    /// `0.x.y` with `x > 0`, the minor is breaking, so `^0.2.3` allows `>=0.2.3, <0.3.0`.
+    Initial,
+    /// `>=1.0.0`, only the major is breaking, so `^1.2.3` allows `>=1.2.3, <2.0.0`.
+    Stable,
+}
+
+impl Version {
+    /// The stability stage of the version.
+    /// ```
+    /// # use fast_version_core::{release_stage::ReleaseStage, version::Version};
+    /// assert_eq!(Version::new(0, 0, 7).stage(), ReleaseStage::Experimental);
+    /// assert_eq!(Version::new(0, 3, 0).stage(), ReleaseStage::Initial);
+    /// assert_eq!(Version::new(1, 0, 0).stage(), ReleaseStage::Stable);
+    /// ```
+    pub const fn stage(&self) -> ReleaseStage {
+        if self.major > 0 {
+            ReleaseStage::Stable
+        } else if self.minor > 0 {
+            ReleaseStage::Initial
+        } else {
+            ReleaseStage::Experimental
+        }
+    }
+
+    /// Checks wether the version is `>=1.0.0`.
+    #[inline]
+    pub const fn is_stable(&self) -> bool {
+        matches!(self.stage(), ReleaseStage::Stable)
+    }
+
+    /// Checks wether the version is exactly `0.0.0`. It is [ReleaseStage::Experimental] like any
+    /// other `0.0.x` version.
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.major == 0 && self.minor == 0 && self.patch == 0
+    }
+}
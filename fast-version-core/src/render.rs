@@ -0,0 +1,267 @@
+//! Parse errors rendered as annotated snippets of their input, behind the `fancy-errors` feature.
+
+use crate::full_version::{IdentifierError, Prerelease};
+use crate::version::{ComponentKind, VersionParseError};
+use crate::version_req::VersionReqParseError;
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::fmt::Write;
+use core::ops::Range;
+
+/// The byte range of the input an error points at, with a hint shown next to the carets.
+struct Label {
+    start: usize,
+    len: usize,
+    hint: String,
+}
+
+impl VersionParseError {
+    /// Renders the error with the input it was returned for, the offending part marked with
+    /// carets and a hint. Meant for errors of [Version::from_str](core::str::FromStr) and
+    /// [Version::parse_checked](crate::version::Version::parse_checked).
+    /// ```
+    /// # use fast_version_core::version::Version;
+    /// let source = "1.x.3";
+    /// let error = Version::parse_checked(source).unwrap_err();
+    ///
+    /// assert_eq!(
+    ///     error.render(source),
+    ///     "\
+    /// error: Minor Parse Error
+    ///   |
+    /// 1 | 1.x.3
+    ///   |   ^ expected a digit, found `x`
+    /// "
+    /// );
+    /// ```
+    pub fn render(&self, source: &str) -> String {
+        render(self, source, version_label(self, source, 0..source.len()))
+    }
+}
+
+impl VersionReqParseError {
+    /// Renders the error with the input it was returned for, the offending part marked with
+    /// carets and a hint. Meant for errors of [VersionReq::parse](crate::version_req::VersionReq::parse).
+    /// ```
+    /// # use fast_version_core::version_req::VersionReq;
+    /// let source = ">=1.2, =>2";
+    /// let error = VersionReq::parse(source).unwrap_err();
+    ///
+    /// assert_eq!(
+    ///     error.render(source),
+    ///     "\
+    /// error: Unknown operator at position 7
+    ///   |
+    /// 1 | >=1.2, =>2
+    ///   |        ^^ expected one of `=`, `>`, `>=`, `<`, `<=`, `^` or `~`
+    /// "
+    /// );
+    /// ```
+    pub fn render(&self, source: &str) -> String {
+        render(self, source, req_label(self, source))
+    }
+}
+
+fn render(error: &dyn core::fmt::Display, source: &str, label: Option<Label>) -> String {
+    let mut rendered = format!("error: {error}\n");
+    let Some(Label { start, len, hint }) = label else {
+        return rendered;
+    };
+    let start = floor_char_boundary(source, start.min(source.len()));
+    let line_start = source[..start].rfind('\n').map_or(0, |newline| newline + 1);
+    let line_end = source[start..]
+        .find('\n')
+        .map_or(source.len(), |newline| start + newline);
+    let line = &source[line_start..line_end];
+    let line_number = source[..line_start].matches('\n').count() + 1;
+    let end = floor_char_boundary(source, (start + len).min(line_end));
+    let column = source[line_start..start].chars().count();
+    let carets = source[start..end].chars().count().max(1);
+    let gutter = " ".repeat(line_number.to_string().len());
+    let _ = write!(
+        rendered,
+        "{gutter} |\n{line_number} | {line}\n{gutter} | {}{} {hint}\n",
+        " ".repeat(column),
+        "^".repeat(carets),
+    );
+    rendered
+}
+
+fn floor_char_boundary(source: &str, mut index: usize) -> usize {
+    while !source.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+/// Locates the error in the version at `range` of `source`.
+fn version_label(error: &VersionParseError, source: &str, range: Range<usize>) -> Option<Label> {
+    use VersionParseError::*;
+    let offset = range.start;
+    let text = &source[range];
+    let mut fields = [None; 4];
+    let mut start = 0;
+    for (field, split) in fields.iter_mut().zip(text.split('.')) {
+        *field = Some((start, split));
+        start += split.len() + 1;
+    }
+    let component = match error {
+        TooFewComponents { .. } | TooManyComponents { .. } => {
+            let label = match fields[3] {
+                Some((start, _)) => Label {
+                    start: start - 1,
+                    len: 1,
+                    hint: String::from("expected the end of the version, found `.`"),
+                },
+                None => Label {
+                    start: text.len(),
+                    len: 1,
+                    hint: String::from("expected `.`, found the end of the version"),
+                },
+            };
+            return Some(offset_label(label, offset));
+        }
+        MajorParseError | MajorNotFound => ComponentKind::Major,
+        MinorParseError | MinorNotFound => ComponentKind::Minor,
+        PatchParseError | PatchNotFound => ComponentKind::Patch,
+        ComponentOverflow { component, .. } => *component,
+        Prerelease { position } => {
+            let label = Label {
+                start: *position,
+                len: text.len() - position,
+                hint: String::from("prereleases and build metadata need a full version"),
+            };
+            return Some(offset_label(label, offset));
+        }
+        // Invalid UTF-8 and the deprecated `FormatWrong`, which isn't returned anymore.
+        _ => return None,
+    };
+    let index = component as usize;
+    let label = match fields[index] {
+        None => Label {
+            start: text.len(),
+            len: 1,
+            hint: format!("expected `.` and the {component}, found the end of the version"),
+        },
+        Some((start, field)) if matches!(error, ComponentOverflow { .. }) => Label {
+            start,
+            len: field.len(),
+            hint: String::from("doesn't fit into a u64"),
+        },
+        Some((start, field)) => match field.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+            Some((position, found)) => Label {
+                start: start + position,
+                len: found.len_utf8(),
+                hint: format!("expected a digit, found `{}`", found.escape_debug()),
+            },
+            None if field.is_empty() => Label {
+                start,
+                len: 1,
+                hint: match text[start..].chars().next() {
+                    Some(found) => format!("expected a digit, found `{}`", found.escape_debug()),
+                    None => String::from("expected a digit, found the end of the version"),
+                },
+            },
+            None => Label {
+                start,
+                len: field.len(),
+                hint: format!("invalid {component}"),
+            },
+        },
+    };
+    Some(offset_label(label, offset))
+}
+
+fn offset_label(label: Label, offset: usize) -> Label {
+    Label {
+        start: label.start + offset,
+        ..label
+    }
+}
+
+fn req_label(error: &VersionReqParseError, source: &str) -> Option<Label> {
+    use VersionReqParseError::*;
+    let comparator_len = |position: usize| {
+        let rest = source.get(position..).unwrap_or_default();
+        rest[..rest.find(',').unwrap_or(rest.len())]
+            .trim_end()
+            .len()
+    };
+    match error {
+        UnknownOperator { position } => {
+            let rest = source.get(*position..).unwrap_or_default();
+            let operator_len = rest
+                .find(|c: char| !matches!(c, '<' | '>' | '=' | '^' | '~' | '!'))
+                .unwrap_or(rest.len());
+            Some(Label {
+                start: *position,
+                len: operator_len,
+                hint: String::from("expected one of `=`, `>`, `>=`, `<`, `<=`, `^` or `~`"),
+            })
+        }
+        MalformedNumber {
+            position,
+            source: inner,
+        } => {
+            let start = floor_char_boundary(source, (*position).min(source.len()));
+            let len = source[start..]
+                .find(|c: char| c == ',' || c.is_whitespace())
+                .unwrap_or(source.len() - start);
+            version_label(inner, source, start..start + len)
+        }
+        ConflictingBounds { position } => Some(Label {
+            start: *position,
+            len: comparator_len(*position),
+            hint: String::from("conflicts with the previous comparator"),
+        }),
+        TooManyComparators { .. } => {
+            let second_comma = source.match_indices(',').nth(1).map(|(index, _)| index);
+            Some(Label {
+                start: second_comma.unwrap_or(source.len()),
+                len: source.len() - second_comma.unwrap_or(source.len()),
+                hint: String::from("at most 2 comparators are supported"),
+            })
+        }
+        InvalidIdentifiers {
+            position,
+            source: inner,
+        } => {
+            let rest = source.get(*position..).unwrap_or_default();
+            let len = rest
+                .find(|c: char| matches!(c, ',' | '+') || c.is_whitespace())
+                .unwrap_or(rest.len());
+            Some(match inner {
+                IdentifierError::InvalidCharacter { position: offset } => Label {
+                    start: position + offset,
+                    len: 1,
+                    hint: String::from("expected ASCII alphanumerics, `-` or `.`"),
+                },
+                IdentifierError::LeadingZero { position: offset } => Label {
+                    start: position + offset,
+                    len: 1,
+                    hint: String::from("numeric identifiers can't have leading zeros"),
+                },
+                IdentifierError::TooLong { .. } => Label {
+                    start: *position,
+                    len,
+                    hint: format!("at most {} bytes are supported", Prerelease::CAPACITY),
+                },
+                IdentifierError::EmptyIdentifier { position: offset } => Label {
+                    start: position + offset,
+                    len: 0,
+                    hint: String::from("expected an identifier"),
+                },
+            })
+        }
+        PrereleaseInSet { position } => {
+            let rest = source.get(*position..).unwrap_or_default();
+            Some(Label {
+                start: *position,
+                len: rest
+                    .find(|c: char| matches!(c, ',' | '+' | '|') || c.is_whitespace())
+                    .unwrap_or(rest.len()),
+                hint: String::from("prereleases need a single VersionReq"),
+            })
+        }
+    }
+}
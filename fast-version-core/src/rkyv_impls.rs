@@ -0,0 +1,143 @@
+//! Zero-copy archives behind the `rkyv` feature.
+//!
+//! [Version], [VersionReq] and [ReleaseRange](crate::release_range::ReleaseRange) derive
+//! `Archive`, a [VersionReqSet] is archived as the list of its ranges. The archived requirements
+//! can be queried in place, without deserializing them first.
+
+use crate::release_range::ArchivedReleaseRange;
+use crate::version::{ArchivedVersion, Version};
+use crate::version_req::{
+    ArchivedFullBound, ArchivedVersionReq, FullBound, PrereleaseBounds, VersionReq,
+};
+use crate::version_req_set::VersionReqSet;
+use rkyv::bytecheck::CheckBytes;
+use rkyv::munge::munge;
+use rkyv::option::ArchivedOption;
+use rkyv::rancor::Fallible;
+use rkyv::ser::{Allocator, Writer};
+use rkyv::vec::{ArchivedVec, VecResolver};
+use rkyv::{Archive, Deserialize, Place, Portable, Serialize};
+
+impl ArchivedVersion {
+    /// The version in native representation.
+    #[inline]
+    pub fn to_native(&self) -> Version {
+        Version::new(
+            self.major.to_native(),
+            self.minor.to_native(),
+            self.patch.to_native(),
+        )
+    }
+}
+
+impl ArchivedVersionReq {
+    /// Checks wether the version matches, like [VersionReq::matches] does.
+    #[inline]
+    pub fn matches(&self, version: &Version) -> bool {
+        self.to_native().matches(version)
+    }
+
+    /// The requirement in native representation.
+    pub fn to_native(&self) -> VersionReq {
+        let req =
+            VersionReq::new_bounded(Some(self.lower.to_native()), Some(self.upper.to_native()));
+        let Some(bounds) = self.pre.as_ref() else {
+            return req;
+        };
+        match (
+            bound_to_native(&bounds.lower),
+            bound_to_native(&bounds.upper),
+        ) {
+            (Some(lower), Some(upper)) => {
+                req.with_prerelease_bounds(PrereleaseBounds { lower, upper })
+            }
+            _ => req,
+        }
+    }
+}
+
+/// The bound in native representation, None if its prerelease is invalid.
+fn bound_to_native(bound: &ArchivedOption<ArchivedFullBound>) -> Option<Option<FullBound>> {
+    let Some(bound) = bound.as_ref() else {
+        return Some(None);
+    };
+    Some(Some(FullBound {
+        version: bound.version.to_native(),
+        pre: bound.pre.to_native()?,
+        inclusive: bound.inclusive,
+    }))
+}
+
+/// Archives with crossed bounds deserialize to [VersionReq::EMPTY], bounds on prereleases that
+/// aren't valid are dropped.
+impl<D: Fallible + ?Sized> Deserialize<VersionReq, D> for ArchivedVersionReq {
+    fn deserialize(&self, _: &mut D) -> Result<VersionReq, D::Error> {
+        Ok(self.to_native())
+    }
+}
+
+impl ArchivedReleaseRange {
+    /// Checks wether the version lies in the range, like
+    /// [ReleaseRange::matches](crate::release_range::ReleaseRange::matches) does.
+    #[inline]
+    pub fn matches(&self, version: &Version) -> bool {
+        self.to_req().matches(version)
+    }
+
+    /// The requirement matching the releases of the range, [VersionReq::EMPTY] for crossed
+    /// bounds.
+    pub fn to_req(&self) -> VersionReq {
+        VersionReq::new_bounded(Some(self.lower.to_native()), Some(self.upper.to_native()))
+    }
+}
+
+/// An archived [VersionReqSet], the ranges in the order they were stored.
+#[derive(Portable, CheckBytes)]
+#[bytecheck(crate = rkyv::bytecheck)]
+#[rkyv(crate = rkyv)]
+#[repr(transparent)]
+pub struct ArchivedVersionReqSet {
+    ranges: ArchivedVec<ArchivedReleaseRange>,
+}
+
+impl ArchivedVersionReqSet {
+    /// The archived ranges.
+    #[inline]
+    pub fn ranges(&self) -> &[ArchivedReleaseRange] {
+        self.ranges.as_slice()
+    }
+
+    /// Checks wether any of the ranges matches the version, like [VersionReqSet::matches] does.
+    pub fn matches(&self, version: &Version) -> bool {
+        self.ranges().iter().any(|range| range.matches(version))
+    }
+}
+
+impl Archive for VersionReqSet {
+    type Archived = ArchivedVersionReqSet;
+    type Resolver = VecResolver;
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        munge!(let ArchivedVersionReqSet { ranges } = out);
+        ArchivedVec::resolve_from_slice(self.ranges(), resolver, ranges);
+    }
+}
+
+impl<S: Fallible + Allocator + Writer + ?Sized> Serialize<S> for VersionReqSet {
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        ArchivedVec::serialize_from_slice(self.ranges(), serializer)
+    }
+}
+
+/// The ranges are merged like [VersionReqSet::from_unsorted] does, so a crafted archive still
+/// gives a canonical set.
+impl<D: Fallible + ?Sized> Deserialize<VersionReqSet, D> for ArchivedVersionReqSet {
+    fn deserialize(&self, _: &mut D) -> Result<VersionReqSet, D::Error> {
+        Ok(VersionReqSet::from_unsorted(
+            self.ranges()
+                .iter()
+                .map(ArchivedReleaseRange::to_req)
+                .collect(),
+        ))
+    }
+}
@@ -0,0 +1,83 @@
+//! Requirements read from an environment variable on first use, behind the `std` feature.
+
+use crate::version_req::{VersionReq, VersionReqParseError};
+use std::env::{self, VarError};
+use std::sync::OnceLock;
+use thiserror::Error;
+
+/// A [VersionReq] taken from an environment variable, parsed once on first access and cached
+/// afterwards, so it can be a `static`.
+/// ## Example:
+/// ```
+/// # use fast_version_core::{runtime_req::RuntimeReq, version::Version, version_req::VersionReq};
+/// static ACCEPTED: RuntimeReq = RuntimeReq::from_env("ACCEPTED_PLUGIN_VERSIONS", VersionReq::STAR);
+///
+/// // Falls back to the default while the variable isn't set.
+/// assert!(ACCEPTED.get().matches(&Version::new(0, 1, 0)));
+/// assert!(ACCEPTED.try_get().is_err());
+/// ```
+#[derive(Debug)]
+pub struct RuntimeReq {
+    var: &'static str,
+    default: VersionReq,
+    cell: OnceLock<Result<VersionReq, RuntimeReqError>>,
+}
+
+/// Why a [RuntimeReq] falls back to its default.
+#[derive(Error, Debug)]
+pub enum RuntimeReqError {
+    #[error("Environment variable {var} is not set")]
+    Missing { var: &'static str },
+    #[error("Environment variable {var} is not valid unicode")]
+    NotUnicode { var: &'static str },
+    #[error("Environment variable {var} is not a valid version requirement: {source}")]
+    Parse {
+        var: &'static str,
+        #[source]
+        source: VersionReqParseError,
+    },
+}
+
+impl RuntimeReq {
+    /// Create a requirement read from `var`, the variable isn't read before the first access.
+    pub const fn from_env(var: &'static str, default: VersionReq) -> Self {
+        Self {
+            var,
+            default,
+            cell: OnceLock::new(),
+        }
+    }
+
+    /// The requirement from the variable, or the error that occurred on the first access. Later
+    /// changes of the variable are never seen.
+    pub fn try_get(&self) -> Result<VersionReq, &RuntimeReqError> {
+        self.cell
+            .get_or_init(|| {
+                let value = env::var(self.var).map_err(|error| match error {
+                    VarError::NotPresent => RuntimeReqError::Missing { var: self.var },
+                    VarError::NotUnicode(_) => RuntimeReqError::NotUnicode { var: self.var },
+                })?;
+                VersionReq::parse(&value).map_err(|source| RuntimeReqError::Parse {
+                    var: self.var,
+                    source,
+                })
+            })
+            .as_ref()
+            .copied()
+    }
+
+    /// The requirement from the variable, or the default if it is missing or invalid.
+    pub fn get(&self) -> VersionReq {
+        self.try_get().unwrap_or(self.default)
+    }
+
+    /// The name of the environment variable.
+    pub const fn var(&self) -> &'static str {
+        self.var
+    }
+
+    /// The requirement used when the variable is missing or invalid.
+    pub const fn default_req(&self) -> VersionReq {
+        self.default
+    }
+}
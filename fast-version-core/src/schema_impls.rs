@@ -0,0 +1,53 @@
+//! [JsonSchema] implementations behind the `schemars` feature, describing the human-readable serde
+//! forms.
+
+use crate::version::Version;
+use crate::version_req::VersionReq;
+use alloc::borrow::Cow;
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+/// A string in comparator notation, see [FromStr for VersionReq](VersionReq#impl-FromStr-for-VersionReq).
+/// The pattern only checks the characters, the grammar itself is described in prose.
+impl JsonSchema for VersionReq {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("VersionReq")
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        Cow::Borrowed(concat!(module_path!(), "::VersionReq"))
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "description": "Version requirement of up to two comma separated comparators. A \
+                comparator is `*` or an optional operator (`=`, `>`, `>=`, `<`, `<=`, `^`, `~`) \
+                followed by a version with one to three numeric components, like `1`, `1.2` or \
+                `1.2.3`. A version with three components may have a prerelease and build \
+                metadata, like `1.2.3-alpha.1`. Two comparators form a lower and an upper bound.",
+            "pattern": r"^([\s0-9.,*=<>^~]|[0-9][-+][0-9A-Za-z.+-]+)+$",
+            "examples": [">=1.2.3, <2", "^1.2", "~0.4.1", "=1.0.0", ">=1.0.0-alpha.1", "*"],
+        })
+    }
+}
+
+/// A string of three dot separated numeric components, like `1.2.3`.
+impl JsonSchema for Version {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("Version")
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        Cow::Borrowed(concat!(module_path!(), "::Version"))
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "description": "Version of three dot separated numeric components, major, minor \
+                and patch, each fitting into an unsigned 64 bit integer.",
+            "pattern": r"^[0-9]+\.[0-9]+\.[0-9]+$",
+            "examples": ["1.2.3", "0.1.0"],
+        })
+    }
+}
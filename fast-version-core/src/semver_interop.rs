@@ -0,0 +1,190 @@
+//! Matching [semver::Version]s against requirements, behind the `semver-interop` feature.
+//!
+//! ## Prerelease policy
+//! Prereleases match like in Cargo, see [prereleases](VersionReq#prereleases): a prerelease like
+//! `1.2.3-beta.1` is rejected by every requirement that doesn't name a prerelease of `1.2.3`, even
+//! by `*`, but matches `>=1.2.3-alpha`. Build metadata like `1.2.3+build.5` doesn't take part in
+//! matching at all.
+//!
+//! ## Conversion
+//! Converting a [semver::Version] into a [Version] with [TryFrom] fails instead of dropping a
+//! prerelease or build metadata, so a `-rc.1` build can't slip through a gate. Callers that do
+//! want to drop them use [Version::from_semver_lossy], which hands back what was dropped.
+//!
+//! A [semver::VersionReq] converts into a [VersionReq] with [TryFrom] as long as its prereleases
+//! fit into a [Prerelease]. Note that a bare version like `1.2.3` means `^1.2.3` to the
+//! semver crate and Cargo, while [VersionReq::parse] reads it as `=1.2.3`. The conversion follows
+//! the semver crate, it's the requirement the [semver::VersionReq] stands for.
+
+use crate::full_version::Prerelease;
+use crate::version::Version;
+use crate::version_req::{Comparator, Comparators, VersionReq};
+use thiserror::Error;
+
+/// Returned when a [semver::Version] has parts a [Version] can't hold.
+#[derive(Error, PartialEq, Eq, Hash, Debug, Clone)]
+pub enum SemverLossError {
+    /// The prerelease, like `rc.1`. Reported first if there is build metadata too.
+    #[error("The prerelease {0} can't be represented")]
+    Prerelease(String),
+    /// The build metadata, like `build.5`.
+    #[error("The build metadata {0} can't be represented")]
+    BuildMetadata(String),
+}
+
+/// Returned when a [semver::VersionReq] has a comparator a [VersionReq] can't represent.
+#[derive(Error, PartialEq, Eq, Hash, Debug, Clone)]
+pub enum SemverReqError {
+    /// A comparator with a prerelease longer than [Prerelease::CAPACITY].
+    #[error("The comparator {0} has a prerelease longer than requirements can represent")]
+    Prerelease(String),
+    /// A comparator with an operator added to the semver crate after this conversion.
+    #[error("The comparator {0} has an unsupported operator")]
+    UnsupportedOperator(String),
+}
+
+impl From<Version> for semver::Version {
+    /// ```
+    /// # use fast_version_core::version::Version;
+    /// let version = semver::Version::from(Version::new(1, 2, 3));
+    ///
+    /// assert_eq!(version, semver::Version::parse("1.2.3").unwrap());
+    /// ```
+    fn from(version: Version) -> Self {
+        semver::Version::new(version.major, version.minor, version.patch)
+    }
+}
+
+impl TryFrom<&semver::VersionReq> for VersionReq {
+    type Error = SemverReqError;
+    /// Intersects the requirements of the comparators, an empty list is [VersionReq::STAR].
+    /// ```
+    /// # use fast_version_core::{semver_interop::SemverReqError, version_req::VersionReq};
+    /// let req = semver::VersionReq::parse("1.2, <1.8").unwrap();
+    /// assert_eq!(VersionReq::try_from(&req), Ok(VersionReq::parse(">=1.2, <1.8").unwrap()));
+    ///
+    /// let req = semver::VersionReq::parse(">=1.2.3-rc.1").unwrap();
+    /// assert_eq!(VersionReq::try_from(&req), Ok(VersionReq::parse(">=1.2.3-rc.1").unwrap()));
+    ///
+    /// let req = semver::VersionReq::parse(&format!(">=1.2.3-{}", "a".repeat(33))).unwrap();
+    /// assert!(matches!(VersionReq::try_from(&req), Err(SemverReqError::Prerelease(_))));
+    /// ```
+    fn try_from(req: &semver::VersionReq) -> Result<Self, Self::Error> {
+        let mut comparators = Comparators::STAR;
+        for comparator in &req.comparators {
+            comparators.push(&convert_comparator(comparator)?);
+        }
+        Ok(comparators.req())
+    }
+}
+
+impl TryFrom<semver::VersionReq> for VersionReq {
+    type Error = SemverReqError;
+    fn try_from(req: semver::VersionReq) -> Result<Self, Self::Error> {
+        VersionReq::try_from(&req)
+    }
+}
+
+fn convert_comparator(comparator: &semver::Comparator) -> Result<Comparator, SemverReqError> {
+    // The semver crate only parses valid prereleases, so the length is all that can fail.
+    let pre = Prerelease::new(comparator.pre.as_str())
+        .map_err(|_| SemverReqError::Prerelease(comparator.to_string()))?;
+    let operator = match comparator.op {
+        semver::Op::Exact | semver::Op::Wildcard => "=",
+        semver::Op::Greater => ">",
+        semver::Op::GreaterEq => ">=",
+        semver::Op::Less => "<",
+        semver::Op::LessEq => "<=",
+        semver::Op::Tilde => "~",
+        semver::Op::Caret => "^",
+        _ => return Err(SemverReqError::UnsupportedOperator(comparator.to_string())),
+    };
+    Ok(Comparator::new(
+        operator,
+        comparator.major,
+        comparator.minor,
+        comparator.patch,
+        pre,
+    ))
+}
+
+impl TryFrom<&semver::Version> for Version {
+    type Error = SemverLossError;
+    /// ```
+    /// # use fast_version_core::{semver_interop::SemverLossError, version::Version};
+    /// let clean = semver::Version::parse("1.4.0").unwrap();
+    /// assert_eq!(Version::try_from(&clean), Ok(Version::new(1, 4, 0)));
+    ///
+    /// let rc = semver::Version::parse("1.4.0-rc.1").unwrap();
+    /// assert_eq!(
+    ///     Version::try_from(&rc),
+    ///     Err(SemverLossError::Prerelease("rc.1".to_string()))
+    /// );
+    /// ```
+    fn try_from(version: &semver::Version) -> Result<Self, Self::Error> {
+        if !version.pre.is_empty() {
+            return Err(SemverLossError::Prerelease(version.pre.to_string()));
+        }
+        if !version.build.is_empty() {
+            return Err(SemverLossError::BuildMetadata(version.build.to_string()));
+        }
+        Ok(Version::new(version.major, version.minor, version.patch))
+    }
+}
+
+impl TryFrom<semver::Version> for Version {
+    type Error = SemverLossError;
+    fn try_from(version: semver::Version) -> Result<Self, Self::Error> {
+        Version::try_from(&version)
+    }
+}
+
+impl Version {
+    /// Converts the numeric components and returns the dropped prerelease and build metadata,
+    /// which are empty if nothing was dropped.
+    /// ```
+    /// # use fast_version_core::version::Version;
+    /// let version = semver::Version::parse("1.4.0-rc.1+build.5").unwrap();
+    /// let (version, pre, build) = Version::from_semver_lossy(&version);
+    ///
+    /// assert_eq!(version, Version::new(1, 4, 0));
+    /// assert_eq!(pre.as_str(), "rc.1");
+    /// assert_eq!(build.as_str(), "build.5");
+    /// ```
+    pub fn from_semver_lossy(
+        version: &semver::Version,
+    ) -> (Self, semver::Prerelease, semver::BuildMetadata) {
+        (
+            Version::new(version.major, version.minor, version.patch),
+            version.pre.clone(),
+            version.build.clone(),
+        )
+    }
+}
+
+impl VersionReq {
+    /// Checks wether the [semver::Version] matches, following the
+    /// [prerelease policy](crate::semver_interop#prerelease-policy). Nothing is allocated, a
+    /// prerelease longer than [Prerelease::CAPACITY] never matches.
+    /// ```
+    /// # use fast_version_core::version_req::VersionReq;
+    /// let req = VersionReq::parse("^1.2").unwrap();
+    ///
+    /// assert!(req.matches_semver(&semver::Version::parse("1.4.0").unwrap()));
+    /// assert!(req.matches_semver(&semver::Version::parse("1.4.0+build.7").unwrap()));
+    /// assert!(!req.matches_semver(&semver::Version::parse("1.4.0-rc.1").unwrap()));
+    ///
+    /// let req = VersionReq::parse("^1.4.0-rc.1").unwrap();
+    /// assert!(req.matches_semver(&semver::Version::parse("1.4.0-rc.2").unwrap()));
+    /// ```
+    pub fn matches_semver(&self, version: &semver::Version) -> bool {
+        let release = Version::new(version.major, version.minor, version.patch);
+        if version.pre.is_empty() {
+            return self.matches(&release);
+        }
+        match (&self.pre, Prerelease::new(version.pre.as_str())) {
+            (Some(bounds), Ok(pre)) => bounds.matches(&release, &pre),
+            _ => false,
+        }
+    }
+}
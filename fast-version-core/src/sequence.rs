@@ -0,0 +1,113 @@
+//! Validating streams of released versions, like the ones listed in a changelog.
+
+use crate::bump::BumpKind;
+use crate::version::Version;
+use thiserror::Error;
+
+/// Checks that every version of a sequence is exactly one bump above the previous one, see
+/// [Version::bumped_between].
+/// ## Example:
+/// ```
+/// # use fast_version_core::{sequence::{SequenceError, VersionSequence}, version::Version};
+/// let released = [(1, 0, 0), (1, 0, 1), (1, 1, 0), (2, 0, 0)]
+///     .map(|(major, minor, patch)| Version::new(major, minor, patch));
+///
+/// let summary = VersionSequence::STRICT.validate(released).unwrap();
+/// assert_eq!((summary.major, summary.minor, summary.patch), (1, 1, 1));
+///
+/// let skipped = [Version::new(1, 0, 0), Version::new(1, 2, 0)];
+/// assert!(matches!(
+///     VersionSequence::STRICT.validate(skipped),
+///     Err(SequenceError::Gap { index: 1, .. })
+/// ));
+/// ```
+#[derive(PartialEq, Eq, Hash, Debug, Default, Copy, Clone)]
+pub struct VersionSequence {
+    /// Wether a version may repeat the previous one, like a release that was tagged twice.
+    pub allow_retags: bool,
+}
+
+/// What a valid sequence consists of, returned by [VersionSequence::validate].
+#[derive(PartialEq, Eq, Hash, Debug, Default, Copy, Clone)]
+pub struct SequenceSummary {
+    /// The number of versions, including retags.
+    pub len: usize,
+    pub major: usize,
+    pub minor: usize,
+    pub patch: usize,
+    /// Versions repeating the previous one, only possible with
+    /// [allow_retags](VersionSequence::allow_retags).
+    pub retags: usize,
+}
+
+/// The first step of a sequence that isn't a single bump. `index` is the position of `version`,
+/// `previous` is the version before it.
+#[derive(Error, PartialEq, Eq, Hash, Debug, Copy, Clone)]
+pub enum SequenceError {
+    #[error("Version {version} at index {index} repeats the previous version")]
+    Duplicate { index: usize, version: Version },
+    #[error("Version {version} at index {index} is lower than the previous version {previous}")]
+    Decreasing {
+        index: usize,
+        previous: Version,
+        version: Version,
+    },
+    #[error("Version {version} at index {index} is more than one bump above {previous}")]
+    Gap {
+        index: usize,
+        previous: Version,
+        version: Version,
+    },
+}
+
+impl VersionSequence {
+    /// Every version has to be one bump above the previous one.
+    pub const STRICT: Self = Self {
+        allow_retags: false,
+    };
+    /// Like [VersionSequence::STRICT], but a version may repeat the previous one.
+    pub const ALLOW_RETAGS: Self = Self { allow_retags: true };
+
+    /// Checks the versions in order, stopping at the first invalid step. An empty sequence is
+    /// valid.
+    pub fn validate(
+        self,
+        versions: impl IntoIterator<Item = Version>,
+    ) -> Result<SequenceSummary, SequenceError> {
+        let mut summary = SequenceSummary::default();
+        let mut previous = None;
+        for (index, version) in versions.into_iter().enumerate() {
+            summary.len += 1;
+            let Some(previous) = previous.replace(version) else {
+                continue;
+            };
+            if version == previous {
+                if !self.allow_retags {
+                    return Err(SequenceError::Duplicate { index, version });
+                }
+                summary.retags += 1;
+                continue;
+            }
+            if version < previous {
+                return Err(SequenceError::Decreasing {
+                    index,
+                    previous,
+                    version,
+                });
+            }
+            match Version::bumped_between(previous, version) {
+                Some(BumpKind::Major) => summary.major += 1,
+                Some(BumpKind::Minor) => summary.minor += 1,
+                Some(BumpKind::Patch) => summary.patch += 1,
+                None => {
+                    return Err(SequenceError::Gap {
+                        index,
+                        previous,
+                        version,
+                    })
+                }
+            }
+        }
+        Ok(summary)
+    }
+}
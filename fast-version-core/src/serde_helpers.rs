@@ -0,0 +1,617 @@
+//! Alternative serde representations of [Version] and [VersionReq] for use with
+//! `#[serde(with = "...")]`.
+//!
+//! Everything works without the `alloc` feature, like with `serde-json-core`, except for
+//! [version_as_tuple::vec], the `VersionReqSet` impls and floats in [lenient]. The default
+//! [Version] and [VersionReq] impls, [lenient] and [version_with_defaults] accept several forms
+//! and therefore need a self-describing format supporting `deserialize_any` when it's
+//! human-readable, [version_as_str] and [version_req_as_str] are the alternatives for the
+//! others.
+//!
+//! [VersionFields] isn't used with `with`, but in place of a [Version] for `#[serde(flatten)]`.
+//!
+//! [Version]: crate::version::Version
+//! [VersionReq]: crate::version_req::VersionReq
+
+use crate::version::Version;
+use core::fmt;
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// A [Version] as three sibling `major`, `minor` and `patch` fields, for
+/// `#[serde(flatten)]` into flat records like CSV rows, query strings or database rows.
+///
+/// Flattened fields are buffered and lose their types in most formats, so the components
+/// are also accepted as strings of digits, like `"3"`. This needs a self-describing format,
+/// which flattening requires anyway.
+///
+/// The csv crate can't serialize flattened fields at all. Write a flat record there, or nest a
+/// [VersionFields] into a record written without headers.
+/// ```
+/// # use fast_version_core::{serde_helpers::VersionFields, version::Version};
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(Serialize, Deserialize)]
+/// struct Row {
+///     name: String,
+///     #[serde(flatten)]
+///     version: VersionFields,
+/// }
+///
+/// let row: Row = serde_json::from_str(r#"{"name":"crate","major":1,"minor":2,"patch":"3"}"#)
+///     .unwrap();
+/// assert_eq!(Version::from(row.version), Version::new(1, 2, 3));
+/// ```
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub struct VersionFields {
+    #[serde(deserialize_with = "flat_component")]
+    pub major: u64,
+    #[serde(deserialize_with = "flat_component")]
+    pub minor: u64,
+    #[serde(deserialize_with = "flat_component")]
+    pub patch: u64,
+}
+
+impl From<Version> for VersionFields {
+    fn from(version: Version) -> Self {
+        Self {
+            major: version.major,
+            minor: version.minor,
+            patch: version.patch,
+        }
+    }
+}
+
+impl From<VersionFields> for Version {
+    fn from(fields: VersionFields) -> Self {
+        Version::new(fields.major, fields.minor, fields.patch)
+    }
+}
+
+/// A component of [VersionFields], given as a number or a string of digits.
+fn flat_component<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+    struct FlatVisitor;
+
+    impl<'de> Visitor<'de> for FlatVisitor {
+        type Value = u64;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a version component as number or string")
+        }
+
+        fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+            Ok(value)
+        }
+
+        fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+            u64::try_from(value).map_err(|_| E::invalid_value(de::Unexpected::Signed(value), &self))
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+            value
+                .parse()
+                .map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self))
+        }
+    }
+
+    deserializer.deserialize_any(FlatVisitor)
+}
+
+pub mod version_as_tuple {
+    //! Serializes a [Version] as the 3 element sequence `[major, minor, patch]`.
+    //!
+    //! ```
+    //! # use fast_version_core::version::Version;
+    //! # use serde::{Deserialize, Serialize};
+    //! #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    //! struct Package {
+    //!     #[serde(with = "fast_version_core::serde_helpers::version_as_tuple")]
+    //!     version: Version,
+    //!     #[serde(with = "fast_version_core::serde_helpers::version_as_tuple::option")]
+    //!     previous: Option<Version>,
+    //! }
+    //!
+    //! let package = Package {
+    //!     version: Version::new(1, 2, 3),
+    //!     previous: None,
+    //! };
+    //! let json = serde_json::to_string(&package).unwrap();
+    //! assert_eq!(json, r#"{"version":[1,2,3],"previous":null}"#);
+    //! assert_eq!(serde_json::from_str::<Package>(&json).unwrap(), package);
+    //! ```
+
+    use crate::serde_impls::Component;
+    use crate::version::{ComponentKind, Version};
+    use core::fmt;
+    use serde::de::{self, IgnoredAny, SeqAccess, Visitor};
+    use serde::ser::SerializeTuple;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(version: &Version, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut tuple = serializer.serialize_tuple(3)?;
+        tuple.serialize_element(&version.major)?;
+        tuple.serialize_element(&version.minor)?;
+        tuple.serialize_element(&version.patch)?;
+        tuple.end()
+    }
+
+    /// Accepts any sequence of exactly three non-negative integers. Sequences of a different
+    /// length are rejected with their observed length.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Version, D::Error> {
+        deserializer.deserialize_tuple(3, TupleVisitor)
+    }
+
+    struct TupleVisitor;
+
+    impl<'de> Visitor<'de> for TupleVisitor {
+        type Value = Version;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a sequence of 3 components")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut components = [0; 3];
+            let kinds = [
+                ComponentKind::Major,
+                ComponentKind::Minor,
+                ComponentKind::Patch,
+            ];
+            for (index, kind) in kinds.into_iter().enumerate() {
+                components[index] = seq
+                    .next_element_seed(Component(kind))?
+                    .ok_or_else(|| de::Error::invalid_length(index, &self))?;
+            }
+            let mut len = 3;
+            while seq.next_element::<IgnoredAny>()?.is_some() {
+                len += 1;
+            }
+            if len != 3 {
+                return Err(de::Error::invalid_length(len, &self));
+            }
+            let [major, minor, patch] = components;
+            Ok(Version::new(major, minor, patch))
+        }
+    }
+
+    /// [Version] with the tuple representation, to reuse serde's impls for wrapper types.
+    struct Tuple(Version);
+
+    impl Serialize for Tuple {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serialize(&self.0, serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Tuple {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserialize(deserializer).map(Tuple)
+        }
+    }
+
+    pub mod option {
+        //! The tuple representation for `Option<Version>`, with `None` as the format's null.
+
+        use super::Tuple;
+        use crate::version::Version;
+        use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+        pub fn serialize<S: Serializer>(
+            version: &Option<Version>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            version.map(Tuple).serialize(serializer)
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<Version>, D::Error> {
+            Ok(Option::<Tuple>::deserialize(deserializer)?.map(|tuple| tuple.0))
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    pub mod vec {
+        //! The tuple representation for `Vec<Version>`, a sequence of tuples.
+
+        use super::Tuple;
+        use crate::version::Version;
+        use alloc::vec::Vec;
+        use serde::{Deserialize, Deserializer, Serializer};
+
+        pub fn serialize<S: Serializer>(
+            versions: &[Version],
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serializer.collect_seq(versions.iter().copied().map(Tuple))
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Vec<Version>, D::Error> {
+            let tuples = Vec::<Tuple>::deserialize(deserializer)?;
+            Ok(tuples.into_iter().map(|tuple| tuple.0).collect())
+        }
+    }
+}
+
+pub mod version_as_packed_u64 {
+    //! Serializes a [Version] as a single `u64`, see [Version::to_packed_u64].
+    //!
+    //! Serialization fails for versions whose components don't fit into the packing,
+    //! deserialization fails for numbers that aren't a `u64`.
+
+    use crate::version::Version;
+    use serde::{ser, Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(version: &Version, serializer: S) -> Result<S::Ok, S::Error> {
+        let packed = version.to_packed_u64().map_err(ser::Error::custom)?;
+        packed.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Version, D::Error> {
+        u64::deserialize(deserializer).map(Version::from_packed_u64)
+    }
+}
+
+pub mod version_as_packed_u128 {
+    //! Serializes a [Version] as a single `u128`, see [Version::to_packed_u128].
+    //!
+    //! Serialization fails for versions whose components don't fit into the packing,
+    //! deserialization fails for numbers that aren't a `u128`.
+
+    use crate::version::Version;
+    use serde::{ser, Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(version: &Version, serializer: S) -> Result<S::Ok, S::Error> {
+        let packed = version.to_packed_u128().map_err(ser::Error::custom)?;
+        packed.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Version, D::Error> {
+        u128::deserialize(deserializer).map(Version::from_packed_u128)
+    }
+}
+
+pub mod version_as_str {
+    //! Serializes a [Version] as a string like `"1.2.3"` in every format.
+    //!
+    //! Deserialization only asks for a string, so unlike the default impl it works with formats
+    //! without `deserialize_any`, like `serde-json-core`, but doesn't accept the struct form.
+
+    use crate::serde_impls::parse_version;
+    use crate::version::Version;
+    use core::fmt;
+    use serde::de::{self, Visitor};
+    use serde::{Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(version: &Version, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(version)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Version, D::Error> {
+        deserializer.deserialize_str(StrVisitor)
+    }
+
+    struct StrVisitor;
+
+    impl<'de> Visitor<'de> for StrVisitor {
+        type Value = Version;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a version string")
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+            parse_version(value)
+        }
+    }
+}
+
+pub mod version_req_as_str {
+    //! Serializes a [VersionReq] as its comparator string, like `">=1.2.0, <2.0.0"`, in every
+    //! format.
+    //!
+    //! Deserialization only asks for a string, so unlike the default impl it works with formats
+    //! without `deserialize_any`, but doesn't accept the legacy struct form.
+
+    use crate::version_req::VersionReq;
+    use core::fmt;
+    use core::str::FromStr;
+    use serde::de::{self, Visitor};
+    use serde::{Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(req: &VersionReq, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(req)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<VersionReq, D::Error> {
+        deserializer.deserialize_str(StrVisitor)
+    }
+
+    struct StrVisitor;
+
+    impl<'de> Visitor<'de> for StrVisitor {
+        type Value = VersionReq;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a version requirement string")
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+            VersionReq::from_str(value).map_err(E::custom)
+        }
+    }
+}
+
+pub mod lenient {
+    //! Lenient deserialization of a [Version] from messy third party data.
+    //!
+    //! Accepted inputs:
+    //! * strings with one to three components, an optional `v` prefix and surrounding whitespace,
+    //!   like `" v1.2 "`; missing components are 0
+    //! * non-negative integers, taken as the major: `3` becomes `3.0.0`
+    //! * non-negative floats, taken as `major.minor`: `1.2` becomes `1.2.0`, needs the `alloc`
+    //!   feature
+    //!
+    //! Floats are interpreted through their shortest decimal representation, which has some
+    //! caveats: trailing zeros are lost, so the float `1.10` becomes `1.1.0`, and a float with more
+    //! significant digits than an `f64` can hold is rounded before splitting, so
+    //! `1.123456789012345678` becomes `1.1234567890123457.0`. Send strings whenever possible.
+    //!
+    //! Versions are serialized as strings like `"1.2.3"`.
+    //!
+    //! ```
+    //! # use fast_version_core::version::Version;
+    //! # use serde::Deserialize;
+    //! #[derive(Deserialize)]
+    //! struct Feed {
+    //!     #[serde(with = "fast_version_core::serde_helpers::lenient")]
+    //!     version: Version,
+    //! }
+    //!
+    //! let feed: Feed = serde_json::from_str(r#"{"version": " v1.2.3 "}"#).unwrap();
+    //! assert_eq!(feed.version, Version::new(1, 2, 3));
+    //! let feed: Feed = serde_json::from_str(r#"{"version": 1.2}"#).unwrap();
+    //! assert_eq!(feed.version, Version::new(1, 2, 0));
+    //! ```
+
+    use crate::version::{parse_short_version, Version};
+    #[cfg(feature = "alloc")]
+    use alloc::string::ToString;
+    use core::fmt;
+    use serde::de::{self, Visitor};
+    use serde::{Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(version: &Version, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(version)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Version, D::Error> {
+        deserializer.deserialize_any(LenientVisitor)
+    }
+
+    struct LenientVisitor;
+
+    impl<'de> Visitor<'de> for LenientVisitor {
+        type Value = Version;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a version string, integer or float")
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+            let trimmed = value.trim();
+            let without_prefix = trimmed.strip_prefix(['v', 'V']).unwrap_or(trimmed);
+            parse_short_version(without_prefix)
+                .ok_or_else(|| E::invalid_value(de::Unexpected::Str(value), &self))
+        }
+
+        fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+            Ok(Version::new(value, 0, 0))
+        }
+
+        fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+            let major = u64::try_from(value)
+                .map_err(|_| E::invalid_value(de::Unexpected::Signed(value), &self))?;
+            Ok(Version::new(major, 0, 0))
+        }
+
+        #[cfg(feature = "alloc")]
+        fn visit_f64<E: de::Error>(self, value: f64) -> Result<Self::Value, E> {
+            let invalid = || E::invalid_value(de::Unexpected::Float(value), &self);
+            if !value.is_finite() || value.is_sign_negative() {
+                return Err(invalid());
+            }
+            // The shortest representation that round trips, never in exponent notation.
+            let rendered = value.to_string();
+            let (major, minor) = rendered.split_once('.').unwrap_or((&rendered, "0"));
+            let major = major.parse().map_err(|_| invalid())?;
+            let minor = minor.parse().map_err(|_| invalid())?;
+            Ok(Version::new(major, minor, 0))
+        }
+    }
+}
+
+pub mod version_with_defaults {
+    //! The struct form of a [Version] with optional minor and patch, which default to 0.
+    //!
+    //! The major is still required and unknown fields are rejected. Strings like `"2.1"` with
+    //! one to three components are accepted as well. As either form is accepted, this only works
+    //! with self-describing formats like JSON.
+    //!
+    //! Versions are serialized like [Version] itself, as strings in human-readable formats.
+    //!
+    //! ```
+    //! # use fast_version_core::version::Version;
+    //! # use serde::Deserialize;
+    //! #[derive(Deserialize)]
+    //! struct Release {
+    //!     #[serde(with = "fast_version_core::serde_helpers::version_with_defaults")]
+    //!     version: Version,
+    //! }
+    //!
+    //! let release: Release = serde_json::from_str(r#"{"version": {"major": 2, "minor": 1}}"#).unwrap();
+    //! assert_eq!(release.version, Version::new(2, 1, 0));
+    //! let release: Release = serde_json::from_str(r#"{"version": "2.1"}"#).unwrap();
+    //! assert_eq!(release.version, Version::new(2, 1, 0));
+    //! ```
+
+    use crate::serde_impls::visit_fields;
+    use crate::version::{parse_short_version, Version};
+    use core::fmt;
+    use serde::de::{self, MapAccess, Visitor};
+    use serde::{Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(version: &Version, serializer: S) -> Result<S::Ok, S::Error> {
+        version.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Version, D::Error> {
+        deserializer.deserialize_any(DefaultsVisitor)
+    }
+
+    struct DefaultsVisitor;
+
+    impl<'de> Visitor<'de> for DefaultsVisitor {
+        type Value = Version;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a version with at least a major or a version string")
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+            parse_short_version(value)
+                .ok_or_else(|| E::invalid_value(de::Unexpected::Str(value), &self))
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+            let [major, minor, patch] = visit_fields(map)?;
+            Ok(Version::new(
+                major.ok_or_else(|| de::Error::missing_field("major"))?,
+                minor.unwrap_or(0),
+                patch.unwrap_or(0),
+            ))
+        }
+    }
+}
+
+pub mod option_version_empty_as_none {
+    //! An `Option<Version>` as a version string, where empty and whitespace-only strings as well
+    //! as null deserialize to `None`. Other strings are parsed like [Version::from_str], with
+    //! the same errors.
+    //!
+    //! `None` is serialized as null, `Some` as a string like `"1.2.3"`. Use the [empty] module
+    //! to serialize `None` as `""` instead.
+    //!
+    //! ```
+    //! # use fast_version_core::version::Version;
+    //! # use serde::{Deserialize, Serialize};
+    //! #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    //! struct Device {
+    //!     #[serde(with = "fast_version_core::serde_helpers::option_version_empty_as_none")]
+    //!     firmware: Option<Version>,
+    //! }
+    //!
+    //! let device: Device = serde_json::from_str(r#"{"firmware": " "}"#).unwrap();
+    //! assert_eq!(device.firmware, None);
+    //! assert_eq!(serde_json::to_string(&device).unwrap(), r#"{"firmware":null}"#);
+    //!
+    //! let device: Device = serde_json::from_str(r#"{"firmware": "1.2.3"}"#).unwrap();
+    //! assert_eq!(device.firmware, Some(Version::new(1, 2, 3)));
+    //! ```
+    //!
+    //! [Version::from_str]: core::str::FromStr::from_str
+
+    use crate::version::Version;
+    use core::fmt;
+    use core::str::FromStr;
+    use serde::de::{self, Visitor};
+    use serde::{Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        version: &Option<Version>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match version {
+            Some(version) => serializer.serialize_some(&AsStr(version)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Version>, D::Error> {
+        deserializer.deserialize_option(EmptyAsNoneVisitor)
+    }
+
+    /// A [Version] serialized as string.
+    struct AsStr<'a>(&'a Version);
+
+    impl Serialize for AsStr<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_str(self.0)
+        }
+    }
+
+    struct EmptyAsNoneVisitor;
+
+    impl<'de> Visitor<'de> for EmptyAsNoneVisitor {
+        type Value = Option<Version>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a version string, an empty string or null")
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+            if value.trim().is_empty() {
+                return Ok(None);
+            }
+            Version::from_str(value).map(Some).map_err(E::custom)
+        }
+
+        fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_some<D: Deserializer<'de>>(
+            self,
+            deserializer: D,
+        ) -> Result<Self::Value, D::Error> {
+            deserializer.deserialize_str(self)
+        }
+    }
+
+    pub mod empty {
+        //! Like [option_version_empty_as_none](super), but `None` is serialized as `""`, for
+        //! APIs that expect a string in every case. Formats that aren't human-readable only
+        //! accept the string, not null.
+
+        use super::EmptyAsNoneVisitor;
+        use crate::version::Version;
+        use serde::{Deserializer, Serializer};
+
+        pub fn serialize<S: Serializer>(
+            version: &Option<Version>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            match version {
+                Some(version) => serializer.collect_str(version),
+                None => serializer.serialize_str(""),
+            }
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<Version>, D::Error> {
+            if deserializer.is_human_readable() {
+                super::deserialize(deserializer)
+            } else {
+                deserializer.deserialize_str(EmptyAsNoneVisitor)
+            }
+        }
+    }
+}
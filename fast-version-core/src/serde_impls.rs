@@ -0,0 +1,561 @@
+//! Hand written serde implementations.
+
+use crate::bounded::VersionBounded;
+use crate::encoding;
+use crate::epoch_version::EpochVersion;
+use crate::full_version::FullVersion;
+#[cfg(feature = "alloc")]
+use crate::release_range::ReleaseRange;
+use crate::version::{ComponentKind, Version};
+use crate::version4::Version4;
+use crate::version_or_req::VersionOrReq;
+use crate::version_req::VersionReq;
+#[cfg(feature = "alloc")]
+use crate::version_req_set::VersionReqSet;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
+use core::str::FromStr;
+use serde::de::value::{MapAccessDeserializer, SeqAccessDeserializer};
+use serde::de::{self, DeserializeSeed, MapAccess, SeqAccess, Visitor};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+const FIELDS: &[&str] = &["major", "minor", "patch"];
+
+/// Human-readable formats get the string form, like `"1.2.3"`. Other formats get the struct with
+/// the fields `major`, `minor` and `patch`, which binary formats like postcard encode as three
+/// integers.
+impl Serialize for Version {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            return serializer.collect_str(self);
+        }
+        let mut state = serializer.serialize_struct("Version", FIELDS.len())?;
+        state.serialize_field("major", &self.major)?;
+        state.serialize_field("minor", &self.minor)?;
+        state.serialize_field("patch", &self.patch)?;
+        state.end()
+    }
+}
+
+/// Human-readable formats accept the string form and, for data written by older versions, the
+/// struct form. Struct deserialization is strict: unknown fields are rejected with their name,
+/// missing fields are reported individually and negative numbers or floats get a message naming
+/// the component.
+impl<'de> Deserialize<'de> for Version {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(VersionVisitor)
+        } else {
+            deserializer.deserialize_struct("Version", FIELDS, VersionVisitor)
+        }
+    }
+}
+
+struct VersionVisitor;
+
+impl<'de> Visitor<'de> for VersionVisitor {
+    type Value = Version;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a version string or a version with the fields major, minor and patch")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        parse_version(value)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let major = seq
+            .next_element_seed(Component(ComponentKind::Major))?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let minor = seq
+            .next_element_seed(Component(ComponentKind::Minor))?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        let patch = seq
+            .next_element_seed(Component(ComponentKind::Patch))?
+            .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+        Ok(Version::new(major, minor, patch))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        let [major, minor, patch] = visit_fields(map)?;
+        Ok(Version::new(
+            major.ok_or_else(|| de::Error::missing_field("major"))?,
+            minor.ok_or_else(|| de::Error::missing_field("minor"))?,
+            patch.ok_or_else(|| de::Error::missing_field("patch"))?,
+        ))
+    }
+}
+
+/// Parses the string form, naming the input in the error.
+pub(crate) fn parse_version<E: de::Error>(value: &str) -> Result<Version, E> {
+    Version::from_str(value)
+        .map_err(|error| E::custom(format_args!("invalid version `{value}`: {error}")))
+}
+
+/// The components present in the struct form, rejecting unknown and duplicate fields.
+pub(crate) fn visit_fields<'de, A: MapAccess<'de>>(
+    mut map: A,
+) -> Result<[Option<u64>; 3], A::Error> {
+    let mut components = [None; 3];
+    while let Some(kind) = map.next_key::<Field>()? {
+        let index = kind.0 as usize;
+        if components[index].is_some() {
+            return Err(de::Error::duplicate_field(FIELDS[index]));
+        }
+        components[index] = Some(map.next_value_seed(Component(kind.0))?);
+    }
+    Ok(components)
+}
+
+/// Field name of the struct form.
+struct Field(ComponentKind);
+
+impl<'de> Deserialize<'de> for Field {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FieldVisitor;
+
+        impl<'de> Visitor<'de> for FieldVisitor {
+            type Value = Field;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("`major`, `minor` or `patch`")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                match value {
+                    "major" => Ok(Field(ComponentKind::Major)),
+                    "minor" => Ok(Field(ComponentKind::Minor)),
+                    "patch" => Ok(Field(ComponentKind::Patch)),
+                    _ => Err(de::Error::unknown_field(value, FIELDS)),
+                }
+            }
+        }
+
+        deserializer.deserialize_identifier(FieldVisitor)
+    }
+}
+
+/// Deserializes a single component, naming it in the error messages.
+pub(crate) struct Component(pub(crate) ComponentKind);
+
+impl<'de> DeserializeSeed<'de> for Component {
+    type Value = u64;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_u64(self)
+    }
+}
+
+impl<'de> Visitor<'de> for Component {
+    type Value = u64;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a non-negative integer as {}", self.0)
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        Ok(value)
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        u64::try_from(value).map_err(|_| {
+            E::custom(format_args!(
+                "{} must not be negative, found {}",
+                self.0, value
+            ))
+        })
+    }
+
+    fn visit_u128<E: de::Error>(self, value: u128) -> Result<Self::Value, E> {
+        u64::try_from(value)
+            .map_err(|_| E::custom(format_args!("{} {} is too large", self.0, value)))
+    }
+
+    fn visit_i128<E: de::Error>(self, value: i128) -> Result<Self::Value, E> {
+        if value < 0 {
+            return Err(E::custom(format_args!(
+                "{} must not be negative, found {}",
+                self.0, value
+            )));
+        }
+        self.visit_u128(value as u128)
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<Self::Value, E> {
+        Err(E::custom(format_args!(
+            "{} must be an integer, found floating point {}",
+            self.0, value
+        )))
+    }
+}
+
+impl<const MIN: u128, const MAX: u128> Serialize for VersionBounded<MIN, MAX> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.get().serialize(serializer)
+    }
+}
+
+/// Deserializes like [Version] and fails for versions outside the bounds.
+impl<'de, const MIN: u128, const MAX: u128> Deserialize<'de> for VersionBounded<MIN, MAX> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Self::new(Version::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+const LEGACY_REQ_FIELDS: &[&str] = &[
+    "major_lower",
+    "minor_lower",
+    "patch_lower",
+    "major_higher",
+    "minor_higher",
+    "patch_higher",
+];
+
+/// Human-readable formats get the comparator string, like `">=1.2.0, <2.0.0"`. Other formats get
+/// the [encoding] as bytes, which also holds the bounds of requirements matching
+/// [prereleases](VersionReq#prereleases).
+impl Serialize for VersionReq {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            return serializer.collect_str(self);
+        }
+        let mut buffer = [0; encoding::MAX_LEN];
+        let len = self.encode_into(&mut buffer);
+        serializer.serialize_bytes(&buffer[..len])
+    }
+}
+
+/// Human-readable formats accept the comparator string and, for data written by older versions,
+/// the struct with the six fields `major_lower` to `patch_higher` holding the inclusive bounds.
+impl<'de> Deserialize<'de> for VersionReq {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(VersionReqVisitor)
+        } else {
+            deserializer.deserialize_bytes(VersionReqVisitor)
+        }
+    }
+}
+
+struct VersionReqVisitor;
+
+impl<'de> Visitor<'de> for VersionReqVisitor {
+    type Value = VersionReq;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a version requirement")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        VersionReq::from_str(value).map_err(E::custom)
+    }
+
+    fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<Self::Value, E> {
+        let (req, len) = VersionReq::decode(value).map_err(E::custom)?;
+        if len != value.len() {
+            return Err(E::invalid_length(value.len(), &self));
+        }
+        Ok(req)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bounds = [0; 6];
+        for (index, bound) in bounds.iter_mut().enumerate() {
+            *bound = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(index, &self))?;
+        }
+        Ok(legacy_req(bounds))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut bounds = [None; 6];
+        while let Some(LegacyField(index)) = map.next_key()? {
+            if bounds[index].is_some() {
+                return Err(de::Error::duplicate_field(LEGACY_REQ_FIELDS[index]));
+            }
+            bounds[index] = Some(map.next_value()?);
+        }
+        let mut complete = [0; 6];
+        for (index, bound) in bounds.into_iter().enumerate() {
+            complete[index] =
+                bound.ok_or_else(|| de::Error::missing_field(LEGACY_REQ_FIELDS[index]))?;
+        }
+        Ok(legacy_req(complete))
+    }
+}
+
+/// Field name of the legacy struct form, as index into [LEGACY_REQ_FIELDS].
+struct LegacyField(usize);
+
+impl<'de> Deserialize<'de> for LegacyField {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct LegacyFieldVisitor;
+
+        impl<'de> Visitor<'de> for LegacyFieldVisitor {
+            type Value = LegacyField;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a bound like `major_lower`")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                LEGACY_REQ_FIELDS
+                    .iter()
+                    .position(|field| *field == value)
+                    .map(LegacyField)
+                    .ok_or_else(|| de::Error::unknown_field(value, LEGACY_REQ_FIELDS))
+            }
+        }
+
+        deserializer.deserialize_identifier(LegacyFieldVisitor)
+    }
+}
+
+/// Inclusive bounds in the order of [LEGACY_REQ_FIELDS].
+fn legacy_req(
+    [major_lower, minor_lower, patch_lower, major_higher, minor_higher, patch_higher]: [u64; 6],
+) -> VersionReq {
+    VersionReq::new_bounded(
+        Some(Version::new(major_lower, minor_lower, patch_lower)),
+        Some(Version::new(major_higher, minor_higher, patch_higher)),
+    )
+}
+
+/// Human-readable formats get the `||` separated string, like `">=1.2.0, <2.0.0 || >=3.0.0"`.
+/// Other formats get a length-prefixed sequence of the ranges in their binary form.
+#[cfg(feature = "alloc")]
+impl Serialize for VersionReqSet {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            return serializer.collect_str(self);
+        }
+        serializer.collect_seq(self.ranges().iter().map(ReleaseRange::to_req))
+    }
+}
+
+/// Ranges read from binary formats may overlap or come in any order, they are merged like
+/// [VersionReqSet::from_unsorted] does.
+#[cfg(feature = "alloc")]
+impl<'de> Deserialize<'de> for VersionReqSet {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(VersionReqSetVisitor)
+        } else {
+            deserializer.deserialize_seq(VersionReqSetVisitor)
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+struct VersionReqSetVisitor;
+
+#[cfg(feature = "alloc")]
+impl<'de> Visitor<'de> for VersionReqSetVisitor {
+    type Value = VersionReqSet;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a set of version requirements")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        VersionReqSet::from_str(value).map_err(E::custom)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut ranges = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(64));
+        while let Some(range) = seq.next_element()? {
+            ranges.push(range);
+        }
+        Ok(VersionReqSet::from_unsorted(ranges))
+    }
+}
+
+impl Serialize for VersionOrReq {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for VersionOrReq {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct VersionOrReqVisitor;
+
+        impl<'de> Visitor<'de> for VersionOrReqVisitor {
+            type Value = VersionOrReq;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a version or a version requirement")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                VersionOrReq::from_str(value).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(VersionOrReqVisitor)
+    }
+}
+
+/// Serialized as string, like `1.2.3-alpha.1+build.5`.
+impl Serialize for FullVersion {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for FullVersion {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FullVersionVisitor;
+
+        impl<'de> Visitor<'de> for FullVersionVisitor {
+            type Value = FullVersion;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a version with optional prerelease and build metadata")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                FullVersion::from_str(value).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(FullVersionVisitor)
+    }
+}
+
+/// The struct form of [EpochVersion], as the derive wrote it before the string form.
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "EpochVersion")]
+struct EpochVersionFields {
+    epoch: u32,
+    version: Version,
+}
+
+impl From<EpochVersionFields> for EpochVersion {
+    fn from(EpochVersionFields { epoch, version }: EpochVersionFields) -> Self {
+        EpochVersion::new(epoch, version)
+    }
+}
+
+/// Human-readable formats get the string form, like `"2:1.4.0"`. Other formats get the struct
+/// with the fields `epoch` and `version`.
+impl Serialize for EpochVersion {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            return serializer.collect_str(self);
+        }
+        EpochVersionFields {
+            epoch: self.epoch,
+            version: self.version,
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Human-readable formats accept the string form and, for data written by older versions, the
+/// struct form.
+impl<'de> Deserialize<'de> for EpochVersion {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(StrOrFields::<EpochVersion, EpochVersionFields>::new(
+                "an epoch version string or a version with the fields epoch and version",
+            ))
+        } else {
+            EpochVersionFields::deserialize(deserializer).map(EpochVersion::from)
+        }
+    }
+}
+
+/// The struct form of [Version4], as the derive wrote it before the string form.
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Version4")]
+struct Version4Fields {
+    major: u64,
+    minor: u64,
+    build: u64,
+    revision: u64,
+}
+
+impl From<Version4Fields> for Version4 {
+    fn from(fields: Version4Fields) -> Self {
+        Version4::new(fields.major, fields.minor, fields.build, fields.revision)
+    }
+}
+
+/// Human-readable formats get the string form, like `"10.0.19041.1288"`. Other formats get the
+/// struct with the fields `major`, `minor`, `build` and `revision`.
+impl Serialize for Version4 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            return serializer.collect_str(self);
+        }
+        Version4Fields {
+            major: self.major,
+            minor: self.minor,
+            build: self.build,
+            revision: self.revision,
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Human-readable formats accept the string form and, for data written by older versions, the
+/// struct form.
+impl<'de> Deserialize<'de> for Version4 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(StrOrFields::<Version4, Version4Fields>::new(
+                "a version string with four components or a version with the fields major, \
+                    minor, build and revision",
+            ))
+        } else {
+            Version4Fields::deserialize(deserializer).map(Version4::from)
+        }
+    }
+}
+
+/// Parses the string form of `T` and hands maps and sequences to its derived struct form `F`.
+struct StrOrFields<T, F> {
+    expecting: &'static str,
+    types: PhantomData<(T, F)>,
+}
+
+impl<T, F> StrOrFields<T, F> {
+    fn new(expecting: &'static str) -> Self {
+        StrOrFields {
+            expecting,
+            types: PhantomData,
+        }
+    }
+}
+
+impl<'de, T, F> Visitor<'de> for StrOrFields<T, F>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+    F: Deserialize<'de> + Into<T>,
+{
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(self.expecting)
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        T::from_str(value)
+            .map_err(|error| E::custom(format_args!("invalid version `{value}`: {error}")))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+        F::deserialize(SeqAccessDeserializer::new(seq)).map(Into::into)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        F::deserialize(MapAccessDeserializer::new(map)).map(Into::into)
+    }
+}
@@ -0,0 +1,132 @@
+//! Extremes and ordering checks for slices of versions.
+//!
+//! The slice functions are plain functions, so they can take faster paths, like SIMD with the
+//! `nightly` feature, without changing their signatures. The `_const` variants take arrays and
+//! work in const context, e.g. to check a compile-time table:
+//! ```
+//! # use fast_version_core::{slice, version::Version};
+//! const SUPPORTED: [Version; 3] = [
+//!     Version::new(1, 0, 0),
+//!     Version::new(1, 4, 2),
+//!     Version::new(2, 0, 0),
+//! ];
+//! const _: () = assert!(slice::is_sorted_ascending_const(SUPPORTED));
+//! const LATEST: Option<Version> = slice::max_const(SUPPORTED);
+//!
+//! assert_eq!(LATEST, Some(Version::new(2, 0, 0)));
+//! ```
+
+use crate::version::Version;
+
+/// The largest version, the last one if several are equal, like [Iterator::max].
+/// ```
+/// # use fast_version_core::{slice, version::Version};
+/// let versions = [Version::new(1, 2, 3), Version::new(1, 10, 0), Version::new(1, 9, 9)];
+///
+/// assert_eq!(slice::max(&versions), Some(&Version::new(1, 10, 0)));
+/// assert_eq!(slice::max(&[]), None);
+/// ```
+#[inline]
+pub fn max(versions: &[Version]) -> Option<&Version> {
+    max_index(versions).map(|index| &versions[index])
+}
+
+/// The smallest version, the first one if several are equal, like [Iterator::min].
+#[inline]
+pub fn min(versions: &[Version]) -> Option<&Version> {
+    min_index(versions).map(|index| &versions[index])
+}
+
+/// The smallest and the largest version in a single pass, the same as [min] and [max].
+/// ```
+/// # use fast_version_core::{slice, version::Version};
+/// let versions = [Version::new(0, 3, 0), Version::new(0, 1, 5), Version::new(0, 2, 0)];
+///
+/// assert_eq!(
+///     slice::minmax(&versions),
+///     Some((&Version::new(0, 1, 5), &Version::new(0, 3, 0)))
+/// );
+/// ```
+#[inline]
+pub fn minmax(versions: &[Version]) -> Option<(&Version, &Version)> {
+    minmax_indices(versions).map(|(min, max)| (&versions[min], &versions[max]))
+}
+
+/// Checks wether every version is less than or equal to the next one. True for empty slices
+/// and single versions.
+#[inline]
+pub fn is_sorted_ascending(versions: &[Version]) -> bool {
+    is_sorted(versions)
+}
+
+/// Const variant of [max].
+pub const fn max_const<const N: usize>(versions: [Version; N]) -> Option<Version> {
+    match max_index(&versions) {
+        Some(index) => Some(versions[index]),
+        None => None,
+    }
+}
+
+/// Const variant of [min].
+pub const fn min_const<const N: usize>(versions: [Version; N]) -> Option<Version> {
+    match min_index(&versions) {
+        Some(index) => Some(versions[index]),
+        None => None,
+    }
+}
+
+/// Const variant of [minmax].
+pub const fn minmax_const<const N: usize>(versions: [Version; N]) -> Option<(Version, Version)> {
+    match minmax_indices(&versions) {
+        Some((min, max)) => Some((versions[min], versions[max])),
+        None => None,
+    }
+}
+
+/// Const variant of [is_sorted_ascending].
+pub const fn is_sorted_ascending_const<const N: usize>(versions: [Version; N]) -> bool {
+    is_sorted(&versions)
+}
+
+const fn max_index(versions: &[Version]) -> Option<usize> {
+    match minmax_indices(versions) {
+        Some((_, max)) => Some(max),
+        None => None,
+    }
+}
+
+const fn min_index(versions: &[Version]) -> Option<usize> {
+    match minmax_indices(versions) {
+        Some((min, _)) => Some(min),
+        None => None,
+    }
+}
+
+const fn minmax_indices(versions: &[Version]) -> Option<(usize, usize)> {
+    if versions.is_empty() {
+        return None;
+    }
+    let (mut min, mut max) = (0, 0);
+    let mut index = 1;
+    while index < versions.len() {
+        if versions[index].const_cmp(&versions[min]).is_lt() {
+            min = index;
+        }
+        if versions[index].const_cmp(&versions[max]).is_ge() {
+            max = index;
+        }
+        index += 1;
+    }
+    Some((min, max))
+}
+
+const fn is_sorted(versions: &[Version]) -> bool {
+    let mut index = 1;
+    while index < versions.len() {
+        if versions[index - 1].const_cmp(&versions[index]).is_gt() {
+            return false;
+        }
+        index += 1;
+    }
+    true
+}
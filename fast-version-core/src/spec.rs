@@ -0,0 +1,89 @@
+//! Package specs like `serde@^1.0`, as used on command lines and in lockfile-like formats.
+
+use crate::version_req::{VersionReq, VersionReqParseError};
+use alloc::string::{String, ToString};
+use core::fmt;
+use core::str::FromStr;
+use thiserror::Error;
+
+/// A package name with an optional requirement, separated by `@`.
+///
+/// A bare name requires [VersionReq::STAR]. Names consist of ASCII letters, digits, `-` and `_`.
+/// ## Example:
+/// ```
+/// # use fast_version_core::{spec::PackageSpec, version::Version};
+/// use core::str::FromStr;
+///
+/// let spec = PackageSpec::from_str("tokio@1.28.2").unwrap();
+/// assert_eq!(spec.name, "tokio");
+/// assert!(spec.req.matches(&Version::new(1, 28, 2)));
+///
+/// let spec = PackageSpec::from_str("serde").unwrap();
+/// assert_eq!(spec.to_string(), "serde");
+/// ```
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct PackageSpec {
+    pub name: String,
+    pub req: VersionReq,
+}
+
+/// Returned when parsing a [PackageSpec] fails. Positions are byte offsets into the input.
+#[derive(Error, Debug)]
+pub enum PackageSpecParseError {
+    #[error("Invalid package name, unexpected character at position {position}")]
+    InvalidName { position: usize },
+    #[error("Invalid requirement starting at position {position}")]
+    InvalidRequirement {
+        position: usize,
+        source: VersionReqParseError,
+    },
+}
+
+impl FromStr for PackageSpec {
+    type Err = PackageSpecParseError;
+    /// Splits at the first `@`. A second `@` is reported as part of an invalid name, as it most
+    /// likely is a typo like `user@example.com@1.0`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, req) = match s.split_once('@') {
+            Some((name, req)) => (name, Some(req)),
+            None => (s, None),
+        };
+        if name.is_empty() {
+            return Err(PackageSpecParseError::InvalidName { position: 0 });
+        }
+        if let Some(position) =
+            name.find(|c: char| !(c.is_ascii_alphanumeric() || c == '-' || c == '_'))
+        {
+            return Err(PackageSpecParseError::InvalidName { position });
+        }
+        let req = match req {
+            Some(req) if req.contains('@') => {
+                return Err(PackageSpecParseError::InvalidName {
+                    position: name.len(),
+                })
+            }
+            Some(req) => VersionReq::from_str(req).map_err(|source| {
+                PackageSpecParseError::InvalidRequirement {
+                    position: name.len() + 1,
+                    source,
+                }
+            })?,
+            None => VersionReq::STAR,
+        };
+        Ok(Self {
+            name: name.to_string(),
+            req,
+        })
+    }
+}
+
+/// Prints the bare name for [VersionReq::STAR] and `name@requirement` otherwise.
+impl fmt::Display for PackageSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.req == VersionReq::STAR {
+            f.write_str(&self.name)
+        } else {
+            write!(f, "{}@{}", self.name, self.req)
+        }
+    }
+}
@@ -0,0 +1,52 @@
+//! [proptest] strategies for versions and requirements, behind the `proptest` feature.
+//!
+//! Components favour small numbers and `u64::MAX`, where most edge cases live.
+//! ```
+//! # use fast_version_core::strategies;
+//! use proptest::prelude::*;
+//!
+//! proptest!(|(req in strategies::version_req(), version in strategies::version())| {
+//!     prop_assert_eq!(req.matches(&version), req.intersection(&req).matches(&version));
+//! });
+//! ```
+
+use crate::version::Version;
+use crate::version_req::VersionReq;
+use proptest::prelude::*;
+
+/// A single component, shrinking toward 0.
+pub fn component() -> impl Strategy<Value = u64> {
+    prop_oneof![0..16u64, Just(u64::MAX), Just(u64::MAX - 1), any::<u64>()]
+}
+
+/// Any version, shrinking toward `0.0.0`.
+pub fn version() -> BoxedStrategy<Version> {
+    (component(), component(), component())
+        .prop_map(|(major, minor, patch)| Version::new(major, minor, patch))
+        .boxed()
+}
+
+/// Non-empty requirements of every shape, shrinking toward [VersionReq::STAR].
+pub fn version_req() -> BoxedStrategy<VersionReq> {
+    prop_oneof![
+        Just(VersionReq::STAR),
+        version().prop_map(VersionReq::at_least),
+        version().prop_map(VersionReq::at_most),
+        version().prop_map(VersionReq::exact),
+        (version(), version()).prop_map(|(first, second)| VersionReq::between(
+            Version::const_min(first, second),
+            Version::const_max(first, second),
+        )),
+    ]
+    .boxed()
+}
+
+/// Like [version_req], but also yields [VersionReq::EMPTY] and requirements with crossed bounds.
+pub fn maybe_empty() -> BoxedStrategy<VersionReq> {
+    prop_oneof![
+        version_req(),
+        Just(VersionReq::EMPTY),
+        (version(), version()).prop_map(|(lower, upper)| VersionReq::between(lower, upper)),
+    ]
+    .boxed()
+}
@@ -0,0 +1,255 @@
+//! Rejecting clients whose version isn't supported, with errors ready for logs and responses.
+//!
+//! ```
+//! # use fast_version_core::{support::MinSupported, version::Version};
+//! const MIN_CLIENT: MinSupported = MinSupported::new(Version::new(2, 4, 0));
+//!
+//! assert!(MIN_CLIENT.check(&Version::new(2, 5, 1)).is_ok());
+//!
+//! let error = MIN_CLIENT.check(&Version::new(2, 1, 7)).unwrap_err();
+//! assert_eq!(
+//!     error.to_string(),
+//!     "Client version 2.1.7 is older than the minimum supported version 2.4.0 (minor behind)"
+//! );
+//! ```
+
+use crate::bump::VersionDiff;
+use crate::release_range::ReleaseRange;
+use crate::version::Version;
+use crate::version_req::VersionReq;
+use core::fmt;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Accepts every client version at or above `min`.
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone)]
+pub struct MinSupported {
+    pub min: Version,
+}
+
+/// Accepts every client version from `min` up to and including `max`, for deprecation windows.
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone)]
+pub struct SupportWindow {
+    pub min: Version,
+    pub max: Version,
+}
+
+/// Returned for a client version outside the supported ones.
+#[derive(Error, PartialEq, Eq, Hash, Debug, Copy, Clone)]
+pub enum UnsupportedVersion {
+    /// The client is older than the minimum, returned by [MinSupported] and [SupportWindow].
+    #[error(
+        "Client version {client} is older than the minimum supported version {min} ({diff} behind)"
+    )]
+    TooOld {
+        client: Version,
+        min: Version,
+        diff: VersionDiff,
+    },
+    /// The client is newer than the maximum, only returned by [SupportWindow].
+    #[error(
+        "Client version {client} is newer than the maximum supported version {max} ({diff} ahead)"
+    )]
+    TooNew {
+        client: Version,
+        max: Version,
+        diff: VersionDiff,
+    },
+}
+
+impl UnsupportedVersion {
+    /// The rejected client version.
+    pub const fn client(&self) -> Version {
+        match self {
+            UnsupportedVersion::TooOld { client, .. }
+            | UnsupportedVersion::TooNew { client, .. } => *client,
+        }
+    }
+}
+
+impl MinSupported {
+    /// Create a new guard accepting `min` and everything above.
+    #[inline]
+    pub const fn new(min: Version) -> Self {
+        Self { min }
+    }
+
+    /// Accepts the client version if it is at least the minimum.
+    pub const fn check(&self, client: &Version) -> Result<(), UnsupportedVersion> {
+        if client.const_cmp(&self.min).is_lt() {
+            return Err(UnsupportedVersion::TooOld {
+                client: *client,
+                min: self.min,
+                diff: client.diff(&self.min),
+            });
+        }
+        Ok(())
+    }
+
+    /// The requirement matching exactly the supported versions.
+    pub const fn to_req(&self) -> VersionReq {
+        VersionReq::at_least(self.min)
+    }
+}
+
+impl SupportWindow {
+    /// Create a new guard accepting `min..=max`.
+    ///
+    /// ## Panics
+    /// Panics, or fails to compile in const evaluation, if `min` is larger than `max`.
+    pub const fn new(min: Version, max: Version) -> Self {
+        assert!(min.const_cmp(&max).is_le(), "min is larger than max");
+        Self { min, max }
+    }
+
+    /// Accepts the client version if it lies within the window.
+    /// ```
+    /// # use fast_version_core::{support::SupportWindow, version::Version};
+    /// let window = SupportWindow::new(Version::new(1, 0, 0), Version::new(1, 9, 0));
+    ///
+    /// assert!(window.check(&Version::new(1, 9, 0)).is_ok());
+    /// assert!(window.check(&Version::new(1, 9, 1)).is_err());
+    /// ```
+    pub const fn check(&self, client: &Version) -> Result<(), UnsupportedVersion> {
+        if let Err(error) = MinSupported::new(self.min).check(client) {
+            return Err(error);
+        }
+        if client.const_cmp(&self.max).is_gt() {
+            return Err(UnsupportedVersion::TooNew {
+                client: *client,
+                max: self.max,
+                diff: client.diff(&self.max),
+            });
+        }
+        Ok(())
+    }
+
+    /// The requirement matching exactly the supported versions.
+    pub const fn to_req(&self) -> VersionReq {
+        VersionReq::between(self.min, self.max)
+    }
+}
+
+/// A support matrix of labeled ranges, like it's kept in a config file.
+///
+/// Everything below `eol_below` is end of life, no matter what the requirements say. Versions
+/// that aren't below it and match neither requirement, e.g. ones newer than any release, are
+/// [SupportStatus::Unknown].
+/// ```
+/// # use fast_version_core::{support::{SupportPolicy, SupportStatus}, version::Version, version_req::VersionReq};
+/// let policy = SupportPolicy {
+///     supported: VersionReq::parse(">=3").unwrap(),
+///     deprecated: VersionReq::parse(">=2, <3").unwrap(),
+///     eol_below: Version::new(2, 0, 0),
+/// };
+/// assert!(policy.validate().is_ok());
+///
+/// assert_eq!(policy.classify(&Version::new(3, 1, 0)), SupportStatus::Supported);
+/// assert_eq!(policy.classify(&Version::new(2, 9, 9)), SupportStatus::Deprecated);
+/// assert_eq!(policy.classify(&Version::new(1, 9, 9)), SupportStatus::EndOfLife);
+/// assert_eq!(
+///     policy.to_string(),
+///     "supported: >=3.0.0; deprecated: >=2.0.0, <3.0.0; end of life: <2.0.0"
+/// );
+/// ```
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SupportPolicy {
+    pub supported: VersionReq,
+    pub deprecated: VersionReq,
+    pub eol_below: Version,
+}
+
+/// The status of a version under a [SupportPolicy].
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SupportStatus {
+    Supported,
+    Deprecated,
+    EndOfLife,
+    /// Neither end of life nor matched by one of the requirements.
+    Unknown,
+}
+
+/// Returned by [SupportPolicy::validate] for the first two labels sharing releases, with the
+/// releases they share.
+#[derive(Error, PartialEq, Eq, Hash, Debug, Copy, Clone)]
+pub enum SupportPolicyError {
+    #[error("Supported and deprecated versions overlap in {overlap}")]
+    SupportedDeprecated { overlap: ReleaseRange },
+    #[error("Supported versions are end of life in {overlap}")]
+    SupportedEndOfLife { overlap: ReleaseRange },
+    #[error("Deprecated versions are end of life in {overlap}")]
+    DeprecatedEndOfLife { overlap: ReleaseRange },
+}
+
+impl SupportPolicy {
+    /// The status of the version. End of life takes precedence, then supported, then
+    /// deprecated, so an invalid policy still gives a well defined answer.
+    pub const fn classify(&self, version: &Version) -> SupportStatus {
+        if version.const_cmp(&self.eol_below).is_lt() {
+            SupportStatus::EndOfLife
+        } else if self.supported.matches(version) {
+            SupportStatus::Supported
+        } else if self.deprecated.matches(version) {
+            SupportStatus::Deprecated
+        } else {
+            SupportStatus::Unknown
+        }
+    }
+
+    /// Checks that no version carries two labels. Only releases are
+    /// [classified](SupportPolicy::classify), so labels may share prereleases.
+    /// ```
+    /// # use fast_version_core::{release_range::ReleaseRange, support::{SupportPolicy, SupportPolicyError}, version::Version, version_req::VersionReq};
+    /// let policy = SupportPolicy {
+    ///     supported: VersionReq::parse(">=2.5").unwrap(),
+    ///     deprecated: VersionReq::parse(">=2, <3").unwrap(),
+    ///     eol_below: Version::new(2, 0, 0),
+    /// };
+    ///
+    /// assert_eq!(
+    ///     policy.validate(),
+    ///     Err(SupportPolicyError::SupportedDeprecated {
+    ///         overlap: ReleaseRange::of(&VersionReq::parse(">=2.5, <3").unwrap()).unwrap(),
+    ///     })
+    /// );
+    /// ```
+    pub const fn validate(&self) -> Result<(), SupportPolicyError> {
+        if let Some(overlap) = ReleaseRange::of(&self.supported.intersection(&self.deprecated)) {
+            return Err(SupportPolicyError::SupportedDeprecated { overlap });
+        }
+        let end_of_life = VersionReq::less_than(self.eol_below);
+        if let Some(overlap) = ReleaseRange::of(&self.supported.intersection(&end_of_life)) {
+            return Err(SupportPolicyError::SupportedEndOfLife { overlap });
+        }
+        if let Some(overlap) = ReleaseRange::of(&self.deprecated.intersection(&end_of_life)) {
+            return Err(SupportPolicyError::DeprecatedEndOfLife { overlap });
+        }
+        Ok(())
+    }
+}
+
+/// Prints the labels with their requirements, like
+/// `supported: >=3.0.0; deprecated: >=2.0.0, <3.0.0; end of life: <2.0.0`.
+impl fmt::Display for SupportPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "supported: {}; deprecated: {}; end of life: <{}",
+            self.supported, self.deprecated, self.eol_below
+        )
+    }
+}
+
+impl fmt::Display for SupportStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            SupportStatus::Supported => "supported",
+            SupportStatus::Deprecated => "deprecated",
+            SupportStatus::EndOfLife => "end of life",
+            SupportStatus::Unknown => "unknown",
+        })
+    }
+}
@@ -0,0 +1,152 @@
+//! Versions from git tag names, like the output of `git tag --list`. [collect] and [TagScan] are
+//! behind the `alloc` feature.
+//!
+//! ```
+//! # use fast_version_core::{tags, version::Version, version_req::VersionReq};
+//! let scan = tags::collect(["v1.2.0", "release-1.10.0", "1.2.0", "nightly", "v2.0.0-rc.1"]);
+//!
+//! let versions: Vec<String> = scan.versions.iter().map(|v| v.to_string()).collect();
+//! assert_eq!(versions, ["2.0.0-rc.1", "1.10.0", "1.2.0"]);
+//! assert_eq!(scan.skipped.len(), 1);
+//! assert_eq!(scan.latest_stable().unwrap().version, Version::new(1, 10, 0));
+//! assert_eq!(
+//!     scan.latest_matching(&VersionReq::parse("^1").unwrap()).unwrap().version,
+//!     Version::new(1, 10, 0)
+//! );
+//! ```
+
+#[cfg(feature = "alloc")]
+use crate::channel::Channel;
+use crate::full_version::{FullVersion, FullVersionParseError, IdentifierError};
+#[cfg(feature = "alloc")]
+use crate::matcher::VersionMatcher;
+use crate::version::{Version, VersionParseError};
+#[cfg(feature = "alloc")]
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::str::FromStr;
+use thiserror::Error;
+
+/// Returned by [parse_tag] for a tag that doesn't name a release.
+#[derive(Error, Debug)]
+pub enum TagParseError {
+    /// There is no number after any of the prefixes, like in `nightly` or `docs-update`.
+    #[error("The tag doesn't contain a version")]
+    NoVersion,
+    /// Returned by [parse_tag] if the version has a prerelease or build metadata, like in
+    /// `v1.2.3-rc.1`, which [Version] doesn't carry. [parse_full_tag] accepts them.
+    #[error("Prereleases and build metadata aren't supported")]
+    Prerelease,
+    /// There is a number after a prefix, but it isn't a `major.minor.patch` version.
+    #[error("Invalid version: {0}")]
+    Version(#[source] VersionParseError),
+    /// The version is followed by an invalid prerelease or build metadata, like in `v1.2.3-rc..1`.
+    #[error("Invalid prerelease or build metadata: {0}")]
+    Identifiers(#[source] IdentifierError),
+}
+
+/// Parses the version a git tag names, rejecting prereleases and build metadata.
+///
+/// Surrounding whitespace and a leading `refs/tags/` are ignored. The version may follow a
+/// prefix ending in `/`, `-`, `_` or `@` and may start with `v` or `V`, so `v1.2.3`,
+/// `release/1.2.3`, `my-crate-v1.2.3` and `pkg@1.2.3` all name `1.2.3`. If several positions
+/// could start a version, the first one that parses wins.
+/// ```
+/// # use fast_version_core::{tags::{parse_tag, TagParseError}, version::Version};
+/// assert_eq!(parse_tag("refs/tags/release-v1.2.3").unwrap(), Version::new(1, 2, 3));
+/// assert!(matches!(parse_tag("v1.2"), Err(TagParseError::Version(_))));
+/// assert!(matches!(parse_tag("v1.2.3-rc.1"), Err(TagParseError::Prerelease)));
+/// assert!(matches!(parse_tag("latest"), Err(TagParseError::NoVersion)));
+/// ```
+pub fn parse_tag(tag: &str) -> Result<Version, TagParseError> {
+    let version = parse_full_tag(tag)?;
+    if version.is_prerelease() || !version.build.is_empty() {
+        return Err(TagParseError::Prerelease);
+    }
+    Ok(version.version)
+}
+
+/// Like [parse_tag], but keeps the prerelease and build metadata of tags like `v1.2.3-rc.1`.
+/// ```
+/// # use fast_version_core::tags::parse_full_tag;
+/// let version = parse_full_tag("my-crate-v0.4.0-beta.2").unwrap();
+/// assert_eq!(version.to_string(), "0.4.0-beta.2");
+/// ```
+pub fn parse_full_tag(tag: &str) -> Result<FullVersion, TagParseError> {
+    let tag = tag.trim();
+    let tag = tag.strip_prefix("refs/tags/").unwrap_or(tag);
+    let starts = core::iter::once(0).chain(
+        tag.match_indices(['/', '-', '_', '@'])
+            .map(|(index, separator)| index + separator.len()),
+    );
+    let mut first_error = None;
+    for start in starts {
+        let candidate = &tag[start..];
+        let candidate = candidate.strip_prefix(['v', 'V']).unwrap_or(candidate);
+        if !candidate.starts_with(|c: char| c.is_ascii_digit()) {
+            continue;
+        }
+        let error = match FullVersion::from_str(candidate) {
+            Ok(version) => return Ok(version),
+            Err(FullVersionParseError::Version(error)) => TagParseError::Version(error),
+            Err(
+                FullVersionParseError::Prerelease(error)
+                | FullVersionParseError::BuildMetadata(error),
+            ) => TagParseError::Identifiers(error),
+        };
+        first_error.get_or_insert(error);
+    }
+    Err(first_error.unwrap_or(TagParseError::NoVersion))
+}
+
+/// The result of [collect].
+#[cfg(feature = "alloc")]
+#[derive(Debug, Default)]
+pub struct TagScan {
+    /// The versions of all tags, prereleases included, highest first and without duplicates.
+    pub versions: Vec<FullVersion>,
+    /// The tags that don't name a version, in their original order.
+    pub skipped: Vec<(String, TagParseError)>,
+}
+
+/// Parses every tag with [parse_full_tag], sorting the versions and keeping the rejected tags
+/// with their errors.
+#[cfg(feature = "alloc")]
+pub fn collect<'a>(tags: impl IntoIterator<Item = &'a str>) -> TagScan {
+    let mut scan = TagScan::default();
+    for tag in tags {
+        match parse_full_tag(tag) {
+            Ok(version) => scan.versions.push(version),
+            Err(error) => scan.skipped.push((tag.to_string(), error)),
+        }
+    }
+    scan.versions.sort_unstable_by(|a, b| b.cmp(a));
+    scan.versions.dedup();
+    scan
+}
+
+#[cfg(feature = "alloc")]
+impl TagScan {
+    /// The highest version, which may be a prerelease.
+    #[inline]
+    pub fn latest(&self) -> Option<&FullVersion> {
+        self.versions.first()
+    }
+
+    /// The highest version without a prerelease accepted by the matcher. Matchers only see the
+    /// [Version], so prereleases are never offered to them.
+    pub fn latest_matching<M: VersionMatcher + ?Sized>(&self, matcher: &M) -> Option<&FullVersion> {
+        self.versions
+            .iter()
+            .find(|version| !version.is_prerelease() && matcher.matches(&version.version))
+    }
+
+    /// The highest version on the [stable channel](Channel::Stable), skipping prereleases.
+    pub fn latest_stable(&self) -> Option<&FullVersion> {
+        self.versions
+            .iter()
+            .find(|version| version.channel() == Channel::Stable)
+    }
+}
@@ -0,0 +1,142 @@
+//! Reference implementation of requirement matching for differential testing, behind the
+//! `test-util` feature.
+//!
+//! [naive_matches] follows the definition of every [VersionReqVariant] literally, comparing the
+//! components it names as a tuple, while [VersionReq::matches] first collapses all variants into
+//! inclusive bounds. Any optimization of the latter can be checked against the former.
+//! ```
+//! # use fast_version_core::{test_util, version_req::VersionReqVariant};
+//! let variant = VersionReqVariant::MinorGreater { major: 1, minor: 2 };
+//! assert_eq!(test_util::first_disagreement(&variant), None);
+//! ```
+
+use crate::version::Version;
+use crate::version_req::{
+    VersionReq, VersionReqVariant, VersionReqVariantLowerBound, VersionReqVariantUpperBound,
+};
+
+/// The components checked by [first_disagreement], `0..=4`.
+pub const SMALL_DOMAIN: core::ops::RangeInclusive<u64> = 0..=4;
+
+/// Checks wether the version satisfies the variant, straight from its definition: `>1.2` holds
+/// if `(major, minor)` is larger than `(1, 2)`, `<=1` if `major` is at most `1` and so on.
+pub fn naive_matches(variant: &VersionReqVariant, version: &Version) -> bool {
+    use VersionReqVariant::*;
+    let Version {
+        major: v_major,
+        minor: v_minor,
+        patch: v_patch,
+    } = *version;
+    match *variant {
+        Strict(strict) => *version == strict,
+        Compound(lower, upper) => naive_lower(&lower, version) && naive_upper(&upper, version),
+        MajorGreater { major } => v_major > major,
+        MinorGreater { major, minor } => (v_major, v_minor) > (major, minor),
+        PatchGreater {
+            major,
+            minor,
+            patch,
+        } => (v_major, v_minor, v_patch) > (major, minor, patch),
+        MajorGreaterEqual { major } => v_major >= major,
+        MinorGreaterEqual { major, minor } => (v_major, v_minor) >= (major, minor),
+        PatchGreaterEqual {
+            major,
+            minor,
+            patch,
+        } => (v_major, v_minor, v_patch) >= (major, minor, patch),
+        MajorLess { major } => v_major < major,
+        MinorLess { major, minor } => (v_major, v_minor) < (major, minor),
+        PatchLess {
+            major,
+            minor,
+            patch,
+        } => (v_major, v_minor, v_patch) < (major, minor, patch),
+        MajorLessEqual { major } => v_major <= major,
+        MinorLessEqual { major, minor } => (v_major, v_minor) <= (major, minor),
+        PatchLessEqual {
+            major,
+            minor,
+            patch,
+        } => (v_major, v_minor, v_patch) <= (major, minor, patch),
+        Caret(caret) if *version < caret => false,
+        Caret(caret) if (caret.major, caret.minor) == (0, 0) => *version == caret,
+        Caret(caret) if caret.major == 0 => (v_major, v_minor) == (0, caret.minor),
+        Caret(caret) => v_major == caret.major,
+        Tilde(tilde) => *version >= tilde && (v_major, v_minor) == (tilde.major, tilde.minor),
+    }
+}
+
+fn naive_lower(bound: &VersionReqVariantLowerBound, version: &Version) -> bool {
+    use VersionReqVariantLowerBound::*;
+    let variant = match *bound {
+        MajorGreater { major } => VersionReqVariant::MajorGreater { major },
+        MinorGreater { major, minor } => VersionReqVariant::MinorGreater { major, minor },
+        PatchGreater {
+            major,
+            minor,
+            patch,
+        } => VersionReqVariant::PatchGreater {
+            major,
+            minor,
+            patch,
+        },
+        MajorGreaterEqual { major } => VersionReqVariant::MajorGreaterEqual { major },
+        MinorGreaterEqual { major, minor } => VersionReqVariant::MinorGreaterEqual { major, minor },
+        PatchGreaterEqual {
+            major,
+            minor,
+            patch,
+        } => VersionReqVariant::PatchGreaterEqual {
+            major,
+            minor,
+            patch,
+        },
+    };
+    naive_matches(&variant, version)
+}
+
+fn naive_upper(bound: &VersionReqVariantUpperBound, version: &Version) -> bool {
+    use VersionReqVariantUpperBound::*;
+    let variant = match *bound {
+        MajorLess { major } => VersionReqVariant::MajorLess { major },
+        MinorLess { major, minor } => VersionReqVariant::MinorLess { major, minor },
+        PatchLess {
+            major,
+            minor,
+            patch,
+        } => VersionReqVariant::PatchLess {
+            major,
+            minor,
+            patch,
+        },
+        MajorLessEqual { major } => VersionReqVariant::MajorLessEqual { major },
+        MinorLessEqual { major, minor } => VersionReqVariant::MinorLessEqual { major, minor },
+        PatchLessEqual {
+            major,
+            minor,
+            patch,
+        } => VersionReqVariant::PatchLessEqual {
+            major,
+            minor,
+            patch,
+        },
+    };
+    naive_matches(&variant, version)
+}
+
+/// Compares [naive_matches] with [VersionReq::matches] for every version with components in
+/// [SMALL_DOMAIN], returning the first version they disagree on.
+pub fn first_disagreement(variant: &VersionReqVariant) -> Option<Version> {
+    let req = VersionReq::new(variant);
+    for major in SMALL_DOMAIN {
+        for minor in SMALL_DOMAIN {
+            for patch in SMALL_DOMAIN {
+                let version = Version::new(major, minor, patch);
+                if naive_matches(variant, &version) != req.matches(&version) {
+                    return Some(version);
+                }
+            }
+        }
+    }
+    None
+}
@@ -0,0 +1,139 @@
+//! Deciding wether to install an update, e.g. a firmware image, with downgrade protection.
+
+use crate::version::Version;
+use crate::version_req::VersionReq;
+use core::fmt;
+
+/// What an update may do besides going to a higher version of the same major.
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone)]
+pub struct UpdatePolicy {
+    /// Wether the candidate may be lower than the current version.
+    pub allow_downgrade: bool,
+    /// Wether the candidate may be on a higher major than the current version.
+    pub allow_major_jump: bool,
+    /// Wether a candidate on the same `major.minor` line must have a higher patch, even if
+    /// downgrades are allowed otherwise. Rolling back to an older line stays possible, rolling
+    /// back a patch isn't.
+    pub require_monotonic_patch: bool,
+}
+
+impl UpdatePolicy {
+    /// Only upgrades within the current major.
+    pub const STRICT: Self = Self {
+        allow_downgrade: false,
+        allow_major_jump: false,
+        require_monotonic_patch: true,
+    };
+    /// Any version but the current one, as long as it matches the requirement.
+    pub const PERMISSIVE: Self = Self {
+        allow_downgrade: true,
+        allow_major_jump: true,
+        require_monotonic_patch: false,
+    };
+}
+
+/// [UpdatePolicy::STRICT].
+impl Default for UpdatePolicy {
+    fn default() -> Self {
+        Self::STRICT
+    }
+}
+
+/// Why [UpdateDecision::evaluate] rejected a candidate. The checks run in the order of the
+/// variants, so only the first applicable reason is reported.
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone)]
+pub enum RejectReason {
+    /// The candidate is the current version.
+    SameVersion,
+    /// The candidate doesn't match the allowed requirement.
+    OutsideRequirement,
+    /// The candidate is lower than the current version, and either downgrades aren't allowed
+    /// or it rolls back the patch with [UpdatePolicy::require_monotonic_patch].
+    Downgrade { from: Version, to: Version },
+    /// The candidate is on a higher major and major jumps aren't allowed.
+    MajorJump { from: u64, to: u64 },
+}
+
+impl fmt::Display for RejectReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RejectReason::SameVersion => write!(f, "the candidate is the current version"),
+            RejectReason::OutsideRequirement => {
+                write!(f, "the candidate doesn't match the allowed requirement")
+            }
+            RejectReason::Downgrade { from, to } => write!(f, "downgrade from {from} to {to}"),
+            RejectReason::MajorJump { from, to } => write!(f, "jump from major {from} to {to}"),
+        }
+    }
+}
+
+/// The outcome of [UpdateDecision::evaluate].
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone)]
+pub enum UpdateDecision {
+    Accept,
+    Reject(RejectReason),
+}
+
+impl UpdateDecision {
+    /// Decides wether `candidate` may replace `current`.
+    /// ```
+    /// # use fast_version_core::update::{RejectReason, UpdateDecision, UpdatePolicy};
+    /// # use fast_version_core::{version::Version, version_req::VersionReq};
+    /// const CURRENT: Version = Version::new(1, 4, 2);
+    /// const ALLOWED: VersionReq = VersionReq::STAR;
+    ///
+    /// const NEXT: UpdateDecision = UpdateDecision::evaluate(
+    ///     CURRENT,
+    ///     Version::new(1, 5, 0),
+    ///     &ALLOWED,
+    ///     UpdatePolicy::STRICT,
+    /// );
+    /// assert_eq!(NEXT, UpdateDecision::Accept);
+    ///
+    /// let rollback =
+    ///     UpdateDecision::evaluate(CURRENT, Version::new(1, 4, 1), &ALLOWED, UpdatePolicy::STRICT);
+    /// assert_eq!(
+    ///     rollback,
+    ///     UpdateDecision::Reject(RejectReason::Downgrade {
+    ///         from: CURRENT,
+    ///         to: Version::new(1, 4, 1),
+    ///     })
+    /// );
+    /// ```
+    pub const fn evaluate(
+        current: Version,
+        candidate: Version,
+        allowed: &VersionReq,
+        policy: UpdatePolicy,
+    ) -> UpdateDecision {
+        let ordering = current.const_cmp(&candidate);
+        let reason = if ordering.is_eq() {
+            RejectReason::SameVersion
+        } else if !allowed.matches(&candidate) {
+            RejectReason::OutsideRequirement
+        } else if ordering.is_gt() {
+            let same_line = current.major == candidate.major && current.minor == candidate.minor;
+            if policy.allow_downgrade && !(policy.require_monotonic_patch && same_line) {
+                return UpdateDecision::Accept;
+            }
+            RejectReason::Downgrade {
+                from: current,
+                to: candidate,
+            }
+        } else if candidate.major != current.major && !policy.allow_major_jump {
+            RejectReason::MajorJump {
+                from: current.major,
+                to: candidate.major,
+            }
+        } else {
+            return UpdateDecision::Accept;
+        };
+        UpdateDecision::Reject(reason)
+    }
+
+    /// Wether the update was accepted.
+    #[inline]
+    pub const fn is_accept(&self) -> bool {
+        matches!(self, UpdateDecision::Accept)
+    }
+}
@@ -1,15 +1,20 @@
-#[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
-use std::str::FromStr;
+use crate::version_req::{version_le, VersionReq};
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Pod, PodCastError, Zeroable};
+use core::array;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::num::IntErrorKind;
+use core::str::FromStr;
 use thiserror::Error;
 
-
 /// Version in a SemVer **like** way.
 ///
 /// ## Example:
 /// ```
 /// # use fast_version_core::version::Version;
-/// use std::str::FromStr;
+/// use core::str::FromStr;
 ///
 /// const version_str: &'static str = "1.2.3";
 ///
@@ -31,47 +36,130 @@ use thiserror::Error;
 /// assert_eq!(VERSION.minor, 2);
 /// assert_eq!(VERSION.patch, 3);
 /// ```
-#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+///
+/// ## Layout
+/// The struct is `#[repr(C)]`: three consecutive native endian `u64`s in the order major, minor,
+/// patch, 24 bytes in total without padding. With the `bytemuck` feature it implements `Pod` and
+/// `Zeroable`, so slices of versions can be reinterpreted as bytes and back, see
+/// [Version::slice_from_bytes].
+///
+/// ## Precedence
+/// A [Version] carries no prerelease or build metadata, so [Ord] is its SemVer precedence.
+/// Versions with them are [FullVersion](crate::full_version::FullVersion)s, see
+/// [FullVersion::cmp_precedence](crate::full_version::FullVersion::cmp_precedence).
+#[derive(PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "bytemuck", derive(Pod, Zeroable))]
+#[repr(C)]
 pub struct Version {
     pub major: u64,
     pub minor: u64,
     pub patch: u64,
 }
 
-impl PartialOrd for Version {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        let major_ordering = self.major.partial_cmp(&other.major);
-        if let Some(d) = major_ordering {
-            if d.is_ne() {
-                return Some(d);
-            }
-        }
-        let minor_ordering = self.minor.partial_cmp(&other.minor);
-        if let Some(d) = minor_ordering {
-            if d.is_ne() {
-                return Some(d);
-            }
+/// Prints the compact `Version(1.2.3)`, the alternate flag (`{:#?}`) prints the fields.
+/// ```
+/// # use fast_version_core::version::Version;
+/// let version = Version::new(1, 2, 3);
+///
+/// assert_eq!(format!("{:?}", version), "Version(1.2.3)");
+/// assert_eq!(format!("{:?}", [version]), "[Version(1.2.3)]");
+/// ```
+impl fmt::Debug for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.debug_struct("Version")
+                .field("major", &self.major)
+                .field("minor", &self.minor)
+                .field("patch", &self.patch)
+                .finish()
+        } else {
+            write!(f, "Version({})", self)
         }
-        self.patch.partial_cmp(&other.patch)
+    }
+}
+
+/// Hashes major, minor and patch in that order, the same as
+/// [PackedKey](crate::packed::PackedKey) does for the packed form.
+impl Hash for Version {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_components(self.major, self.minor, self.patch, state);
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
     }
 }
 
 impl Ord for Version {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        let major_ordering = self.major.cmp(&other.major);
-        if major_ordering.is_ne() {
-            return major_ordering;
-        }
-        let minor_ordering = self.minor.cmp(&other.minor);
-        if minor_ordering.is_ne() {
-            return minor_ordering;
-        }
-        self.patch.cmp(&other.patch)
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.const_cmp(other)
+    }
+}
+
+/// Compares against the parsed string. A string that isn't a valid version is never equal.
+/// ```
+/// # use fast_version_core::version::Version;
+/// let version = Version::new(1, 2, 3);
+///
+/// assert_eq!(version, "1.2.3");
+/// assert_ne!(version, "1.2");
+/// assert!(version >= "1.2.0");
+/// // Invalid strings are neither equal, smaller nor greater.
+/// assert!(!(version < "one") && !(version >= "one"));
+/// ```
+impl PartialEq<str> for Version {
+    fn eq(&self, other: &str) -> bool {
+        Version::from_str(other).is_ok_and(|other| *self == other)
+    }
+}
+
+impl PartialEq<&str> for Version {
+    fn eq(&self, other: &&str) -> bool {
+        *self == **other
+    }
+}
+
+impl PartialEq<Version> for str {
+    fn eq(&self, other: &Version) -> bool {
+        *other == *self
+    }
+}
+
+impl PartialEq<Version> for &str {
+    fn eq(&self, other: &Version) -> bool {
+        *other == **self
+    }
+}
+
+/// Orders against the parsed string, returns None if it isn't a valid version.
+impl PartialOrd<&str> for Version {
+    fn partial_cmp(&self, other: &&str) -> Option<Ordering> {
+        Version::from_str(other).ok().map(|other| self.cmp(&other))
+    }
+}
+
+impl PartialOrd<Version> for &str {
+    fn partial_cmp(&self, other: &Version) -> Option<Ordering> {
+        other.partial_cmp(self).map(Ordering::reverse)
     }
 }
 
 impl Version {
+    /// The smallest version, `0.0.0`.
+    pub const ZERO: Self = Self::new(0, 0, 0);
+
+    /// The largest version, with every component `u64::MAX`.
+    pub const MAX: Self = Self::new(u64::MAX, u64::MAX, u64::MAX);
+
     /// Create a new version from major, minor and patch.
     /// ```
     /// # use fast_version_core::version::Version;
@@ -91,25 +179,433 @@ impl Version {
         }
     }
 
+    /// Returns a copy of the version with the major replaced.
+    /// ```
+    /// # use fast_version_core::version::Version;
+    /// const VERSION: Version = Version::new(1, 2, 3).with_major(4);
+    ///
+    /// assert_eq!(VERSION, Version::new(4, 2, 3));
+    /// ```
+    #[inline]
+    pub const fn with_major(self, major: u64) -> Self {
+        Self::new(major, self.minor, self.patch)
+    }
+
+    /// Returns a copy of the version with the minor replaced.
+    #[inline]
+    pub const fn with_minor(self, minor: u64) -> Self {
+        Self::new(self.major, minor, self.patch)
+    }
+
+    /// Returns a copy of the version with the patch replaced.
+    #[inline]
+    pub const fn with_patch(self, patch: u64) -> Self {
+        Self::new(self.major, self.minor, patch)
+    }
+
+    /// Zeroes the patch, normalizing the version to its minor release line.
+    /// ```
+    /// # use fast_version_core::version::Version;
+    /// assert_eq!(Version::new(1, 2, 3).truncate_to_minor(), Version::new(1, 2, 0));
+    /// ```
+    #[inline]
+    pub const fn truncate_to_minor(self) -> Self {
+        Self::new(self.major, self.minor, 0)
+    }
+
+    /// Zeroes minor and patch, normalizing the version to its major release line.
+    /// ```
+    /// # use fast_version_core::version::Version;
+    /// assert_eq!(Version::new(1, 2, 3).truncate_to_major(), Version::new(1, 0, 0));
+    /// ```
+    #[inline]
+    pub const fn truncate_to_major(self) -> Self {
+        Self::new(self.major, 0, 0)
+    }
+
+    /// Checks wether both versions have the same major.
+    /// ```
+    /// # use fast_version_core::version::Version;
+    /// const HOST: Version = Version::new(2, 4, 1);
+    /// let plugin_built_against = Version::new(2, 1, 0);
+    ///
+    /// // A plugin built against another major is never compatible.
+    /// assert!(HOST.same_major(&plugin_built_against));
+    /// assert!(!HOST.same_major(&Version::new(1, 4, 1)));
+    /// ```
+    #[inline]
+    pub const fn same_major(&self, other: &Version) -> bool {
+        self.major == other.major
+    }
+
+    /// Checks wether both versions have the same major and minor.
+    /// ```
+    /// # use fast_version_core::version::Version;
+    /// const HOST: Version = Version::new(0, 4, 1);
+    ///
+    /// // Before 1.0.0 plugins have to match the minor release line.
+    /// assert!(HOST.same_minor_line(&Version::new(0, 4, 7)));
+    /// assert!(!HOST.same_minor_line(&Version::new(0, 3, 1)));
+    /// // The minor alone is not enough.
+    /// assert!(!HOST.same_minor_line(&Version::new(1, 4, 1)));
+    /// ```
+    #[inline]
+    pub const fn same_minor_line(&self, other: &Version) -> bool {
+        self.major == other.major && self.minor == other.minor
+    }
+
+    /// Const equivalent of [Ord::cmp], which the `Ord` implementation delegates to.
+    /// ```
+    /// # use fast_version_core::version::Version;
+    /// use core::cmp::Ordering;
+    ///
+    /// const ORDERING: Ordering = Version::new(1, 2, 3).const_cmp(&Version::new(1, 10, 0));
+    ///
+    /// assert_eq!(ORDERING, Ordering::Less);
+    /// ```
+    #[inline]
+    pub const fn const_cmp(&self, other: &Version) -> Ordering {
+        match self.cmp_ignoring_patch(other) {
+            Ordering::Equal => cmp_u64(self.patch, other.patch),
+            ordering => ordering,
+        }
+    }
+
+    /// Const equivalent of `==`.
+    #[inline]
+    pub const fn const_eq(&self, other: &Version) -> bool {
+        matches!(self.const_cmp(other), Ordering::Equal)
+    }
+
+    /// Const equivalent of [Ord::max], returning `b` if both are equal.
+    /// ```
+    /// # use fast_version_core::version::Version;
+    /// const MINIMUM_SUPPORTED: Version = Version::new(1, 4, 0);
+    /// const REQUIRED_BY_PLUGIN: Version = Version::new(1, 2, 0);
+    /// const REQUIRED: Version = Version::const_max(MINIMUM_SUPPORTED, REQUIRED_BY_PLUGIN);
+    ///
+    /// assert_eq!(REQUIRED, Version::new(1, 4, 0));
+    /// ```
+    #[inline]
+    pub const fn const_max(a: Version, b: Version) -> Version {
+        match a.const_cmp(&b) {
+            Ordering::Greater => a,
+            _ => b,
+        }
+    }
+
+    /// Const equivalent of [Ord::min], returning `a` if both are equal.
+    #[inline]
+    pub const fn const_min(a: Version, b: Version) -> Version {
+        match a.const_cmp(&b) {
+            Ordering::Greater => b,
+            _ => a,
+        }
+    }
+
+    /// Compares major and minor, ignoring the patch.
+    /// ```
+    /// # use fast_version_core::version::Version;
+    /// use core::cmp::Ordering;
+    ///
+    /// const HOST: Version = Version::new(2, 4, 1);
+    ///
+    /// // Plugins may not use API of a newer minor release than the host provides.
+    /// assert_eq!(HOST.cmp_ignoring_patch(&Version::new(2, 4, 9)), Ordering::Equal);
+    /// assert_eq!(HOST.cmp_ignoring_patch(&Version::new(2, 5, 0)), Ordering::Less);
+    /// assert_eq!(HOST.cmp_ignoring_patch(&Version::new(1, 9, 0)), Ordering::Greater);
+    /// ```
+    #[inline]
+    pub const fn cmp_ignoring_patch(&self, other: &Version) -> Ordering {
+        match cmp_u64(self.major, other.major) {
+            Ordering::Equal => cmp_u64(self.minor, other.minor),
+            ordering => ordering,
+        }
+    }
+
+    /// Compares only the major.
+    /// ```
+    /// # use fast_version_core::version::Version;
+    /// use core::cmp::Ordering;
+    ///
+    /// assert_eq!(Version::new(2, 4, 1).cmp_major_only(&Version::new(2, 0, 0)), Ordering::Equal);
+    /// assert_eq!(Version::new(2, 4, 1).cmp_major_only(&Version::new(3, 0, 0)), Ordering::Less);
+    /// ```
+    #[inline]
+    pub const fn cmp_major_only(&self, other: &Version) -> Ordering {
+        cmp_u64(self.major, other.major)
+    }
+
+    /// Iterates over major, minor and patch in that order.
+    /// ```
+    /// # use fast_version_core::version::Version;
+    /// let version = Version::new(1, 2, 3);
+    /// let joined: Vec<String> = version.components().map(|c| c.to_string()).collect();
+    ///
+    /// assert_eq!(joined.join("-"), "1-2-3");
+    /// ```
+    #[inline]
+    pub fn components(&self) -> array::IntoIter<u64, 3> {
+        [self.major, self.minor, self.patch].into_iter()
+    }
+
+    /// Number of leading components both versions share, between 0 and 3.
+    /// ```
+    /// # use fast_version_core::version::Version;
+    /// assert_eq!(Version::new(1, 2, 3).common_prefix_len(&Version::new(1, 2, 4)), 2);
+    /// assert_eq!(Version::new(1, 2, 3).common_prefix_len(&Version::new(2, 2, 3)), 0);
+    /// ```
+    pub fn common_prefix_len(&self, other: &Version) -> u8 {
+        self.components()
+            .zip(other.components())
+            .take_while(|(lhs, rhs)| lhs == rhs)
+            .count() as u8
+    }
+
+    /// Renders the version into a buffer in const context, returning the buffer and the number of
+    /// bytes used. The output is the same as the [Display](fmt::Display) output.
+    /// ```
+    /// # use fast_version_core::version::Version;
+    /// const ASCII: ([u8; 62], usize) = Version::new(1, 2, 3).to_ascii_array();
+    ///
+    /// assert_eq!(&ASCII.0[..ASCII.1], b"1.2.3");
+    /// ```
+    pub const fn to_ascii_array(self) -> ([u8; MAX_STR_LEN], usize) {
+        let mut bytes = [0; MAX_STR_LEN];
+        let mut len = write_ascii_digits(&mut bytes, 0, self.major);
+        bytes[len] = b'.';
+        len = write_ascii_digits(&mut bytes, len + 1, self.minor);
+        bytes[len] = b'.';
+        len = write_ascii_digits(&mut bytes, len + 1, self.patch);
+        (bytes, len)
+    }
+
     pub fn new_from_str(input: &str) -> Result<Self, VersionParseError> {
-        let splits: Vec<&str> = input.split('.').collect();
-        if splits.len() != 3 {
-            return Err(VersionParseError::FormatWrong);
+        Self::parse_checked(input)
+    }
+
+    /// Parses a strict `major.minor.patch` version, the same as [FromStr].
+    ///
+    /// Never panics, whatever the input: any length, lone dots, NULs or other control characters
+    /// all result in an error. The work is linear in the length of the input. Meant for untrusted
+    /// input, every other parser of the crate gives the same guarantee and is fuzzed along with
+    /// this one.
+    /// ```
+    /// # use fast_version_core::version::{Version, VersionParseError};
+    /// assert_eq!(Version::parse_checked("1.2.3").unwrap(), Version::new(1, 2, 3));
+    /// assert!(matches!(
+    ///     Version::parse_checked("..."),
+    ///     Err(VersionParseError::TooManyComponents { found: 4 })
+    /// ));
+    /// assert!(Version::parse_checked("1.2.3\0").is_err());
+    /// assert!(matches!(
+    ///     Version::parse_checked("1.2.3-alpha.1"),
+    ///     Err(VersionParseError::Prerelease { position: 5 })
+    /// ));
+    /// ```
+    pub fn parse_checked(input: &str) -> Result<Self, VersionParseError> {
+        Self::parse_const(input)
+    }
+
+    /// [Version::parse_checked] in const context, with the same results. Unlike the
+    /// `const_version!` macro of the facade crate it takes any `&str`, e.g. from `env!`.
+    /// ```
+    /// # use fast_version_core::version::{ComponentKind, Version, VersionParseError};
+    /// const VERSION: Version = match Version::parse_const(env!("CARGO_PKG_VERSION")) {
+    ///     Ok(version) => version,
+    ///     Err(_) => panic!("bad version"),
+    /// };
+    /// assert_eq!(VERSION.to_string(), env!("CARGO_PKG_VERSION"));
+    ///
+    /// assert!(matches!(
+    ///     Version::parse_const("18446744073709551616.0.0"),
+    ///     Err(VersionParseError::ComponentOverflow { component: ComponentKind::Major, digits_len: 20 })
+    /// ));
+    /// ```
+    pub const fn parse_const(input: &str) -> Result<Self, VersionParseError> {
+        const KINDS: [ComponentKind; 3] = [
+            ComponentKind::Major,
+            ComponentKind::Minor,
+            ComponentKind::Patch,
+        ];
+        let bytes = input.as_bytes();
+        let mut index = 0;
+        while index < bytes.len() {
+            if bytes[index] == b'-' || bytes[index] == b'+' {
+                let (version, suffix) = input.split_at(index);
+                if index > 0 && is_identifier_suffix(suffix) && Self::parse_const(version).is_ok() {
+                    return Err(VersionParseError::Prerelease { position: index });
+                }
+                break;
+            }
+            index += 1;
+        }
+        let mut dots = 0usize;
+        let mut index = 0;
+        while index < bytes.len() {
+            if bytes[index] == b'.' {
+                dots += 1;
+            }
+            index += 1;
         }
-        let major_str = splits.get(0).unwrap();
-        let major = u64::from_str(major_str).map_err(|_| VersionParseError::MajorParseError)?;
-        let minor_str = splits.get(1).unwrap();
-        let minor = u64::from_str(minor_str).map_err(|_| VersionParseError::MinorParseError)?;
-        let patch_str = splits.get(2).unwrap();
-        let patch = u64::from_str(patch_str).map_err(|_| VersionParseError::PatchParseError)?;
-        Ok(Self::new(major, minor, patch))
+        if dots != 2 {
+            let found = if dots >= u8::MAX as usize {
+                u8::MAX
+            } else {
+                dots as u8 + 1
+            };
+            return Err(if found < 3 {
+                VersionParseError::TooFewComponents { found }
+            } else {
+                VersionParseError::TooManyComponents { found }
+            });
+        }
+        let mut components = [0; 3];
+        let mut start = 0;
+        let mut kind = 0;
+        while kind < KINDS.len() {
+            let mut end = start;
+            while end < bytes.len() && bytes[end] != b'.' {
+                end += 1;
+            }
+            components[kind] = match parse_component_bytes(bytes, start, end, KINDS[kind]) {
+                Ok(component) => component,
+                Err(error) => return Err(error),
+            };
+            start = end + 1;
+            kind += 1;
+        }
+        Ok(Self::new(components[0], components[1], components[2]))
+    }
+
+    /// [Version::parse_checked] for raw bytes, like a network buffer, which also never panics.
+    /// ```
+    /// # use fast_version_core::version::{Version, VersionParseError};
+    /// assert_eq!(Version::parse_checked_bytes(b"1.2.3").unwrap(), Version::new(1, 2, 3));
+    /// assert!(matches!(
+    ///     Version::parse_checked_bytes(b"1.\xff.3"),
+    ///     Err(VersionParseError::InvalidUtf8)
+    /// ));
+    /// ```
+    pub fn parse_checked_bytes(input: &[u8]) -> Result<Self, VersionParseError> {
+        let input = core::str::from_utf8(input).map_err(|_| VersionParseError::InvalidUtf8)?;
+        Self::parse_checked(input)
+    }
+
+    /// Parses a version from the start of the input and returns the remaining input, starting at
+    /// the first character that isn't part of the version.
+    ///
+    /// Only an error if the input doesn't start with a complete `major.minor.patch` version.
+    /// ```
+    /// # use fast_version_core::version::Version;
+    /// let (version, rest) = Version::parse_partial("1.2.3/linux").unwrap();
+    /// assert_eq!(version, Version::new(1, 2, 3));
+    /// assert_eq!(rest, "/linux");
+    ///
+    /// let (version, rest) = Version::parse_partial("1.2.3.tar.gz").unwrap();
+    /// assert_eq!(version, Version::new(1, 2, 3));
+    /// assert_eq!(rest, ".tar.gz");
+    /// ```
+    pub fn parse_partial(input: &str) -> Result<(Self, &str), VersionParseError> {
+        let (major_str, rest) = split_digits(input);
+        if major_str.is_empty() {
+            return Err(VersionParseError::MajorNotFound);
+        }
+        let major = parse_component(major_str, ComponentKind::Major)?;
+        let (minor_str, rest) = split_digits(rest.strip_prefix('.').unwrap_or_default());
+        if minor_str.is_empty() {
+            return Err(VersionParseError::MinorNotFound);
+        }
+        let minor = parse_component(minor_str, ComponentKind::Minor)?;
+        let (patch_str, rest) = split_digits(rest.strip_prefix('.').unwrap_or_default());
+        if patch_str.is_empty() {
+            return Err(VersionParseError::PatchNotFound);
+        }
+        let patch = parse_component(patch_str, ComponentKind::Patch)?;
+        Ok((Self::new(major, minor, patch), rest))
+    }
+
+    /// Checks wether the version satisfies the requirement, exactly the same as
+    /// [VersionReq::matches] with the arguments flipped.
+    /// ```
+    /// # use fast_version_core::{version::Version, version_req::VersionReq};
+    /// let versions = [Version::new(1, 2, 3), Version::new(4, 5, 6)];
+    ///
+    /// assert_eq!(versions.iter().filter(|v| v.satisfies(&VersionReq::STAR)).count(), 2);
+    /// ```
+    #[inline]
+    pub const fn satisfies(&self, req: &VersionReq) -> bool {
+        req.matches(self)
+    }
+
+    /// Checks wether the version satisfies at least one of the requirements.
+    pub const fn satisfies_any(&self, reqs: &[VersionReq]) -> bool {
+        let mut index = 0;
+        while index < reqs.len() {
+            if self.satisfies(&reqs[index]) {
+                return true;
+            }
+            index += 1;
+        }
+        false
+    }
+
+    /// Returns the version closest to this one that satisfies the requirement: the version
+    /// itself if it matches, the [minimal](VersionReq::minimal) version of the requirement if it
+    /// is below and the [maximal](VersionReq::maximal) version if it is above. None if the
+    /// requirement is empty.
+    /// ```
+    /// # use fast_version_core::{version::Version, version_req::{VersionReq, VersionReqVariant}};
+    /// const REQ: VersionReq = VersionReq::new(&VersionReqVariant::MajorGreater { major: 1 });
+    ///
+    /// assert_eq!(Version::new(1, 2, 3).clamp_to(&REQ), Some(Version::new(2, 0, 0)));
+    /// assert_eq!(Version::new(2, 1, 0).clamp_to(&REQ), Some(Version::new(2, 1, 0)));
+    /// ```
+    pub const fn clamp_to(&self, req: &VersionReq) -> Option<Version> {
+        let (lower, upper) = match (req.minimal(), req.maximal()) {
+            (Some(lower), Some(upper)) => (lower, upper),
+            _ => return None,
+        };
+        if !version_le(&lower, self) {
+            Some(lower)
+        } else if !version_le(self, &upper) {
+            Some(upper)
+        } else {
+            Some(*self)
+        }
+    }
+
+    /// Reinterprets raw bytes, e.g. from a memory mapped file, as a slice of versions without
+    /// copying.
+    ///
+    /// The bytes have to be aligned to 8 bytes and their length has to be a multiple of 24.
+    /// ```
+    /// # use fast_version_core::version::Version;
+    /// let versions = [Version::new(1, 2, 3), Version::new(4, 5, 6)];
+    /// let bytes: &[u8] = bytemuck::cast_slice(&versions);
+    ///
+    /// assert_eq!(Version::slice_from_bytes(bytes).unwrap(), &versions);
+    /// ```
+    #[cfg(feature = "bytemuck")]
+    pub fn slice_from_bytes(bytes: &[u8]) -> Result<&[Version], PodCastError> {
+        bytemuck::try_cast_slice(bytes)
     }
 }
 
 #[derive(Error, Debug)]
 pub enum VersionParseError {
+    /// No longer returned, the parsers report [VersionParseError::TooFewComponents] and
+    /// [VersionParseError::TooManyComponents] instead.
+    #[deprecated(note = "returned as TooFewComponents or TooManyComponents instead")]
     #[error("Format of version string is wrong")]
     FormatWrong,
+    /// There are less than three dot separated components, `found` of them.
+    #[error("Expected 3 components, found only {found}")]
+    TooFewComponents { found: u8 },
+    /// There are more than three dot separated components, `found` of them saturating at
+    /// `u8::MAX`.
+    #[error("Expected 3 components, found {found}")]
+    TooManyComponents { found: u8 },
     #[error("Parsing error in major")]
     MajorParseError,
     #[error("Major element was not found")]
@@ -122,6 +618,166 @@ pub enum VersionParseError {
     PatchParseError,
     #[error("Patch element was not found")]
     PatchNotFound,
+    /// The component only consists of digits, but is too large for a `u64`.
+    #[error("The {component} component with {digits_len} digits is too large")]
+    ComponentOverflow {
+        component: ComponentKind,
+        digits_len: usize,
+    },
+    /// The bytes given to [Version::parse_checked_bytes] aren't valid UTF-8.
+    #[error("Version is not valid UTF-8")]
+    InvalidUtf8,
+    /// A valid version is followed by a prerelease or build metadata starting at byte
+    /// `position`, like `1.2.3-alpha.1`, which only a
+    /// [FullVersion](crate::full_version::FullVersion) can hold.
+    #[error("Prerelease or build metadata at position {position}, parse a FullVersion instead")]
+    Prerelease { position: usize },
+}
+
+/// One of the three components of a [Version].
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone)]
+pub enum ComponentKind {
+    Major,
+    Minor,
+    Patch,
+}
+
+impl ComponentKind {
+    /// The generic parse error of the component, used for anything but an overflow.
+    pub(crate) const fn parse_error(self) -> VersionParseError {
+        match self {
+            ComponentKind::Major => VersionParseError::MajorParseError,
+            ComponentKind::Minor => VersionParseError::MinorParseError,
+            ComponentKind::Patch => VersionParseError::PatchParseError,
+        }
+    }
+}
+
+impl fmt::Display for ComponentKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ComponentKind::Major => "major",
+            ComponentKind::Minor => "minor",
+            ComponentKind::Patch => "patch",
+        };
+        f.write_str(name)
+    }
+}
+
+/// The canonical hash of a version, shared by every type that has to hash like one.
+#[inline]
+pub(crate) fn hash_components<H: Hasher>(major: u64, minor: u64, patch: u64, state: &mut H) {
+    state.write_u64(major);
+    state.write_u64(minor);
+    state.write_u64(patch);
+}
+
+/// The number of dot separated components of the input, saturating at `u8::MAX`.
+pub(crate) fn component_count(input: &str) -> u8 {
+    u8::try_from(input.split('.').count()).unwrap_or(u8::MAX)
+}
+
+pub(crate) fn parse_component(
+    input: &str,
+    component: ComponentKind,
+) -> Result<u64, VersionParseError> {
+    u64::from_str(input).map_err(|error| match error.kind() {
+        IntErrorKind::PosOverflow => VersionParseError::ComponentOverflow {
+            component,
+            digits_len: input.len(),
+        },
+        _ => component.parse_error(),
+    })
+}
+
+/// Const equivalent of [parse_component] for `bytes[start..end]`, accepting a leading `+` and
+/// reporting the first error from the left the same way `u64::from_str` does.
+const fn parse_component_bytes(
+    bytes: &[u8],
+    start: usize,
+    end: usize,
+    component: ComponentKind,
+) -> Result<u64, VersionParseError> {
+    let mut index = start;
+    if index < end && bytes[index] == b'+' {
+        index += 1;
+    }
+    if index == end {
+        return Err(component.parse_error());
+    }
+    let mut value: u64 = 0;
+    while index < end {
+        let digit = bytes[index].wrapping_sub(b'0');
+        if digit > 9 {
+            return Err(component.parse_error());
+        }
+        value = match value.checked_mul(10) {
+            Some(shifted) => match shifted.checked_add(digit as u64) {
+                Some(value) => value,
+                None => return Err(overflow(component, end - start)),
+            },
+            None => return Err(overflow(component, end - start)),
+        };
+        index += 1;
+    }
+    Ok(value)
+}
+
+const fn overflow(component: ComponentKind, digits_len: usize) -> VersionParseError {
+    VersionParseError::ComponentOverflow {
+        component,
+        digits_len,
+    }
+}
+
+/// Const equivalent of `lhs.cmp(&rhs)`.
+#[inline]
+const fn cmp_u64(lhs: u64, rhs: u64) -> Ordering {
+    if lhs < rhs {
+        Ordering::Less
+    } else if lhs > rhs {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    }
+}
+
+/// Parses one to three dot separated components consisting only of ASCII digits, filling missing
+/// components with 0.
+pub(crate) fn parse_short_version(input: &str) -> Option<Version> {
+    let mut components = [0u64; 3];
+    for (index, split) in input.split('.').enumerate() {
+        if index == components.len()
+            || split.is_empty()
+            || !split.bytes().all(|b| b.is_ascii_digit())
+        {
+            return None;
+        }
+        components[index] = split.parse().ok()?;
+    }
+    Some(Version::new(components[0], components[1], components[2]))
+}
+
+/// Splits the input after the leading ASCII digits.
+pub(crate) fn split_digits(input: &str) -> (&str, &str) {
+    let digits = input.bytes().take_while(u8::is_ascii_digit).count();
+    input.split_at(digits)
+}
+
+impl IntoIterator for Version {
+    type Item = u64;
+    type IntoIter = array::IntoIter<u64, 3>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.components()
+    }
+}
+
+impl IntoIterator for &Version {
+    type Item = u64;
+    type IntoIter = array::IntoIter<u64, 3>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.components()
+    }
 }
 
 impl FromStr for Version {
@@ -131,9 +787,56 @@ impl FromStr for Version {
     }
 }
 
-#[cfg(feature = "alloc")]
-impl ToString for Version {
-    fn to_string(&self) -> String {
-        format!("{}.{}.{}", self.major, self.minor, self.patch)
+/// Returns true if the suffix only has the characters of a prerelease and build metadata, ASCII
+/// alphanumerics, `.`, `-` and `+`.
+const fn is_identifier_suffix(suffix: &str) -> bool {
+    let bytes = suffix.as_bytes();
+    let mut index = 0;
+    while index < bytes.len() {
+        if !bytes[index].is_ascii_alphanumeric() && !matches!(bytes[index], b'.' | b'-' | b'+') {
+            return false;
+        }
+        index += 1;
+    }
+    true
+}
+
+/// Writes the decimal digits of `value` starting at `start`, returns the index after the last digit.
+const fn write_ascii_digits(bytes: &mut [u8; MAX_STR_LEN], start: usize, mut value: u64) -> usize {
+    let mut digits = 1;
+    let mut rest = value / 10;
+    while rest != 0 {
+        digits += 1;
+        rest /= 10;
+    }
+    let end = start + digits;
+    let mut index = end;
+    while index > start {
+        index -= 1;
+        bytes[index] = b'0' + (value % 10) as u8;
+        value /= 10;
+    }
+    end
+}
+
+/// Longest possible textual representation: three times `u64::MAX` with 20 digits and two dots.
+pub const MAX_STR_LEN: usize = 62;
+
+/// Supports the same formatting flags as `str`: width, fill and alignment pad the whole version
+/// and a precision truncates it to that many characters.
+/// ```
+/// # use fast_version_core::version::Version;
+/// let version = Version::new(1, 2, 3);
+///
+/// assert_eq!(format!("{:>8}", version), "   1.2.3");
+/// assert_eq!(format!("{:-^9}", version), "--1.2.3--");
+/// assert_eq!(format!("{:.3}", version), "1.2");
+/// ```
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (bytes, len) = self.to_ascii_array();
+        // Only ASCII digits and dots have been written.
+        let rendered = core::str::from_utf8(&bytes[..len]).map_err(|_| fmt::Error)?;
+        f.pad(rendered)
     }
 }
@@ -0,0 +1,180 @@
+use crate::version::Version;
+use crate::version_req::VersionReq;
+use core::cmp::Ordering;
+use core::fmt;
+use core::str::FromStr;
+use thiserror::Error;
+
+/// Four component version in the `major.minor.build.revision` style used by Windows installers,
+/// drivers and .NET assemblies.
+///
+/// ## Example:
+/// ```
+/// # use fast_version_core::version4::Version4;
+/// use core::str::FromStr;
+///
+/// let version = Version4::from_str("10.0.19041.1288").unwrap();
+/// assert_eq!(version.major, 10);
+/// assert_eq!(version.minor, 0);
+/// assert_eq!(version.build, 19041);
+/// assert_eq!(version.revision, 1288);
+///
+/// assert_eq!(version.to_string(), "10.0.19041.1288");
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Copy, Clone)]
+pub struct Version4 {
+    pub major: u64,
+    pub minor: u64,
+    pub build: u64,
+    pub revision: u64,
+}
+
+impl Version4 {
+    /// Create a new version from major, minor, build and revision.
+    /// ```
+    /// # use fast_version_core::version4::Version4;
+    /// const VERSION: Version4 = Version4::new(1, 2, 3, 4);
+    ///
+    /// assert_eq!(VERSION.build, 3);
+    /// assert_eq!(VERSION.revision, 4);
+    /// ```
+    #[inline]
+    pub const fn new(major: u64, minor: u64, build: u64, revision: u64) -> Self {
+        Version4 {
+            major,
+            minor,
+            build,
+            revision,
+        }
+    }
+
+    pub fn new_from_str(input: &str) -> Result<Self, Version4ParseError> {
+        let mut splits = input.split('.');
+        let (Some(major), Some(minor), Some(build), Some(revision), None) = (
+            splits.next(),
+            splits.next(),
+            splits.next(),
+            splits.next(),
+            splits.next(),
+        ) else {
+            return Err(Version4ParseError::FormatWrong);
+        };
+        let major = u64::from_str(major).map_err(|_| Version4ParseError::MajorParseError)?;
+        let minor = u64::from_str(minor).map_err(|_| Version4ParseError::MinorParseError)?;
+        let build = u64::from_str(build).map_err(|_| Version4ParseError::BuildParseError)?;
+        let revision =
+            u64::from_str(revision).map_err(|_| Version4ParseError::RevisionParseError)?;
+        Ok(Self::new(major, minor, build, revision))
+    }
+
+    /// Drops the revision and maps `major.minor.build` onto [Version]'s `major.minor.patch`.
+    /// ```
+    /// # use fast_version_core::{version::Version, version4::Version4};
+    /// const TRUNCATED: Version = Version4::new(10, 0, 19041, 1288).truncate();
+    ///
+    /// assert_eq!(TRUNCATED, Version::new(10, 0, 19041));
+    /// ```
+    #[inline]
+    pub const fn truncate(&self) -> Version {
+        Version::new(self.major, self.minor, self.build)
+    }
+
+    /// Checks the version against a [VersionReq].
+    ///
+    /// Requirements only know three components, so they are evaluated against the
+    /// [truncated](Version4::truncate) form: the revision is ignored entirely and `1.2.3.4`
+    /// matches everything `1.2.3` matches.
+    /// ```
+    /// # use fast_version_core::{version::Version, version4::Version4};
+    /// # use fast_version_core::version_req::{VersionReq, VersionReqVariant};
+    /// const REQ: VersionReq = VersionReq::new(&VersionReqVariant::Strict(Version::new(1, 2, 3)));
+    ///
+    /// assert!(Version4::new(1, 2, 3, 4).matches(&REQ));
+    /// assert!(!Version4::new(1, 2, 4, 0).matches(&REQ));
+    /// ```
+    #[inline]
+    pub const fn matches(&self, req: &VersionReq) -> bool {
+        req.matches(&self.truncate())
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum Version4ParseError {
+    #[error("Format of version string is wrong")]
+    FormatWrong,
+    #[error("Parsing error in major")]
+    MajorParseError,
+    #[error("Minor Parse Error")]
+    MinorParseError,
+    #[error("Build Parse Error")]
+    BuildParseError,
+    #[error("Revision Parse Error")]
+    RevisionParseError,
+}
+
+/// Returned when converting a [Version4] with a non zero revision into a [Version].
+#[derive(Error, Debug)]
+#[error("Revision {revision} would be lost in the conversion, use truncate() instead")]
+pub struct RevisionNotZero {
+    pub revision: u64,
+}
+
+impl FromStr for Version4 {
+    type Err = Version4ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new_from_str(s)
+    }
+}
+
+impl fmt::Display for Version4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}.{}.{}.{}",
+            self.major, self.minor, self.build, self.revision
+        )
+    }
+}
+
+impl From<Version> for Version4 {
+    fn from(version: Version) -> Self {
+        Self::new(version.major, version.minor, version.patch, 0)
+    }
+}
+
+impl TryFrom<Version4> for Version {
+    type Error = RevisionNotZero;
+    fn try_from(version: Version4) -> Result<Self, Self::Error> {
+        if version.revision != 0 {
+            return Err(RevisionNotZero {
+                revision: version.revision,
+            });
+        }
+        Ok(version.truncate())
+    }
+}
+
+/// A three component version is compared as if its revision was 0.
+impl PartialEq<Version> for Version4 {
+    fn eq(&self, other: &Version) -> bool {
+        *self == Version4::from(*other)
+    }
+}
+
+impl PartialEq<Version4> for Version {
+    fn eq(&self, other: &Version4) -> bool {
+        Version4::from(*self) == *other
+    }
+}
+
+impl PartialOrd<Version> for Version4 {
+    fn partial_cmp(&self, other: &Version) -> Option<Ordering> {
+        Some(self.cmp(&Version4::from(*other)))
+    }
+}
+
+impl PartialOrd<Version4> for Version {
+    fn partial_cmp(&self, other: &Version4) -> Option<Ordering> {
+        Some(Version4::from(*self).cmp(other))
+    }
+}
@@ -0,0 +1,80 @@
+//! Config values that are either a pinned version or a requirement.
+
+use crate::version::Version;
+use crate::version_req::{VersionReq, VersionReqParseError};
+use core::fmt;
+use core::str::FromStr;
+
+/// Either a pinned version like `1.2.3` or a requirement like `>=1.2, <2`, as written in a config
+/// file.
+///
+/// Parsing tries an exact `major.minor.patch` version first, so `1.2.3` always becomes
+/// [VersionOrReq::Pinned]. Write `=1.2.3` to get the equivalent requirement instead. With the
+/// `serde` feature it (de)serializes as the string, the way it's [displayed](fmt::Display).
+/// ```
+/// # use fast_version_core::{version::Version, version_or_req::VersionOrReq};
+/// let pinned: VersionOrReq = "1.2.3".parse().unwrap();
+/// let req: VersionOrReq = ">=1.2, <2".parse().unwrap();
+///
+/// assert_eq!(pinned, VersionOrReq::Pinned(Version::new(1, 2, 3)));
+/// assert!(!pinned.matches(&Version::new(1, 2, 4)));
+/// assert!(req.matches(&Version::new(1, 2, 4)));
+/// assert_eq!(req.to_string(), ">=1.2.0, <2.0.0");
+/// ```
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone)]
+pub enum VersionOrReq {
+    Pinned(Version),
+    Req(VersionReq),
+}
+
+impl VersionOrReq {
+    /// Checks wether the version is the pinned one or matches the requirement.
+    pub const fn matches(&self, version: &Version) -> bool {
+        match self {
+            VersionOrReq::Pinned(pinned) => pinned.const_cmp(version).is_eq(),
+            VersionOrReq::Req(req) => req.matches(version),
+        }
+    }
+
+    /// The requirement matching the same versions, [VersionReq::exact] for a pin.
+    pub const fn to_req(&self) -> VersionReq {
+        match self {
+            VersionOrReq::Pinned(pinned) => VersionReq::exact(*pinned),
+            VersionOrReq::Req(req) => *req,
+        }
+    }
+}
+
+impl From<Version> for VersionOrReq {
+    fn from(version: Version) -> Self {
+        VersionOrReq::Pinned(version)
+    }
+}
+
+impl From<VersionReq> for VersionOrReq {
+    fn from(req: VersionReq) -> Self {
+        VersionOrReq::Req(req)
+    }
+}
+
+/// Tries [Version::from_str] on the trimmed input, then [VersionReq::parse]. The error is the
+/// one of the requirement.
+impl FromStr for VersionOrReq {
+    type Err = VersionReqParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match Version::from_str(s.trim()) {
+            Ok(version) => Ok(VersionOrReq::Pinned(version)),
+            Err(_) => VersionReq::parse(s).map(VersionOrReq::Req),
+        }
+    }
+}
+
+impl fmt::Display for VersionOrReq {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VersionOrReq::Pinned(version) => version.fmt(f),
+            VersionOrReq::Req(req) => req.fmt(f),
+        }
+    }
+}
@@ -0,0 +1,218 @@
+use crate::version::{parse_component, ComponentKind, Version, VersionParseError};
+use crate::version_req::VersionReq;
+use core::fmt;
+use core::str::FromStr;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Single component of a [VersionPattern].
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PatternComponent {
+    /// Matches exactly this number.
+    Literal(u64),
+    /// Matches any number, written as `x`, `X` or `*`.
+    Wildcard,
+}
+
+impl PatternComponent {
+    #[inline]
+    const fn matches(&self, component: u64) -> bool {
+        match self {
+            PatternComponent::Literal(literal) => *literal == component,
+            PatternComponent::Wildcard => true,
+        }
+    }
+}
+
+/// Pattern like `1.2.x` where every component is either a literal number or a wildcard.
+///
+/// Unlike a [VersionReq] the wildcards may appear anywhere, `x.2.3` matches every version with
+/// minor 2 and patch 3. Missing trailing components are wildcards, so `1` is the same as `1.x.x`.
+///
+/// ## Example:
+/// ```
+/// # use fast_version_core::{version::Version, version_pattern::VersionPattern};
+/// use core::str::FromStr;
+///
+/// let pattern = VersionPattern::from_str("1.2.x").unwrap();
+/// assert!(pattern.matches(&Version::new(1, 2, 7)));
+/// assert!(!pattern.matches(&Version::new(1, 3, 0)));
+///
+/// let pattern = VersionPattern::from_str("x.2.3").unwrap();
+/// assert!(pattern.matches(&Version::new(5, 2, 3)));
+/// assert_eq!(pattern.to_req(), None);
+/// ```
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VersionPattern {
+    pub major: PatternComponent,
+    pub minor: PatternComponent,
+    pub patch: PatternComponent,
+}
+
+impl VersionPattern {
+    /// Create a new pattern from its components.
+    #[inline]
+    pub const fn new(
+        major: PatternComponent,
+        minor: PatternComponent,
+        patch: PatternComponent,
+    ) -> Self {
+        VersionPattern {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    pub fn new_from_str(input: &str) -> Result<Self, VersionPatternParseError> {
+        let mut splits = input.split('.');
+        let kinds = [
+            ComponentKind::Major,
+            ComponentKind::Minor,
+            ComponentKind::Patch,
+        ];
+        let mut components = [PatternComponent::Wildcard; 3];
+        for (index, kind) in kinds.into_iter().enumerate() {
+            let split = match splits.next() {
+                Some(split) => split,
+                None => break,
+            };
+            components[index] = match split {
+                "x" | "X" | "*" => PatternComponent::Wildcard,
+                _ => PatternComponent::Literal(parse_component(split, kind)?),
+            };
+        }
+        if splits.next().is_some() {
+            return Err(VersionPatternParseError::FormatWrong);
+        }
+        let [major, minor, patch] = components;
+        Ok(Self::new(major, minor, patch))
+    }
+
+    /// Checks wether every literal component of the pattern is equal to the version's.
+    pub const fn matches(&self, version: &Version) -> bool {
+        self.major.matches(version.major)
+            && self.minor.matches(version.minor)
+            && self.patch.matches(version.patch)
+    }
+
+    /// The requirement matching exactly the same versions.
+    ///
+    /// None if a wildcard comes before a literal, like in `x.2.3` or `1.x.3`, as such a pattern
+    /// doesn't describe a contiguous range.
+    /// ```
+    /// # use fast_version_core::version_pattern::VersionPattern;
+    /// use core::str::FromStr;
+    ///
+    /// let req = VersionPattern::from_str("1.2.x").unwrap().to_req().unwrap();
+    /// assert_eq!(req.to_string(), ">=1.2.0, <1.3.0");
+    /// ```
+    pub const fn to_req(&self) -> Option<VersionReq> {
+        use PatternComponent::{Literal, Wildcard};
+        const MAX: u64 = u64::MAX;
+        let (lower, upper) = match (self.major, self.minor, self.patch) {
+            (Literal(major), Literal(minor), Literal(patch)) => (
+                Version::new(major, minor, patch),
+                Version::new(major, minor, patch),
+            ),
+            (Literal(major), Literal(minor), Wildcard) => (
+                Version::new(major, minor, 0),
+                Version::new(major, minor, MAX),
+            ),
+            (Literal(major), Wildcard, Wildcard) => {
+                (Version::new(major, 0, 0), Version::new(major, MAX, MAX))
+            }
+            (Wildcard, Wildcard, Wildcard) => return Some(VersionReq::STAR),
+            _ => return None,
+        };
+        Some(VersionReq::new_bounded(Some(lower), Some(upper)))
+    }
+}
+
+impl VersionReq {
+    /// The pattern matching exactly the same versions, the inverse of [VersionPattern::to_req].
+    ///
+    /// None unless the requirement is [VersionReq::STAR], a single version or all versions of a
+    /// major or a minor, like `>=1.2.0, <1.3.0`.
+    /// ```
+    /// # use fast_version_core::version_req::VersionReq;
+    /// let pattern = VersionReq::parse(">=1.2.0, <1.3.0").unwrap().to_pattern().unwrap();
+    /// assert_eq!(pattern.to_string(), "1.2.x");
+    ///
+    /// assert!(VersionReq::parse(">=1.2.3, <1.3.0").unwrap().to_pattern().is_none());
+    /// ```
+    pub const fn to_pattern(&self) -> Option<VersionPattern> {
+        use PatternComponent::{Literal, Wildcard};
+        const MAX: u64 = u64::MAX;
+        // Patterns only match releases.
+        if self.pre.is_some() {
+            return None;
+        }
+        if self.is_star() {
+            return Some(VersionPattern::new(Wildcard, Wildcard, Wildcard));
+        }
+        let (lower, upper) = (self.lower, self.upper);
+        if lower.major != upper.major || !self.has_releases() {
+            return None;
+        }
+        let major = Literal(lower.major);
+        if lower.minor == 0 && lower.patch == 0 && upper.minor == MAX && upper.patch == MAX {
+            return Some(VersionPattern::new(major, Wildcard, Wildcard));
+        }
+        if lower.minor != upper.minor {
+            return None;
+        }
+        let minor = Literal(lower.minor);
+        if lower.patch == 0 && upper.patch == MAX {
+            return Some(VersionPattern::new(major, minor, Wildcard));
+        }
+        if lower.patch == upper.patch {
+            return Some(VersionPattern::new(major, minor, Literal(lower.patch)));
+        }
+        None
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum VersionPatternParseError {
+    #[error("Format of version pattern string is wrong")]
+    FormatWrong,
+    #[error(transparent)]
+    ComponentParseError(#[from] VersionParseError),
+}
+
+impl FromStr for VersionPattern {
+    type Err = VersionPatternParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new_from_str(s)
+    }
+}
+
+impl fmt::Display for PatternComponent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PatternComponent::Literal(literal) => write!(f, "{}", literal),
+            PatternComponent::Wildcard => f.write_str("x"),
+        }
+    }
+}
+
+/// Always prints all three components, wildcards as `x`.
+impl fmt::Display for VersionPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+impl From<Version> for VersionPattern {
+    fn from(version: Version) -> Self {
+        Self::new(
+            PatternComponent::Literal(version.major),
+            PatternComponent::Literal(version.minor),
+            PatternComponent::Literal(version.patch),
+        )
+    }
+}
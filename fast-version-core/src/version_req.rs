@@ -1,12 +1,24 @@
-use crate::version::Version;
+use crate::full_version::{BuildMetadata, IdentifierError, Prerelease};
+use crate::version::{component_count, parse_component, ComponentKind, Version, VersionParseError};
+#[cfg(feature = "alloc")]
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+use core::cmp::Ordering;
+use core::fmt;
+use core::iter::FusedIterator;
+use core::ops::{Bound, RangeBounds, RangeInclusive};
+#[cfg(feature = "nightly")]
+use core::simd::{cmp::SimdPartialOrd, u64x4};
+use core::str::FromStr;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
-#[cfg(feature = "nightly")]
-use core::simd::u64x4;
+use thiserror::Error;
 
 /// The variants in which a version requirenment can be constructed.
 #[non_exhaustive]
-#[derive(PartialEq, PartialOrd, Eq, Ord, Hash, Clone, Copy)]
+#[derive(PartialEq, PartialOrd, Eq, Ord, Hash, Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum VersionReqVariant {
     /// Equivalent of "1.2.3" where `1.2.3` is the only version this requirenment will match to.
@@ -37,12 +49,18 @@ pub enum VersionReqVariant {
     MinorLessEqual { major: u64, minor: u64 },
     /// Equivalent of "<=1.2.3"
     PatchLessEqual { major: u64, minor: u64, patch: u64 },
+    /// Equivalent of "^1.2.3", the versions Cargo considers compatible: `>=1.2.3, <2.0.0`,
+    /// `^0.2.3` is `>=0.2.3, <0.3.0` and `^0.0.3` is `=0.0.3`.
+    Caret(Version),
+    /// Equivalent of "~1.2.3", only patch updates: `>=1.2.3, <1.3.0`.
+    Tilde(Version),
 }
 
 /// Lower bound part of [VersionReqVariant::Compound]
 #[non_exhaustive]
-#[derive(PartialEq, PartialOrd, Eq, Ord, Hash, Clone, Copy)]
+#[derive(PartialEq, PartialOrd, Eq, Ord, Hash, Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum VersionReqVariantLowerBound {
     /// Equivalent of ">1"
     MajorGreater { major: u64 },
@@ -60,8 +78,9 @@ pub enum VersionReqVariantLowerBound {
 
 /// Lower bound part of [VersionReqVariant::Compound]
 #[non_exhaustive]
-#[derive(PartialEq, PartialOrd, Eq, Ord, Hash, Clone, Copy)]
+#[derive(PartialEq, PartialOrd, Eq, Ord, Hash, Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum VersionReqVariantUpperBound {
     /// Equivalent of "<1"
     MajorLess { major: u64 },
@@ -78,44 +97,336 @@ pub enum VersionReqVariantUpperBound {
 }
 
 /// Representing an actual version requirenment, normally constructed through [VersionReq::new].
-#[derive(PartialEq, PartialOrd, Eq, Ord, Hash, Clone, Copy, Debug)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+///
+/// Internally a requirement is a contiguous range of versions between an inclusive lower and an
+/// inclusive upper bound, compared the same way [Ord for Version](Version) does. Exclusive bounds
+/// are turned into inclusive ones on construction, `>1.2.3` becomes `>=1.2.4` and `<2.0.0` becomes
+/// `<=1.18446744073709551615.18446744073709551615`.
+///
+/// ## Prereleases
+/// A requirement whose comparators name a prerelease, like `>=1.0.0-alpha.1, <1.0.0`, keeps its
+/// bounds in the order of [FullVersion](crate::full_version::FullVersion)s as well. Like in Cargo,
+/// [matches_full](VersionReq::matches_full) accepts a prerelease only if it lies between these
+/// bounds and one of them names a prerelease of the same `major.minor.patch`, so `1.0.0-beta`
+/// matches `>=1.0.0-alpha` while `1.1.0-alpha` matches neither `>=1.0.0-alpha` nor `^1.0.0`.
+/// Everything else, from [matches](VersionReq::matches) to [range](VersionReq::range), is about
+/// the [Version]s, the releases, in between.
+///
+/// ## Presets
+/// | preset                   | equivalent          |
+/// |--------------------------|---------------------|
+/// | [VersionReq::STAR]       | `*`                 |
+/// | [VersionReq::EMPTY]      | `<0.0.0`            |
+/// | [VersionReq::STABLE]     | `>=1.0.0`           |
+/// | [VersionReq::PRE_STABLE] | `<1.0.0`            |
+/// | [VersionReq::major_line] | `>=n.0.0, <n+1.0.0` |
+#[derive(PartialEq, PartialOrd, Eq, Ord, Hash, Clone, Copy)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize))]
 pub struct VersionReq {
-    pub(crate) major_lower: u64,
-    pub(crate) minor_lower: u64,
-    pub(crate) patch_lower: u64,
-    pub(crate) major_higher: u64,
-    pub(crate) minor_higher: u64,
-    pub(crate) patch_higher: u64,
+    pub(crate) lower: Version,
+    pub(crate) upper: Version,
+    /// Only set if a prerelease can match, see [PrereleaseBounds].
+    pub(crate) pre: Option<PrereleaseBounds>,
+}
+
+/// A bound in the order of [FullVersion](crate::full_version::FullVersion)s, like the
+/// `1.0.0-alpha` of `>=1.0.0-alpha`. The prerelease is empty for a bound on a release.
+#[derive(PartialEq, PartialOrd, Eq, Ord, Hash, Debug, Clone, Copy)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize))]
+pub(crate) struct FullBound {
+    pub(crate) version: Version,
+    pub(crate) pre: Prerelease,
+    pub(crate) inclusive: bool,
+}
+
+/// The bounds of a requirement in the order of full versions, which prereleases are matched
+/// against. A bound is None if it doesn't exclude any version.
+///
+/// A prerelease matches if it lies between the bounds and one of them names a prerelease of the
+/// same `major.minor.patch`. Bounds on releases are kept in the form that matters for these
+/// prereleases: `>=1.2` allows `1.2.0-alpha` and is stored as `>1.1.18446744073709551615`, while
+/// `>=1.2.0` doesn't. Where the form doesn't matter they are stored inclusive, so equivalent
+/// requirements stay equal.
+#[derive(PartialEq, PartialOrd, Eq, Ord, Hash, Debug, Clone, Copy)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize))]
+pub(crate) struct PrereleaseBounds {
+    pub(crate) lower: Option<FullBound>,
+    pub(crate) upper: Option<FullBound>,
 }
 
 impl VersionReq {
     /// Equivalent of "*"
     pub const STAR: Self = Self::star();
 
+    /// Requirement no version matches, e.g. the result of `<0.0.0` or `>=2, <1`.
+    pub const EMPTY: Self = Self {
+        lower: Version::MAX,
+        upper: Version::ZERO,
+        pre: None,
+    };
+
+    /// Every stable version, equivalent of ">=1.0.0".
+    pub const STABLE: Self = Self::at_least(Version::new(1, 0, 0));
+
+    /// Every version before the first stable one, equivalent of "<1.0.0".
+    pub const PRE_STABLE: Self = Self::major_line(0);
+
+    /// Every version with the given major, equivalent of ">=1.0.0, <2.0.0" for 1.
+    /// ```
+    /// # use fast_version_core::{version::Version, version_req::VersionReq};
+    /// const ANY_V1: VersionReq = VersionReq::major_line(1);
+    ///
+    /// let version = Version::new(1, 7, 2);
+    /// let channel = match version {
+    ///     v if ANY_V1.matches(&v) => "v1",
+    ///     v if VersionReq::STABLE.matches(&v) => "newer",
+    ///     _ => "experimental",
+    /// };
+    /// assert_eq!(channel, "v1");
+    /// assert_eq!(ANY_V1.to_string(), ">=1.0.0, <2.0.0");
+    /// ```
+    #[inline]
+    pub const fn major_line(major: u64) -> Self {
+        Self {
+            lower: Version::new(major, 0, 0),
+            upper: Version::new(major, u64::MAX, u64::MAX),
+            pre: None,
+        }
+    }
+
     const fn star() -> Self {
-        const MAX: u64 = u64::MAX;
-        const MIN: u64 = u64::MIN;
         Self {
-            major_lower: MIN,
-            minor_lower: MIN,
-            patch_lower: MIN,
-            major_higher: MAX,
-            minor_higher: MAX,
-            patch_higher: MAX,
+            lower: Version::ZERO,
+            upper: Version::MAX,
+            pre: None,
+        }
+    }
+
+    /// Requirement matching only the given version, equivalent of "=1.2.3".
+    /// ```
+    /// # use fast_version_core::{version::Version, version_req::VersionReq};
+    /// const PINNED: VersionReq = VersionReq::exact(Version::new(1, 2, 3));
+    ///
+    /// assert!(PINNED.matches(&Version::new(1, 2, 3)));
+    /// assert_eq!(PINNED.as_exact(), Some(Version::new(1, 2, 3)));
+    /// ```
+    #[inline]
+    pub const fn exact(version: Version) -> Self {
+        Self::new_strict(&version)
+    }
+
+    /// Equivalent of ">=1.2.3".
+    /// ```
+    /// # use fast_version_core::{version::Version, version_req::VersionReq};
+    /// const REQ: VersionReq = VersionReq::at_least(Version::new(1, 2, 3));
+    ///
+    /// assert_eq!(REQ.to_string(), ">=1.2.3");
+    /// ```
+    #[inline]
+    pub const fn at_least(version: Version) -> Self {
+        Self::new_lower_bounded_equal(Some(version))
+    }
+
+    /// Equivalent of "<=1.2.3".
+    #[inline]
+    pub const fn at_most(version: Version) -> Self {
+        Self::new_upper_bounded_equal(Some(version))
+    }
+
+    /// Equivalent of ">1.2.3".
+    #[inline]
+    pub const fn greater_than(version: Version) -> Self {
+        Self::new_lower_bounded_equal(successor(&version))
+    }
+
+    /// Equivalent of "<1.2.3".
+    #[inline]
+    pub const fn less_than(version: Version) -> Self {
+        Self::new_upper_bounded_equal(predecessor(&version))
+    }
+
+    /// Equivalent of ">=1.2.3, <=2.0.0", [VersionReq::EMPTY] if `lower` is larger than `upper`.
+    /// ```
+    /// # use fast_version_core::{version::Version, version_req::VersionReq};
+    /// const REQ: VersionReq = VersionReq::between(Version::new(1, 2, 3), Version::new(2, 0, 0));
+    ///
+    /// assert_eq!(REQ.to_string(), ">=1.2.3, <=2.0.0");
+    /// ```
+    #[inline]
+    pub const fn between(lower: Version, upper: Version) -> Self {
+        Self::new_bounded(Some(lower), Some(upper))
+    }
+
+    /// Requirement matching every version from `lower` up to and including `upper`, displayed
+    /// as `>=lower, <=upper`. Fails if `lower` is larger than `upper`.
+    /// ```
+    /// # use fast_version_core::{version::Version, version_req::VersionReq};
+    /// let req = VersionReq::from_inclusive_range(Version::new(1, 2, 0), Version::new(1, 4, 7));
+    /// assert_eq!(req.unwrap().to_string(), ">=1.2.0, <=1.4.7");
+    ///
+    /// let inverted = VersionReq::from_inclusive_range(Version::new(2, 0, 0), Version::new(1, 0, 0));
+    /// assert!(inverted.is_err());
+    /// ```
+    pub const fn from_inclusive_range(
+        lower: Version,
+        upper: Version,
+    ) -> Result<Self, InvalidBounds> {
+        if version_le(&lower, &upper) {
+            Ok(Self {
+                lower,
+                upper,
+                pre: None,
+            })
+        } else {
+            Err(InvalidBounds { lower, upper })
+        }
+    }
+
+    /// Like [VersionReq::from_inclusive_range], but returns [VersionReq::EMPTY] if `lower` is
+    /// larger than `upper`. The same as [VersionReq::between].
+    #[inline]
+    pub const fn from_inclusive_range_unchecked(lower: Version, upper: Version) -> Self {
+        Self::between(lower, upper)
+    }
+
+    /// The only version matching the requirement, None if it matches none or more than one.
+    /// Requirements that match a prerelease match more than one.
+    #[inline]
+    pub const fn as_exact(&self) -> Option<Version> {
+        if self.is_exact() {
+            Some(self.lower)
+        } else {
+            None
+        }
+    }
+
+    /// Returns true if exactly one version matches, see [as_exact](Self::as_exact).
+    /// ```
+    /// # use fast_version_core::version_req::VersionReq;
+    /// assert!(VersionReq::parse("=1.2.3").unwrap().is_exact());
+    /// assert!(VersionReq::parse(">=1.2.3, <1.2.4").unwrap().is_exact());
+    /// assert!(!VersionReq::parse("~1.2.3").unwrap().is_exact());
+    /// ```
+    #[inline]
+    pub const fn is_exact(&self) -> bool {
+        self.lower.const_eq(&self.upper) && self.pre.is_none()
+    }
+
+    /// Returns true if every version matches, like `*` or `>=0.0.0`.
+    /// ```
+    /// # use fast_version_core::version_req::VersionReq;
+    /// assert!(VersionReq::parse(">=0").unwrap().is_star());
+    /// assert!(!VersionReq::parse(">0").unwrap().is_star());
+    /// ```
+    #[inline]
+    pub const fn is_star(&self) -> bool {
+        self.is_unbounded_below() && self.is_unbounded_above()
+    }
+
+    /// Returns true if the requirement matches the largest possible version, so it has no
+    /// effective upper bound. `<=MAX.MAX.MAX` counts as unbounded.
+    /// ```
+    /// # use fast_version_core::version_req::VersionReq;
+    /// assert!(VersionReq::parse(">=1.2").unwrap().is_unbounded_above());
+    /// assert!(!VersionReq::parse("^1.2").unwrap().is_unbounded_above());
+    /// ```
+    #[inline]
+    pub const fn is_unbounded_above(&self) -> bool {
+        self.upper.const_eq(&Self::STAR.upper) && self.has_releases()
+    }
+
+    /// Returns true if the requirement matches `0.0.0`, so it has no effective lower bound.
+    /// `>=0.0.0` counts as unbounded.
+    /// ```
+    /// # use fast_version_core::version_req::VersionReq;
+    /// assert!(VersionReq::parse("<2").unwrap().is_unbounded_below());
+    /// assert!(!VersionReq::parse(">=0.0.1").unwrap().is_unbounded_below());
+    /// ```
+    #[inline]
+    pub const fn is_unbounded_below(&self) -> bool {
+        self.lower.const_eq(&Self::STAR.lower) && self.has_releases()
+    }
+
+    /// Renders the requirement the way it is usually written in a `Cargo.toml`, falling back to
+    /// [Display](fmt::Display) if there's no shorter form.
+    ///
+    /// * `*` for [VersionReq::STAR]
+    /// * `=1.2.3` if only a single version matches, this includes `^0.0.3`
+    /// * `1.2.3` for a caret range, `0.2.3` stands for `>=0.2.3, <0.3.0`
+    /// * `~1.2.3` for a tilde range that isn't a caret range, like `>=1.2.3, <1.3.0`
+    /// ```
+    /// # use fast_version_core::version_req::VersionReq;
+    /// let cargo = |req: &str| VersionReq::parse(req).unwrap().to_cargo_string();
+    ///
+    /// assert_eq!(cargo(">=1.2.3, <2.0.0"), "1.2.3");
+    /// assert_eq!(cargo(">=1.2.3, <1.3.0"), "~1.2.3");
+    /// assert_eq!(cargo("=1.2.3"), "=1.2.3");
+    /// assert_eq!(cargo(">=1.2.3, <1.5.0"), ">=1.2.3, <1.5.0");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_cargo_string(&self) -> String {
+        if *self == Self::STAR {
+            return String::from("*");
+        }
+        if self.pre.is_some() {
+            return self.to_string();
+        }
+        if let Some(version) = self.as_exact() {
+            return format!("={version}");
         }
+        if self.is_empty() {
+            return self.to_string();
+        }
+        let Version {
+            major,
+            minor,
+            patch: _,
+        } = self.lower;
+        let caret_upper = match (major, minor) {
+            (0, 0) => self.lower,
+            (0, _) => Version::new(0, minor, u64::MAX),
+            _ => Version::new(major, u64::MAX, u64::MAX),
+        };
+        if self.upper == caret_upper {
+            return self.lower.to_string();
+        }
+        if self.upper == Version::new(major, minor, u64::MAX) {
+            return format!("~{}", self.lower);
+        }
+        self.to_string()
     }
 
     /// checks wether the Version Requirenment matches with the version. Returns true if the
     /// Requirenments are met.
+    ///
+    /// Versions are compared as a whole, in the order of [Version]'s [Ord], so this is the same as
+    /// `self.range().is_some_and(|range| range.contains(version))`, just usable in const. For a
+    /// prerelease see [matches_full](VersionReq::matches_full).
     pub const fn matches(&self, version: &Version) -> bool {
-        let lower_match = self.major_lower <= version.major
-            && self.minor_lower <= version.minor
-            && self.patch_lower <= version.patch;
-        let higher_match = self.major_higher >= version.major
-            && self.minor_higher >= version.minor
-            && self.patch_higher >= version.patch;
-        lower_match && higher_match
+        version_le(&self.lower, version) && version_le(version, &self.upper)
+    }
+
+    /// Parses the version and checks wether the requirement matches it. Surrounding whitespace
+    /// is ignored.
+    ///
+    /// Returns the [VersionParseError] of [Version::from_str] if the string isn't a version, a
+    /// version that doesn't match is `Ok(false)`.
+    /// ```
+    /// # use fast_version_core::version_req::VersionReq;
+    /// let req = VersionReq::parse(">=1.2, <2").unwrap();
+    ///
+    /// assert!(req.matches_str(" 1.4.0 ").unwrap());
+    /// assert!(!req.matches_str("2.0.0").unwrap());
+    /// assert!(req.matches_str("1.4").is_err());
+    /// ```
+    pub fn matches_str(&self, version: &str) -> Result<bool, VersionParseError> {
+        Version::from_str(version.trim()).map(|version| self.matches(&version))
+    }
+
+    /// Like [VersionReq::matches_str], but returns `default` if the string isn't a version.
+    #[inline]
+    pub fn matches_str_or(&self, version: &str, default: bool) -> bool {
+        self.matches_str(version).unwrap_or(default)
     }
 
     /// checks wether the version requirenment matches with the version. Returns true if the
@@ -132,10 +443,338 @@ impl VersionReq {
     /// ```
     #[cfg(feature = "nightly")]
     pub fn simd_matches(&self, version: &Version) -> bool {
-        let simd_version: u64x4 = u64x4::from_array([version.major, version.minor, version.patch, 0]);
-        let simd_req_lower: u64x4 = u64x4::from_array([self.major_lower, self.minor_lower, self.patch_lower, 0]);
-        let simd_req_higher = u64x4::from_array([self.major_higher, self.minor_higher, self.patch_higher, 0]);
-        simd_req_lower.lanes_le(simd_version).all() && simd_req_higher.lanes_ge(simd_version).all()
+        let simd_version = u64x4::from_array([version.major, version.minor, version.patch, 0]);
+        let simd_lower =
+            u64x4::from_array([self.lower.major, self.lower.minor, self.lower.patch, 0]);
+        let simd_upper =
+            u64x4::from_array([self.upper.major, self.upper.minor, self.upper.patch, 0]);
+        simd_le(simd_lower, simd_version) && simd_le(simd_version, simd_upper)
+    }
+
+    /// Returns true if no version matches the requirement, not even a prerelease. `=1.0.0-beta`
+    /// isn't empty, although no [Version] matches it.
+    /// ```
+    /// # use fast_version_core::version_req::{VersionReq, VersionReqVariant};
+    /// const REQ: VersionReq = VersionReq::new(&VersionReqVariant::MajorLess { major: 0 });
+    ///
+    /// assert!(REQ.is_empty());
+    /// assert!(!VersionReq::STAR.is_empty());
+    /// assert!(!VersionReq::parse("=1.0.0-beta").unwrap().is_empty());
+    /// ```
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        !self.has_releases() && self.pre.is_none()
+    }
+
+    /// Returns true if a comparator names a prerelease that
+    /// [matches_full](VersionReq::matches_full) can accept, see [Prereleases](VersionReq#prereleases).
+    /// ```
+    /// # use fast_version_core::version_req::VersionReq;
+    /// assert!(VersionReq::parse(">=1.0.0-alpha, <2").unwrap().admits_prereleases());
+    /// assert!(!VersionReq::parse("^1.2").unwrap().admits_prereleases());
+    /// ```
+    #[inline]
+    pub const fn admits_prereleases(&self) -> bool {
+        self.pre.is_some()
+    }
+
+    /// Returns true if a [Version] matches, the numeric bounds aren't crossed.
+    #[inline]
+    pub(crate) const fn has_releases(&self) -> bool {
+        version_le(&self.lower, &self.upper)
+    }
+
+    /// Keeps the bounds for prereleases, if a prerelease can match them.
+    #[inline]
+    pub(crate) const fn with_prerelease_bounds(self, bounds: PrereleaseBounds) -> Self {
+        Self {
+            pre: bounds.normalize(),
+            ..self
+        }
+    }
+
+    /// The smallest version matching the requirement, None if the requirement is empty.
+    /// ```
+    /// # use fast_version_core::{version::Version, version_req::{VersionReq, VersionReqVariant}};
+    /// const REQ: VersionReq = VersionReq::new(&VersionReqVariant::PatchGreater {
+    ///     major: 1,
+    ///     minor: 2,
+    ///     patch: 3,
+    /// });
+    ///
+    /// assert_eq!(REQ.minimal(), Some(Version::new(1, 2, 4)));
+    /// ```
+    #[inline]
+    pub const fn minimal(&self) -> Option<Version> {
+        if !self.has_releases() {
+            None
+        } else {
+            Some(self.lower)
+        }
+    }
+
+    /// The largest version matching the requirement, None if the requirement is empty.
+    /// ```
+    /// # use fast_version_core::{version::Version, version_req::{VersionReq, VersionReqVariant}};
+    /// const REQ: VersionReq = VersionReq::new(&VersionReqVariant::MinorLess { major: 2, minor: 0 });
+    ///
+    /// assert_eq!(REQ.maximal(), Some(Version::new(1, u64::MAX, u64::MAX)));
+    /// ```
+    #[inline]
+    pub const fn maximal(&self) -> Option<Version> {
+        if !self.has_releases() {
+            None
+        } else {
+            Some(self.upper)
+        }
+    }
+
+    /// The matching versions as a range, None if the requirement is empty.
+    /// ```
+    /// # use fast_version_core::{version::Version, version_req::VersionReq};
+    /// let req = VersionReq::parse(">=1.2, <1.4").unwrap();
+    /// let range = req.range().unwrap();
+    ///
+    /// assert_eq!(range, Version::new(1, 2, 0)..=Version::new(1, 3, u64::MAX));
+    /// assert!(range.contains(&Version::new(1, 3, 7)));
+    /// assert_eq!(VersionReq::EMPTY.range(), None);
+    /// ```
+    #[inline]
+    pub const fn range(&self) -> Option<RangeInclusive<Version>> {
+        if !self.has_releases() {
+            None
+        } else {
+            Some(RangeInclusive::new(self.lower, self.upper))
+        }
+    }
+
+    /// Checks wether any version with the given major matches the requirement.
+    ///
+    /// `<2.0.0` doesn't allow major 2, while `<2.0.1` does because `2.0.0` matches.
+    /// ```
+    /// # use fast_version_core::version_req::VersionReq;
+    /// let req = VersionReq::parse(">=1.4, <3").unwrap();
+    ///
+    /// assert!(!req.allows_major(0));
+    /// assert!(req.allows_major(1));
+    /// assert!(req.allows_major(2));
+    /// assert!(!req.allows_major(3));
+    /// ```
+    #[inline]
+    pub const fn allows_major(&self, major: u64) -> bool {
+        self.has_releases() && self.lower.major <= major && major <= self.upper.major
+    }
+
+    /// Checks wether any version with the given major and minor matches the requirement.
+    /// ```
+    /// # use fast_version_core::version_req::VersionReq;
+    /// let req = VersionReq::parse(">=1.4.2, <1.6").unwrap();
+    ///
+    /// assert!(!req.allows_minor(1, 3));
+    /// assert!(req.allows_minor(1, 4));
+    /// assert!(req.allows_minor(1, 5));
+    /// assert!(!req.allows_minor(1, 6));
+    /// ```
+    #[inline]
+    pub const fn allows_minor(&self, major: u64, minor: u64) -> bool {
+        self.has_releases()
+            && version_le(&self.lower, &Version::new(major, minor, u64::MAX))
+            && version_le(&Version::new(major, minor, 0), &self.upper)
+    }
+
+    /// The majors [allows_major](Self::allows_major) returns true for. The range is empty, with
+    /// a start above its end, if the requirement is empty.
+    /// ```
+    /// # use fast_version_core::version_req::VersionReq;
+    /// assert_eq!(VersionReq::parse("^1.2").unwrap().major_span(), 1..=1);
+    /// assert_eq!(VersionReq::parse(">=1, <=3.0.0").unwrap().major_span(), 1..=3);
+    /// assert!(VersionReq::EMPTY.major_span().is_empty());
+    /// ```
+    #[inline]
+    pub const fn major_span(&self) -> RangeInclusive<u64> {
+        RangeInclusive::new(self.lower.major, self.upper.major)
+    }
+
+    /// The requirement restricted to each of its majors in ascending order, e.g. for a
+    /// compatibility table. The pieces are lazily computed and together match exactly the
+    /// versions of the requirement.
+    ///
+    /// A requirement without an upper bound covers every major up to `u64::MAX`, cap it with
+    /// [split_by_major_capped](Self::split_by_major_capped) or by taking a limited number of
+    /// entries.
+    /// ```
+    /// # use fast_version_core::version_req::VersionReq;
+    /// let req = VersionReq::parse(">=1.4, <3.2").unwrap();
+    /// let pieces: Vec<_> = req
+    ///     .split_by_major()
+    ///     .map(|(major, piece)| (major, piece.to_string()))
+    ///     .collect();
+    ///
+    /// assert_eq!(
+    ///     pieces,
+    ///     [
+    ///         (1, String::from(">=1.4.0, <2.0.0")),
+    ///         (2, String::from(">=2.0.0, <3.0.0")),
+    ///         (3, String::from(">=3.0.0, <3.2.0")),
+    ///     ]
+    /// );
+    /// ```
+    #[inline]
+    pub const fn split_by_major(&self) -> SplitByMajor {
+        self.split_by_major_capped(u64::MAX)
+    }
+
+    /// Like [split_by_major](Self::split_by_major), but the majors above `cap` are not split and
+    /// end in a single open-ended entry, keyed by the first of them.
+    /// ```
+    /// # use fast_version_core::version_req::VersionReq;
+    /// let pieces: Vec<_> = VersionReq::parse(">=1.2").unwrap()
+    ///     .split_by_major_capped(2)
+    ///     .map(|(major, piece)| (major, piece.to_string()))
+    ///     .collect();
+    ///
+    /// assert_eq!(
+    ///     pieces,
+    ///     [
+    ///         (1, String::from(">=1.2.0, <2.0.0")),
+    ///         (2, String::from(">=2.0.0, <3.0.0")),
+    ///         (3, String::from(">=3.0.0")),
+    ///     ]
+    /// );
+    /// ```
+    #[inline]
+    pub const fn split_by_major_capped(&self, cap: u64) -> SplitByMajor {
+        SplitByMajor { rest: *self, cap }
+    }
+
+    /// Requirement matching the versions both requirements match. A prerelease matches only if
+    /// both requirements allow it, so `>=1.0.0-alpha` and `<2.0.0-beta` have none in common.
+    /// ```
+    /// # use fast_version_core::version_req::VersionReq;
+    /// let policy = VersionReq::parse(">=1.2").unwrap();
+    /// let plugin = VersionReq::parse("<1.5").unwrap();
+    ///
+    /// assert_eq!(policy.intersection(&plugin).to_string(), ">=1.2.0, <1.5.0");
+    ///
+    /// let policy = VersionReq::parse(">=1.0.0-alpha").unwrap();
+    /// let plugin = VersionReq::parse(">=1.0.0-beta, <1.1").unwrap();
+    /// assert_eq!(policy.intersection(&plugin).to_string(), ">=1.0.0-beta, <1.1.0");
+    /// ```
+    pub const fn intersection(&self, other: &VersionReq) -> Self {
+        let lower = Version::const_max(self.lower, other.lower);
+        let upper = Version::const_min(self.upper, other.upper);
+        let releases = Self::new_bounded(Some(lower), Some(upper));
+        match (&self.pre, &other.pre) {
+            (Some(lhs), Some(rhs)) => Self {
+                pre: lhs.intersection(rhs),
+                ..releases
+            },
+            _ => releases,
+        }
+    }
+
+    /// The shortest list of comparators whose [intersection](VersionReq::intersection) is this
+    /// requirement, see [VariantList::to_req].
+    ///
+    /// [VersionReq::STAR] has no comparators, [VersionReq::EMPTY] is `<0` and a single version is
+    /// [VersionReqVariant::Strict]. Otherwise there is a lower and an upper bound, each using the
+    /// shortest variant, so `>=1.2.0` becomes [VersionReqVariant::MinorGreaterEqual]. Variants
+    /// can't name a prerelease, so they only cover the matching [Version]s.
+    /// ```
+    /// # use fast_version_core::version_req::{VersionReq, VersionReqVariant};
+    /// let req = VersionReq::parse(">=1.2.0, <2.0.0").unwrap();
+    /// let variants = req.to_variants();
+    ///
+    /// assert_eq!(
+    ///     variants.as_slice(),
+    ///     [
+    ///         VersionReqVariant::MinorGreaterEqual { major: 1, minor: 2 },
+    ///         VersionReqVariant::MajorLessEqual { major: 1 },
+    ///     ]
+    /// );
+    /// assert_eq!(variants.to_req(), req);
+    /// ```
+    pub fn to_variants(&self) -> VariantList {
+        let mut list = VariantList {
+            variants: [VersionReqVariant::Strict(Self::STAR.lower); 2],
+            len: 0,
+        };
+        if !self.has_releases() {
+            list.push(VersionReqVariant::MajorLess { major: 0 });
+            return list;
+        }
+        if self.lower.const_eq(&self.upper) {
+            list.push(VersionReqVariant::Strict(self.lower));
+            return list;
+        }
+        let Version {
+            major,
+            minor,
+            patch,
+        } = self.lower;
+        match (minor, patch) {
+            _ if self.lower == Self::STAR.lower => {}
+            (0, 0) => list.push(VersionReqVariant::MajorGreaterEqual { major }),
+            (_, 0) => list.push(VersionReqVariant::MinorGreaterEqual { major, minor }),
+            _ => list.push(VersionReqVariant::PatchGreaterEqual {
+                major,
+                minor,
+                patch,
+            }),
+        }
+        let Version {
+            major,
+            minor,
+            patch,
+        } = self.upper;
+        match (minor, patch) {
+            _ if self.upper == Self::STAR.upper => {}
+            (u64::MAX, u64::MAX) => list.push(VersionReqVariant::MajorLessEqual { major }),
+            (_, u64::MAX) => list.push(VersionReqVariant::MinorLessEqual { major, minor }),
+            _ => list.push(VersionReqVariant::PatchLessEqual {
+                major,
+                minor,
+                patch,
+            }),
+        }
+        list
+    }
+
+    /// Hash of the requirement that is stable across runs, platforms and releases of this crate.
+    ///
+    /// Requirements are stored normalized, so equivalent requirements like `>1.2.3` and
+    /// `>=1.2.4` are equal and hash to the same value. The hash is 64 bit FNV-1a over the
+    /// [sort keys](Version::sort_key) of the inclusive lower and upper bound, followed by the
+    /// bounds for prereleases if there are any.
+    /// ```
+    /// # use fast_version_core::version_req::VersionReq;
+    /// let lhs = VersionReq::parse(">1.2.3").unwrap();
+    /// let rhs = VersionReq::parse(">=1.2.4").unwrap();
+    ///
+    /// assert_eq!(lhs.canonical_hash(), rhs.canonical_hash());
+    /// ```
+    pub const fn canonical_hash(&self) -> u64 {
+        const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        let hash = fnv1a(OFFSET_BASIS, &self.lower.sort_key());
+        let mut hash = fnv1a(hash, &self.upper.sort_key());
+        if let Some(bounds) = &self.pre {
+            let bounds = [bounds.lower, bounds.upper];
+            let mut index = 0;
+            while index < bounds.len() {
+                hash = match &bounds[index] {
+                    // Tagged like the bounds of the binary encoding.
+                    None => fnv1a(hash, &[0]),
+                    Some(bound) => {
+                        let hash = fnv1a(hash, &[1 + bound.inclusive as u8]);
+                        let hash = fnv1a(hash, &bound.version.sort_key());
+                        let pre = bound.pre.as_bytes();
+                        fnv1a(fnv1a(hash, &[pre.len() as u8]), pre)
+                    }
+                };
+                index += 1;
+            }
+        }
+        hash
     }
 
     /// Normal constructer of the Version Requirenment.
@@ -144,105 +783,99 @@ impl VersionReq {
             VersionReqVariant::Strict(d) => Self::new_strict(d),
             VersionReqVariant::Compound(lower, upper) => Self::new_compound(lower, upper),
             VersionReqVariant::MajorGreater { major } => {
-                let major_geq = major.saturating_add(1);
-                Self::new_lower_bounded_equal(major_geq, 0, 0)
+                Self::new_lower_bounded_equal(successor(&Version::new(*major, u64::MAX, u64::MAX)))
             }
             VersionReqVariant::MinorGreater { major, minor } => {
-                let major_geq = major.saturating_add(1);
-                let minor_geq = minor.saturating_add(1);
-                Self::new_lower_bounded_equal(major_geq, minor_geq, 0)
+                Self::new_lower_bounded_equal(successor(&Version::new(*major, *minor, u64::MAX)))
             }
             VersionReqVariant::PatchGreater {
                 major,
                 minor,
                 patch,
-            } => {
-                let major_geq = major.saturating_add(1);
-                let minor_geq = minor.saturating_add(1);
-                let patch_geq = patch.saturating_add(1);
-                Self::new_lower_bounded_equal(major_geq, minor_geq, patch_geq)
-            }
+            } => Self::new_lower_bounded_equal(successor(&Version::new(*major, *minor, *patch))),
             VersionReqVariant::MajorGreaterEqual { major } => {
-                Self::new_lower_bounded_equal(*major, 0, 0)
+                Self::new_lower_bounded_equal(Some(Version::new(*major, 0, 0)))
             }
             VersionReqVariant::MinorGreaterEqual { major, minor } => {
-                Self::new_lower_bounded_equal(*major, *minor, 0)
+                Self::new_lower_bounded_equal(Some(Version::new(*major, *minor, 0)))
             }
             VersionReqVariant::PatchGreaterEqual {
                 major,
                 minor,
                 patch,
-            } => Self::new_lower_bounded_equal(*major, *minor, *patch),
+            } => Self::new_lower_bounded_equal(Some(Version::new(*major, *minor, *patch))),
             VersionReqVariant::MajorLess { major } => {
-                let major_leq = major.saturating_sub(1);
-                Self::new_upper_bounded_equal(major_leq, u64::MAX, u64::MAX)
+                Self::new_upper_bounded_equal(predecessor(&Version::new(*major, 0, 0)))
             }
             VersionReqVariant::MinorLess { major, minor } => {
-                let major_leq = major.saturating_sub(1);
-                let minor_leq = minor.saturating_sub(1);
-                Self::new_upper_bounded_equal(major_leq, minor_leq, u64::MAX)
+                Self::new_upper_bounded_equal(predecessor(&Version::new(*major, *minor, 0)))
             }
             VersionReqVariant::PatchLess {
                 major,
                 minor,
                 patch,
-            } => {
-                let major_leq = major.saturating_sub(1);
-                let minor_leq = minor.saturating_sub(1);
-                let patch_leq = patch.saturating_sub(1);
-                Self::new_upper_bounded_equal(major_leq, minor_leq, patch_leq)
-            }
+            } => Self::new_upper_bounded_equal(predecessor(&Version::new(*major, *minor, *patch))),
             VersionReqVariant::MajorLessEqual { major } => {
-                Self::new_upper_bounded_equal(*major, u64::MAX, u64::MAX)
+                Self::new_upper_bounded_equal(Some(Version::new(*major, u64::MAX, u64::MAX)))
             }
             VersionReqVariant::MinorLessEqual { major, minor } => {
-                Self::new_upper_bounded_equal(*major, *minor, u64::MAX)
+                Self::new_upper_bounded_equal(Some(Version::new(*major, *minor, u64::MAX)))
             }
             VersionReqVariant::PatchLessEqual {
                 major,
                 minor,
                 patch,
-            } => Self::new_upper_bounded_equal(*major, *minor, *patch),
+            } => Self::new_upper_bounded_equal(Some(Version::new(*major, *minor, *patch))),
+            VersionReqVariant::Caret(version) => Self::new_caret(version),
+            VersionReqVariant::Tilde(version) => Self::new_tilde(version),
         }
     }
 
+    /// Creates a requirement from inclusive bounds, returning [VersionReq::EMPTY] if there is no
+    /// version between them.
     #[inline]
-    const fn new_upper_bounded_equal(major: u64, minor: u64, patch: u64) -> Self {
-        Self {
-            major_lower: 0,
-            minor_lower: 0,
-            patch_lower: 0,
-            major_higher: major,
-            minor_higher: minor,
-            patch_higher: patch,
+    pub(crate) const fn new_bounded(lower: Option<Version>, upper: Option<Version>) -> Self {
+        match (lower, upper) {
+            (Some(lower), Some(upper)) if version_le(&lower, &upper) => Self {
+                lower,
+                upper,
+                pre: None,
+            },
+            _ => Self::EMPTY,
         }
     }
 
     #[inline]
-    const fn new_lower_bounded_equal(major: u64, minor: u64, patch: u64) -> Self {
-        Self {
-            major_lower: major,
-            minor_lower: minor,
-            patch_lower: patch,
-            major_higher: u64::MAX,
-            minor_higher: u64::MAX,
-            patch_higher: u64::MAX,
-        }
+    const fn new_upper_bounded_equal(upper: Option<Version>) -> Self {
+        Self::new_bounded(Some(Self::STAR.lower), upper)
+    }
+
+    #[inline]
+    const fn new_lower_bounded_equal(lower: Option<Version>) -> Self {
+        Self::new_bounded(lower, Some(Self::STAR.upper))
     }
 
     #[inline]
     const fn new_strict(version: &Version) -> Self {
-        let major = version.major;
-        let minor = version.minor;
-        let patch = version.patch;
-        VersionReq {
-            major_lower: major,
-            minor_lower: minor,
-            patch_lower: patch,
-            major_higher: major,
-            minor_higher: minor,
-            patch_higher: patch,
-        }
+        Self::new_bounded(Some(*version), Some(*version))
+    }
+
+    /// The inclusive upper bound keeps the components up to the first non-zero one, so it can't
+    /// overflow like the exclusive one would for `^18446744073709551615.0.0`.
+    #[inline]
+    const fn new_caret(version: &Version) -> Self {
+        let upper = match (version.major, version.minor) {
+            (0, 0) => *version,
+            (0, minor) => Version::new(0, minor, u64::MAX),
+            (major, _) => Version::new(major, u64::MAX, u64::MAX),
+        };
+        Self::new_bounded(Some(*version), Some(upper))
+    }
+
+    #[inline]
+    const fn new_tilde(version: &Version) -> Self {
+        let upper = Version::new(version.major, version.minor, u64::MAX);
+        Self::new_bounded(Some(*version), Some(upper))
     }
 
     #[inline]
@@ -250,81 +883,1272 @@ impl VersionReq {
         lower_bound: &VersionReqVariantLowerBound,
         upper_bound: &VersionReqVariantUpperBound,
     ) -> Self {
-        let (major_lower, minor_lower, patch_lower) = Self::new_lower_bound(lower_bound);
-        let (major_higher, minor_higher, patch_higher) = Self::new_upper_bound(upper_bound);
-        Self {
-            major_lower,
-            minor_lower,
-            patch_lower,
-            major_higher,
-            minor_higher,
-            patch_higher,
-        }
+        Self::new_bounded(
+            Self::new_lower_bound(lower_bound),
+            Self::new_upper_bound(upper_bound),
+        )
     }
 
+    /// Inclusive lower bound, None if no version can satisfy the bound.
     #[inline]
-    const fn new_lower_bound(lower_bound: &VersionReqVariantLowerBound) -> (u64, u64, u64) {
+    const fn new_lower_bound(lower_bound: &VersionReqVariantLowerBound) -> Option<Version> {
         match lower_bound {
             VersionReqVariantLowerBound::MajorGreater { major } => {
-                let major_geq = major.saturating_add(1);
-                (major_geq, 0, 0)
+                successor(&Version::new(*major, u64::MAX, u64::MAX))
             }
             VersionReqVariantLowerBound::MinorGreater { major, minor } => {
-                let major_geq = major.saturating_add(1);
-                let minor_geq = minor.saturating_add(1);
-                (major_geq, minor_geq, 0)
+                successor(&Version::new(*major, *minor, u64::MAX))
             }
             VersionReqVariantLowerBound::PatchGreater {
                 major,
                 minor,
                 patch,
-            } => {
-                let major_geq = major.saturating_add(1);
-                let minor_geq = minor.saturating_add(1);
-                let patch_geq = patch.saturating_add(1);
-                (major_geq, minor_geq, patch_geq)
-            }
-            VersionReqVariantLowerBound::MajorGreaterEqual { major } => (*major, 0, 0),
-            VersionReqVariantLowerBound::MinorGreaterEqual { major, minor } => (*major, *minor, 0),
+            } => successor(&Version::new(*major, *minor, *patch)),
+            VersionReqVariantLowerBound::MajorGreaterEqual { major } => {
+                Some(Version::new(*major, 0, 0))
+            }
+            VersionReqVariantLowerBound::MinorGreaterEqual { major, minor } => {
+                Some(Version::new(*major, *minor, 0))
+            }
             VersionReqVariantLowerBound::PatchGreaterEqual {
                 major,
                 minor,
                 patch,
-            } => (*major, *minor, *patch),
+            } => Some(Version::new(*major, *minor, *patch)),
         }
     }
 
+    /// Inclusive upper bound, None if no version can satisfy the bound.
     #[inline]
-    const fn new_upper_bound(upper_bound: &VersionReqVariantUpperBound) -> (u64, u64, u64) {
+    const fn new_upper_bound(upper_bound: &VersionReqVariantUpperBound) -> Option<Version> {
         match upper_bound {
             VersionReqVariantUpperBound::MajorLess { major } => {
-                let major_leq = major.saturating_sub(1);
-                (major_leq, u64::MAX, u64::MAX)
+                predecessor(&Version::new(*major, 0, 0))
             }
             VersionReqVariantUpperBound::MinorLess { major, minor } => {
-                let major_leq = major.saturating_sub(1);
-                let minor_leq = minor.saturating_sub(1);
-                (major_leq, minor_leq, u64::MAX)
+                predecessor(&Version::new(*major, *minor, 0))
             }
             VersionReqVariantUpperBound::PatchLess {
                 major,
                 minor,
                 patch,
-            } => {
-                let major_leq = major.saturating_sub(1);
-                let minor_leq = minor.saturating_sub(1);
-                let patch_leq = patch.saturating_sub(1);
-                (major_leq, minor_leq, patch_leq)
+            } => predecessor(&Version::new(*major, *minor, *patch)),
+            VersionReqVariantUpperBound::MajorLessEqual { major } => {
+                Some(Version::new(*major, u64::MAX, u64::MAX))
             }
-            VersionReqVariantUpperBound::MajorLessEqual { major } => (*major, u64::MAX, u64::MAX),
             VersionReqVariantUpperBound::MinorLessEqual { major, minor } => {
-                (*major, *minor, u64::MAX)
+                Some(Version::new(*major, *minor, u64::MAX))
             }
             VersionReqVariantUpperBound::PatchLessEqual {
                 major,
                 minor,
                 patch,
-            } => (*major, *minor, *patch),
+            } => Some(Version::new(*major, *minor, *patch)),
         }
     }
 }
+
+impl VersionReq {
+    /// Parses a requirement from comparator notation, see [FromStr for VersionReq](VersionReq#impl-FromStr-for-VersionReq).
+    #[inline]
+    pub fn parse(input: &str) -> Result<Self, VersionReqParseError> {
+        Self::from_str(input)
+    }
+}
+
+/// Up to two comparators returned by [VersionReq::to_variants].
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub struct VariantList {
+    variants: [VersionReqVariant; 2],
+    len: u8,
+}
+
+impl VariantList {
+    #[inline]
+    fn push(&mut self, variant: VersionReqVariant) {
+        self.variants[self.len as usize] = variant;
+        self.len += 1;
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[VersionReqVariant] {
+        &self.variants[..self.len as usize]
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    /// Whether there are no comparators, which is the case for [VersionReq::STAR].
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Folds the comparators back into a requirement by intersecting them.
+    pub fn to_req(&self) -> VersionReq {
+        self.as_slice()
+            .iter()
+            .fold(VersionReq::STAR, |req, variant| {
+                req.intersection(&VersionReq::new(variant))
+            })
+    }
+}
+
+impl<'a> IntoIterator for &'a VariantList {
+    type Item = &'a VersionReqVariant;
+    type IntoIter = core::slice::Iter<'a, VersionReqVariant>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_slice().iter()
+    }
+}
+
+/// Iterator returned by [VersionReq::split_by_major] and [VersionReq::split_by_major_capped].
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub struct SplitByMajor {
+    /// The part of the requirement not yielded yet.
+    rest: VersionReq,
+    cap: u64,
+}
+
+impl Iterator for SplitByMajor {
+    type Item = (u64, VersionReq);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.rest.has_releases() {
+            return None;
+        }
+        let major = self.rest.lower.major;
+        if major > self.cap || major == self.rest.upper.major {
+            return Some((major, core::mem::replace(&mut self.rest, VersionReq::EMPTY)));
+        }
+        let piece = self.rest.intersection(&VersionReq::major_line(major));
+        // The upper major is larger, so `major + 1` can't overflow.
+        self.rest = self
+            .rest
+            .intersection(&VersionReq::at_least(Version::new(major + 1, 0, 0)));
+        Some((major, piece))
+    }
+}
+
+impl FusedIterator for SplitByMajor {}
+
+/// Returned by [VersionReq::from_inclusive_range] if the lower bound is larger than the upper one.
+#[derive(Error, Debug, PartialEq, Eq, Clone, Copy)]
+#[error("The lower bound {lower} is larger than the upper bound {upper}")]
+pub struct InvalidBounds {
+    pub lower: Version,
+    pub upper: Version,
+}
+
+/// Returned when parsing a [VersionReq] fails. Positions are byte offsets into the input.
+#[derive(Error, Debug)]
+pub enum VersionReqParseError {
+    #[error("Unknown operator at position {position}")]
+    UnknownOperator { position: usize },
+    #[error("Malformed version at position {position}")]
+    MalformedNumber {
+        position: usize,
+        source: VersionParseError,
+    },
+    #[error("Comparator at position {position} conflicts with the previous one")]
+    ConflictingBounds { position: usize },
+    #[error("Found {found} comparators, at most 2 are supported")]
+    TooManyComparators { found: usize },
+    /// The prerelease or build metadata of a bound, like the `alpha..1` of `>=1.0.0-alpha..1`,
+    /// isn't valid SemVer.
+    #[error("Invalid prerelease or build metadata at position {position}")]
+    InvalidIdentifiers {
+        position: usize,
+        source: IdentifierError,
+    },
+    /// A [VersionReqSet](crate::version_req_set::VersionReqSet) comparator names a prerelease,
+    /// like the `-alpha` of `>=1.0.0-alpha`, but sets only hold releases.
+    #[error("Prerelease at position {position}, version requirement sets only hold releases")]
+    PrereleaseInSet { position: usize },
+}
+
+/// Parses up to two comma separated comparators, surrounding whitespace is ignored.
+///
+/// | comparator        | meaning                                   |
+/// |-------------------|-------------------------------------------|
+/// | `*`               | any version                               |
+/// | `1.2.3`, `=1.2.3` | exactly `1.2.3`                           |
+/// | `1.2`, `=1.2`     | `>=1.2.0, <1.3.0`                         |
+/// | `>1.2`, `>=1.2`   | [VersionReqVariant::MinorGreater] and so on |
+/// | `<1.2`, `<=1.2`   | [VersionReqVariant::MinorLess] and so on  |
+/// | `^1.2.3`          | `>=1.2.3, <2.0.0`, `^0.2.3` is `>=0.2.3, <0.3.0` and `^0.0.3` is `=0.0.3` |
+/// | `~1.2.3`          | `>=1.2.3, <1.3.0`                         |
+///
+/// Two comparators have to be a lower and an upper bound, like `>=1.2.3, <2.0.0`. The full
+/// grammar, with whitespace allowed around every comparator and after the operator:
+///
+/// ```text
+/// requirement = comparator [ "," comparator ]
+/// comparator  = "*" | [ operator ] partial
+/// operator    = "=" | ">" | ">=" | "<" | "<=" | "^" | "~"
+/// partial     = number [ "." number [ "." number [ "-" prerelease ] [ "+" build ] ] ]
+/// number      = digit { digit }    (at most u64::MAX)
+/// ```
+///
+/// A bound with all three numbers may name a prerelease, like `>=1.0.0-alpha.1`, see
+/// [prereleases](VersionReq#prereleases). Build metadata is checked and ignored, like in Cargo.
+///
+/// Everything [Display](fmt::Display) prints parses back to an equal requirement, including
+/// `<0.0.0` for [VersionReq::EMPTY] and bounds with `u64::MAX` components.
+/// ```
+/// # use fast_version_core::{version::Version, version_req::VersionReq};
+/// use core::str::FromStr;
+///
+/// let req = VersionReq::from_str(">=1.2, <2").unwrap();
+/// assert!(req.matches(&Version::new(1, 9, 0)));
+/// assert!(!req.matches(&Version::new(2, 0, 0)));
+///
+/// assert!(VersionReq::from_str(">=>1").is_err());
+/// ```
+impl FromStr for VersionReq {
+    type Err = VersionReqParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let found = s.split(',').count();
+        if found > 2 {
+            return Err(VersionReqParseError::TooManyComparators { found });
+        }
+        let mut comparators = Comparators::STAR;
+        let (mut lower, mut upper) = (false, false);
+        let mut offset = 0;
+        let mut position = 0;
+        for part in s.split(',') {
+            let comparator = part.trim_start();
+            position = offset + part.len() - comparator.len();
+            offset += part.len() + 1;
+            let comparator = parse_comparator(comparator.trim_end(), position)?;
+            let (comparator_lower, comparator_upper) = comparator.bounds;
+            if (lower && comparator_lower.is_some()) || (upper && comparator_upper.is_some()) {
+                return Err(VersionReqParseError::ConflictingBounds { position });
+            }
+            lower |= comparator_lower.is_some();
+            upper |= comparator_upper.is_some();
+            comparators.push(&comparator);
+        }
+        let req = comparators.req();
+        if found == 2 && req.is_empty() {
+            return Err(VersionReqParseError::ConflictingBounds { position });
+        }
+        Ok(req)
+    }
+}
+
+/// Version with optional minor and patch, like `1` or `1.2`.
+#[derive(Clone, Copy)]
+struct PartialVersion {
+    major: u64,
+    minor: Option<u64>,
+    patch: Option<u64>,
+}
+
+impl PartialVersion {
+    fn parse(input: &str) -> Result<Self, VersionParseError> {
+        let mut splits = input.split('.');
+        let major = match splits.next() {
+            Some("") | None => return Err(VersionParseError::MajorNotFound),
+            Some(major) => parse_digits(major, ComponentKind::Major)?,
+        };
+        let minor = splits
+            .next()
+            .map(|minor| parse_digits(minor, ComponentKind::Minor))
+            .transpose()?;
+        let patch = splits
+            .next()
+            .map(|patch| parse_digits(patch, ComponentKind::Patch))
+            .transpose()?;
+        if splits.next().is_some() {
+            return Err(VersionParseError::TooManyComponents {
+                found: component_count(input),
+            });
+        }
+        Ok(Self {
+            major,
+            minor,
+            patch,
+        })
+    }
+
+    fn greater(self, equal: bool) -> VersionReqVariantLowerBound {
+        use VersionReqVariantLowerBound::*;
+        let major = self.major;
+        match (self.minor, self.patch, equal) {
+            (None, _, false) => MajorGreater { major },
+            (None, _, true) => MajorGreaterEqual { major },
+            (Some(minor), None, false) => MinorGreater { major, minor },
+            (Some(minor), None, true) => MinorGreaterEqual { major, minor },
+            (Some(minor), Some(patch), false) => PatchGreater {
+                major,
+                minor,
+                patch,
+            },
+            (Some(minor), Some(patch), true) => PatchGreaterEqual {
+                major,
+                minor,
+                patch,
+            },
+        }
+    }
+
+    fn less(self, equal: bool) -> VersionReqVariantUpperBound {
+        use VersionReqVariantUpperBound::*;
+        let major = self.major;
+        match (self.minor, self.patch, equal) {
+            (None, _, false) => MajorLess { major },
+            (None, _, true) => MajorLessEqual { major },
+            (Some(minor), None, false) => MinorLess { major, minor },
+            (Some(minor), None, true) => MinorLessEqual { major, minor },
+            (Some(minor), Some(patch), false) => PatchLess {
+                major,
+                minor,
+                patch,
+            },
+            (Some(minor), Some(patch), true) => PatchLessEqual {
+                major,
+                minor,
+                patch,
+            },
+        }
+    }
+
+    /// Keeps the components up to the first non-zero one, which is the last compatible one.
+    fn caret_upper(self) -> VersionReqVariantUpperBound {
+        match (self.major, self.minor, self.patch) {
+            (0, Some(0), Some(_)) => self.less(true),
+            (0, Some(_), _) => self.without_patch().less(true),
+            _ => self.without_minor().less(true),
+        }
+    }
+
+    /// The comparator with the operator, anything but the valid ones is treated like `=`. A
+    /// prerelease needs all three components.
+    fn comparator(self, operator: &str, pre: Prerelease) -> Comparator {
+        if pre.is_empty() {
+            let bounds = self.bounds(operator);
+            return Comparator {
+                bounds,
+                full: full_bounds(bounds),
+            };
+        }
+        let version = Version::new(
+            self.major,
+            self.minor.unwrap_or_default(),
+            self.patch.unwrap_or_default(),
+        );
+        let at = |inclusive| Some(FullBound::new(version, pre, inclusive));
+        // Every release of the version is above its prereleases.
+        let (lower, upper) = (self.greater(true), self.less(false));
+        let (bounds, full_lower, full_upper) = match operator {
+            ">" => ((Some(lower), None), at(false), None),
+            ">=" => ((Some(lower), None), at(true), None),
+            "<" => ((None, Some(upper)), None, at(false)),
+            "<=" => ((None, Some(upper)), None, at(true)),
+            "^" => {
+                let upper = self.caret_upper();
+                ((Some(lower), Some(upper)), at(true), upper.full_bound())
+            }
+            "~" => {
+                let upper = self.tilde_upper();
+                ((Some(lower), Some(upper)), at(true), upper.full_bound())
+            }
+            _ => ((Some(lower), Some(upper)), at(true), at(true)),
+        };
+        Comparator {
+            bounds,
+            full: Some(PrereleaseBounds {
+                lower: full_lower,
+                upper: full_upper,
+            }),
+        }
+    }
+
+    /// The bounds of a comparator with the operator, anything but the valid ones is treated like
+    /// `=`.
+    fn bounds(self, operator: &str) -> Bounds {
+        match operator {
+            ">" => (Some(self.greater(false)), None),
+            ">=" => (Some(self.greater(true)), None),
+            "<" => (None, Some(self.less(false))),
+            "<=" => (None, Some(self.less(true))),
+            "^" => (Some(self.greater(true)), Some(self.caret_upper())),
+            "~" => (Some(self.greater(true)), Some(self.tilde_upper())),
+            _ => (Some(self.greater(true)), Some(self.less(true))),
+        }
+    }
+
+    /// Keeps major and minor.
+    fn tilde_upper(self) -> VersionReqVariantUpperBound {
+        self.without_patch().less(true)
+    }
+
+    fn without_patch(self) -> Self {
+        Self {
+            patch: None,
+            ..self
+        }
+    }
+
+    fn without_minor(self) -> Self {
+        Self {
+            minor: None,
+            patch: None,
+            ..self
+        }
+    }
+}
+
+type Bounds = (
+    Option<VersionReqVariantLowerBound>,
+    Option<VersionReqVariantUpperBound>,
+);
+
+/// The bounds of a single comparator, on releases and on full versions.
+#[derive(Clone, Copy)]
+pub(crate) struct Comparator {
+    bounds: Bounds,
+    /// None if the comparator excludes every full version, like `<0`.
+    full: Option<PrereleaseBounds>,
+}
+
+impl Comparator {
+    const STAR: Self = Self {
+        bounds: (None, None),
+        full: Some(PrereleaseBounds::UNBOUNDED),
+    };
+
+    /// The comparator that doesn't come from a string, with one of the operators `=`, `>`, `>=`,
+    /// `<`, `<=`, `^` and `~`. A prerelease needs all three components.
+    #[cfg(feature = "semver-interop")]
+    pub(crate) fn new(
+        operator: &str,
+        major: u64,
+        minor: Option<u64>,
+        patch: Option<u64>,
+        pre: Prerelease,
+    ) -> Self {
+        let partial = PartialVersion {
+            major,
+            minor,
+            patch,
+        };
+        partial.comparator(operator, pre)
+    }
+}
+
+/// Collects the comparators a requirement is the intersection of.
+///
+/// Like in Cargo a prerelease only has to lie within the bounds of all comparators and share its
+/// `major.minor.patch` with the prerelease one of them names, so the bounds on full versions are
+/// tightened here and only [normalized](PrereleaseBounds::normalize) at the end.
+pub(crate) struct Comparators {
+    releases: VersionReq,
+    /// None if a comparator excludes every full version.
+    full: Option<PrereleaseBounds>,
+}
+
+impl Comparators {
+    pub(crate) const STAR: Self = Self {
+        releases: VersionReq::STAR,
+        full: Some(PrereleaseBounds::UNBOUNDED),
+    };
+
+    pub(crate) fn push(&mut self, comparator: &Comparator) {
+        self.releases = self.releases.intersection(&bounds_req(comparator.bounds));
+        self.full = match (self.full, comparator.full) {
+            (Some(full), Some(bounds)) => Some(full.tighten(&bounds)),
+            _ => None,
+        };
+    }
+
+    pub(crate) fn req(&self) -> VersionReq {
+        match self.full {
+            Some(full) => self.releases.with_prerelease_bounds(full),
+            None => VersionReq::EMPTY,
+        }
+    }
+}
+
+fn parse_comparator(comparator: &str, position: usize) -> Result<Comparator, VersionReqParseError> {
+    if comparator == "*" {
+        return Ok(Comparator::STAR);
+    }
+    let (operator, partial, pre, _) = parse_comparator_parts(comparator, position)?;
+    Ok(partial.comparator(operator, pre))
+}
+
+/// The operator, the version, its prerelease and the position of the prerelease's `-`.
+fn parse_comparator_parts(
+    comparator: &str,
+    position: usize,
+) -> Result<(&str, PartialVersion, Prerelease, usize), VersionReqParseError> {
+    let operator_len = comparator
+        .find(|c: char| !matches!(c, '<' | '>' | '=' | '^' | '~' | '!'))
+        .unwrap_or(comparator.len());
+    let (operator, rest) = comparator.split_at(operator_len);
+    if !matches!(operator, "" | "=" | ">" | ">=" | "<" | "<=" | "^" | "~") {
+        return Err(VersionReqParseError::UnknownOperator { position });
+    }
+    let version = rest.trim_start();
+    let version_position = position + comparator.len() - version.len();
+    let malformed = |source| VersionReqParseError::MalformedNumber {
+        position: version_position,
+        source,
+    };
+    let (version, build) = split_identifiers(version, '+');
+    let (version, pre) = split_identifiers(version, '-');
+    let partial = PartialVersion::parse(version).map_err(malformed)?;
+    if pre.is_some() || build.is_some() {
+        match (partial.minor, partial.patch) {
+            (None, _) => return Err(malformed(VersionParseError::MinorNotFound)),
+            (_, None) => return Err(malformed(VersionParseError::PatchNotFound)),
+            _ => {}
+        }
+    }
+    // Build metadata doesn't take part in matching, it only has to be valid.
+    if let Some(build) = build {
+        parse_identifiers(build, version_position + version.len(), BuildMetadata::new)?;
+    }
+    let pre_position = version_position + version.len();
+    let pre = match pre {
+        Some(pre) => parse_identifiers(pre, pre_position, Prerelease::new)?,
+        None => Prerelease::EMPTY,
+    };
+    Ok((operator, partial, pre, pre_position))
+}
+
+/// Splits `version` at the first `separator` that isn't its first character, like `1.0.0-alpha`
+/// at `-`.
+fn split_identifiers(version: &str, separator: char) -> (&str, Option<&str>) {
+    match version.find(separator).filter(|index| *index > 0) {
+        Some(index) => (&version[..index], Some(&version[index + 1..])),
+        None => (version, None),
+    }
+}
+
+/// Parses the identifiers after the separator at byte `position` of the input, which must not
+/// be empty.
+fn parse_identifiers<T>(
+    identifiers: &str,
+    position: usize,
+    parse: fn(&str) -> Result<T, IdentifierError>,
+) -> Result<T, VersionReqParseError> {
+    let error = |source| VersionReqParseError::InvalidIdentifiers {
+        position: position + 1,
+        source,
+    };
+    if identifiers.is_empty() {
+        return Err(error(IdentifierError::EmptyIdentifier { position: 0 }));
+    }
+    parse(identifiers).map_err(error)
+}
+
+/// Parses a single trimmed comparator of a [VersionReqSet](crate::version_req_set::VersionReqSet)
+/// starting at byte `position` of the input, rejecting prereleases.
+#[cfg(feature = "alloc")]
+pub(crate) fn parse_comparator_req(
+    comparator: &str,
+    position: usize,
+) -> Result<VersionReq, VersionReqParseError> {
+    if comparator == "*" {
+        return Ok(VersionReq::STAR);
+    }
+    let (operator, partial, pre, pre_position) = parse_comparator_parts(comparator, position)?;
+    if !pre.is_empty() {
+        return Err(VersionReqParseError::PrereleaseInSet {
+            position: pre_position,
+        });
+    }
+    Ok(bounds_req(partial.comparator(operator, pre).bounds))
+}
+
+/// The requirement between the bounds, unbounded where one is missing.
+fn bounds_req((lower, upper): Bounds) -> VersionReq {
+    VersionReq::new_bounded(
+        lower.map_or(Some(VersionReq::STAR.lower), |lower| {
+            VersionReq::new_lower_bound(&lower)
+        }),
+        upper.map_or(Some(VersionReq::STAR.upper), |upper| {
+            VersionReq::new_upper_bound(&upper)
+        }),
+    )
+}
+
+/// The bounds on full versions, None if they exclude all of them.
+fn full_bounds((lower, upper): Bounds) -> Option<PrereleaseBounds> {
+    let upper = match upper {
+        Some(upper) => Some(upper.full_bound()?),
+        None => None,
+    };
+    Some(PrereleaseBounds {
+        lower: lower.and_then(VersionReqVariantLowerBound::full_bound),
+        upper,
+    })
+}
+
+/// Only plain digits, rejecting signs `u64::from_str` would accept.
+fn parse_digits(input: &str, component: ComponentKind) -> Result<u64, VersionParseError> {
+    if input.is_empty() || !input.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(component.parse_error());
+    }
+    parse_component(input, component)
+}
+
+impl VersionReqVariantLowerBound {
+    /// The single bound variant equivalent to this bound.
+    const fn to_variant(self) -> VersionReqVariant {
+        use VersionReqVariantLowerBound::*;
+        match self {
+            MajorGreater { major } => VersionReqVariant::MajorGreater { major },
+            MinorGreater { major, minor } => VersionReqVariant::MinorGreater { major, minor },
+            PatchGreater {
+                major,
+                minor,
+                patch,
+            } => VersionReqVariant::PatchGreater {
+                major,
+                minor,
+                patch,
+            },
+            MajorGreaterEqual { major } => VersionReqVariant::MajorGreaterEqual { major },
+            MinorGreaterEqual { major, minor } => {
+                VersionReqVariant::MinorGreaterEqual { major, minor }
+            }
+            PatchGreaterEqual {
+                major,
+                minor,
+                patch,
+            } => VersionReqVariant::PatchGreaterEqual {
+                major,
+                minor,
+                patch,
+            },
+        }
+    }
+}
+
+impl VersionReqVariantLowerBound {
+    /// The bound in the order of full versions, None if it doesn't exclude any. Like in Cargo a
+    /// partial version allows the prereleases of the versions it covers, so `>=1.2` is above
+    /// `1.1.18446744073709551615` while `>=1.2.0` is at `1.2.0`.
+    const fn full_bound(self) -> Option<FullBound> {
+        use VersionReqVariantLowerBound::*;
+        let (version, inclusive) = match self {
+            MajorGreater { major } => (Version::new(major, u64::MAX, u64::MAX), false),
+            MinorGreater { major, minor } => (Version::new(major, minor, u64::MAX), false),
+            PatchGreater {
+                major,
+                minor,
+                patch,
+            } => (Version::new(major, minor, patch), false),
+            MajorGreaterEqual { major } => match predecessor(&Version::new(major, 0, 0)) {
+                Some(version) => (version, false),
+                None => return None,
+            },
+            MinorGreaterEqual { major, minor } => {
+                match predecessor(&Version::new(major, minor, 0)) {
+                    Some(version) => (version, false),
+                    None => return None,
+                }
+            }
+            PatchGreaterEqual {
+                major,
+                minor,
+                patch,
+            } => (Version::new(major, minor, patch), true),
+        };
+        Some(FullBound::release(version, inclusive))
+    }
+}
+
+impl VersionReqVariantUpperBound {
+    /// The bound in the order of full versions, None if it excludes all of them. `<1.2` is at
+    /// `1.1.18446744073709551615`, while `<1.2.0` allows the prereleases of `1.2.0`.
+    const fn full_bound(self) -> Option<FullBound> {
+        use VersionReqVariantUpperBound::*;
+        let (version, inclusive) = match self {
+            MajorLess { major } => match predecessor(&Version::new(major, 0, 0)) {
+                Some(version) => (version, true),
+                None => return None,
+            },
+            MinorLess { major, minor } => match predecessor(&Version::new(major, minor, 0)) {
+                Some(version) => (version, true),
+                None => return None,
+            },
+            PatchLess {
+                major,
+                minor,
+                patch,
+            } => (Version::new(major, minor, patch), false),
+            MajorLessEqual { major } => (Version::new(major, u64::MAX, u64::MAX), true),
+            MinorLessEqual { major, minor } => (Version::new(major, minor, u64::MAX), true),
+            PatchLessEqual {
+                major,
+                minor,
+                patch,
+            } => (Version::new(major, minor, patch), true),
+        };
+        Some(FullBound::release(version, inclusive))
+    }
+}
+
+impl VersionReqVariantUpperBound {
+    /// The single bound variant equivalent to this bound.
+    const fn to_variant(self) -> VersionReqVariant {
+        use VersionReqVariantUpperBound::*;
+        match self {
+            MajorLess { major } => VersionReqVariant::MajorLess { major },
+            MinorLess { major, minor } => VersionReqVariant::MinorLess { major, minor },
+            PatchLess {
+                major,
+                minor,
+                patch,
+            } => VersionReqVariant::PatchLess {
+                major,
+                minor,
+                patch,
+            },
+            MajorLessEqual { major } => VersionReqVariant::MajorLessEqual { major },
+            MinorLessEqual { major, minor } => VersionReqVariant::MinorLessEqual { major, minor },
+            PatchLessEqual {
+                major,
+                minor,
+                patch,
+            } => VersionReqVariant::PatchLessEqual {
+                major,
+                minor,
+                patch,
+            },
+        }
+    }
+}
+
+/// Renders the variant the way it was written, keeping partial versions like `>=1.2` and
+/// exclusive bounds. Strict versions get a leading `=`, compound variants are joined by `, `.
+/// Caret and tilde variants are printed with their operator, like `^1.2.3`.
+/// Parsing the result gives the same [VersionReq] as [VersionReq::new], except for compound
+/// variants that allow no version, which parsing rejects as conflicting.
+/// ```
+/// # use fast_version_core::version_req::{VersionReqVariant, VersionReqVariantLowerBound, VersionReqVariantUpperBound};
+/// let variant = VersionReqVariant::Compound(
+///     VersionReqVariantLowerBound::MinorGreaterEqual { major: 1, minor: 2 },
+///     VersionReqVariantUpperBound::MajorLess { major: 2 },
+/// );
+/// assert_eq!(variant.to_string(), ">=1.2, <2");
+/// ```
+impl fmt::Display for VersionReqVariant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use VersionReqVariant::*;
+        match *self {
+            Strict(version) => write!(f, "={version}"),
+            Compound(lower, upper) => write!(f, "{lower}, {upper}"),
+            MajorGreater { major } => write!(f, ">{major}"),
+            MinorGreater { major, minor } => write!(f, ">{major}.{minor}"),
+            PatchGreater {
+                major,
+                minor,
+                patch,
+            } => write!(f, ">{major}.{minor}.{patch}"),
+            MajorGreaterEqual { major } => write!(f, ">={major}"),
+            MinorGreaterEqual { major, minor } => write!(f, ">={major}.{minor}"),
+            PatchGreaterEqual {
+                major,
+                minor,
+                patch,
+            } => write!(f, ">={major}.{minor}.{patch}"),
+            MajorLess { major } => write!(f, "<{major}"),
+            MinorLess { major, minor } => write!(f, "<{major}.{minor}"),
+            PatchLess {
+                major,
+                minor,
+                patch,
+            } => write!(f, "<{major}.{minor}.{patch}"),
+            MajorLessEqual { major } => write!(f, "<={major}"),
+            MinorLessEqual { major, minor } => write!(f, "<={major}.{minor}"),
+            PatchLessEqual {
+                major,
+                minor,
+                patch,
+            } => write!(f, "<={major}.{minor}.{patch}"),
+            Caret(version) => write!(f, "^{version}"),
+            Tilde(version) => write!(f, "~{version}"),
+        }
+    }
+}
+
+/// Renders the bound like `>1.2` or `>=1.2.3`.
+impl fmt::Display for VersionReqVariantLowerBound {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_variant(), f)
+    }
+}
+
+/// Renders the bound like `<2` or `<=1.2.3`.
+impl fmt::Display for VersionReqVariantUpperBound {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_variant(), f)
+    }
+}
+
+/// Renders the requirement in comparator notation.
+///
+/// * `*` for [VersionReq::STAR] and `<0.0.0` for [VersionReq::EMPTY]
+/// * `=1.2.3` if only a single version matches
+/// * otherwise `>=lower` and `<upper` or `<=upper` joined by `, `, leaving out the sides without
+///   bound. An upper bound is printed exclusive if its patch is `u64::MAX`, so `<2.0.0` instead of
+///   `<=1.18446744073709551615.18446744073709551615`.
+///
+/// A requirement matching prereleases prints the bounds that name them, like `>=1.0.0-alpha`
+/// or `=1.0.0-beta`. Bounds on releases are printed the same way, except where the exclusive form
+/// matters for the prereleases, as in `>1.2.3, <1.2.4-beta` or `>=1.2, <1.2.0-beta`.
+/// ```
+/// # use fast_version_core::version_req::{VersionReq, VersionReqVariant};
+/// let req = VersionReq::new(&VersionReqVariant::MinorGreaterEqual { major: 1, minor: 2 });
+/// assert_eq!(req.to_string(), ">=1.2.0");
+///
+/// let req = VersionReq::new(&VersionReqVariant::MajorLess { major: 2 });
+/// assert_eq!(req.to_string(), "<2.0.0");
+///
+/// assert_eq!(VersionReq::STAR.to_string(), "*");
+///
+/// let req = VersionReq::parse("^1.2.3-alpha.1").unwrap();
+/// assert_eq!(req.to_string(), ">=1.2.3-alpha.1, <2.0.0");
+/// ```
+impl fmt::Display for VersionReq {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(bounds) = &self.pre {
+            return fmt::Display::fmt(bounds, f);
+        }
+        if self.is_empty() {
+            return write!(f, "<0.0.0");
+        }
+        if let Some(version) = self.as_exact() {
+            return write!(f, "={}", version);
+        }
+        let lower = (self.lower != Self::STAR.lower).then_some(self.lower);
+        let upper = (self.upper != Self::STAR.upper).then_some(self.upper);
+        if let Some(lower) = lower {
+            write!(f, ">={}", lower)?;
+        }
+        if lower.is_some() && upper.is_some() {
+            write!(f, ", ")?;
+        }
+        if let Some(upper) = upper {
+            match successor(&upper) {
+                Some(next) if upper.patch == u64::MAX => write!(f, "<{}", next)?,
+                _ => write!(f, "<={}", upper)?,
+            }
+        }
+        if lower.is_none() && upper.is_none() {
+            write!(f, "*")?;
+        }
+        Ok(())
+    }
+}
+
+/// Prints the requirement in comparator notation, like `VersionReq(">=1.2.0, <2.0.0")`. The
+/// alternate flag (`{:#?}`) prints the inclusive bounds.
+impl fmt::Debug for VersionReq {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            let mut debug = f.debug_struct("VersionReq");
+            debug
+                .field("lower", &self.lower)
+                .field("upper", &self.upper);
+            if let Some(bounds) = &self.pre {
+                debug.field("pre", bounds);
+            }
+            debug.finish()
+        } else {
+            write!(f, "VersionReq(\"{}\")", self)
+        }
+    }
+}
+
+/// The matching versions as bounds, so a requirement can query ordered collections directly.
+///
+/// A bound is [Bound::Unbounded] if it doesn't exclude any version. The empty requirement is
+/// `Included(v)..Excluded(v)`, which ranges over nothing without making
+/// `BTreeSet::range` panic.
+/// ```
+/// # use fast_version_core::{version::Version, version_req::VersionReq};
+/// use std::collections::BTreeSet;
+///
+/// let set: BTreeSet<_> = [(1, 0, 0), (1, 4, 2), (2, 0, 0)]
+///     .map(|(major, minor, patch)| Version::new(major, minor, patch))
+///     .into();
+/// let req = VersionReq::parse("^1.2").unwrap();
+///
+/// assert_eq!(set.range(req).collect::<Vec<_>>(), [&Version::new(1, 4, 2)]);
+/// assert_eq!(set.range(VersionReq::EMPTY).count(), 0);
+/// ```
+impl RangeBounds<Version> for VersionReq {
+    fn start_bound(&self) -> Bound<&Version> {
+        if !self.has_releases() {
+            Bound::Included(&self.lower)
+        } else if self.is_unbounded_below() {
+            Bound::Unbounded
+        } else {
+            Bound::Included(&self.lower)
+        }
+    }
+
+    fn end_bound(&self) -> Bound<&Version> {
+        if !self.has_releases() {
+            Bound::Excluded(&self.lower)
+        } else if self.is_unbounded_above() {
+            Bound::Unbounded
+        } else {
+            Bound::Included(&self.upper)
+        }
+    }
+}
+
+/// Prints the bounds like [VersionReq] does, `=version` if they are the same inclusive one.
+impl fmt::Display for PrereleaseBounds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.lower, self.upper) {
+            (Some(lower), Some(upper)) if lower == upper && lower.inclusive => {
+                write!(f, "={}-{}", lower.version, lower.pre)
+            }
+            (Some(lower), Some(upper)) => write!(f, "{}, {}", Lower(lower), Upper(upper)),
+            (Some(lower), None) => write!(f, "{}", Lower(lower)),
+            (None, Some(upper)) => write!(f, "{}", Upper(upper)),
+            (None, None) => write!(f, "*"),
+        }
+    }
+}
+
+/// A [FullBound] printed as lower bound.
+struct Lower(FullBound);
+
+/// A [FullBound] printed as upper bound.
+struct Upper(FullBound);
+
+impl fmt::Display for Lower {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let FullBound {
+            version,
+            pre,
+            inclusive,
+        } = self.0;
+        match (pre.is_empty(), inclusive, successor(&version)) {
+            (false, true, _) => write!(f, ">={version}-{pre}"),
+            (false, false, _) => write!(f, ">{version}-{pre}"),
+            (true, true, _) => write!(f, ">={version}"),
+            // Above all of a minor or major line is the partial version after it.
+            (true, false, Some(next)) if next.patch == 0 && next.minor == 0 => {
+                write!(f, ">={}", next.major)
+            }
+            (true, false, Some(next)) if next.patch == 0 => {
+                write!(f, ">={}.{}", next.major, next.minor)
+            }
+            (true, false, _) => write!(f, ">{version}"),
+        }
+    }
+}
+
+impl fmt::Display for Upper {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let FullBound {
+            version,
+            pre,
+            inclusive,
+        } = self.0;
+        match (pre.is_empty(), inclusive, successor(&version)) {
+            (false, true, _) => write!(f, "<={version}-{pre}"),
+            (false, false, _) => write!(f, "<{version}-{pre}"),
+            (true, true, Some(next)) if version.patch == u64::MAX => write!(f, "<{next}"),
+            (true, true, _) => write!(f, "<={version}"),
+            (true, false, _) => write!(f, "<{version}"),
+        }
+    }
+}
+
+/// Same as for [VersionReq], for `set.range(&req)`.
+impl RangeBounds<Version> for &VersionReq {
+    fn start_bound(&self) -> Bound<&Version> {
+        (*self).start_bound()
+    }
+
+    fn end_bound(&self) -> Bound<&Version> {
+        (*self).end_bound()
+    }
+}
+
+impl FullBound {
+    #[inline]
+    const fn new(version: Version, pre: Prerelease, inclusive: bool) -> Self {
+        Self {
+            version,
+            pre,
+            inclusive,
+        }
+    }
+
+    /// Bound on the release `version`.
+    #[inline]
+    const fn release(version: Version, inclusive: bool) -> Self {
+        Self::new(version, Prerelease::EMPTY, inclusive)
+    }
+
+    /// Compares the versions the bounds are on, ignoring whether they are inclusive.
+    const fn cmp_position(&self, other: &FullBound) -> Ordering {
+        match self.version.const_cmp(&other.version) {
+            Ordering::Equal => self.pre.const_cmp(&other.pre),
+            ordering => ordering,
+        }
+    }
+
+    /// Returns true if the full version `version-pre` is above this lower bound.
+    const fn is_below(&self, version: &Version, pre: &Prerelease) -> bool {
+        match self.cmp_position(&Self::new(*version, *pre, true)) {
+            Ordering::Less => true,
+            Ordering::Equal => self.inclusive,
+            Ordering::Greater => false,
+        }
+    }
+
+    /// Returns true if the full version `version-pre` is below this upper bound.
+    const fn is_above(&self, version: &Version, pre: &Prerelease) -> bool {
+        match self.cmp_position(&Self::new(*version, *pre, true)) {
+            Ordering::Less => false,
+            Ordering::Equal => self.inclusive,
+            Ordering::Greater => true,
+        }
+    }
+
+    /// The `major.minor.patch` of the prerelease the bound names, None for a bound on a release.
+    const fn named(bound: &Option<FullBound>) -> Option<Version> {
+        match bound {
+            Some(bound) if !bound.pre.is_empty() => Some(bound.version),
+            _ => None,
+        }
+    }
+
+    /// Whether a prerelease of `version` lies above the `lower` bound on a prerelease of the same
+    /// version and below `upper`.
+    const fn admits_above(lower: &FullBound, upper: &Option<FullBound>) -> bool {
+        let Some(upper) = upper else {
+            return true;
+        };
+        if upper.pre.is_empty() {
+            return version_le(&lower.version, &upper.version);
+        }
+        if !lower.version.const_eq(&upper.version) {
+            return version_le(&lower.version, &upper.version);
+        }
+        match lower.pre.const_cmp(&upper.pre) {
+            Ordering::Less => true,
+            Ordering::Equal => lower.inclusive && upper.inclusive,
+            Ordering::Greater => false,
+        }
+    }
+
+    /// Whether a prerelease of `version` lies below the `upper` bound on a prerelease of the same
+    /// version and above `lower`.
+    const fn admits_below(lower: &Option<FullBound>, upper: &FullBound) -> bool {
+        match lower {
+            None => true,
+            Some(lower) if lower.pre.is_empty() => !version_le(&upper.version, &lower.version),
+            Some(lower) => Self::admits_above(lower, &Some(*upper)),
+        }
+    }
+}
+
+impl PrereleaseBounds {
+    /// The bounds of a comparator that doesn't exclude any version, like `*`.
+    pub(crate) const UNBOUNDED: Self = Self {
+        lower: None,
+        upper: None,
+    };
+
+    /// Returns true if one of the bounds names a prerelease of `version`.
+    const fn names(&self, version: &Version) -> bool {
+        matches!(FullBound::named(&self.lower), Some(named) if named.const_eq(version))
+            || matches!(FullBound::named(&self.upper), Some(named) if named.const_eq(version))
+    }
+
+    /// The bounds of both, if both have to be satisfied.
+    pub(crate) const fn tighten(&self, other: &PrereleaseBounds) -> Self {
+        let lower = match (self.lower, other.lower) {
+            (None, lower) | (lower, None) => lower,
+            (Some(lhs), Some(rhs)) => match lhs.cmp_position(&rhs) {
+                Ordering::Greater => Some(lhs),
+                Ordering::Less => Some(rhs),
+                Ordering::Equal if lhs.inclusive => Some(rhs),
+                Ordering::Equal => Some(lhs),
+            },
+        };
+        let upper = match (self.upper, other.upper) {
+            (None, upper) | (upper, None) => upper,
+            (Some(lhs), Some(rhs)) => match lhs.cmp_position(&rhs) {
+                Ordering::Less => Some(lhs),
+                Ordering::Greater => Some(rhs),
+                Ordering::Equal if lhs.inclusive => Some(rhs),
+                Ordering::Equal => Some(lhs),
+            },
+        };
+        Self { lower, upper }
+    }
+
+    /// The bounds for the prereleases matching both requirements. Prereleases of a version
+    /// only one of them names are excluded, the bounds on them are moved to the release.
+    const fn intersection(&self, other: &PrereleaseBounds) -> Option<Self> {
+        let Self {
+            mut lower,
+            mut upper,
+        } = self.tighten(other);
+        if let Some(version) = FullBound::named(&lower) {
+            if !self.names(&version) || !other.names(&version) {
+                lower = Some(FullBound::release(version, true));
+            }
+        }
+        if let Some(version) = FullBound::named(&upper) {
+            if !self.names(&version) || !other.names(&version) {
+                upper = Some(FullBound::release(version, false));
+            }
+        }
+        Self { lower, upper }.normalize()
+    }
+
+    /// Brings the bounds into their canonical form, None if they don't admit any prerelease.
+    ///
+    /// An exclusive bound on a release only differs from the inclusive one on the next or
+    /// previous release in the prereleases of that next release, which only match if the other
+    /// bound names them. Otherwise the inclusive one is kept, so `>1.2.3` and `>=1.2.4` are
+    /// equal here too.
+    pub(crate) const fn normalize(self) -> Option<Self> {
+        let Self {
+            mut lower,
+            mut upper,
+        } = self;
+        let lower_named = FullBound::named(&lower);
+        let upper_named = FullBound::named(&upper);
+        if let Some(FullBound {
+            version,
+            pre,
+            inclusive: false,
+        }) = lower
+        {
+            if let (true, Some(next)) = (pre.is_empty(), successor(&version)) {
+                if !matches!(upper_named, Some(named) if named.const_eq(&next)) {
+                    lower = Some(FullBound::release(next, true));
+                }
+            }
+        }
+        if let Some(FullBound {
+            version,
+            pre,
+            inclusive: false,
+        }) = upper
+        {
+            if let (true, Some(previous)) = (pre.is_empty(), predecessor(&version)) {
+                if !matches!(lower_named, Some(named) if named.const_eq(&version)) {
+                    upper = Some(FullBound::release(previous, true));
+                }
+            }
+        }
+        let admits_lower = match &lower {
+            Some(bound) if lower_named.is_some() => FullBound::admits_above(bound, &upper),
+            _ => false,
+        };
+        let admits_upper = match &upper {
+            Some(bound) if upper_named.is_some() => FullBound::admits_below(&lower, bound),
+            _ => false,
+        };
+        if admits_lower || admits_upper {
+            Some(Self { lower, upper })
+        } else {
+            None
+        }
+    }
+
+    /// Returns true if the prerelease `version-pre` is in bounds and one of them names a
+    /// prerelease of `version`.
+    pub(crate) const fn matches(&self, version: &Version, pre: &Prerelease) -> bool {
+        self.names(version)
+            && match &self.lower {
+                Some(lower) => lower.is_below(version, pre),
+                None => true,
+            }
+            && match &self.upper {
+                Some(upper) => upper.is_above(version, pre),
+                None => true,
+            }
+    }
+}
+
+/// Const equivalent of `lhs <= rhs`.
+#[inline]
+pub(crate) const fn version_le(lhs: &Version, rhs: &Version) -> bool {
+    if lhs.major != rhs.major {
+        return lhs.major < rhs.major;
+    }
+    if lhs.minor != rhs.minor {
+        return lhs.minor < rhs.minor;
+    }
+    lhs.patch <= rhs.patch
+}
+
+/// The next larger version, None for the largest possible version.
+#[inline]
+pub(crate) const fn successor(version: &Version) -> Option<Version> {
+    if version.patch != u64::MAX {
+        Some(Version::new(
+            version.major,
+            version.minor,
+            version.patch + 1,
+        ))
+    } else if version.minor != u64::MAX {
+        Some(Version::new(version.major, version.minor + 1, 0))
+    } else if version.major != u64::MAX {
+        Some(Version::new(version.major + 1, 0, 0))
+    } else {
+        None
+    }
+}
+
+/// The next smaller version, None for `0.0.0`.
+#[inline]
+pub(crate) const fn predecessor(version: &Version) -> Option<Version> {
+    if version.patch != 0 {
+        Some(Version::new(
+            version.major,
+            version.minor,
+            version.patch - 1,
+        ))
+    } else if version.minor != 0 {
+        Some(Version::new(version.major, version.minor - 1, u64::MAX))
+    } else if version.major != 0 {
+        Some(Version::new(version.major - 1, u64::MAX, u64::MAX))
+    } else {
+        None
+    }
+}
+
+/// Folds the bytes into a 64 bit FNV-1a hash.
+const fn fnv1a(mut hash: u64, bytes: &[u8]) -> u64 {
+    const PRIME: u64 = 0x0100_0000_01b3;
+    let mut index = 0;
+    while index < bytes.len() {
+        hash ^= bytes[index] as u64;
+        hash = hash.wrapping_mul(PRIME);
+        index += 1;
+    }
+    hash
+}
+
+/// Lexicographic `lhs <= rhs` over the lanes, the first differing lane decides.
+#[cfg(feature = "nightly")]
+#[inline]
+fn simd_le(lhs: u64x4, rhs: u64x4) -> bool {
+    let less = lhs.simd_lt(rhs).to_bitmask();
+    let greater = lhs.simd_gt(rhs).to_bitmask();
+    greater == 0 || less.trailing_zeros() < greater.trailing_zeros()
+}
@@ -0,0 +1,533 @@
+use crate::release_range::ReleaseRange;
+#[cfg(feature = "rkyv")]
+pub use crate::rkyv_impls::ArchivedVersionReqSet;
+use crate::version::Version;
+use crate::version_req::{
+    parse_comparator_req, predecessor, successor, version_le, VersionReq, VersionReqParseError,
+};
+use alloc::vec::Vec;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::Range;
+use core::str::FromStr;
+use thiserror::Error;
+
+/// Union of disjoint [ReleaseRange]s, for sets of versions a single [VersionReq] can't express
+/// like "`>=1.0.0, <1.5.0` or `>=1.6.0`".
+///
+/// The ranges are kept sorted. Two stored ranges neither overlap nor touch, so every set has
+/// exactly one representation. A set only holds releases, a requirement that
+/// [matches prereleases](VersionReq#prereleases) is added without them.
+/// ## Example:
+/// ```
+/// # use fast_version_core::{version::Version, version_req::VersionReq};
+/// let allowed = VersionReq::parse(">=1, <2").unwrap();
+/// let broken = VersionReq::parse(">=1.5, <1.6").unwrap();
+/// let set = allowed.difference(&broken);
+///
+/// assert_eq!(set.ranges().len(), 2);
+/// assert!(set.matches(&Version::new(1, 4, 9)));
+/// assert!(!set.matches(&Version::new(1, 5, 3)));
+/// assert!(set.matches(&Version::new(1, 6, 0)));
+/// ```
+///
+/// ## Const sets
+/// Up to [INLINE_CAPACITY](Self::INLINE_CAPACITY) ranges are stored inline, so small sets can be
+/// built in a `const` or `static` with [from_array](Self::from_array). Larger sets move their
+/// ranges to the heap. As the set may own heap memory, const evaluation can only query it through
+/// a `static`, using a `const` there would drop a temporary copy.
+/// ```
+/// # use fast_version_core::{version::Version, version_req::VersionReq, version_req_set::VersionReqSet};
+/// static POLICY: VersionReqSet = VersionReqSet::from_array([
+///     VersionReq::between(Version::new(1, 0, 0), Version::new(1, 4, 9)),
+///     VersionReq::at_least(Version::new(2, 0, 0)),
+/// ]);
+///
+/// const _: () = assert!(POLICY.matches(&Version::new(1, 2, 0)));
+/// const _: () = assert!(!POLICY.matches(&Version::new(1, 7, 0)));
+/// ```
+#[derive(Clone)]
+pub struct VersionReqSet {
+    inline: [ReleaseRange; VersionReqSet::INLINE_CAPACITY],
+    len: usize,
+    /// Holds all ranges instead of `inline` once there are more than fit there.
+    heap: Vec<ReleaseRange>,
+}
+
+/// Fills the unused inline slots.
+const UNUSED: ReleaseRange = ReleaseRange {
+    lower: Version::ZERO,
+    upper: Version::ZERO,
+};
+
+impl VersionReqSet {
+    /// The number of ranges stored without allocation, the most [from_array](Self::from_array)
+    /// accepts.
+    pub const INLINE_CAPACITY: usize = 8;
+
+    /// The empty set, matching no version.
+    pub const fn new() -> Self {
+        Self {
+            inline: [UNUSED; Self::INLINE_CAPACITY],
+            len: 0,
+            heap: Vec::new(),
+        }
+    }
+
+    /// Builds a set from ranges that are already in its canonical form: non-empty, ascending and
+    /// neither overlapping nor touching. Use [From] for arbitrary ranges, which merges them.
+    ///
+    /// # Panics
+    /// If the ranges aren't canonical or there are more than
+    /// [INLINE_CAPACITY](Self::INLINE_CAPACITY) of them, which fails the build in a const context.
+    /// ```
+    /// # use fast_version_core::{version::Version, version_req::VersionReq, version_req_set::VersionReqSet};
+    /// static BLOCKED: VersionReqSet = VersionReqSet::from_array([
+    ///     VersionReq::exact(Version::new(1, 5, 0)),
+    ///     VersionReq::exact(Version::new(1, 6, 2)),
+    /// ]);
+    ///
+    /// assert!(BLOCKED.matches(&Version::new(1, 6, 2)));
+    /// ```
+    pub const fn from_array<const N: usize>(array: [VersionReq; N]) -> Self {
+        assert!(
+            N <= Self::INLINE_CAPACITY,
+            "too many ranges for an inline set"
+        );
+        let mut inline = [UNUSED; Self::INLINE_CAPACITY];
+        let mut index = 0;
+        while index < N {
+            let Some(range) = ReleaseRange::of(&array[index]) else {
+                panic!("empty range");
+            };
+            if index > 0 {
+                let touches = match successor(&inline[index - 1].upper) {
+                    Some(next) => version_le(&range.lower, &next),
+                    None => true,
+                };
+                assert!(!touches, "ranges out of order, overlapping or touching");
+            }
+            inline[index] = range;
+            index += 1;
+        }
+        Self {
+            inline,
+            len: N,
+            heap: Vec::new(),
+        }
+    }
+
+    /// The disjoint ranges in ascending order.
+    #[inline]
+    pub const fn ranges(&self) -> &[ReleaseRange] {
+        if self.heap.is_empty() {
+            self.inline.split_at(self.len).0
+        } else {
+            self.heap.as_slice()
+        }
+    }
+
+    /// Returns true if no version matches the set.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.ranges().is_empty()
+    }
+
+    /// Checks wether any of the ranges matches the version.
+    pub const fn matches(&self, version: &Version) -> bool {
+        let ranges = self.ranges();
+        let mut index = 0;
+        while index < ranges.len() {
+            if ranges[index].matches(version) {
+                return true;
+            }
+            index += 1;
+        }
+        false
+    }
+
+    /// The versions in `self` that don't match `other`.
+    /// ```
+    /// # use fast_version_core::{version::Version, version_req::VersionReq, version_req_set::VersionReqSet};
+    /// let set = VersionReqSet::from(VersionReq::parse(">=1, <2").unwrap());
+    /// let set = set.difference(&VersionReq::exact(Version::new(1, 5, 0)));
+    ///
+    /// assert_eq!(set.ranges().len(), 2);
+    /// assert!(!set.matches(&Version::new(1, 5, 0)));
+    /// ```
+    pub fn difference(&self, other: &VersionReq) -> VersionReqSet {
+        let mut set = Self::new();
+        for range in self.ranges() {
+            for part in range.to_req().difference(other).ranges() {
+                set.push_above(part.to_req());
+            }
+        }
+        set
+    }
+
+    /// Builds the set of versions matching any of the ranges, which may overlap and come in any
+    /// order.
+    /// ```
+    /// # use fast_version_core::{version_req::VersionReq, version_req_set::VersionReqSet};
+    /// let set = VersionReqSet::from_unsorted(vec![
+    ///     VersionReq::parse(">=2, <4").unwrap(),
+    ///     VersionReq::parse(">=1, <3").unwrap(),
+    /// ]);
+    /// assert_eq!(set.ranges(), [VersionReq::parse(">=1, <4").unwrap()]);
+    /// ```
+    pub fn from_unsorted(mut ranges: Vec<VersionReq>) -> Self {
+        ranges.sort_unstable_by_key(|range| range.lower);
+        let mut set = Self::new();
+        for range in ranges {
+            set.push_above(range);
+        }
+        set
+    }
+
+    /// Adds the versions of `range` to the set, merging it with the stored ranges it overlaps or
+    /// touches. `>=1.0.0, <=1.2.3` and `>=1.2.4, <2.0.0` touch and become `>=1.0.0, <2.0.0`.
+    /// ```
+    /// # use fast_version_core::{version_req::VersionReq, version_req_set::VersionReqSet};
+    /// let mut set = VersionReqSet::new();
+    /// set.insert(VersionReq::parse(">=3").unwrap());
+    /// set.insert(VersionReq::parse(">=1, <=1.2.3").unwrap());
+    /// assert_eq!(set.ranges().len(), 2);
+    ///
+    /// set.insert(VersionReq::parse(">=1.2.4, <3").unwrap());
+    /// assert_eq!(set.ranges(), [VersionReq::parse(">=1").unwrap()]);
+    /// ```
+    pub fn insert(&mut self, range: VersionReq) {
+        let Some(range) = ReleaseRange::of(&range) else {
+            return;
+        };
+        let ranges = self.ranges();
+        // The stored ranges from `first` up to `last` overlap or touch `range`.
+        let first = ranges.partition_point(|stored| match successor(&stored.upper) {
+            Some(next) => !version_le(&range.lower, &next),
+            None => false,
+        });
+        let last = match successor(&range.upper) {
+            Some(next) => ranges.partition_point(|stored| version_le(&stored.lower, &next)),
+            None => ranges.len(),
+        };
+        let mut merged = range;
+        if first < last {
+            merged.lower = Version::const_min(range.lower, ranges[first].lower);
+            merged.upper = Version::const_max(range.upper, ranges[last - 1].upper);
+        }
+        self.splice(first..last, merged);
+    }
+
+    /// Moves the ranges back into the inline storage if they fit there and releases unused heap
+    /// memory otherwise. Sets built with many [insert](Self::insert) calls may have moved to the
+    /// heap and merged down again.
+    pub fn shrink_to_canonical(&mut self) {
+        if self.heap.is_empty() {
+            return;
+        }
+        if self.heap.len() <= Self::INLINE_CAPACITY {
+            self.len = self.heap.len();
+            self.inline[..self.len].copy_from_slice(&self.heap);
+            self.heap = Vec::new();
+        } else {
+            self.heap.shrink_to_fit();
+        }
+    }
+
+    /// Parses alternatives of conjunctions with exclusions, see [FromStr for VersionReqSet](VersionReqSet#impl-FromStr-for-VersionReqSet).
+    #[inline]
+    pub fn parse(input: &str) -> Result<Self, VersionReqSetParseError> {
+        Self::from_str(input)
+    }
+
+    /// Appends a range that starts no lower than the already stored ones, merging it with the last
+    /// one if they overlap or touch.
+    fn push_above(&mut self, range: VersionReq) {
+        let Some(range) = ReleaseRange::of(&range) else {
+            return;
+        };
+        if let Some(last) = self.ranges_mut().last_mut() {
+            // A range reaching up to the largest version swallows everything above it.
+            let touches = match successor(&last.upper) {
+                Some(next) => version_le(&range.lower, &next),
+                None => true,
+            };
+            if touches {
+                last.upper = Version::const_max(last.upper, range.upper);
+                return;
+            }
+        }
+        self.push(range);
+    }
+
+    fn ranges_mut(&mut self) -> &mut [ReleaseRange] {
+        if self.heap.is_empty() {
+            &mut self.inline[..self.len]
+        } else {
+            &mut self.heap
+        }
+    }
+
+    /// Replaces the stored ranges in `replaced` with `range`.
+    fn splice(&mut self, replaced: Range<usize>, range: ReleaseRange) {
+        let len = self.ranges().len() - replaced.len() + 1;
+        if !self.heap.is_empty() || len > Self::INLINE_CAPACITY {
+            if self.heap.is_empty() {
+                self.heap.extend_from_slice(&self.inline[..self.len]);
+            }
+            self.heap.splice(replaced, [range]);
+            return;
+        }
+        self.inline
+            .copy_within(replaced.end..self.len, replaced.start + 1);
+        self.inline[replaced.start] = range;
+        self.len = len;
+    }
+
+    /// Appends a range, moving the ranges to the heap once the inline storage is full.
+    fn push(&mut self, range: ReleaseRange) {
+        if !self.heap.is_empty() {
+            self.heap.push(range);
+        } else if self.len < Self::INLINE_CAPACITY {
+            self.inline[self.len] = range;
+            self.len += 1;
+        } else {
+            self.heap.reserve(2 * Self::INLINE_CAPACITY);
+            self.heap.extend_from_slice(&self.inline);
+            self.heap.push(range);
+        }
+    }
+}
+
+impl Default for VersionReqSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Sets are equal if they hold the same ranges, no matter where those are stored.
+impl PartialEq for VersionReqSet {
+    fn eq(&self, other: &Self) -> bool {
+        self.ranges() == other.ranges()
+    }
+}
+
+impl Eq for VersionReqSet {}
+
+impl Hash for VersionReqSet {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.ranges().hash(state);
+    }
+}
+
+impl fmt::Debug for VersionReqSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VersionReqSet")
+            .field("ranges", &self.ranges())
+            .finish()
+    }
+}
+
+/// Merges the ranges like [VersionReqSet::from_unsorted] does.
+impl FromIterator<VersionReq> for VersionReqSet {
+    fn from_iter<I: IntoIterator<Item = VersionReq>>(iter: I) -> Self {
+        Self::from_unsorted(iter.into_iter().collect())
+    }
+}
+
+/// Adds all ranges at once, sorting and merging them together with the stored ones, which is
+/// cheaper than calling [insert](VersionReqSet::insert) for each of many ranges.
+impl Extend<VersionReq> for VersionReqSet {
+    fn extend<I: IntoIterator<Item = VersionReq>>(&mut self, iter: I) {
+        let mut ranges: Vec<_> = self.ranges().iter().map(ReleaseRange::to_req).collect();
+        ranges.extend(iter);
+        *self = Self::from_unsorted(ranges);
+    }
+}
+
+/// Merges the ranges like [VersionReqSet::from_unsorted] does.
+impl<const N: usize> From<[VersionReq; N]> for VersionReqSet {
+    fn from(ranges: [VersionReq; N]) -> Self {
+        Self::from_unsorted(ranges.to_vec())
+    }
+}
+
+impl From<VersionReq> for VersionReqSet {
+    fn from(req: VersionReq) -> Self {
+        let mut set = Self::new();
+        set.push_above(req);
+        set
+    }
+}
+
+/// Returned when parsing a [VersionReqSet] fails, `clause` is the index of the clause that
+/// couldn't be parsed, counting the comma separated clauses of all alternatives.
+#[derive(Error, Debug)]
+#[error("Clause {clause} is invalid")]
+pub struct VersionReqSetParseError {
+    pub clause: usize,
+    #[source]
+    pub source: VersionReqParseError,
+}
+
+/// Parses alternatives separated by `||`, each of them comma separated comparators like
+/// [VersionReq] takes, which may additionally exclude versions with `!=`. An exclusion takes a
+/// bare version, so `!=1.5.0` removes a single version and `!=1.5` all of `1.5.x`.
+///
+/// All comparators of an alternative apart from the exclusions have to hold at once, their number
+/// isn't limited. Excluding a version twice or one outside of the range has no effect, excluding
+/// everything leaves the empty set. Sets only hold releases, so a comparator naming a prerelease
+/// fails with [VersionReqParseError::PrereleaseInSet]. Positions in the returned errors are byte
+/// offsets into the whole input.
+/// ```
+/// # use fast_version_core::{version::Version, version_req_set::VersionReqSet};
+/// let set = VersionReqSet::parse(">=1.0, <2.0, !=1.5.0, !=1.6.2").unwrap();
+/// assert_eq!(set.ranges().len(), 3);
+/// assert!(set.matches(&Version::new(1, 5, 1)));
+/// assert!(!set.matches(&Version::new(1, 6, 2)));
+///
+/// let set = VersionReqSet::parse(">=1.2, <2 || >=3").unwrap();
+/// assert!(!set.matches(&Version::new(2, 1, 0)));
+/// assert!(set.matches(&Version::new(3, 1, 0)));
+///
+/// let err = VersionReqSet::parse(">=1.0 || >=2, !=2.x").unwrap_err();
+/// assert_eq!(err.clause, 2);
+/// ```
+impl FromStr for VersionReqSet {
+    type Err = VersionReqSetParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut ranges = Vec::new();
+        let mut offset = 0;
+        let mut clause = 0;
+        for alternative in s.split("||") {
+            let set = parse_conjunction(alternative, offset, &mut clause)?;
+            ranges.extend(set.ranges().iter().map(ReleaseRange::to_req));
+            offset += alternative.len() + 2;
+        }
+        Ok(Self::from_unsorted(ranges))
+    }
+}
+
+/// Parses the comma separated clauses of one alternative starting at byte `offset` of the input,
+/// `clause` counts the clauses parsed so far.
+fn parse_conjunction(
+    input: &str,
+    mut offset: usize,
+    clause: &mut usize,
+) -> Result<VersionReqSet, VersionReqSetParseError> {
+    let mut base = VersionReq::STAR;
+    let mut exclusions = Vec::new();
+    for part in input.split(',') {
+        let comparator = part.trim_start();
+        let position = offset + part.len() - comparator.len();
+        offset += part.len() + 1;
+        let comparator = comparator.trim_end();
+        let index = *clause;
+        *clause += 1;
+        let error = |source| VersionReqSetParseError {
+            clause: index,
+            source,
+        };
+        match comparator.strip_prefix("!=") {
+            Some(excluded) => {
+                // Only a bare version may follow, `!=>1` is no exclusion.
+                let version = excluded.trim_start();
+                if !version.starts_with(|c: char| c.is_ascii_digit()) {
+                    return Err(error(VersionReqParseError::UnknownOperator { position }));
+                }
+                let version_position = position + comparator.len() - version.len();
+                let excluded = parse_comparator_req(version, version_position).map_err(error)?;
+                if !exclusions.contains(&excluded) {
+                    exclusions.push(excluded);
+                }
+            }
+            None => {
+                let req = parse_comparator_req(comparator, position).map_err(error)?;
+                let narrowed = base.intersection(&req);
+                if narrowed.is_empty() && !base.is_empty() && !req.is_empty() {
+                    return Err(error(VersionReqParseError::ConflictingBounds { position }));
+                }
+                base = narrowed;
+            }
+        }
+    }
+    Ok(exclusions
+        .iter()
+        .fold(VersionReqSet::from(base), |set, excluded| {
+            set.difference(excluded)
+        }))
+}
+
+/// Renders the ranges like [VersionReq] does, joined by ` || `. The empty set is `<0.0.0` like
+/// [VersionReq::EMPTY], so every rendering parses back to an equal set.
+/// ```
+/// # use fast_version_core::{version_req::VersionReq, version_req_set::VersionReqSet};
+/// let set = VersionReqSet::parse(">=1.2, <2 || >=3").unwrap();
+/// assert_eq!(set.to_string(), ">=1.2.0, <2.0.0 || >=3.0.0");
+///
+/// assert_eq!(VersionReqSet::new().to_string(), "<0.0.0");
+/// assert_eq!(VersionReqSet::from(VersionReq::STAR).to_string(), "*");
+/// ```
+impl fmt::Display for VersionReqSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some((first, rest)) = self.ranges().split_first() else {
+            return fmt::Display::fmt(&VersionReq::EMPTY, f);
+        };
+        write!(f, "{first}")?;
+        for range in rest {
+            write!(f, " || {range}")?;
+        }
+        Ok(())
+    }
+}
+
+impl VersionReq {
+    /// The versions matching `self` but not `other`, at most two ranges below and above `other`.
+    /// ```
+    /// # use fast_version_core::version_req::VersionReq;
+    /// let policy = VersionReq::parse(">=1.0.0, <3.0.0").unwrap();
+    /// let blocked = VersionReq::parse(">=2.0.0").unwrap();
+    ///
+    /// let remaining = policy.difference(&blocked);
+    /// assert_eq!(remaining.ranges(), [VersionReq::parse(">=1.0.0, <2.0.0").unwrap()]);
+    /// ```
+    pub fn difference(&self, other: &VersionReq) -> VersionReqSet {
+        if self.intersection(other).is_empty() {
+            return VersionReqSet::from(*self);
+        }
+        let mut set = VersionReqSet::new();
+        // Both are non-empty and overlap, so the cut points lie inside `self`.
+        if !version_le(&other.lower, &self.lower) {
+            set.push_above(VersionReq::new_bounded(
+                Some(self.lower),
+                predecessor(&other.lower),
+            ));
+        }
+        if !version_le(&self.upper, &other.upper) {
+            set.push_above(VersionReq::new_bounded(
+                successor(&other.upper),
+                Some(self.upper),
+            ));
+        }
+        set
+    }
+
+    /// The versions matching `self` or `other`, a single range if they overlap or touch.
+    /// ```
+    /// # use fast_version_core::version_req::VersionReq;
+    /// let lhs = VersionReq::parse(">=1.0.0, <2.0.0").unwrap();
+    /// let rhs = VersionReq::parse(">=2.0.0, <3.0.0").unwrap();
+    ///
+    /// assert_eq!(lhs.union(&rhs).ranges(), [VersionReq::parse(">=1.0.0, <3.0.0").unwrap()]);
+    /// ```
+    pub fn union(&self, other: &VersionReq) -> VersionReqSet {
+        let (first, second) = if version_le(&self.lower, &other.lower) {
+            (*self, *other)
+        } else {
+            (*other, *self)
+        };
+        let mut set = VersionReqSet::from(first);
+        set.push_above(second);
+        set
+    }
+}
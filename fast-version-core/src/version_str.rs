@@ -0,0 +1,96 @@
+//! Sorting version strings without turning them into [Version]s.
+
+use crate::version::{Version, VersionParseError};
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+/// A validated version string that compares like the [Version] it contains.
+///
+/// At 16 bytes it's smaller than a [Version] and borrows the string instead of copying it, so a
+/// large number of strings, e.g. in an arena, can be sorted without materializing the versions.
+/// Every comparison parses both strings again, which doesn't allocate.
+///
+/// Equality, ordering and hashing follow the version, so `1.02.3` and `1.2.3` are equal, while
+/// [Display](fmt::Display) prints the original string.
+/// ```
+/// # use fast_version_core::version_str::VersionStr;
+/// let mut versions = ["1.10.0", "1.2.3", "1.9.0"].map(|version| VersionStr::try_new(version).unwrap());
+/// versions.sort();
+///
+/// assert_eq!(versions.map(|version| version.as_str()), ["1.2.3", "1.9.0", "1.10.0"]);
+/// assert!(VersionStr::try_new("1.2").is_err());
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct VersionStr<'a>(&'a str);
+
+impl<'a> VersionStr<'a> {
+    /// Validates the string like [Version::parse_checked].
+    pub fn try_new(version: &'a str) -> Result<Self, VersionParseError> {
+        Version::parse_checked(version)?;
+        Ok(Self(version))
+    }
+
+    /// The original string.
+    #[inline]
+    pub const fn as_str(&self) -> &'a str {
+        self.0
+    }
+
+    /// Parses the version, which can't fail after the validation in [VersionStr::try_new].
+    #[inline]
+    pub fn to_version(&self) -> Version {
+        match Version::parse_checked(self.0) {
+            Ok(version) => version,
+            Err(_) => unreachable!("the string was validated on construction"),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a str> for VersionStr<'a> {
+    type Error = VersionParseError;
+
+    fn try_from(version: &'a str) -> Result<Self, Self::Error> {
+        Self::try_new(version)
+    }
+}
+
+impl From<VersionStr<'_>> for Version {
+    fn from(version: VersionStr<'_>) -> Self {
+        version.to_version()
+    }
+}
+
+impl PartialEq for VersionStr<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0 || self.to_version() == other.to_version()
+    }
+}
+
+impl Eq for VersionStr<'_> {}
+
+impl PartialOrd for VersionStr<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for VersionStr<'_> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.to_version().cmp(&other.to_version())
+    }
+}
+
+/// Hashes like the [Version].
+impl Hash for VersionStr<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_version().hash(state);
+    }
+}
+
+impl fmt::Display for VersionStr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
@@ -2,10 +2,16 @@
 //!
 //! Refer to the [fast-version](https://crates.io/crates/fast-version) for usage and documentation.
 
-use litrs::Literal;
-use quote::quote;
+use fast_version_core::version::Version;
+use fast_version_core::version_req::VersionReq;
+use litrs::{Literal, StringLit};
+use proc_macro::{TokenStream, TokenTree};
+use proc_macro2::Span;
+use quote::{quote, quote_spanned};
 use std::str::FromStr;
 
+mod lockfile;
+
 /// Allows compile time generation of Versions from string literals.
 /// ```
 /// # use fast_version_core::version::Version;
@@ -21,11 +27,13 @@ pub fn const_version(input: proc_macro::TokenStream) -> proc_macro::TokenStream
     let first_token = input.into_iter().next().expect("expected input into macro");
 
     match Literal::try_from(first_token) {
-        Err(e) => return e.to_compile_error(),
+        Err(e) => e.to_compile_error(),
         Ok(Literal::String(string)) => {
             let value = string.value();
-            let version = fast_version_core::version::Version::from_str(value)
-                .expect("Error while parsing string literal into version");
+            let version =
+                fast_version_core::version::Version::from_str(value).unwrap_or_else(|e| {
+                    panic!("Error while parsing string literal into version: {}", e)
+                });
             let major = version.major;
             let minor = version.minor;
             let patch = version.patch;
@@ -44,3 +52,253 @@ pub fn const_version(input: proc_macro::TokenStream) -> proc_macro::TokenStream
         }
     }
 }
+
+/// Packs a version string literal into the `u128` of
+/// [Version::to_packed_u128](fast_version_core::version::Version::to_packed_u128) at compile time,
+/// for bounds given as const generics.
+/// ```
+/// # use fast_version_core::version::Version;
+/// # use fast_version_derive::const_version_u128;
+/// const PACKED: u128 = const_version_u128!("1.2.3");
+///
+/// assert_eq!(Version::from_packed_u128(PACKED), Version::new(1, 2, 3));
+/// ```
+#[proc_macro]
+pub fn const_version_u128(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let first_token = input.into_iter().next().expect("expected input into macro");
+
+    match Literal::try_from(first_token) {
+        Err(e) => e.to_compile_error(),
+        Ok(Literal::String(string)) => {
+            let value = string.value();
+            let version =
+                fast_version_core::version::Version::from_str(value).unwrap_or_else(|e| {
+                    panic!("Error while parsing string literal into version: {}", e)
+                });
+            let packed = version
+                .to_packed_u128()
+                .unwrap_or_else(|e| panic!("Error while packing version into u128: {}", e));
+            quote! { #packed }.into()
+        }
+        Ok(other) => {
+            panic!("Got non string literal: {}", other);
+        }
+    }
+}
+
+/// Allows compile time generation of version requirements from string literals, in the syntax of
+/// [VersionReq::parse](fast_version_core::version_req::VersionReq::parse). An invalid requirement
+/// is a compile error, as is one naming a prerelease that can match, because prerelease bounds
+/// can't be built in const contexts.
+/// ```
+/// # use fast_version_core::version::Version;
+/// # use fast_version_core::version_req::VersionReq;
+/// # use fast_version_derive::const_version_req;
+/// const REQ: VersionReq = const_version_req!(">=1.2.3, <2.0.0");
+///
+/// assert!(REQ.matches(&Version::new(1, 4, 0)));
+/// assert!(!REQ.matches(&Version::new(2, 0, 0)));
+/// ```
+#[proc_macro]
+pub fn const_version_req(input: TokenStream) -> TokenStream {
+    let first_token = input.into_iter().next().expect("expected input into macro");
+    let span: Span = first_token.span().into();
+
+    match Literal::try_from(first_token) {
+        Err(e) => e.to_compile_error(),
+        Ok(Literal::String(string)) => {
+            let req = match VersionReq::parse(string.value()) {
+                Ok(req) => req,
+                Err(e) => {
+                    let mut message =
+                        format!("Error while parsing string literal into version requirement: {e}");
+                    if let Some(source) = std::error::Error::source(&e) {
+                        message = format!("{message}: {source}");
+                    }
+                    return quote_spanned!(span=> compile_error!(#message)).into();
+                }
+            };
+            if req.admits_prereleases() {
+                let message = format!(
+                    "The version requirement {req} matches prereleases, which const requirements \
+                        can't represent, parse it at runtime with VersionReq::parse"
+                );
+                return quote_spanned!(span=> compile_error!(#message)).into();
+            }
+            let expanded = match req.range() {
+                Some(range) => {
+                    let lower = version_tokens(range.start());
+                    let upper = version_tokens(range.end());
+                    quote! { fast_version_core::version_req::VersionReq::between(#lower, #upper) }
+                }
+                None => quote! { fast_version_core::version_req::VersionReq::EMPTY },
+            };
+            quote! {
+                {
+                    const REQ: fast_version_core::version_req::VersionReq = #expanded;
+                    REQ
+                }
+            }
+            .into()
+        }
+        Ok(other) => {
+            panic!("Got non string literal: {}", other);
+        }
+    }
+}
+
+fn version_tokens(version: &Version) -> proc_macro2::TokenStream {
+    let Version {
+        major,
+        minor,
+        patch,
+    } = version;
+    quote! { fast_version_core::version::Version::new(#major, #minor, #patch) }
+}
+
+/// Embeds the versions of dependencies as locked in the workspace `Cargo.lock`, for diagnostics
+/// output. The lockfile is searched from `CARGO_MANIFEST_DIR` upwards, so the macro is meant
+/// for binaries and crates built inside their own workspace.
+///
+/// Expands to a `&'static [(&'static str, Version)]` in the order the packages are named. A
+/// package that isn't locked is a compile error, as is one locked in several versions unless it
+/// is prefixed with `all`, which embeds every version in ascending order.
+/// ```
+/// # use fast_version_core::version::Version;
+/// # use fast_version_derive::deps_versions;
+/// static DEPS: &[(&str, Version)] = deps_versions!("quote", all "litrs");
+///
+/// assert_eq!(DEPS[0].0, "quote");
+/// assert_eq!(DEPS[0].1.major, 1);
+/// ```
+#[proc_macro]
+pub fn deps_versions(input: TokenStream) -> TokenStream {
+    match expand_deps_versions(input) {
+        Ok(expanded) => expanded.into(),
+        Err((span, message)) => quote_spanned!(span=> compile_error!(#message)).into(),
+    }
+}
+
+type ExpandResult<T> = Result<T, (Span, String)>;
+
+/// A package named in [deps_versions], `all` when every locked version is wanted.
+struct Requested {
+    name: String,
+    all: bool,
+    span: Span,
+}
+
+fn expand_deps_versions(input: TokenStream) -> ExpandResult<proc_macro2::TokenStream> {
+    let requested = parse_requested(input)?;
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").map_err(|_| {
+        (
+            Span::call_site(),
+            "CARGO_MANIFEST_DIR isn't set".to_string(),
+        )
+    })?;
+    let path = lockfile::find(manifest_dir.as_ref()).ok_or_else(|| {
+        let message = format!("no Cargo.lock found in {manifest_dir} or its parents");
+        (Span::call_site(), message)
+    })?;
+    let content = std::fs::read_to_string(&path).map_err(|e| {
+        let message = format!("failed to read {}: {e}", path.display());
+        (Span::call_site(), message)
+    })?;
+    let packages = lockfile::packages(&content);
+
+    let mut entries = Vec::new();
+    for Requested { name, all, span } in requested {
+        let mut versions = Vec::new();
+        for package in packages.iter().filter(|package| package.name == name) {
+            let version = Version::from_str(&package.version).map_err(|e| {
+                let message = format!(
+                    "version {} of `{name}` in {} isn't supported: {e}",
+                    package.version,
+                    path.display()
+                );
+                (span, message)
+            })?;
+            versions.push(version);
+        }
+        versions.sort();
+        versions.dedup();
+        match versions.as_slice() {
+            [] => {
+                let message = format!("package `{name}` isn't in {}", path.display());
+                return Err((span, message));
+            }
+            [_, _, ..] if !all => {
+                let listed = versions.iter().map(Version::to_string).collect::<Vec<_>>();
+                let message = format!(
+                    "package `{name}` is locked in {} versions ({}), use `all \"{name}\"` to embed all of them",
+                    versions.len(),
+                    listed.join(", ")
+                );
+                return Err((span, message));
+            }
+            _ => {}
+        }
+        for Version {
+            major,
+            minor,
+            patch,
+        } in versions
+        {
+            entries.push(quote! {
+                (#name, fast_version_core::version::Version::new(#major, #minor, #patch))
+            });
+        }
+    }
+    // Rebuilds the caller when the lockfile changes.
+    let path = path.to_string_lossy();
+    Ok(quote! {
+        {
+            const _: &[u8] = include_bytes!(#path);
+            const DEPS: &[(&str, fast_version_core::version::Version)] = &[#(#entries),*];
+            DEPS
+        }
+    })
+}
+
+/// Parses `"name"` and `all "name"` separated by commas.
+fn parse_requested(input: TokenStream) -> ExpandResult<Vec<Requested>> {
+    let mut requested = Vec::new();
+    let mut tokens = input.into_iter().peekable();
+    while tokens.peek().is_some() {
+        let mut token = tokens.next();
+        let all = matches!(&token, Some(TokenTree::Ident(ident)) if ident.to_string() == "all");
+        if all {
+            token = tokens.next();
+        }
+        let Some(token) = token else {
+            return Err((
+                Span::call_site(),
+                "expected a package name after `all`".to_string(),
+            ));
+        };
+        let span = token.span().into();
+        let name = StringLit::try_from(token).map_err(|_| {
+            (
+                span,
+                "expected a package name as string literal".to_string(),
+            )
+        })?;
+        requested.push(Requested {
+            name: name.value().to_string(),
+            all,
+            span,
+        });
+        match tokens.next() {
+            None => break,
+            Some(TokenTree::Punct(punct)) if punct.as_char() == ',' => {}
+            Some(other) => return Err((other.span().into(), "expected `,`".to_string())),
+        }
+    }
+    if requested.is_empty() {
+        return Err((
+            Span::call_site(),
+            "expected at least one package name".to_string(),
+        ));
+    }
+    Ok(requested)
+}
@@ -0,0 +1,52 @@
+//! Just enough of a `Cargo.lock` reader for [deps_versions](crate::deps_versions).
+
+use std::path::{Path, PathBuf};
+
+/// A `[[package]]` entry of the lockfile.
+pub(crate) struct LockedPackage {
+    pub(crate) name: String,
+    pub(crate) version: String,
+}
+
+/// Walks up from `start` and returns the first `Cargo.lock` found, the one of the workspace.
+pub(crate) fn find(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .map(|dir| dir.join("Cargo.lock"))
+        .find(|path| path.is_file())
+}
+
+/// Collects the packages of a lockfile. Other tables, like `[metadata]` or `[[patch.unused]]`,
+/// are skipped.
+pub(crate) fn packages(lockfile: &str) -> Vec<LockedPackage> {
+    let mut packages = Vec::new();
+    let mut current: Option<(Option<String>, Option<String>)> = None;
+    let mut finish = |current: Option<(Option<String>, Option<String>)>| {
+        if let Some((Some(name), Some(version))) = current {
+            packages.push(LockedPackage { name, version });
+        }
+    };
+    for line in lockfile.lines().map(str::trim) {
+        if line.starts_with('[') {
+            finish(current.take());
+            if line == "[[package]]" {
+                current = Some((None, None));
+            }
+            continue;
+        }
+        let Some((name, version)) = current.as_mut() else {
+            continue;
+        };
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let value = value.trim().trim_matches('"').to_string();
+        match key.trim() {
+            "name" => *name = Some(value),
+            "version" => *version = Some(value),
+            _ => {}
+        }
+    }
+    finish(current);
+    packages
+}
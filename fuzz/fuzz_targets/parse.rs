@@ -0,0 +1,37 @@
+//! Feeds arbitrary bytes to every parser, backing the no-panic guarantee of
+//! `Version::parse_checked`. Run with `cargo +nightly fuzz run parse` from the repository root.
+
+#![no_main]
+
+use fast_version::docker::classify_tag;
+use fast_version::spec::PackageSpec;
+use fast_version::{
+    DynVersion, EpochVersion, LenientVersion, Version, Version4, VersionPattern, VersionReq,
+    VersionReqSet,
+};
+use libfuzzer_sys::fuzz_target;
+use std::str::FromStr;
+
+fuzz_target!(|data: &[u8]| {
+    let parsed = Version::parse_checked_bytes(data);
+    let Ok(input) = std::str::from_utf8(data) else {
+        return;
+    };
+    assert_eq!(parsed.ok(), Version::parse_checked(input).ok());
+    if let Ok(version) = Version::parse_checked(input) {
+        assert_eq!(
+            Version::parse_checked(&version.to_string()).ok(),
+            Some(version)
+        );
+    }
+    let _ = Version::parse_partial(input);
+    let _ = Version4::from_str(input);
+    let _ = DynVersion::from_str(input);
+    let _ = EpochVersion::from_str(input);
+    let _ = LenientVersion::parse(input);
+    let _ = VersionPattern::from_str(input);
+    let _ = VersionReq::parse(input);
+    let _ = VersionReqSet::parse(input);
+    let _ = PackageSpec::from_str(input);
+    let _ = classify_tag(input);
+});
@@ -0,0 +1,74 @@
+//! Uses the allocation-free API of fast-version, so building this crate for a target without
+//! `std`, like `thumbv7em-none-eabihf`, fails as soon as the API starts depending on `std`. The
+//! `serde` feature is enabled and used through `serde-json-core` to keep it free of `alloc` too.
+
+#![no_std]
+
+use core::str::FromStr;
+use fast_version::tags::parse_full_tag;
+use fast_version::{DynVersion, FullVersion, Version, VersionPattern, VersionReq};
+use serde::Deserialize;
+
+/// Whether `version` parses and is matched by `req`.
+pub fn matches(req: &str, version: &str) -> bool {
+    let (Ok(req), Ok(version)) = (VersionReq::from_str(req), Version::from_str(version)) else {
+        return false;
+    };
+    req.matches(&version)
+}
+
+/// Whether `version` parses and is matched by the wildcard `pattern`.
+pub fn matches_pattern(pattern: &str, version: &str) -> bool {
+    let (Ok(pattern), Ok(version)) = (
+        VersionPattern::from_str(pattern),
+        Version::from_str(version),
+    ) else {
+        return false;
+    };
+    pattern.matches(&version)
+}
+
+/// Whether `version`, which may have a prerelease and build metadata, parses and is matched by
+/// `req`. The identifiers are stored inline, so this works without an allocator too.
+pub fn matches_full(req: &str, version: &str) -> bool {
+    let (Ok(req), Ok(version)) = (VersionReq::from_str(req), FullVersion::from_str(version)) else {
+        return false;
+    };
+    req.matches_full(&version)
+}
+
+/// Whether the git tag `tag` names a prerelease.
+pub fn is_prerelease_tag(tag: &str) -> Option<bool> {
+    parse_full_tag(tag)
+        .ok()
+        .map(|version| version.is_prerelease())
+}
+
+/// The number of components of a version with up to five of them.
+pub fn dyn_components(version: &str) -> Option<usize> {
+    DynVersion::from_str(version)
+        .ok()
+        .map(|version| version.len())
+}
+
+/// Writes the version as JSON into the buffer, returning the number of bytes written.
+pub fn version_to_json(version: &Version, buffer: &mut [u8]) -> Option<usize> {
+    serde_json_core::to_slice(version, buffer).ok()
+}
+
+/// A version as JSON string, serde-json-core doesn't support the `deserialize_any` the default
+/// impl needs to accept the struct form too.
+#[derive(Deserialize)]
+struct VersionString(#[serde(with = "fast_version::serde_helpers::version_as_str")] Version);
+
+/// Reads a version from a JSON string.
+pub fn version_from_json(json: &[u8]) -> Option<Version> {
+    serde_json_core::from_slice(json)
+        .ok()
+        .map(|(VersionString(version), _)| version)
+}
+
+/// Reads a requirement from a JSON string, borrowing from the input.
+pub fn req_from_json(json: &[u8]) -> Option<VersionReq> {
+    serde_json_core::from_slice(json).ok().map(|(req, _)| req)
+}
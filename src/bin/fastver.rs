@@ -0,0 +1,104 @@
+//! Parsing, comparing and matching versions from the shell, behind the `cli` feature.
+//!
+//! Exit codes are meant for scripts: `0` for success, `1` for a negative answer, `2` for invalid
+//! input. `compare` exits with `0` if both versions are equal, `1` if the first is lower and `3`
+//! if it is higher.
+
+use clap::{Parser, Subcommand};
+use fast_version::{matcher, Version, VersionReq};
+use serde_json::json;
+use std::cmp::Ordering;
+use std::io::{self, BufRead};
+use std::process::ExitCode;
+
+#[derive(Parser)]
+#[command(name = "fastver", version, about = "Parse, compare and match versions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Print JSON instead of plain text.
+    #[arg(long, global = true)]
+    json: bool,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Print the version in its canonical form.
+    Parse { version: Version },
+    /// Compare two versions, exits with 0 if equal, 1 if the first is lower and 3 if higher.
+    Compare { first: Version, second: Version },
+    /// Exit with 0 if the version matches the requirement, 1 otherwise.
+    Matches { req: VersionReq, version: Version },
+    /// Print the largest version read from stdin, one per line, that matches the requirement.
+    /// Exits with 1 if none matches.
+    MaxMatching { req: VersionReq },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Parse { version } => {
+            if cli.json {
+                println!("{}", json!(version));
+            } else {
+                println!("{version}");
+            }
+            ExitCode::SUCCESS
+        }
+        Command::Compare { first, second } => {
+            let (symbol, name, code) = match first.cmp(&second) {
+                Ordering::Equal => ("=", "equal", 0),
+                Ordering::Less => ("<", "less", 1),
+                Ordering::Greater => (">", "greater", 3),
+            };
+            if cli.json {
+                println!("{}", json!({ "ordering": name }));
+            } else {
+                println!("{first} {symbol} {second}");
+            }
+            ExitCode::from(code)
+        }
+        Command::Matches { req, version } => {
+            let matches = req.matches(&version);
+            if cli.json {
+                println!("{}", json!({ "matches": matches }));
+            } else {
+                println!("{matches}");
+            }
+            ExitCode::from(u8::from(!matches))
+        }
+        Command::MaxMatching { req } => {
+            let versions = match read_versions(io::stdin().lock()) {
+                Ok(versions) => versions,
+                Err(message) => {
+                    eprintln!("error: {message}");
+                    return ExitCode::from(2);
+                }
+            };
+            let max = matcher::max_matching(&versions, &req);
+            if cli.json {
+                println!("{}", json!(max));
+            } else if let Some(max) = max {
+                println!("{max}");
+            }
+            ExitCode::from(u8::from(max.is_none()))
+        }
+    }
+}
+
+/// Every non-blank line as a version, surrounding whitespace is ignored.
+fn read_versions(input: impl BufRead) -> Result<Vec<Version>, String> {
+    let mut versions = Vec::new();
+    for (index, line) in input.lines().enumerate() {
+        let line = line.map_err(|error| error.to_string())?;
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let version = line
+            .parse()
+            .map_err(|error| format!("line {}: invalid version {line:?}: {error}", index + 1))?;
+        versions.push(version);
+    }
+    Ok(versions)
+}
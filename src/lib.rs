@@ -8,7 +8,25 @@
 //! [semver](https://docs.rs/semver/latest/semver/index.html) crate.
 //!
 //! This implementation however doesn't require allocations, thus can be used in compile time
-//! evaluation. (support for embedded will follow)
+//! evaluation and on embedded targets.
+//!
+//! ## Features
+//! * `std` (default): builds against the standard library, implies `alloc`. The `arbitrary`,
+//!   `proptest`, `schemars`, `rkyv`, `semver-interop` and `test-util` features enable it
+//! * `alloc`: [VersionReqSet], [spec] and other APIs producing `String`s or `Vec`s
+//! * `serde`: versions as strings like `"1.2.3"` in human-readable formats and as three
+//!   integers in binary ones, works without `alloc` as well, `serde_helpers` lists the
+//!   representations that don't need it
+//! * `fancy-errors`: `render` on the parse errors, showing the input with the offending part
+//!   marked, implies `alloc`
+//! * `hashbrown`: lookups of `hashbrown` maps keyed by [Version] with a packed [PackedKey] and the
+//!   other way around
+//! * `semver-interop`, or its alias `semver`: conversions between the types of this crate and the
+//!   ones of the `semver` crate
+//! * `cli`: the `fastver` binary for shell scripts, `cargo install fast-version --features cli`
+//!
+//! Without `std` the crate is `#![no_std]`, the error types implement [core::error::Error] either
+//! way.
 //!
 //! ## Example
 //! ```
@@ -34,6 +52,61 @@
 //!
 //! assert!(!VERSION_REQ_UNMATCH.matches(&VERSION));
 //! ```
-pub use fast_version_core::version::Version;
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(any(feature = "portable-atomic", target_has_atomic = "64"))]
+pub use fast_version_core::atomic::AtomicVersion;
+pub use fast_version_core::bounded::*;
+pub use fast_version_core::bump::{BumpKind, VersionDiff};
+pub use fast_version_core::channel::Channel;
+#[cfg(feature = "alloc")]
+pub use fast_version_core::collections::VersionCollectionExt;
+pub use fast_version_core::delta::VersionDelta;
+pub use fast_version_core::docker;
+pub use fast_version_core::dyn_version::*;
+pub use fast_version_core::encoding;
+pub use fast_version_core::epoch_version::*;
+pub use fast_version_core::explain::MatchFailure;
+pub use fast_version_core::full_version::{
+    BuildMetadata, FullVersion, FullVersionParseError, IdentifierError, Prerelease,
+};
+pub use fast_version_core::lenient::*;
+#[cfg(feature = "alloc")]
+pub use fast_version_core::list_diff::{diff_lists, ListChange, ListDiff};
+pub use fast_version_core::matcher;
+pub use fast_version_core::matcher::VersionMatcher;
+pub use fast_version_core::oneshot::{compare, matches, CompareStrError, MatchStrError};
+pub use fast_version_core::packed::{PackedError, PackedKey};
+pub use fast_version_core::release_range::*;
+pub use fast_version_core::release_stage::ReleaseStage;
+#[cfg(feature = "std")]
+pub use fast_version_core::runtime_req::*;
+#[cfg(feature = "semver-interop")]
+pub use fast_version_core::semver_interop;
+pub use fast_version_core::sequence::*;
+#[cfg(feature = "serde")]
+pub use fast_version_core::serde_helpers;
+pub use fast_version_core::slice;
+#[cfg(feature = "alloc")]
+pub use fast_version_core::spec;
+#[cfg(feature = "proptest")]
+pub use fast_version_core::strategies;
+pub use fast_version_core::support::*;
+pub use fast_version_core::tags;
+#[cfg(feature = "test-util")]
+pub use fast_version_core::test_util;
+pub use fast_version_core::update::{RejectReason, UpdateDecision, UpdatePolicy};
+#[cfg(feature = "rkyv")]
+pub use fast_version_core::version::ArchivedVersion;
+pub use fast_version_core::version::{ComponentKind, Version, VersionParseError};
+pub use fast_version_core::version4::*;
+pub use fast_version_core::version_banner;
+pub use fast_version_core::version_or_req::VersionOrReq;
+pub use fast_version_core::version_pattern::*;
 pub use fast_version_core::version_req::*;
-pub use fast_version_derive::const_version;
+#[cfg(feature = "alloc")]
+pub use fast_version_core::version_req_set::*;
+pub use fast_version_core::version_str::VersionStr;
+pub use fast_version_derive::{
+    const_version, const_version_req, const_version_u128, deps_versions,
+};
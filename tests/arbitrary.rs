@@ -0,0 +1,47 @@
+#![cfg(feature = "arbitrary")]
+
+mod common;
+
+use arbitrary::{Arbitrary, Unstructured};
+use common::Rng;
+use fast_version::{VersionReq, VersionReqVariant};
+
+impl Rng {
+    fn bytes(&mut self) -> Vec<u8> {
+        let len = (self.next() % 96) as usize;
+        (0..len).map(|_| self.next() as u8).collect()
+    }
+}
+
+#[test]
+fn variants_are_never_empty() {
+    let mut rng = Rng(0xa5b1_7a4e);
+    let mut compounds = 0;
+    for _ in 0..5000 {
+        let bytes = rng.bytes();
+        let mut u = Unstructured::new(&bytes);
+        let variant = VersionReqVariant::arbitrary(&mut u).unwrap();
+        if matches!(variant, VersionReqVariant::Compound(..)) {
+            compounds += 1;
+        }
+        assert!(!VersionReq::new(&variant).is_empty(), "{variant:?}");
+    }
+    assert!(compounds > 100);
+}
+
+#[test]
+fn requirements_are_never_empty() {
+    let mut rng = Rng(0x1234_5678);
+    for _ in 0..5000 {
+        let bytes = rng.bytes();
+        let mut u = Unstructured::new(&bytes);
+        let req = VersionReq::arbitrary(&mut u).unwrap();
+        assert!(!req.is_empty(), "{req}");
+    }
+}
+
+#[test]
+fn exhausted_input() {
+    let mut u = Unstructured::new(&[]);
+    assert!(!VersionReq::arbitrary(&mut u).unwrap().is_empty());
+}
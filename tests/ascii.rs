@@ -0,0 +1,51 @@
+use fast_version::{const_version, version_banner, Version};
+
+const MAX: u64 = u64::MAX;
+
+fn ascii(version: Version) -> String {
+    let (bytes, len) = version.to_ascii_array();
+    assert!(bytes[len..].iter().all(|byte| *byte == 0));
+    String::from_utf8(bytes[..len].to_vec()).unwrap()
+}
+
+#[test]
+fn const_output() {
+    const ASCII: ([u8; 62], usize) = Version::new(10, 0, 7).to_ascii_array();
+    assert_eq!(&ASCII.0[..ASCII.1], b"10.0.7");
+}
+
+#[test]
+fn matches_display_across_edge_values() {
+    let values = [0, 1, 9, 10, 99, 100, 1 << 32, MAX / 10, MAX - 1, MAX];
+    for major in values {
+        for minor in values {
+            for patch in values {
+                let version = Version::new(major, minor, patch);
+                assert_eq!(ascii(version), version.to_string());
+            }
+        }
+    }
+}
+
+#[test]
+fn longest_version_fills_buffer() {
+    let (bytes, len) = Version::new(MAX, MAX, MAX).to_ascii_array();
+    assert_eq!(len, bytes.len());
+}
+
+#[test]
+fn banner() {
+    const BANNER: &[u8; 14] = version_banner!(b"myproto/", const_version!("1.2.3"), b"\n");
+    assert_eq!(BANNER, b"myproto/1.2.3\n");
+
+    const EMPTY: &[u8] = version_banner!(b"", Version::new(MAX, 0, 0), b"");
+    assert_eq!(
+        EMPTY,
+        MAX.to_string()
+            .as_bytes()
+            .iter()
+            .chain(b".0.0")
+            .copied()
+            .collect::<Vec<_>>()
+    );
+}
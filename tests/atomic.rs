@@ -0,0 +1,113 @@
+use fast_version::{AtomicVersion, ComponentKind, PackedError, Version, VersionReq};
+use std::sync::atomic::Ordering::{Acquire, Relaxed, Release, SeqCst};
+use std::thread;
+
+static CURRENT: AtomicVersion = AtomicVersion::new(Version::new(1, 0, 0));
+
+#[test]
+fn const_construction() {
+    const LIMIT: Version = Version::new((1 << 16) - 1, (1 << 24) - 1, (1 << 24) - 1);
+    static LARGEST: AtomicVersion = AtomicVersion::new(LIMIT);
+    assert_eq!(LARGEST.load(Relaxed), LIMIT);
+    assert_eq!(AtomicVersion::default().into_inner(), Version::new(0, 0, 0));
+}
+
+#[test]
+fn out_of_range() {
+    let wide = Version::new(0, 1 << 24, 0);
+    let error = PackedError {
+        component: ComponentKind::Minor,
+        value: 1 << 24,
+        bits: 24,
+    };
+    assert_eq!(AtomicVersion::try_new(wide).unwrap_err(), error);
+
+    let cell = AtomicVersion::new(Version::new(1, 2, 3));
+    assert_eq!(cell.store(wide, SeqCst), Err(error));
+    assert_eq!(
+        cell.compare_exchange(Version::new(1, 2, 3), wide, SeqCst, SeqCst),
+        Err(error)
+    );
+    assert_eq!(
+        cell.compare_exchange(wide, Version::new(2, 0, 0), SeqCst, SeqCst),
+        Ok(Err(Version::new(1, 2, 3)))
+    );
+    assert_eq!(cell.load(SeqCst), Version::new(1, 2, 3));
+}
+
+#[test]
+#[should_panic]
+fn new_panics_out_of_range() {
+    AtomicVersion::new(Version::new(1 << 16, 0, 0));
+}
+
+#[test]
+fn matches_req() {
+    let cell = AtomicVersion::new(Version::new(1, 2, 3));
+    let req = VersionReq::parse("^1.2").unwrap();
+    assert!(cell.matches_req(&req, Acquire));
+    cell.store(Version::new(2, 0, 0), Release).unwrap();
+    assert!(!cell.matches_req(&req, Acquire));
+    assert_eq!(format!("{cell:?}"), "Version(2.0.0)");
+}
+
+#[test]
+fn concurrent_increments() {
+    const THREADS: u64 = 8;
+    const INCREMENTS: u64 = 2_000;
+    let cell = AtomicVersion::new(Version::new(3, 0, 0));
+    thread::scope(|scope| {
+        for _ in 0..THREADS {
+            scope.spawn(|| {
+                for _ in 0..INCREMENTS {
+                    let mut current = cell.load(Relaxed);
+                    loop {
+                        let next = current.with_patch(current.patch + 1);
+                        match cell
+                            .compare_exchange(current, next, SeqCst, Relaxed)
+                            .unwrap()
+                        {
+                            Ok(_) => break,
+                            Err(actual) => current = actual,
+                        }
+                    }
+                }
+            });
+        }
+    });
+    assert_eq!(cell.into_inner(), Version::new(3, 0, THREADS * INCREMENTS));
+}
+
+#[test]
+fn readers_see_whole_versions() {
+    // Every stored version has major == minor == patch, a torn read would break that.
+    let cell = AtomicVersion::new(Version::new(0, 0, 0));
+    thread::scope(|scope| {
+        scope.spawn(|| {
+            for value in 1..=20_000 {
+                cell.store(Version::new(value % 1000, value, value), Release)
+                    .unwrap();
+            }
+        });
+        for _ in 0..3 {
+            scope.spawn(|| {
+                let mut last = Version::new(0, 0, 0);
+                for _ in 0..20_000 {
+                    let version = cell.load(Acquire);
+                    assert_eq!(version.major, version.minor % 1000);
+                    assert_eq!(version.minor, version.patch);
+                    assert!(version.minor >= last.minor);
+                    last = version;
+                }
+            });
+        }
+    });
+}
+
+#[test]
+fn shared_static() {
+    thread::scope(|scope| {
+        scope.spawn(|| CURRENT.store(Version::new(1, 5, 0), SeqCst).unwrap());
+    });
+    assert!(CURRENT.matches_req(&VersionReq::parse(">=1.5").unwrap(), SeqCst));
+}
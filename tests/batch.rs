@@ -0,0 +1,165 @@
+mod common;
+
+use common::Rng;
+use fast_version::{Version, VersionReq};
+
+const MAX: u64 = u64::MAX;
+
+impl Rng {
+    fn component(&mut self) -> u64 {
+        match self.next() % 8 {
+            0 => MAX,
+            _ => self.next() % 4,
+        }
+    }
+
+    fn version(&mut self) -> Version {
+        Version::new(self.component(), self.component(), self.component())
+    }
+
+    fn versions(&mut self) -> Vec<Version> {
+        let len = (self.next() % 40) as usize;
+        (0..len).map(|_| self.version()).collect()
+    }
+
+    fn req(&mut self) -> VersionReq {
+        match self.next() % 6 {
+            0 => VersionReq::STAR,
+            1 => VersionReq::EMPTY,
+            2 => VersionReq::exact(self.version()),
+            3 => VersionReq::at_least(self.version()),
+            4 => VersionReq::less_than(self.version()),
+            _ => VersionReq::between(self.version(), self.version()),
+        }
+    }
+}
+
+fn baseline(req: &VersionReq, versions: &[Version]) -> Vec<usize> {
+    versions
+        .iter()
+        .enumerate()
+        .filter(|(_, version)| req.matches(version))
+        .map(|(index, _)| index)
+        .collect()
+}
+
+fn check(req: &VersionReq, versions: &[Version]) {
+    let expected = baseline(req, versions);
+    assert_eq!(req.matching_indices(versions), expected, "{req}");
+    assert_eq!(req.count_matching(versions), expected.len(), "{req}");
+    for n in 0..=expected.len() {
+        assert!(req.has_at_least(versions, n), "{req} {n}");
+    }
+    assert!(!req.has_at_least(versions, expected.len() + 1), "{req}");
+
+    let mut out = vec![false; versions.len()];
+    req.matches_slice(versions, &mut out);
+    let matches: Vec<bool> = versions
+        .iter()
+        .map(|version| req.matches(version))
+        .collect();
+    assert_eq!(out, matches, "{req}");
+}
+
+#[test]
+fn agrees_with_filter_on_unsorted_input() {
+    let mut rng = Rng(0x5eed_1e55);
+    for _ in 0..2000 {
+        let req = rng.req();
+        let versions = rng.versions();
+        check(&req, &versions);
+    }
+}
+
+#[test]
+fn agrees_with_filter_on_sorted_input() {
+    let mut rng = Rng(0xdead_beef);
+    for _ in 0..2000 {
+        let req = rng.req();
+        let mut versions = rng.versions();
+        versions.sort();
+        check(&req, &versions);
+    }
+}
+
+#[test]
+fn sorted_input_gives_contiguous_indices() {
+    let versions: Vec<_> = (0..10).map(|minor| Version::new(1, minor, 0)).collect();
+    let req = VersionReq::parse(">=1.3, <1.7").unwrap();
+    assert_eq!(req.matching_indices(&versions), [3, 4, 5, 6]);
+
+    // Duplicates still count as sorted.
+    let versions = [
+        Version::new(1, 0, 0),
+        Version::new(2, 0, 0),
+        Version::new(2, 0, 0),
+        Version::new(3, 0, 0),
+    ];
+    assert_eq!(
+        VersionReq::exact(Version::new(2, 0, 0)).matching_indices(&versions),
+        [1, 2]
+    );
+}
+
+#[test]
+fn nearly_sorted_input_takes_the_slow_path() {
+    // A single swapped pair would make binary search miss matches.
+    let mut versions: Vec<_> = (0..10).map(|minor| Version::new(1, minor, 0)).collect();
+    versions.swap(0, 9);
+    let req = VersionReq::parse("<1.2").unwrap();
+    assert_eq!(req.matching_indices(&versions), [1, 9]);
+
+    versions.reverse();
+    assert_eq!(req.matching_indices(&versions), [0, 8]);
+}
+
+#[test]
+fn edge_cases() {
+    let versions = [Version::new(MAX, MAX, MAX), Version::new(0, 0, 0)];
+    assert!(VersionReq::EMPTY.matching_indices(&versions).is_empty());
+    assert_eq!(VersionReq::STAR.matching_indices(&versions), [0, 1]);
+
+    let mut sorted = versions;
+    sorted.sort();
+    assert!(VersionReq::EMPTY.matching_indices(&sorted).is_empty());
+    assert_eq!(VersionReq::STAR.matching_indices(&sorted), [0, 1]);
+
+    assert!(VersionReq::STAR.matching_indices(&[]).is_empty());
+    assert_eq!(VersionReq::STAR.count_matching(&[]), 0);
+    assert!(VersionReq::EMPTY.has_at_least(&[], 0));
+    assert!(!VersionReq::STAR.has_at_least(&[], 1));
+
+    let mut out = [true; 2];
+    VersionReq::EMPTY.matches_slice(&versions, &mut out);
+    assert_eq!(out, [false; 2]);
+    VersionReq::STAR.matches_slice(&[], &mut []);
+}
+
+#[test]
+fn matches_slice_across_lanes() {
+    // Versions that only differ in a later component, in every position of a vector and the tail.
+    let req = VersionReq::parse(">=1.2.3, <=1.5.0").unwrap();
+    let versions = [
+        Version::new(1, 2, 2),
+        Version::new(1, 2, 3),
+        Version::new(1, 5, 0),
+        Version::new(1, 5, 1),
+        Version::new(0, MAX, MAX),
+        Version::new(1, 4, MAX),
+        Version::new(2, 0, 0),
+        Version::new(1, 3, 0),
+        Version::new(1, 1, MAX),
+    ];
+    let mut out = [false; 9];
+    req.matches_slice(&versions, &mut out);
+    assert_eq!(
+        out,
+        [false, true, true, false, false, true, false, true, false]
+    );
+}
+
+#[test]
+#[should_panic(expected = "different lengths")]
+fn matches_slice_length_mismatch() {
+    VersionReq::STAR.matches_slice(&[Version::new(1, 0, 0)], &mut []);
+}
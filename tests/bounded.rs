@@ -0,0 +1,100 @@
+use fast_version::{const_version_u128, Version, VersionBounded, VersionOutOfBounds};
+
+type Protocol = VersionBounded<{ const_version_u128!("1.2.0") }, { const_version_u128!("2.0.5") }>;
+
+#[test]
+fn in_range() {
+    for version in [
+        Version::new(1, 2, 0),
+        Version::new(1, 9, 9),
+        Version::new(2, 0, 0),
+        Version::new(2, 0, 5),
+    ] {
+        let bounded = Protocol::new(version).unwrap();
+        assert_eq!(bounded.get(), version);
+        assert_eq!(Version::from(bounded), version);
+        assert_eq!(Protocol::try_from(version), Ok(bounded));
+        assert_eq!(bounded.to_string(), version.to_string());
+    }
+    assert_eq!(Protocol::MIN, Version::new(1, 2, 0));
+    assert_eq!(Protocol::MAX, Version::new(2, 0, 5));
+}
+
+#[test]
+fn below_min() {
+    let error = Protocol::new(Version::new(1, 1, u64::MAX)).unwrap_err();
+    assert_eq!(
+        error,
+        VersionOutOfBounds {
+            version: Version::new(1, 1, u64::MAX),
+            min: Version::new(1, 2, 0),
+            max: Version::new(2, 0, 5),
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "The version 1.1.18446744073709551615 is not within 1.2.0 and 2.0.5"
+    );
+    assert!(Protocol::new(Version::new(0, 0, 0)).is_err());
+}
+
+#[test]
+fn above_max() {
+    assert!(Protocol::new(Version::new(2, 0, 6)).is_err());
+    assert!(Protocol::try_from(Version::new(2, 1, 0)).is_err());
+    // Larger than anything the packed bounds can hold.
+    assert!(Protocol::new(Version::new(u64::MAX, 0, 0)).is_err());
+}
+
+#[test]
+fn const_construction() {
+    const CURRENT: Protocol = Protocol::new_const(Version::new(1, 4, 0));
+    const CHECKED: Result<Protocol, VersionOutOfBounds> = Protocol::new(Version::new(3, 0, 0));
+    assert_eq!(CURRENT.get(), Version::new(1, 4, 0));
+    assert!(CHECKED.is_err());
+}
+
+#[test]
+#[should_panic]
+fn new_const_panics_at_runtime() {
+    Protocol::new_const(Version::new(1, 0, 0));
+}
+
+#[test]
+fn single_version() {
+    type Exact = VersionBounded<{ const_version_u128!("1.2.3") }, { const_version_u128!("1.2.3") }>;
+    assert!(Exact::new(Version::new(1, 2, 3)).is_ok());
+    assert!(Exact::new(Version::new(1, 2, 4)).is_err());
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use super::Protocol;
+    use fast_version::Version;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Hello {
+        version: Protocol,
+    }
+
+    #[test]
+    fn passthrough() {
+        let hello = Hello {
+            version: Protocol::new(Version::new(1, 3, 0)).unwrap(),
+        };
+        let json = serde_json::to_string(&hello).unwrap();
+        assert_eq!(json, r#"{"version":"1.3.0"}"#);
+        assert_eq!(serde_json::from_str::<Hello>(&json).unwrap(), hello);
+    }
+
+    #[test]
+    fn validates_on_deserialize() {
+        let error = serde_json::from_str::<Hello>(r#"{"version":{"major":3,"minor":0,"patch":0}}"#)
+            .unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("The version 3.0.0 is not within 1.2.0 and 2.0.5"));
+        assert!(serde_json::from_str::<Hello>(r#"{"version":{"major":1}}"#).is_err());
+    }
+}
@@ -0,0 +1,203 @@
+use fast_version::{BumpKind, Version, VersionDiff};
+
+const MAX: u64 = u64::MAX;
+
+#[test]
+fn apply_bump_resets_lower_components() {
+    let version = Version::new(1, 4, 9);
+    assert_eq!(version.apply_bump(BumpKind::Patch), Version::new(1, 4, 10));
+    assert_eq!(version.apply_bump(BumpKind::Minor), Version::new(1, 5, 0));
+    assert_eq!(version.apply_bump(BumpKind::Major), Version::new(2, 0, 0));
+}
+
+#[test]
+fn apply_bump_const() {
+    const BUMPED: Version = Version::new(0, 9, 1).apply_bump(BumpKind::Major);
+    assert_eq!(BUMPED, Version::new(1, 0, 0));
+}
+
+#[test]
+fn apply_bump_at_max_of_lower_component() {
+    let version = Version::new(1, MAX, MAX);
+    assert_eq!(version.apply_bump(BumpKind::Major), Version::new(2, 0, 0));
+}
+
+#[test]
+#[should_panic]
+fn apply_bump_overflow() {
+    Version::new(1, 2, MAX).apply_bump(BumpKind::Patch);
+}
+
+#[test]
+fn bumped_between_clean() {
+    let old = Version::new(1, 2, 3);
+    for kind in [BumpKind::Patch, BumpKind::Minor, BumpKind::Major] {
+        assert_eq!(
+            Version::bumped_between(old, old.apply_bump(kind)),
+            Some(kind)
+        );
+    }
+    const KIND: Option<BumpKind> =
+        Version::bumped_between(Version::new(0, 0, 0), Version::new(0, 0, 1));
+    assert_eq!(KIND, Some(BumpKind::Patch));
+}
+
+#[test]
+fn bumped_between_rejections() {
+    let old = Version::new(1, 2, 3);
+    let rejected = [
+        // skipped releases
+        Version::new(1, 4, 0),
+        Version::new(1, 2, 5),
+        Version::new(3, 0, 0),
+        // lower components not reset
+        Version::new(1, 3, 3),
+        Version::new(2, 2, 3),
+        Version::new(2, 0, 3),
+        Version::new(2, 1, 0),
+        Version::new(1, 3, 1),
+        // same and backwards
+        old,
+        Version::new(1, 2, 2),
+        Version::new(1, 1, 0),
+        Version::new(0, 0, 0),
+        // bumped and another component changed
+        Version::new(1, 3, 4),
+        Version::new(2, 3, 0),
+    ];
+    for new in rejected {
+        assert_eq!(
+            Version::bumped_between(old, new),
+            None,
+            "{} -> {}",
+            old,
+            new
+        );
+    }
+}
+
+#[test]
+fn bumped_between_overflow() {
+    let old = Version::new(MAX, MAX, MAX);
+    assert_eq!(Version::bumped_between(old, Version::new(0, 0, 0)), None);
+    assert_eq!(Version::bumped_between(old, old), None);
+    assert_eq!(
+        Version::bumped_between(Version::new(1, MAX, 7), Version::new(2, 0, 0)),
+        Some(BumpKind::Major)
+    );
+}
+
+#[test]
+fn bump_kind_order_and_display() {
+    assert!(BumpKind::Patch < BumpKind::Minor);
+    assert!(BumpKind::Minor < BumpKind::Major);
+    assert_eq!(BumpKind::Minor.to_string(), "minor");
+}
+
+#[test]
+fn diff_is_the_most_significant_difference() {
+    let version = Version::new(1, 2, 3);
+    assert_eq!(version.diff(&version), VersionDiff::Equal);
+    assert_eq!(version.diff(&Version::new(1, 2, 4)), VersionDiff::Patch);
+    assert_eq!(version.diff(&Version::new(1, 0, 3)), VersionDiff::Minor);
+    assert_eq!(version.diff(&Version::new(2, 2, 3)), VersionDiff::Major);
+    assert_eq!(version.diff(&Version::new(0, 9, 9)), VersionDiff::Major);
+    assert_eq!(Version::new(0, 9, 9).diff(&version), VersionDiff::Major);
+    assert_eq!(VersionDiff::Minor.to_string(), "minor");
+    assert!(VersionDiff::Equal < VersionDiff::Patch && VersionDiff::Minor < VersionDiff::Major);
+}
+
+#[test]
+fn bump_components() {
+    let version = Version::new(1, 4, 9);
+    assert_eq!(version.bump_major(), Version::new(2, 0, 0));
+    assert_eq!(version.bump_minor(), Version::new(1, 5, 0));
+    assert_eq!(version.bump_patch(), Version::new(1, 4, 10));
+    assert_eq!(version.checked_bump_minor(), Some(Version::new(1, 5, 0)));
+    assert_eq!(version.saturating_bump_patch(), Version::new(1, 4, 10));
+    for kind in [BumpKind::Patch, BumpKind::Minor, BumpKind::Major] {
+        assert_eq!(
+            version.checked_apply_bump(kind),
+            Some(version.apply_bump(kind))
+        );
+        assert_eq!(
+            version.saturating_apply_bump(kind),
+            version.apply_bump(kind)
+        );
+    }
+
+    const CHAINED: Version = Version::ZERO
+        .bump_patch()
+        .bump_minor()
+        .bump_major()
+        .bump_patch();
+    assert_eq!(CHAINED, Version::new(1, 0, 1));
+}
+
+#[test]
+fn checked_bump_overflow() {
+    assert_eq!(Version::MAX.checked_bump_major(), None);
+    assert_eq!(Version::MAX.checked_bump_minor(), None);
+    assert_eq!(Version::MAX.checked_bump_patch(), None);
+    assert_eq!(Version::new(MAX, 0, 0).checked_bump_major(), None);
+    assert_eq!(
+        Version::new(MAX, 0, 0).checked_bump_minor(),
+        Some(Version::new(MAX, 1, 0))
+    );
+    assert_eq!(
+        Version::new(1, MAX, MAX).checked_bump_major(),
+        Some(Version::new(2, 0, 0))
+    );
+    assert_eq!(
+        Version::new(1, MAX - 1, MAX).checked_bump_minor(),
+        Some(Version::new(1, MAX, 0))
+    );
+}
+
+#[test]
+fn saturating_bump_overflow() {
+    assert_eq!(Version::MAX.saturating_bump_major(), Version::MAX);
+    assert_eq!(Version::MAX.saturating_bump_minor(), Version::MAX);
+    assert_eq!(Version::MAX.saturating_bump_patch(), Version::MAX);
+    assert_eq!(
+        Version::new(MAX, 2, 3).saturating_bump_major(),
+        Version::MAX
+    );
+    assert_eq!(
+        Version::new(1, MAX, 3).saturating_bump_minor(),
+        Version::new(1, MAX, MAX)
+    );
+    assert_eq!(
+        Version::new(1, 2, MAX).saturating_bump_patch(),
+        Version::new(1, 2, MAX)
+    );
+    // Saturating never goes backwards, even though the lower components would be reset.
+    for version in [
+        Version::new(MAX, 5, 5),
+        Version::new(3, MAX, 0),
+        Version::ZERO,
+    ] {
+        for kind in [BumpKind::Patch, BumpKind::Minor, BumpKind::Major] {
+            assert!(
+                version.saturating_apply_bump(kind) >= version,
+                "{version} {kind}"
+            );
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "overflowed")]
+fn bump_minor_overflow() {
+    Version::new(1, MAX, 0).bump_minor();
+}
+
+#[test]
+fn zero_and_max() {
+    assert!(Version::ZERO.is_zero());
+    assert!(!Version::MAX.is_zero());
+    assert_eq!(Version::ZERO, Version::new(0, 0, 0));
+    assert_eq!(Version::MAX.to_string().len(), 62);
+    assert!(Version::ZERO < Version::new(0, 0, 1));
+    assert!(Version::new(MAX, MAX, MAX - 1) < Version::MAX);
+}
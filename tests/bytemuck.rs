@@ -0,0 +1,53 @@
+#![cfg(feature = "bytemuck")]
+
+use bytemuck::PodCastError;
+use fast_version::Version;
+
+const VERSIONS: [Version; 3] = [
+    Version::new(1, 2, 3),
+    Version::new(0, 0, 0),
+    Version::new(u64::MAX, 7, 42),
+];
+
+#[test]
+fn layout() {
+    assert_eq!(std::mem::size_of::<Version>(), 24);
+    assert_eq!(std::mem::align_of::<Version>(), 8);
+
+    let bytes = bytemuck::bytes_of(&VERSIONS[0]);
+    assert_eq!(&bytes[0..8], &1u64.to_ne_bytes());
+    assert_eq!(&bytes[8..16], &2u64.to_ne_bytes());
+    assert_eq!(&bytes[16..24], &3u64.to_ne_bytes());
+}
+
+#[test]
+fn round_trip_through_bytes() {
+    let bytes: Vec<u8> = bytemuck::cast_slice(&VERSIONS).to_vec();
+    assert_eq!(bytes.len(), 72);
+
+    // Copy into u64 storage to guarantee the alignment a memory map would provide.
+    let mut aligned = vec![0u64; bytes.len() / 8];
+    bytemuck::cast_slice_mut(&mut aligned).copy_from_slice(&bytes);
+    let versions = Version::slice_from_bytes(bytemuck::cast_slice(&aligned)).unwrap();
+    assert_eq!(versions, &VERSIONS);
+}
+
+#[test]
+fn misaligned_and_short_buffers() {
+    let aligned = [0u64; 7];
+    let bytes: &[u8] = bytemuck::cast_slice(&aligned);
+
+    assert_eq!(
+        Version::slice_from_bytes(&bytes[1..25]),
+        Err(PodCastError::TargetAlignmentGreaterAndInputNotAligned)
+    );
+    assert_eq!(
+        Version::slice_from_bytes(&bytes[..23]),
+        Err(PodCastError::OutputSliceWouldHaveSlop)
+    );
+    assert_eq!(
+        Version::slice_from_bytes(&bytes[..40]),
+        Err(PodCastError::OutputSliceWouldHaveSlop)
+    );
+    assert_eq!(Version::slice_from_bytes(&bytes[..48]).unwrap().len(), 2);
+}
@@ -0,0 +1,109 @@
+mod common;
+
+use common::req;
+use fast_version::{Version, VersionReq, VersionReqVariant};
+
+#[test]
+fn golden_values() {
+    let golden = [
+        ("*", 17898889572554202957),
+        ("<0.0.0", 2937636301210115149),
+        ("=1.2.3", 553923375995194341),
+        (">=1.2.0, <2.0.0", 17804617656736214351),
+        ("<2.0.0", 4186654509781215330),
+    ];
+    for (input, hash) in golden {
+        assert_eq!(req(input).canonical_hash(), hash, "{}", input);
+    }
+}
+
+#[test]
+fn equivalent_reqs_hash_equal() {
+    let equivalent = [
+        (">1.2.3", ">=1.2.4"),
+        (">1.2", ">=1.3.0"),
+        ("<2", "<=1.18446744073709551615.18446744073709551615"),
+        ("^1.2", ">=1.2.0, <2.0.0"),
+        ("~1.2.3", ">=1.2.3, <1.3"),
+        ("1.2.3", ">=1.2.3, <=1.2.3"),
+        (">=0.0.0", "*"),
+        ("<0", "<0.0.0"),
+    ];
+    for (lhs, rhs) in equivalent {
+        assert_eq!(req(lhs), req(rhs));
+        assert_eq!(
+            req(lhs).canonical_hash(),
+            req(rhs).canonical_hash(),
+            "{} {}",
+            lhs,
+            rhs
+        );
+    }
+}
+
+#[test]
+fn empty_reqs_hash_equal() {
+    let empties = [
+        VersionReq::EMPTY,
+        VersionReq::new(&VersionReqVariant::MajorLess { major: 0 }),
+        VersionReq::between(Version::new(2, 0, 0), Version::new(1, 0, 0)),
+        VersionReq::greater_than(Version::new(u64::MAX, u64::MAX, u64::MAX)),
+    ];
+    for empty in empties {
+        assert_eq!(empty.canonical_hash(), VersionReq::EMPTY.canonical_hash());
+    }
+}
+
+#[test]
+fn different_reqs_hash_differently() {
+    let inputs = [
+        "*", "<0.0.0", "=1.2.3", "=1.2.4", ">=1.2.3", "<=1.2.3", ">=1, <2", ">=1, <3",
+    ];
+    for (index, lhs) in inputs.iter().enumerate() {
+        for rhs in &inputs[index + 1..] {
+            assert_ne!(
+                req(lhs).canonical_hash(),
+                req(rhs).canonical_hash(),
+                "{} {}",
+                lhs,
+                rhs
+            );
+        }
+    }
+}
+
+#[test]
+fn const_context() {
+    const HASH: u64 = VersionReq::STAR.canonical_hash();
+    assert_eq!(HASH, req("*").canonical_hash());
+}
+
+#[test]
+fn equivalent_constructions_property() {
+    let values = [0, 1, 2, u64::MAX - 1, u64::MAX];
+    let mut versions = Vec::new();
+    for major in values {
+        for minor in values {
+            for patch in values {
+                versions.push(Version::new(major, minor, patch));
+            }
+        }
+    }
+    for lower in &versions {
+        for upper in versions.iter().step_by(7) {
+            let direct = VersionReq::between(*lower, *upper);
+            let via_display = req(&direct.to_string());
+            let via_variants = direct.to_variants().to_req();
+            // `>=lower` written as `>predecessor`, where there is one.
+            let via_exclusive = match VersionReq::less_than(*lower).maximal() {
+                Some(before) => VersionReq::greater_than(before),
+                None => VersionReq::STAR,
+            }
+            .intersection(&VersionReq::at_most(*upper));
+            for equivalent in [via_display, via_variants, via_exclusive] {
+                assert_eq!(equivalent, direct);
+                assert_eq!(equivalent.canonical_hash(), direct.canonical_hash());
+            }
+        }
+    }
+}
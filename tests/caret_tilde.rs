@@ -0,0 +1,133 @@
+//! The caret and tilde tables of the semver crate, for full versions without prereleases.
+
+use fast_version::{Version, VersionReq, VersionReqVariant};
+use std::str::FromStr;
+
+const MAX: u64 = u64::MAX;
+
+fn v(input: &str) -> Version {
+    Version::from_str(input).unwrap()
+}
+
+#[track_caller]
+fn check(variant: VersionReqVariant, matching: &[&str], not_matching: &[&str]) {
+    let req = VersionReq::new(&variant);
+    for version in matching {
+        assert!(req.matches(&v(version)), "{variant} should match {version}");
+    }
+    for version in not_matching {
+        assert!(
+            !req.matches(&v(version)),
+            "{variant} shouldn't match {version}"
+        );
+    }
+}
+
+#[test]
+fn caret() {
+    check(
+        VersionReqVariant::Caret(v("1.2.3")),
+        &["1.2.3", "1.8.1", "1.6.5", "1.2.4"],
+        &["2.0.0", "2.0.1", "1.2.2", "1.1.0", "0.9.0", "0.2.3"],
+    );
+    check(
+        VersionReqVariant::Caret(v("1.0.0")),
+        &["1.0.0", "1.1.0", "1.0.1"],
+        &["0.9.1", "0.0.9", "2.0.0", "2.9.0"],
+    );
+    check(
+        VersionReqVariant::Caret(v("0.1.2")),
+        &["0.1.2", "0.1.4"],
+        &["0.9.1", "0.0.9", "0.1.1", "0.2.0", "1.0.0"],
+    );
+    check(
+        VersionReqVariant::Caret(v("0.5.1")),
+        &["0.5.1", "0.5.2", "0.5.99"],
+        &["0.5.0", "0.6.0", "1.5.1"],
+    );
+    check(
+        VersionReqVariant::Caret(v("0.0.2")),
+        &["0.0.2"],
+        &["0.9.1", "0.0.1", "0.0.3", "0.1.1", "0.1.0", "1.0.0"],
+    );
+    check(
+        VersionReqVariant::Caret(v("0.0.0")),
+        &["0.0.0"],
+        &["0.0.1", "0.1.0", "1.0.0"],
+    );
+}
+
+#[test]
+fn tilde() {
+    check(
+        VersionReqVariant::Tilde(v("1.2.2")),
+        &["1.2.2", "1.2.4"],
+        &["1.9.0", "1.0.9", "2.0.1", "0.1.3", "1.3.0", "1.2.1"],
+    );
+    check(
+        VersionReqVariant::Tilde(v("0.0.2")),
+        &["0.0.2", "0.0.9"],
+        &["0.0.1", "0.1.0", "1.0.0"],
+    );
+    check(
+        VersionReqVariant::Tilde(v("0.3.1")),
+        &["0.3.1", "0.3.5"],
+        &["0.3.0", "0.4.0"],
+    );
+}
+
+#[test]
+fn same_as_parsed() {
+    for version in ["1.2.3", "0.2.3", "0.0.3", "0.0.0", "3.0.0", "1.0.9"] {
+        let version = v(version);
+        assert_eq!(
+            VersionReq::new(&VersionReqVariant::Caret(version)),
+            VersionReq::parse(&format!("^{version}")).unwrap()
+        );
+        assert_eq!(
+            VersionReq::new(&VersionReqVariant::Tilde(version)),
+            VersionReq::parse(&format!("~{version}")).unwrap()
+        );
+    }
+}
+
+#[test]
+fn saturating_upper_bound() {
+    let caret = VersionReq::new(&VersionReqVariant::Caret(Version::new(MAX, 2, 3)));
+    assert_eq!(caret.maximal(), Some(Version::new(MAX, MAX, MAX)));
+    assert!(caret.matches(&Version::new(MAX, MAX, 0)));
+    assert!(!caret.matches(&Version::new(MAX, 2, 2)));
+
+    let caret = VersionReq::new(&VersionReqVariant::Caret(Version::new(0, MAX, 3)));
+    assert_eq!(caret.maximal(), Some(Version::new(0, MAX, MAX)));
+    assert!(!caret.matches(&Version::new(1, 0, 0)));
+
+    let caret = VersionReq::new(&VersionReqVariant::Caret(Version::new(1, MAX, MAX)));
+    assert_eq!(caret, VersionReq::exact(Version::new(1, MAX, MAX)));
+
+    let tilde = VersionReq::new(&VersionReqVariant::Tilde(Version::new(1, MAX, 3)));
+    assert_eq!(tilde.maximal(), Some(Version::new(1, MAX, MAX)));
+    assert!(!tilde.matches(&Version::new(2, 0, 0)));
+
+    let tilde = VersionReq::new(&VersionReqVariant::Tilde(Version::new(MAX, MAX, MAX)));
+    assert_eq!(tilde, VersionReq::exact(Version::new(MAX, MAX, MAX)));
+}
+
+#[test]
+fn display() {
+    assert_eq!(
+        VersionReqVariant::Caret(Version::new(1, 2, 3)).to_string(),
+        "^1.2.3"
+    );
+    assert_eq!(
+        VersionReqVariant::Tilde(Version::new(1, 2, 3)).to_string(),
+        "~1.2.3"
+    );
+}
+
+const CARET: VersionReq = VersionReq::new(&VersionReqVariant::Caret(Version::new(0, 2, 3)));
+
+#[test]
+fn is_const() {
+    assert_eq!(CARET.to_string(), ">=0.2.3, <0.3.0");
+}
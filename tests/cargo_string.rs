@@ -0,0 +1,89 @@
+use fast_version::{Version, VersionReq};
+
+const MAX: u64 = u64::MAX;
+
+fn cargo(input: &str) -> String {
+    VersionReq::parse(input).unwrap().to_cargo_string()
+}
+
+/// Reads the string back with cargo's meaning of a bare version, which is a caret requirement.
+fn from_cargo(input: &str) -> VersionReq {
+    if input.starts_with(|c: char| c.is_ascii_digit()) {
+        VersionReq::parse(&format!("^{input}")).unwrap()
+    } else {
+        VersionReq::parse(input).unwrap()
+    }
+}
+
+#[test]
+fn star_and_exact() {
+    assert_eq!(VersionReq::STAR.to_cargo_string(), "*");
+    assert_eq!(cargo("=1.2.3"), "=1.2.3");
+    assert_eq!(cargo("=0.0.0"), "=0.0.0");
+    assert_eq!(
+        VersionReq::exact(Version::new(MAX, MAX, MAX)).to_cargo_string(),
+        "=18446744073709551615.18446744073709551615.18446744073709551615"
+    );
+}
+
+#[test]
+fn caret_regimes() {
+    assert_eq!(cargo("^1.2.3"), "1.2.3");
+    assert_eq!(cargo(">=1.0.0, <2.0.0"), "1.0.0");
+    assert_eq!(cargo("^1"), "1.0.0");
+    assert_eq!(cargo("^0.2.3"), "0.2.3");
+    assert_eq!(cargo(">=0.2.0, <0.3.0"), "0.2.0");
+    assert_eq!(cargo("^0.0.3"), "=0.0.3");
+    assert_eq!(
+        VersionReq::at_least(Version::new(MAX, 4, 0)).to_cargo_string(),
+        "18446744073709551615.4.0"
+    );
+}
+
+#[test]
+fn tilde() {
+    assert_eq!(cargo("~1.2.3"), "~1.2.3");
+    assert_eq!(cargo(">=1.2.0, <1.3.0"), "~1.2.0");
+    assert_eq!(cargo("~0.0.3"), "~0.0.3");
+    assert_eq!(cargo("^0.0"), "~0.0.0");
+    // ~0.2.3 and ^0.2.3 are the same range, the caret form wins.
+    assert_eq!(cargo("~0.2.3"), "0.2.3");
+}
+
+#[test]
+fn almost_caret_falls_back() {
+    assert_eq!(cargo(">=1.2.3, <2.0.1"), ">=1.2.3, <=2.0.0");
+    assert_eq!(cargo(">=1.2.3, <1.99.0"), ">=1.2.3, <1.99.0");
+    assert_eq!(cargo(">1.2.3, <2.0.0"), "1.2.4");
+    assert_eq!(cargo(">=0.2.3, <0.3.1"), ">=0.2.3, <=0.3.0");
+    assert_eq!(cargo(">=0.0.3, <0.0.5"), ">=0.0.3, <=0.0.4");
+    assert_eq!(cargo(">=1.2.3, <1.3.1"), ">=1.2.3, <=1.3.0");
+    assert_eq!(cargo("^0"), "<1.0.0");
+}
+
+#[test]
+fn fallbacks() {
+    assert_eq!(cargo(">=1.2.3"), ">=1.2.3");
+    assert_eq!(cargo("<2"), "<2.0.0");
+    assert_eq!(VersionReq::EMPTY.to_cargo_string(), "<0.0.0");
+}
+
+#[test]
+fn means_the_same_to_cargo() {
+    for input in [
+        "*",
+        "=1.2.3",
+        "^1.2.3",
+        "^0.2.3",
+        "^0.0.3",
+        "~1.2.3",
+        "~0.0.3",
+        ">=1.2.3, <2.0.1",
+        ">=3",
+        "<0.0.0",
+        "<1",
+    ] {
+        let req = VersionReq::parse(input).unwrap();
+        assert_eq!(from_cargo(&req.to_cargo_string()), req, "{input}");
+    }
+}
@@ -0,0 +1,104 @@
+use fast_version::{Channel, FullVersion, VersionReq};
+use std::str::FromStr;
+
+#[test]
+fn spellings() {
+    let cases = [
+        ("", Channel::Stable),
+        ("alpha", Channel::Alpha),
+        ("alpha.1", Channel::Alpha),
+        ("alpha1", Channel::Alpha),
+        ("ALPHA-2", Channel::Alpha),
+        ("beta", Channel::Beta),
+        ("Beta.3.1", Channel::Beta),
+        ("beta2", Channel::Beta),
+        ("rc", Channel::Rc),
+        ("rc.1", Channel::Rc),
+        ("RC1", Channel::Rc),
+        ("nightly", Channel::Nightly),
+        ("nightly.2024-05-01", Channel::Nightly),
+        ("Nightly20240501", Channel::Nightly),
+        ("dev", Channel::Other),
+        ("SNAPSHOT", Channel::Other),
+        ("pre.alpha", Channel::Other),
+        ("a", Channel::Other),
+        ("r", Channel::Other),
+        ("0.rc", Channel::Other),
+        ("ünicode", Channel::Other),
+    ];
+    for (prerelease, channel) in cases {
+        assert_eq!(
+            Channel::from_prerelease(prerelease),
+            channel,
+            "{prerelease}"
+        );
+    }
+}
+
+#[test]
+fn ordering() {
+    let ordered = [
+        Channel::Other,
+        Channel::Nightly,
+        Channel::Alpha,
+        Channel::Beta,
+        Channel::Rc,
+        Channel::Stable,
+    ];
+    assert!(ordered.windows(2).all(|pair| pair[0] < pair[1]));
+    assert_eq!(
+        ordered.map(|channel| channel.to_string()),
+        ["other", "nightly", "alpha", "beta", "rc", "stable"]
+    );
+}
+
+fn full(input: &str) -> FullVersion {
+    FullVersion::from_str(input).unwrap()
+}
+
+#[test]
+fn channel_gate() {
+    let req = VersionReq::parse(">=1.2, <2").unwrap();
+    let inside = full("1.5.0+build.1");
+    let outside = full("2.0.0");
+    for allowed in [
+        Channel::Other,
+        Channel::Nightly,
+        Channel::Alpha,
+        Channel::Beta,
+        Channel::Rc,
+        Channel::Stable,
+    ] {
+        assert!(req.matches_in_channel(&inside, allowed), "{allowed}");
+        assert!(!req.matches_in_channel(&outside, allowed), "{allowed}");
+    }
+    assert!(!VersionReq::EMPTY.matches_in_channel(&inside, Channel::Other));
+}
+
+#[test]
+fn prerelease_channel_gate() {
+    let req = VersionReq::parse(">=2.0.0-0, <3").unwrap();
+    let cases = [
+        ("2.0.0-dev.1", Channel::Other),
+        ("2.0.0-nightly.5", Channel::Nightly),
+        ("2.0.0-alpha", Channel::Alpha),
+        ("2.0.0-beta.2", Channel::Beta),
+        ("2.0.0-rc.1", Channel::Rc),
+        ("2.0.0", Channel::Stable),
+    ];
+    for (input, channel) in cases {
+        let version = full(input);
+        assert_eq!(version.channel(), channel, "{input}");
+        for (_, allowed) in cases {
+            assert_eq!(
+                req.matches_in_channel(&version, allowed),
+                channel >= allowed,
+                "{input} {allowed}"
+            );
+        }
+    }
+    // The channel doesn't let a prerelease past a requirement that doesn't name one.
+    let stable = VersionReq::parse("^2").unwrap();
+    assert!(!stable.matches_in_channel(&full("2.1.0-rc.1"), Channel::Other));
+    assert!(stable.matches_in_channel(&full("2.1.0"), Channel::Stable));
+}
@@ -0,0 +1,89 @@
+use fast_version::{
+    Version, VersionReq, VersionReqVariant, VersionReqVariantLowerBound,
+    VersionReqVariantUpperBound,
+};
+
+const RANGE: VersionReq = VersionReq::new(&VersionReqVariant::Compound(
+    VersionReqVariantLowerBound::PatchGreater {
+        major: 1,
+        minor: 2,
+        patch: 3,
+    },
+    VersionReqVariantUpperBound::MinorLess { major: 2, minor: 0 },
+));
+
+#[test]
+fn minimal_and_maximal_respect_exclusive_bounds() {
+    assert_eq!(RANGE.minimal(), Some(Version::new(1, 2, 4)));
+    assert_eq!(RANGE.maximal(), Some(Version::new(1, u64::MAX, u64::MAX)));
+
+    let req = VersionReq::new(&VersionReqVariant::MinorGreater { major: 1, minor: 2 });
+    assert_eq!(req.minimal(), Some(Version::new(1, 3, 0)));
+    let req = VersionReq::new(&VersionReqVariant::MajorLess { major: 3 });
+    assert_eq!(req.maximal(), Some(Version::new(2, u64::MAX, u64::MAX)));
+    let req = VersionReq::new(&VersionReqVariant::PatchLess {
+        major: 1,
+        minor: 0,
+        patch: 0,
+    });
+    assert_eq!(req.maximal(), Some(Version::new(0, u64::MAX, u64::MAX)));
+
+    assert_eq!(VersionReq::EMPTY.minimal(), None);
+    assert_eq!(VersionReq::EMPTY.maximal(), None);
+}
+
+#[test]
+fn clamp_from_below() {
+    assert_eq!(
+        Version::new(1, 2, 3).clamp_to(&RANGE),
+        Some(Version::new(1, 2, 4))
+    );
+    assert_eq!(
+        Version::new(0, 9, 9).clamp_to(&RANGE),
+        Some(Version::new(1, 2, 4))
+    );
+}
+
+#[test]
+fn clamp_from_above() {
+    assert_eq!(
+        Version::new(2, 0, 0).clamp_to(&RANGE),
+        Some(Version::new(1, u64::MAX, u64::MAX))
+    );
+    let req = VersionReq::new(&VersionReqVariant::PatchLessEqual {
+        major: 1,
+        minor: 4,
+        patch: 2,
+    });
+    assert_eq!(
+        Version::new(1, 5, 0).clamp_to(&req),
+        Some(Version::new(1, 4, 2))
+    );
+}
+
+#[test]
+fn clamp_inside() {
+    for version in [
+        Version::new(1, 2, 4),
+        Version::new(1, 3, 0),
+        Version::new(1, 99, 0),
+    ] {
+        assert_eq!(version.clamp_to(&RANGE), Some(version));
+    }
+    assert_eq!(
+        Version::new(7, 7, 7).clamp_to(&VersionReq::STAR),
+        Some(Version::new(7, 7, 7))
+    );
+}
+
+#[test]
+fn clamp_to_empty() {
+    const EMPTY: VersionReq = VersionReq::new(&VersionReqVariant::Compound(
+        VersionReqVariantLowerBound::MajorGreaterEqual { major: 2 },
+        VersionReqVariantUpperBound::MajorLess { major: 2 },
+    ));
+    assert!(EMPTY.is_empty());
+    assert_eq!(EMPTY, VersionReq::EMPTY);
+    assert_eq!(Version::new(2, 0, 0).clamp_to(&EMPTY), None);
+    assert_eq!(Version::new(1, 2, 3).clamp_to(&VersionReq::EMPTY), None);
+}
@@ -0,0 +1,109 @@
+#![cfg(feature = "cli")]
+
+use assert_cmd::Command;
+use serde_json::{json, Value};
+
+fn fastver(args: &[&str]) -> Command {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_fastver"));
+    command.args(args);
+    command
+}
+
+fn json_output(command: &mut Command, code: i32) -> Value {
+    let output = command.assert().code(code).get_output().stdout.clone();
+    serde_json::from_slice(&output).unwrap()
+}
+
+#[test]
+fn parse() {
+    fastver(&["parse", "1.2.3"])
+        .assert()
+        .success()
+        .stdout("1.2.3\n");
+    let parsed = json_output(&mut fastver(&["parse", "1.2.3", "--json"]), 0);
+    assert_eq!(parsed, json!("1.2.3"));
+}
+
+#[test]
+fn parse_invalid() {
+    fastver(&["parse", "1.2"]).assert().code(2).stdout("");
+    fastver(&["parse", "--json", "v1.2.3"]).assert().code(2);
+}
+
+#[test]
+fn compare() {
+    fastver(&["compare", "1.2.3", "1.10.0"])
+        .assert()
+        .code(1)
+        .stdout("1.2.3 < 1.10.0\n");
+    fastver(&["compare", "1.2.3", "1.2.3"])
+        .assert()
+        .code(0)
+        .stdout("1.2.3 = 1.2.3\n");
+    fastver(&["compare", "2.0.0", "1.99.99"])
+        .assert()
+        .code(3)
+        .stdout("2.0.0 > 1.99.99\n");
+    for (first, second, ordering, code) in [
+        ("1.2.3", "1.10.0", "less", 1),
+        ("0.0.0", "0.0.0", "equal", 0),
+        ("1.0.1", "1.0.0", "greater", 3),
+    ] {
+        let compared = json_output(&mut fastver(&["compare", "--json", first, second]), code);
+        assert_eq!(compared, json!({ "ordering": ordering }));
+    }
+    fastver(&["compare", "1.2.3"]).assert().code(2);
+}
+
+#[test]
+fn matches() {
+    fastver(&["matches", ">=1.2, <2", "1.5.0"])
+        .assert()
+        .success()
+        .stdout("true\n");
+    fastver(&["matches", ">=1.2, <2", "2.0.0"])
+        .assert()
+        .code(1)
+        .stdout("false\n");
+    let matched = json_output(&mut fastver(&["matches", "^1", "1.0.0", "--json"]), 0);
+    assert_eq!(matched, json!({"matches": true}));
+    let matched = json_output(&mut fastver(&["matches", "^1", "0.9.0", "--json"]), 1);
+    assert_eq!(matched, json!({"matches": false}));
+    fastver(&["matches", ">>1", "1.0.0"]).assert().code(2);
+}
+
+#[test]
+fn max_matching() {
+    let versions = "1.2.0\n  1.9.3  \n\n2.0.0\n1.10.0\n0.9.0\n";
+    fastver(&["max-matching", ">=1.2, <2"])
+        .write_stdin(versions)
+        .assert()
+        .success()
+        .stdout("1.10.0\n");
+    let max = json_output(
+        fastver(&["max-matching", "--json", "^1"]).write_stdin(versions),
+        0,
+    );
+    assert_eq!(max, json!("1.10.0"));
+}
+
+#[test]
+fn max_matching_none() {
+    fastver(&["max-matching", ">=3"])
+        .write_stdin("1.0.0\n2.0.0\n")
+        .assert()
+        .code(1)
+        .stdout("");
+    let max = json_output(fastver(&["max-matching", "--json", "*"]).write_stdin(""), 1);
+    assert_eq!(max, Value::Null);
+}
+
+#[test]
+fn max_matching_invalid_line() {
+    fastver(&["max-matching", "*"])
+        .write_stdin("1.0.0\n1.x\n")
+        .assert()
+        .code(2)
+        .stdout("")
+        .stderr("error: line 2: invalid version \"1.x\": Expected 3 components, found only 2\n");
+}
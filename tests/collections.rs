@@ -0,0 +1,110 @@
+mod common;
+
+use common::req;
+use fast_version::{Version, VersionCollectionExt, VersionReq};
+use std::collections::{BTreeMap, BTreeSet};
+
+const MAX: u64 = u64::MAX;
+
+fn versions() -> Vec<Version> {
+    [
+        (0, 1, 0),
+        (1, 0, 0),
+        (1, 2, 0),
+        (1, 2, 7),
+        (1, 9, 0),
+        (2, 0, 1),
+        (3, 1, 4),
+    ]
+    .into_iter()
+    .map(|(major, minor, patch)| Version::new(major, minor, patch))
+    .collect()
+}
+
+#[test]
+fn set_bounds_between_keys() {
+    let set: BTreeSet<_> = versions().into_iter().collect();
+    // Neither bound is a key.
+    let between = req(">=1.1, <1.5");
+    assert_eq!(set.latest_matching(&between), Some(&Version::new(1, 2, 7)));
+    assert_eq!(set.count_matching(&between), 2);
+    assert_eq!(
+        set.matching(&between).copied().collect::<Vec<_>>(),
+        [Version::new(1, 2, 0), Version::new(1, 2, 7)]
+    );
+    // Both bounds between the same pair of keys.
+    let gap = req(">=2.0.2, <3.1.4");
+    assert_eq!(set.latest_matching(&gap), None);
+    assert_eq!(set.count_matching(&gap), 0);
+    // Lower bound below and upper bound above every key.
+    assert_eq!(
+        set.latest_matching(&req("<=9")),
+        Some(&Version::new(3, 1, 4))
+    );
+    assert_eq!(set.count_matching(&VersionReq::STAR), set.len());
+}
+
+#[test]
+fn set_bounds_on_keys() {
+    let set: BTreeSet<_> = versions().into_iter().collect();
+    assert_eq!(
+        set.latest_matching(&req("<=1.9.0")),
+        Some(&Version::new(1, 9, 0))
+    );
+    assert_eq!(
+        set.latest_matching(&req("<1.9.0")),
+        Some(&Version::new(1, 2, 7))
+    );
+    assert_eq!(
+        set.latest_matching(&req("=1.2.0")),
+        Some(&Version::new(1, 2, 0))
+    );
+    assert_eq!(set.count_matching(&req(">1.2.7, <2.0.1")), 1);
+}
+
+#[test]
+fn set_empty() {
+    let set: BTreeSet<_> = versions().into_iter().collect();
+    assert_eq!(set.latest_matching(&VersionReq::EMPTY), None);
+    assert_eq!(set.count_matching(&VersionReq::EMPTY), 0);
+    assert_eq!(BTreeSet::new().latest_matching(&VersionReq::STAR), None);
+}
+
+#[test]
+fn map_bounds_between_keys() {
+    let map: BTreeMap<_, _> = versions()
+        .into_iter()
+        .map(|version| (version, version.to_string()))
+        .collect();
+    assert_eq!(
+        map.latest_matching(&req("^1.1")),
+        Some((&Version::new(1, 9, 0), &"1.9.0".to_string()))
+    );
+    assert_eq!(map.count_matching(&req("~1.2")), 2);
+    assert_eq!(
+        map.matching(&req(">0.1.0, <1.2.1"))
+            .map(|(_, name)| name.as_str())
+            .collect::<Vec<_>>(),
+        ["1.0.0", "1.2.0"]
+    );
+    assert_eq!(map.latest_matching(&req(">=3.1.5")), None);
+    assert_eq!(map.latest_matching(&VersionReq::EMPTY), None);
+}
+
+#[test]
+fn agrees_with_scanning() {
+    let set: BTreeSet<_> = versions().into_iter().collect();
+    for lower in &set {
+        for upper in [
+            Version::new(1, 2, 3),
+            Version::new(2, 0, 0),
+            Version::new(MAX, 0, 0),
+        ] {
+            let req = VersionReq::at_least(*lower)
+                .intersection(&VersionReq::between(Version::new(0, 0, 0), upper));
+            let scanned = set.iter().filter(|version| req.matches(version));
+            assert_eq!(set.latest_matching(&req), scanned.clone().max(), "{req}");
+            assert_eq!(set.count_matching(&req), scanned.count(), "{req}");
+        }
+    }
+}
@@ -0,0 +1,32 @@
+//! Helpers shared by the integration tests, each of them includes this with `mod common;`.
+
+// Every test crate compiles its own copy and uses only some of the helpers.
+#![allow(dead_code)]
+
+use fast_version::VersionReq;
+
+/// Xorshift generator, good enough to sample deterministically. Tests add the samplers they need
+/// in an `impl Rng` block of their own.
+pub struct Rng(pub u64);
+
+impl Rng {
+    pub fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// A string of fewer than `max_len` characters from the alphabet.
+    pub fn string(&mut self, alphabet: &[char], max_len: u64) -> String {
+        let len = self.next() % max_len;
+        (0..len)
+            .map(|_| alphabet[(self.next() % alphabet.len() as u64) as usize])
+            .collect()
+    }
+}
+
+/// Parses a requirement the test knows to be valid.
+pub fn req(input: &str) -> VersionReq {
+    VersionReq::parse(input).unwrap()
+}
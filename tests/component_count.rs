@@ -0,0 +1,66 @@
+use fast_version::{Version, VersionParseError, VersionReq, VersionReqParseError};
+use std::str::FromStr;
+
+#[test]
+fn too_few() {
+    for (input, found) in [("1", 1), ("1.2", 2), ("", 1), ("1.", 2)] {
+        assert!(
+            matches!(
+                Version::from_str(input),
+                Err(VersionParseError::TooFewComponents { found: actual }) if actual == found
+            ),
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn too_many() {
+    for (input, found) in [("1.2.3.4", 4), ("1.2.3.4.5", 5), ("...", 4), ("1.2.3.", 4)] {
+        assert!(
+            matches!(
+                Version::parse_checked(input),
+                Err(VersionParseError::TooManyComponents { found: actual }) if actual == found
+            ),
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn count_saturates() {
+    let input = ".".repeat(1000);
+    assert!(matches!(
+        Version::parse_checked(&input),
+        Err(VersionParseError::TooManyComponents { found: u8::MAX })
+    ));
+}
+
+#[test]
+fn bytes_and_display() {
+    assert!(matches!(
+        Version::parse_checked_bytes(b"1.2.3.4"),
+        Err(VersionParseError::TooManyComponents { found: 4 })
+    ));
+    assert_eq!(
+        Version::from_str("1").unwrap_err().to_string(),
+        "Expected 3 components, found only 1"
+    );
+    assert_eq!(
+        Version::from_str("1.2.3.4.5").unwrap_err().to_string(),
+        "Expected 3 components, found 5"
+    );
+}
+
+#[test]
+fn in_requirement() {
+    assert!(matches!(
+        VersionReq::parse(">=1.2.3.4"),
+        Err(VersionReqParseError::MalformedNumber {
+            source: VersionParseError::TooManyComponents { found: 4 },
+            ..
+        })
+    ));
+    // Requirements may leave out minor and patch.
+    assert!(VersionReq::parse(">=1").is_ok());
+}
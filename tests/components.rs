@@ -0,0 +1,30 @@
+use fast_version::Version;
+
+#[test]
+fn iteration_order() {
+    let version = Version::new(1, 2, 3);
+    assert_eq!(version.components().collect::<Vec<_>>(), [1, 2, 3]);
+    assert_eq!(version.components().len(), 3);
+    assert_eq!(version.components().rev().collect::<Vec<_>>(), [3, 2, 1]);
+
+    let mut owned = Vec::new();
+    for component in version {
+        owned.push(component);
+    }
+    let borrowed: Vec<u64> = (&version).into_iter().collect();
+    assert_eq!(owned, borrowed);
+    assert_eq!(owned, [1, 2, 3]);
+}
+
+#[test]
+fn common_prefix() {
+    let version = Version::new(1, 2, 3);
+    assert_eq!(version.common_prefix_len(&version), 3);
+    assert_eq!(version.common_prefix_len(&Version::new(1, 2, 4)), 2);
+    assert_eq!(version.common_prefix_len(&Version::new(1, 3, 3)), 1);
+    assert_eq!(version.common_prefix_len(&Version::new(0, 2, 3)), 0);
+    assert_eq!(
+        Version::new(0, 0, 0).common_prefix_len(&Version::new(0, 0, 0)),
+        3
+    );
+}
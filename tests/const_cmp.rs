@@ -0,0 +1,48 @@
+use fast_version::Version;
+use std::cmp::Ordering;
+
+const LOW: Version = Version::new(1, 2, 3);
+const HIGH: Version = Version::new(1, 10, 0);
+
+const ORDERINGS: [Ordering; 3] = [
+    LOW.const_cmp(&HIGH),
+    HIGH.const_cmp(&LOW),
+    LOW.const_cmp(&LOW),
+];
+const EQUALITIES: [bool; 2] = [LOW.const_eq(&LOW), LOW.const_eq(&HIGH)];
+const MAX: Version = Version::const_max(LOW, HIGH);
+const MIN: Version = Version::const_min(HIGH, LOW);
+static STATIC_MAX: Version = Version::const_max(HIGH, LOW);
+
+#[test]
+fn const_context() {
+    assert_eq!(
+        ORDERINGS,
+        [Ordering::Less, Ordering::Greater, Ordering::Equal]
+    );
+    assert_eq!(EQUALITIES, [true, false]);
+    assert_eq!(MAX, HIGH);
+    assert_eq!(MIN, LOW);
+    assert_eq!(STATIC_MAX, HIGH);
+}
+
+#[test]
+fn agrees_with_ord() {
+    let values = [0, 1, 2, u64::MAX - 1, u64::MAX];
+    let mut versions = Vec::new();
+    for major in values {
+        for minor in values {
+            for patch in values {
+                versions.push(Version::new(major, minor, patch));
+            }
+        }
+    }
+    for lhs in &versions {
+        for rhs in &versions {
+            assert_eq!(lhs.const_cmp(rhs), lhs.cmp(rhs));
+            assert_eq!(lhs.const_eq(rhs), lhs == rhs);
+            assert_eq!(Version::const_max(*lhs, *rhs), (*lhs).max(*rhs));
+            assert_eq!(Version::const_min(*lhs, *rhs), (*lhs).min(*rhs));
+        }
+    }
+}
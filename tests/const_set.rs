@@ -0,0 +1,115 @@
+use fast_version::{ReleaseRange, Version, VersionReq, VersionReqSet};
+
+static POLICY: VersionReqSet = VersionReqSet::from_array([
+    VersionReq::between(Version::new(1, 0, 0), Version::new(1, 4, 9)),
+    VersionReq::exact(Version::new(1, 6, 0)),
+    VersionReq::at_least(Version::new(2, 0, 0)),
+]);
+
+static EMPTY: VersionReqSet = VersionReqSet::from_array([]);
+
+const BUILT: VersionReqSet = VersionReqSet::from_array([VersionReq::STAR]);
+
+const _: () = {
+    assert!(POLICY.matches(&Version::new(1, 0, 0)));
+    assert!(POLICY.matches(&Version::new(1, 4, 9)));
+    assert!(!POLICY.matches(&Version::new(1, 5, 0)));
+    assert!(POLICY.matches(&Version::new(1, 6, 0)));
+    assert!(!POLICY.matches(&Version::new(1, 6, 1)));
+    assert!(POLICY.matches(&Version::new(u64::MAX, 0, 0)));
+    assert!(POLICY.ranges().len() == 3);
+    assert!(!POLICY.is_empty());
+    assert!(EMPTY.is_empty());
+};
+
+const MATCHES: [bool; 3] = [
+    POLICY.matches(&Version::new(0, 9, 0)),
+    POLICY.matches(&Version::new(1, 2, 3)),
+    EMPTY.matches(&Version::new(1, 2, 3)),
+];
+
+#[test]
+fn const_context() {
+    assert_eq!(MATCHES, [false, true, false]);
+    assert_eq!(BUILT.ranges(), [VersionReq::STAR]);
+    assert!(EMPTY.is_empty());
+    assert_eq!(
+        POLICY,
+        VersionReqSet::parse(">=1.0.0, <=1.4.9 || =1.6.0 || >=2").unwrap()
+    );
+    assert_eq!(POLICY.to_string(), ">=1.0.0, <=1.4.9 || =1.6.0 || >=2.0.0");
+}
+
+#[test]
+fn full_inline_storage() {
+    let ranges =
+        [0, 2, 4, 6, 8, 10, 12, 14].map(|minor| VersionReq::exact(Version::new(1, minor, 0)));
+    let set = VersionReqSet::from_array(ranges);
+    assert_eq!(set.ranges(), ranges);
+    assert_eq!(set, VersionReqSet::from(ranges));
+    // The inline ranges only hold the bounds on releases.
+    assert_eq!(
+        std::mem::size_of::<ReleaseRange>(),
+        2 * std::mem::size_of::<Version>()
+    );
+}
+
+#[test]
+fn spills_to_the_heap() {
+    let exclusions: String = (0..20)
+        .map(|patch| format!(", !=1.0.{}", 2 * patch))
+        .collect();
+    let set = VersionReqSet::parse(&format!(">=1.0.0, <1.1.0{exclusions}")).unwrap();
+    assert_eq!(set.ranges().len(), 20);
+    for patch in 0..40 {
+        assert_eq!(
+            set.matches(&Version::new(1, 0, patch)),
+            patch % 2 == 1,
+            "{patch}"
+        );
+    }
+    assert!(set.matches(&Version::new(1, 0, 40)));
+    assert_eq!(VersionReqSet::parse(&set.to_string()).unwrap(), set);
+    assert_eq!(set.clone(), set);
+}
+
+#[test]
+fn checked_constructor_merges() {
+    let set = VersionReqSet::from([
+        VersionReq::at_least(Version::new(3, 0, 0)),
+        VersionReq::between(Version::new(1, 0, 0), Version::new(2, 0, 0)),
+        VersionReq::between(Version::new(1, 5, 0), Version::new(2, 5, 0)),
+        VersionReq::EMPTY,
+    ]);
+    assert_eq!(set.to_string(), ">=1.0.0, <=2.5.0 || >=3.0.0");
+}
+
+#[test]
+#[should_panic(expected = "overlapping or touching")]
+fn from_array_rejects_touching_ranges() {
+    VersionReqSet::from_array([
+        VersionReq::between(Version::new(1, 0, 0), Version::new(1, 0, 5)),
+        VersionReq::at_least(Version::new(1, 0, 6)),
+    ]);
+}
+
+#[test]
+#[should_panic(expected = "overlapping or touching")]
+fn from_array_rejects_unsorted_ranges() {
+    VersionReqSet::from_array([
+        VersionReq::exact(Version::new(2, 0, 0)),
+        VersionReq::exact(Version::new(1, 0, 0)),
+    ]);
+}
+
+#[test]
+#[should_panic(expected = "empty range")]
+fn from_array_rejects_empty_ranges() {
+    VersionReqSet::from_array([VersionReq::EMPTY]);
+}
+
+#[test]
+#[should_panic(expected = "too many ranges")]
+fn from_array_rejects_large_arrays() {
+    VersionReqSet::from_array([0; 9].map(|major| VersionReq::exact(Version::new(major, 0, 0))));
+}
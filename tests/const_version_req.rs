@@ -0,0 +1,6 @@
+#[test]
+fn const_version_req() {
+    let cases = trybuild::TestCases::new();
+    cases.pass("tests/ui/const_version_req_pass.rs");
+    cases.compile_fail("tests/ui/const_version_req_fail.rs");
+}
@@ -0,0 +1,124 @@
+use fast_version::{
+    Version, VersionReq, VersionReqVariant, VersionReqVariantLowerBound,
+    VersionReqVariantUpperBound,
+};
+
+const MAX: u64 = u64::MAX;
+
+fn samples() -> Vec<Version> {
+    let values = [0, 1, 2, MAX - 1, MAX];
+    let mut versions = Vec::new();
+    for major in values {
+        for minor in values {
+            for patch in values {
+                versions.push(Version::new(major, minor, patch));
+            }
+        }
+    }
+    versions
+}
+
+#[test]
+fn identical_to_variants() {
+    for version in samples() {
+        let Version {
+            major,
+            minor,
+            patch,
+        } = version;
+        assert_eq!(
+            VersionReq::at_least(version),
+            VersionReq::new(&VersionReqVariant::PatchGreaterEqual {
+                major,
+                minor,
+                patch
+            })
+        );
+        assert_eq!(
+            VersionReq::at_most(version),
+            VersionReq::new(&VersionReqVariant::PatchLessEqual {
+                major,
+                minor,
+                patch
+            })
+        );
+        assert_eq!(
+            VersionReq::greater_than(version),
+            VersionReq::new(&VersionReqVariant::PatchGreater {
+                major,
+                minor,
+                patch
+            })
+        );
+        assert_eq!(
+            VersionReq::less_than(version),
+            VersionReq::new(&VersionReqVariant::PatchLess {
+                major,
+                minor,
+                patch
+            })
+        );
+    }
+}
+
+#[test]
+fn between_identical_to_compound() {
+    let samples = samples();
+    for lower in &samples {
+        for upper in &samples {
+            let compound = VersionReqVariant::Compound(
+                VersionReqVariantLowerBound::PatchGreaterEqual {
+                    major: lower.major,
+                    minor: lower.minor,
+                    patch: lower.patch,
+                },
+                VersionReqVariantUpperBound::PatchLessEqual {
+                    major: upper.major,
+                    minor: upper.minor,
+                    patch: upper.patch,
+                },
+            );
+            assert_eq!(
+                VersionReq::between(*lower, *upper),
+                VersionReq::new(&compound)
+            );
+        }
+    }
+}
+
+#[test]
+fn comparator_strings() {
+    let version = Version::new(1, 2, 3);
+    assert_eq!(VersionReq::at_least(version).to_string(), ">=1.2.3");
+    assert_eq!(VersionReq::at_most(version).to_string(), "<=1.2.3");
+    assert_eq!(VersionReq::greater_than(version).to_string(), ">=1.2.4");
+    assert_eq!(VersionReq::less_than(version).to_string(), "<=1.2.2");
+    for (req, input) in [
+        (VersionReq::at_least(version), ">=1.2.3"),
+        (VersionReq::at_most(version), "<=1.2.3"),
+        (VersionReq::greater_than(version), ">1.2.3"),
+        (VersionReq::less_than(version), "<1.2.3"),
+    ] {
+        assert_eq!(req, VersionReq::parse(input).unwrap());
+    }
+}
+
+#[test]
+fn edges() {
+    let zero = Version::new(0, 0, 0);
+    let max = Version::new(MAX, MAX, MAX);
+    assert!(VersionReq::less_than(zero).is_empty());
+    assert!(VersionReq::greater_than(max).is_empty());
+    assert_eq!(VersionReq::at_least(zero), VersionReq::STAR);
+    assert_eq!(VersionReq::at_most(max), VersionReq::STAR);
+    assert_eq!(VersionReq::between(zero, max), VersionReq::STAR);
+    assert!(VersionReq::between(max, zero).is_empty());
+}
+
+#[test]
+fn const_context() {
+    const RANGE: VersionReq = VersionReq::between(Version::new(1, 0, 0), Version::new(1, 4, 0));
+    const NEWER: VersionReq = VersionReq::greater_than(Version::new(1, 4, 0));
+    assert!(RANGE.matches(&Version::new(1, 4, 0)));
+    assert!(!NEWER.matches(&Version::new(1, 4, 0)));
+}
@@ -0,0 +1,84 @@
+use fast_version::{
+    Version, VersionReq, VersionReqVariant, VersionReqVariantLowerBound,
+    VersionReqVariantUpperBound,
+};
+
+#[test]
+fn version_debug() {
+    let version = Version::new(1, 2, 3);
+    assert_eq!(format!("{:?}", version), "Version(1.2.3)");
+    assert_eq!(
+        format!("{:?}", vec![version, Version::new(0, 0, 1)]),
+        "[Version(1.2.3), Version(0.0.1)]"
+    );
+    assert_eq!(
+        format!("{:#?}", version),
+        "Version {\n    major: 1,\n    minor: 2,\n    patch: 3,\n}"
+    );
+}
+
+#[test]
+fn version_req_debug() {
+    let req = VersionReq::new(&VersionReqVariant::Compound(
+        VersionReqVariantLowerBound::MinorGreaterEqual { major: 1, minor: 2 },
+        VersionReqVariantUpperBound::MajorLess { major: 2 },
+    ));
+    assert_eq!(format!("{:?}", req), "VersionReq(\">=1.2.0, <2.0.0\")");
+    assert_eq!(format!("{:?}", VersionReq::STAR), "VersionReq(\"*\")");
+    assert_eq!(
+        format!("{:#?}", req),
+        "VersionReq {
+    lower: Version {
+        major: 1,
+        minor: 2,
+        patch: 0,
+    },
+    upper: Version {
+        major: 1,
+        minor: 18446744073709551615,
+        patch: 18446744073709551615,
+    },
+}"
+    );
+}
+
+#[test]
+fn version_req_display() {
+    let display = |variant: VersionReqVariant| VersionReq::new(&variant).to_string();
+    assert_eq!(
+        display(VersionReqVariant::Strict(Version::new(1, 2, 3))),
+        "=1.2.3"
+    );
+    assert_eq!(
+        display(VersionReqVariant::MajorGreater { major: 1 }),
+        ">=2.0.0"
+    );
+    assert_eq!(
+        display(VersionReqVariant::PatchLessEqual {
+            major: 1,
+            minor: 2,
+            patch: 3
+        }),
+        "<=1.2.3"
+    );
+    assert_eq!(
+        display(VersionReqVariant::MinorLessEqual { major: 1, minor: 2 }),
+        "<1.3.0"
+    );
+    assert_eq!(
+        display(VersionReqVariant::Compound(
+            VersionReqVariantLowerBound::PatchGreater {
+                major: 1,
+                minor: 2,
+                patch: 3
+            },
+            VersionReqVariantUpperBound::PatchLessEqual {
+                major: 1,
+                minor: 9,
+                patch: 0
+            },
+        )),
+        ">=1.2.4, <=1.9.0"
+    );
+    assert_eq!(VersionReq::EMPTY.to_string(), "<0.0.0");
+}
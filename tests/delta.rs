@@ -0,0 +1,121 @@
+mod common;
+
+use common::Rng;
+use fast_version::{Version, VersionDelta};
+
+const MAX: u64 = u64::MAX;
+
+#[test]
+fn delta_components() {
+    let delta = Version::delta(Version::new(1, 4, 2), Version::new(2, 0, 7));
+    assert_eq!(delta, VersionDelta::new(1, -4, 5));
+    assert_eq!(delta.to_string(), "+1.-4.+5");
+    let same = Version::delta(Version::new(3, 2, 1), Version::new(3, 2, 1));
+    assert_eq!(same, VersionDelta::ZERO);
+    assert!(same.is_zero());
+    assert_eq!(same.to_string(), "+0.+0.+0");
+}
+
+#[test]
+fn delta_const() {
+    const DELTA: VersionDelta = Version::delta(Version::new(1, 0, 0), Version::new(1, 1, 0));
+    const NEXT: Option<Version> = DELTA.apply(Version::new(2, 5, 9));
+    assert_eq!(NEXT, Some(Version::new(2, 6, 9)));
+}
+
+#[test]
+fn delta_extremes() {
+    let up = Version::delta(Version::new(0, 0, 0), Version::new(MAX, MAX, MAX));
+    assert_eq!(up.major, MAX as i128);
+    let down = up.invert();
+    assert_eq!(down.patch, -(MAX as i128));
+    assert_eq!(
+        down.to_string(),
+        "-18446744073709551615.-18446744073709551615.-18446744073709551615"
+    );
+    assert_eq!(
+        down.apply(Version::new(MAX, MAX, MAX)),
+        Some(Version::new(0, 0, 0))
+    );
+}
+
+impl Rng {
+    fn component(&mut self) -> u64 {
+        match self.next() % 4 {
+            0 => MAX - self.next() % 3,
+            1 => self.next(),
+            _ => self.next() % 5,
+        }
+    }
+
+    fn version(&mut self) -> Version {
+        Version::new(self.component(), self.component(), self.component())
+    }
+}
+
+#[test]
+fn apply_invert_round_trip() {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    for _ in 0..10_000 {
+        let (from, to) = (rng.version(), rng.version());
+        let delta = Version::delta(from, to);
+        assert_eq!(delta.apply(from), Some(to), "{from} {to}");
+        assert_eq!(delta.invert(), Version::delta(to, from));
+        assert_eq!(delta.invert().apply(to), Some(from), "{from} {to}");
+        assert_eq!(delta.invert().invert(), delta);
+    }
+}
+
+#[test]
+fn apply_rejects_overflow() {
+    let bump = VersionDelta::new(0, 0, 1);
+    assert_eq!(bump.apply(Version::new(1, 2, MAX)), None);
+    assert_eq!(
+        bump.apply(Version::new(1, 2, MAX - 1)),
+        Some(Version::new(1, 2, MAX))
+    );
+    let beyond = VersionDelta::new(MAX as i128 + 1, 0, 0);
+    assert_eq!(beyond.apply(Version::new(0, 0, 0)), None);
+    assert_eq!(
+        VersionDelta::new(i128::MAX, 0, 0).apply(Version::new(1, 0, 0)),
+        None
+    );
+}
+
+#[test]
+fn apply_rejects_underflow() {
+    let rollback = VersionDelta::new(-1, 0, 0);
+    assert_eq!(rollback.apply(Version::new(0, 9, 9)), None);
+    assert_eq!(
+        rollback.apply(Version::new(1, 9, 9)),
+        Some(Version::new(0, 9, 9))
+    );
+    assert_eq!(
+        VersionDelta::new(0, i128::MIN, 0).apply(Version::new(1, MAX, 0)),
+        None
+    );
+    assert_eq!(
+        VersionDelta::new(0, i128::MIN, 0).invert(),
+        VersionDelta::new(0, i128::MAX, 0)
+    );
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use super::*;
+
+    #[test]
+    fn json_round_trip() {
+        let delta = VersionDelta::new(1, -2, 0);
+        let json = serde_json::to_string(&delta).unwrap();
+        assert_eq!(json, r#"{"major":1,"minor":-2,"patch":0}"#);
+        assert_eq!(serde_json::from_str::<VersionDelta>(&json).unwrap(), delta);
+    }
+
+    #[test]
+    fn postcard_round_trip() {
+        let delta = Version::delta(Version::new(MAX, 0, 3), Version::new(0, MAX, 3));
+        let bytes = postcard::to_allocvec(&delta).unwrap();
+        assert_eq!(postcard::from_bytes::<VersionDelta>(&bytes).unwrap(), delta);
+    }
+}
@@ -0,0 +1,54 @@
+//! Builds the workspace in tests/fixtures/deps_versions, whose committed lockfile pins the
+//! versions `deps_versions!` embeds.
+
+use std::process::{Command, Output};
+
+fn fixture(args: &[&str]) -> Output {
+    Command::new(env!("CARGO"))
+        .args(args)
+        .args(["--quiet", "--manifest-path"])
+        .arg(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/deps_versions/Cargo.toml"
+        ))
+        .env("CARGO_TARGET_DIR", env!("CARGO_TARGET_TMPDIR"))
+        .output()
+        .unwrap()
+}
+
+fn compile_error(feature: &str) -> String {
+    let output = fixture(&["check", "--lib", "--features", feature]);
+    assert!(!output.status.success());
+    String::from_utf8(output.stderr).unwrap()
+}
+
+#[test]
+fn embeds_locked_versions() {
+    let output = fixture(&["test"]);
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn missing_package() {
+    let stderr = compile_error("missing");
+    assert!(
+        stderr.contains("package `not-a-dependency` isn't in"),
+        "{stderr}"
+    );
+    assert!(!stderr.contains("`quote`"), "{stderr}");
+}
+
+#[test]
+fn ambiguous_package() {
+    let stderr = compile_error("ambiguous");
+    assert!(
+        stderr.contains(
+            "package `thiserror` is locked in 2 versions (1.0.69, 2.0.21), use `all \"thiserror\"` to embed all of them"
+        ),
+        "{stderr}"
+    );
+}
@@ -0,0 +1,149 @@
+mod common;
+
+use common::{req, Rng};
+use fast_version::{Version, VersionReq, VersionReqSet};
+
+const MAX: u64 = u64::MAX;
+
+impl Rng {
+    fn component(&mut self) -> u64 {
+        match self.next() % 8 {
+            0 => MAX,
+            1 => MAX - 1,
+            _ => self.next() % 4,
+        }
+    }
+
+    fn version(&mut self) -> Version {
+        Version::new(self.component(), self.component(), self.component())
+    }
+
+    fn req(&mut self) -> VersionReq {
+        match self.next() % 8 {
+            0 => VersionReq::STAR,
+            1 => VersionReq::EMPTY,
+            2 => VersionReq::at_least(self.version()),
+            3 => VersionReq::at_most(self.version()),
+            4 => VersionReq::exact(self.version()),
+            _ => VersionReq::between(self.version(), self.version()),
+        }
+    }
+}
+
+#[test]
+fn remnants_on_both_sides() {
+    let set = req(">=1.0.0, <3.0.0").difference(&req(">=1.5.0, <=2.0.0"));
+    assert_eq!(
+        set.ranges(),
+        [req(">=1.0.0, <1.5.0"), req(">=2.0.1, <3.0.0")]
+    );
+}
+
+#[test]
+fn inclusivity_at_cut_points() {
+    let set = req(">=1.0.0, <=2.0.0").difference(&req(">1.2.3, <1.5.0"));
+    assert!(set.matches(&Version::new(1, 2, 3)));
+    assert!(!set.matches(&Version::new(1, 2, 4)));
+    assert!(!set.matches(&Version::new(1, 4, MAX)));
+    assert!(set.matches(&Version::new(1, 5, 0)));
+    assert!(set.matches(&Version::new(2, 0, 0)));
+    assert!(!set.matches(&Version::new(2, 0, 1)));
+}
+
+#[test]
+fn single_remnant() {
+    let set = req(">=1.0.0, <3.0.0").difference(&req(">=2.0.0"));
+    assert_eq!(set.ranges(), [req(">=1.0.0, <2.0.0")]);
+    let set = req(">=1.0.0, <3.0.0").difference(&req("<2.0.0"));
+    assert_eq!(set.ranges(), [req(">=2.0.0, <3.0.0")]);
+}
+
+#[test]
+fn disjoint_returns_original() {
+    let original = req(">=1.0.0, <2.0.0");
+    assert_eq!(original.difference(&req(">=2.0.0")).ranges(), [original]);
+    assert_eq!(original.difference(&VersionReq::EMPTY).ranges(), [original]);
+}
+
+#[test]
+fn fully_covered() {
+    assert!(req(">=1.2, <1.3").difference(&req("^1")).is_empty());
+    assert!(req("=1.2.3").difference(&req("=1.2.3")).is_empty());
+    assert!(VersionReq::EMPTY.difference(&VersionReq::EMPTY).is_empty());
+    assert!(VersionReq::STAR.difference(&VersionReq::STAR).is_empty());
+}
+
+#[test]
+fn extremes() {
+    let set = VersionReq::STAR.difference(&VersionReq::exact(Version::new(0, 0, 0)));
+    assert_eq!(set.ranges(), [req(">0.0.0")]);
+    let set = VersionReq::STAR.difference(&VersionReq::exact(Version::new(MAX, MAX, MAX)));
+    assert_eq!(
+        set.ranges(),
+        [VersionReq::less_than(Version::new(MAX, MAX, MAX))]
+    );
+}
+
+#[test]
+fn union() {
+    assert_eq!(
+        req(">=1, <2").union(&req(">=2, <3")).ranges(),
+        [req(">=1, <3")]
+    );
+    assert_eq!(
+        req(">=2, <3").union(&req(">=1, <2.5")).ranges(),
+        [req(">=1, <3")]
+    );
+    assert_eq!(
+        req(">=1, <2").union(&req(">=3")).ranges(),
+        [req(">=1, <2"), req(">=3")]
+    );
+    assert_eq!(
+        req(">=3").union(&req(">=1, <2")).ranges(),
+        [req(">=1, <2"), req(">=3")]
+    );
+    assert_eq!(VersionReq::EMPTY.union(&req("<1")).ranges(), [req("<1")]);
+    assert!(VersionReq::EMPTY.union(&VersionReq::EMPTY).is_empty());
+    assert_eq!(VersionReqSet::from(VersionReq::EMPTY), VersionReqSet::new());
+}
+
+#[test]
+fn membership_property() {
+    let mut rng = Rng(0x853c_49e6_748f_ea9b);
+    for _ in 0..2_000 {
+        let (lhs, rhs) = (rng.req(), rng.req());
+        let difference = lhs.difference(&rhs);
+        let union = lhs.union(&rhs);
+        for set in [&difference, &union] {
+            for pair in set.ranges().windows(2) {
+                assert!(
+                    pair[0].maximal() < pair[1].minimal(),
+                    "{} {} {:?}",
+                    lhs,
+                    rhs,
+                    set
+                );
+            }
+        }
+        let mut samples: Vec<Version> = (0..20).map(|_| rng.version()).collect();
+        for bound in [lhs, rhs] {
+            if let (Some(minimal), Some(maximal)) = (bound.minimal(), bound.maximal()) {
+                samples.extend([minimal, maximal]);
+            }
+        }
+        for version in samples {
+            assert_eq!(
+                difference.matches(&version),
+                lhs.matches(&version) && !rhs.matches(&version),
+                "{} \\ {} at {}",
+                lhs,
+                rhs,
+                version
+            );
+            assert_eq!(
+                union.matches(&version),
+                lhs.matches(&version) || rhs.matches(&version)
+            );
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use fast_version::Version;
+
+const SHORT: Version = Version::new(1, 2, 3);
+const LONG: Version = Version::new(u64::MAX, u64::MAX, u64::MAX);
+const LONG_STR: &str = "18446744073709551615.18446744073709551615.18446744073709551615";
+
+#[test]
+fn plain() {
+    assert_eq!(SHORT.to_string(), "1.2.3");
+    assert_eq!(LONG.to_string(), LONG_STR);
+    assert_eq!(format!("{}", SHORT), "1.2.3");
+}
+
+#[test]
+fn width_and_alignment() {
+    assert_eq!(format!("{:>12}", SHORT), "       1.2.3");
+    assert_eq!(format!("{:<12}|", SHORT), "1.2.3       |");
+    assert_eq!(format!("{:^11}", SHORT), "   1.2.3   ");
+    assert_eq!(format!("{:*^10}", SHORT), "**1.2.3***");
+    assert_eq!(format!("{:12}|", SHORT), format!("{:12}|", "1.2.3"));
+    assert_eq!(format!("{:>1$}", SHORT, 8), "   1.2.3");
+
+    // Widths smaller than the version don't truncate.
+    assert_eq!(format!("{:>12}", LONG), LONG_STR);
+    assert_eq!(format!("{:>64}", LONG), format!("  {}", LONG_STR));
+}
+
+#[test]
+fn precision_truncates() {
+    assert_eq!(format!("{:.3}", SHORT), "1.2");
+    assert_eq!(format!("{:.0}", SHORT), "");
+    assert_eq!(format!("{:.10}", SHORT), "1.2.3");
+    assert_eq!(format!("{:>6.3}|", SHORT), "   1.2|");
+    assert_eq!(format!("{:.20}", LONG), &LONG_STR[..20]);
+}
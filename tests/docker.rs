@@ -0,0 +1,79 @@
+use fast_version::docker::{best_version_tag, classify_tag, TagKind};
+use fast_version::{Version, VersionReq, VersionReqVariant};
+
+fn version(tag: &str) -> Option<(Version, Option<&str>)> {
+    match classify_tag(tag) {
+        TagKind::Version { version, suffix } => Some((version, suffix)),
+        TagKind::Named(_) => None,
+    }
+}
+
+#[test]
+fn classify_popular_tags() {
+    assert_eq!(version("1.25.3"), Some((Version::new(1, 25, 3), None)));
+    assert_eq!(version("1.25"), Some((Version::new(1, 25, 0), None)));
+    assert_eq!(version("1"), Some((Version::new(1, 0, 0), None)));
+    assert_eq!(version("v1.2"), Some((Version::new(1, 2, 0), None)));
+    assert_eq!(
+        version("1.25.3-alpine"),
+        Some((Version::new(1, 25, 3), Some("alpine")))
+    );
+    assert_eq!(
+        version("3.12.1-slim-bookworm"),
+        Some((Version::new(3, 12, 1), Some("slim-bookworm")))
+    );
+    assert_eq!(
+        version("7.2.3-alpine3.19"),
+        Some((Version::new(7, 2, 3), Some("alpine3.19")))
+    );
+    assert_eq!(version("22.04"), Some((Version::new(22, 4, 0), None)));
+    assert_eq!(
+        version("20-bullseye"),
+        Some((Version::new(20, 0, 0), Some("bullseye")))
+    );
+
+    for named in [
+        "latest",
+        "stable",
+        "bullseye",
+        "stable-alpine",
+        "lts",
+        "jammy",
+        "1.21rc2",
+        "1.2.3.4",
+        "1.2-",
+        "v",
+        "",
+        "+1.2",
+        "1..2",
+    ] {
+        assert_eq!(classify_tag(named), TagKind::Named(named));
+    }
+}
+
+#[test]
+fn best_tag() {
+    let tags = [
+        "latest",
+        "1.24.0",
+        "1.25.3-alpine",
+        "1.25.3",
+        "1.25",
+        "2.0.0-alpine",
+        "mainline",
+    ];
+    assert_eq!(
+        best_version_tag(&tags, &VersionReq::STAR),
+        Some("2.0.0-alpine")
+    );
+
+    let req = VersionReq::new(&VersionReqVariant::MajorLessEqual { major: 1 });
+    assert_eq!(best_version_tag(&tags, &req), Some("1.25.3"));
+
+    let req = VersionReq::new(&VersionReqVariant::MajorGreaterEqual { major: 3 });
+    assert_eq!(best_version_tag(&tags, &req), None);
+    assert_eq!(
+        best_version_tag(&["latest", "stable"], &VersionReq::STAR),
+        None
+    );
+}
@@ -0,0 +1,75 @@
+use fast_version::{DynVersion, TooManyComponents, Version};
+use std::collections::HashSet;
+use std::str::FromStr;
+
+fn dyn_version(input: &str) -> DynVersion {
+    DynVersion::from_str(input).unwrap()
+}
+
+#[test]
+fn parse_and_display() {
+    let version = dyn_version("2.3.4.5.6");
+    assert_eq!(version.components(), &[2, 3, 4, 5, 6]);
+    assert_eq!(version.to_string(), "2.3.4.5.6");
+
+    let long = dyn_version("1.2.3.4.5.6.7");
+    assert_eq!(long.len(), 7);
+    assert_eq!(long.to_string(), "1.2.3.4.5.6.7");
+
+    assert_eq!(dyn_version("1").components(), &[1]);
+    assert_eq!(dyn_version("1.2.0").to_string(), "1.2.0");
+
+    assert!(DynVersion::from_str("").is_err());
+    assert!(DynVersion::from_str("1..2").is_err());
+    assert!(DynVersion::from_str("1.2.x").is_err());
+}
+
+#[test]
+fn trailing_zeros_are_equal() {
+    assert_eq!(dyn_version("1.2"), dyn_version("1.2.0"));
+    assert_eq!(dyn_version("1.2"), dyn_version("1.2.0.0.0.0.0"));
+    assert_ne!(dyn_version("1.2"), dyn_version("1.2.0.0.0.0.1"));
+
+    let set: HashSet<DynVersion> = ["1.2", "1.2.0", "1.2.0.0.0.0.0", "1.2.1"]
+        .into_iter()
+        .map(dyn_version)
+        .collect();
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn mixed_length_ordering() {
+    assert!(dyn_version("1.2") < dyn_version("1.2.0.1"));
+    assert!(dyn_version("1.3") > dyn_version("1.2.9.9.9.9"));
+    assert!(dyn_version("2") > dyn_version("1.99.99"));
+    assert!(dyn_version("1.2.3.4.5.6") < dyn_version("1.2.3.4.5.7"));
+    assert!(dyn_version("1.2.3.4.5.6.0") < dyn_version("1.2.3.4.5.6.0.1"));
+
+    let mut versions: Vec<DynVersion> = ["1.10", "1.2.3.4", "1.2", "1.2.3", "0.9.9.9.9.9"]
+        .into_iter()
+        .map(dyn_version)
+        .collect();
+    versions.sort();
+    let sorted: Vec<String> = versions.iter().map(ToString::to_string).collect();
+    assert_eq!(sorted, ["0.9.9.9.9.9", "1.2", "1.2.3", "1.2.3.4", "1.10"]);
+}
+
+#[test]
+fn conversions() {
+    assert_eq!(
+        Version::try_from(dyn_version("1.2")).unwrap(),
+        Version::new(1, 2, 0)
+    );
+    assert_eq!(
+        Version::try_from(dyn_version("1.2.3")).unwrap(),
+        Version::new(1, 2, 3)
+    );
+    assert!(matches!(
+        Version::try_from(dyn_version("1.2.3.0")),
+        Err(TooManyComponents { found: 4 })
+    ));
+    assert_eq!(
+        DynVersion::from(Version::new(1, 2, 3)),
+        dyn_version("1.2.3")
+    );
+}
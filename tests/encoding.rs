@@ -0,0 +1,214 @@
+mod common;
+
+use common::req;
+use fast_version::encoding::{DecodeError, FORMAT_FIXED, FORMAT_PRERELEASE, FORMAT_VARINT};
+use fast_version::{Version, VersionReq};
+
+const MAX: u64 = u64::MAX;
+
+fn encoded(req: &VersionReq) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    req.encode(&mut bytes);
+    bytes
+}
+
+/// `^1.2` as six little endian u64: 1.2.0 to 1.MAX.MAX.
+const CARET_FIXED: [u8; 48] = [
+    1, 0, 0, 0, 0, 0, 0, 0, //
+    2, 0, 0, 0, 0, 0, 0, 0, //
+    0, 0, 0, 0, 0, 0, 0, 0, //
+    1, 0, 0, 0, 0, 0, 0, 0, //
+    255, 255, 255, 255, 255, 255, 255, 255, //
+    255, 255, 255, 255, 255, 255, 255, 255, //
+];
+
+#[test]
+fn golden_format_1() {
+    let mut bytes = vec![FORMAT_FIXED];
+    bytes.extend_from_slice(&CARET_FIXED);
+    assert_eq!(VersionReq::decode(&bytes), Ok((req("^1.2"), 49)));
+    assert_eq!(
+        VersionReq::decode_legacy(&CARET_FIXED),
+        Ok((req("^1.2"), 48))
+    );
+}
+
+#[test]
+fn golden_format_2() {
+    let golden: &[(&str, &[u8])] = &[
+        ("=1.2.3", &[2, 1, 2, 3, 1, 2, 3]),
+        (
+            "*",
+            &[
+                2, 0, 0, 0, 255, 255, 255, 255, 255, 255, 255, 255, 255, 1, 255, 255, 255, 255,
+                255, 255, 255, 255, 255, 1, 255, 255, 255, 255, 255, 255, 255, 255, 255, 1,
+            ],
+        ),
+        ("=300.0.128", &[2, 172, 2, 0, 128, 1, 172, 2, 0, 128, 1]),
+        (
+            "<0.0.0",
+            &[
+                2, 255, 255, 255, 255, 255, 255, 255, 255, 255, 1, 255, 255, 255, 255, 255, 255,
+                255, 255, 255, 1, 255, 255, 255, 255, 255, 255, 255, 255, 255, 1, 0, 0, 0,
+            ],
+        ),
+    ];
+    for (text, bytes) in golden {
+        let req = req(text);
+        assert_eq!(encoded(&req), *bytes, "{text}");
+        assert_eq!(VersionReq::decode(bytes), Ok((req, bytes.len())), "{text}");
+    }
+}
+
+#[test]
+fn golden_format_3() {
+    // No release matches, so the bounds on releases are the ones of the empty requirement.
+    let text = "=1.0.0-rc.1";
+    #[rustfmt::skip]
+    let bytes: &[u8] = &[
+        3, 255, 255, 255, 255, 255, 255, 255, 255, 255, 1, 255, 255, 255, 255, 255, 255, 255,
+        255, 255, 1, 255, 255, 255, 255, 255, 255, 255, 255, 255, 1, 0, 0, 0,
+        2, 1, 0, 0, 4, b'r', b'c', b'.', b'1',
+        2, 1, 0, 0, 4, b'r', b'c', b'.', b'1',
+    ];
+    let req = req(text);
+    assert_eq!(encoded(&req), bytes, "{text}");
+    assert_eq!(VersionReq::decode(bytes), Ok((req, bytes.len())), "{text}");
+}
+
+#[test]
+fn invalid_prerelease() {
+    // The lower bound `2, 1, 0, 0, 5, b"alpha"` is followed by the missing upper one, `0`.
+    let mut bytes = encoded(&req(">=1.0.0-alpha"));
+    let tag = bytes.len() - 11;
+    assert_eq!(bytes[tag], 2);
+    bytes[tag] = 3;
+    assert_eq!(
+        VersionReq::decode(&bytes),
+        Err(DecodeError::InvalidPrerelease { position: tag })
+    );
+    bytes[tag] = 2;
+    let last = bytes.len() - 2;
+    bytes[last] = b'!';
+    assert_eq!(
+        VersionReq::decode(&bytes),
+        Err(DecodeError::InvalidPrerelease { position: tag })
+    );
+}
+
+#[test]
+fn legacy_empty_is_canonical() {
+    // Any lower bound above the upper one is the empty requirement.
+    let mut bytes = [0; 48];
+    bytes[0] = 2;
+    bytes[24] = 1;
+    assert_eq!(
+        VersionReq::decode_legacy(&bytes),
+        Ok((VersionReq::EMPTY, 48))
+    );
+}
+
+#[test]
+fn round_trip() {
+    for text in [
+        "*",
+        "<0.0.0",
+        "=0.0.0",
+        "^1.2",
+        "~0.3.1",
+        ">=1.2.0, <2.0.7",
+        "<=18446744073709551615.0.0",
+        ">18446744073709551615.18446744073709551614.18446744073709551615",
+    ] {
+        let req = req(text);
+        let bytes = encoded(&req);
+        assert_eq!(bytes[0], FORMAT_VARINT);
+        assert_eq!(VersionReq::decode(&bytes), Ok((req, bytes.len())), "{text}");
+    }
+    for text in [
+        ">=1.0.0-alpha",
+        ">=1.2, <1.2.0-beta.2",
+        "<=0.0.0-0",
+        ">1.2.3, <1.2.4-rc",
+        "^0.0.3-x-y-z",
+    ] {
+        let req = req(text);
+        let bytes = encoded(&req);
+        assert_eq!(bytes[0], FORMAT_PRERELEASE);
+        assert_eq!(VersionReq::decode(&bytes), Ok((req, bytes.len())), "{text}");
+    }
+}
+
+#[test]
+fn concatenated() {
+    let reqs = [
+        req("^1"),
+        VersionReq::STAR,
+        req("=3.2.1"),
+        VersionReq::EMPTY,
+    ];
+    let mut bytes = Vec::new();
+    for req in &reqs {
+        req.encode(&mut bytes);
+    }
+    let mut rest = &bytes[..];
+    for req in &reqs {
+        let (decoded, len) = VersionReq::decode(rest).unwrap();
+        assert_eq!(decoded, *req);
+        rest = &rest[len..];
+    }
+    assert!(rest.is_empty());
+}
+
+#[test]
+fn truncated() {
+    let bytes = encoded(&VersionReq::exact(Version::new(MAX, 1, 2)));
+    for len in 0..bytes.len() {
+        assert_eq!(
+            VersionReq::decode(&bytes[..len]),
+            Err(DecodeError::UnexpectedEnd),
+            "{len}"
+        );
+    }
+    assert_eq!(
+        VersionReq::decode(&[FORMAT_FIXED, 0, 0, 0]),
+        Err(DecodeError::UnexpectedEnd)
+    );
+    assert_eq!(
+        VersionReq::decode_legacy(&CARET_FIXED[..47]),
+        Err(DecodeError::UnexpectedEnd)
+    );
+}
+
+#[test]
+fn unknown_format() {
+    let mut bytes = encoded(&req("^1"));
+    bytes[0] = 0;
+    assert_eq!(
+        VersionReq::decode(&bytes),
+        Err(DecodeError::UnknownFormat(0))
+    );
+    assert_eq!(
+        DecodeError::UnknownFormat(7).to_string(),
+        "Unknown format 7"
+    );
+}
+
+#[test]
+fn varint_overflow() {
+    // u64::MAX + 1 as varint, as the lower minor.
+    let mut bytes = vec![FORMAT_VARINT, 0];
+    bytes.extend_from_slice(&[128, 128, 128, 128, 128, 128, 128, 128, 128, 2]);
+    assert_eq!(
+        VersionReq::decode(&bytes),
+        Err(DecodeError::VarintOverflow { position: 2 })
+    );
+    // Eleven bytes.
+    let mut bytes = vec![FORMAT_VARINT];
+    bytes.extend_from_slice(&[255; 10]);
+    bytes.push(0);
+    assert_eq!(
+        VersionReq::decode(&bytes),
+        Err(DecodeError::VarintOverflow { position: 1 })
+    );
+}
@@ -0,0 +1,90 @@
+use fast_version::{EpochVersion, Version, VersionReq, VersionReqVariant};
+use std::str::FromStr;
+
+#[test]
+fn epoch_dominates_ordering() {
+    let old_epoch = EpochVersion::from_str("1:9.9.9").unwrap();
+    let new_epoch = EpochVersion::from_str("2:0.0.1").unwrap();
+    let no_epoch = EpochVersion::from_str("10.0.0").unwrap();
+    assert!(old_epoch < new_epoch);
+    assert!(no_epoch < old_epoch);
+
+    let mut versions = vec![new_epoch, no_epoch, old_epoch];
+    versions.sort();
+    assert_eq!(versions, vec![no_epoch, old_epoch, new_epoch]);
+}
+
+#[test]
+fn zero_epoch_round_trip() {
+    let implicit = EpochVersion::from_str("1.4.0").unwrap();
+    let explicit = EpochVersion::from_str("0:1.4.0").unwrap();
+    assert_eq!(implicit, explicit);
+    assert_eq!(implicit, EpochVersion::from(Version::new(1, 4, 0)));
+    assert_eq!(implicit.to_string(), "1.4.0");
+    assert_eq!(explicit.to_string(), "1.4.0");
+
+    let with_epoch = EpochVersion::from_str("2:1.4.0").unwrap();
+    assert_eq!(with_epoch.to_string(), "2:1.4.0");
+    assert_eq!(
+        EpochVersion::from_str(&with_epoch.to_string()).unwrap(),
+        with_epoch
+    );
+}
+
+#[test]
+fn parse_errors() {
+    assert!(EpochVersion::from_str("x:1.4.0").is_err());
+    assert!(EpochVersion::from_str("-1:1.4.0").is_err());
+    assert!(EpochVersion::from_str("1:1.4").is_err());
+    assert!(EpochVersion::from_str("1:2:1.4.0").is_err());
+}
+
+#[test]
+fn matches_requires_same_epoch() {
+    let req = VersionReq::new(&VersionReqVariant::Strict(Version::new(1, 4, 0)));
+    let zero = EpochVersion::from_str("1.4.0").unwrap();
+    let two = EpochVersion::from_str("2:1.4.0").unwrap();
+    assert!(zero.matches(&req));
+    assert!(!two.matches(&req));
+    assert!(two.matches_in_epoch(2, &req));
+    assert!(!zero.matches_in_epoch(2, &req));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() {
+    let version = EpochVersion::new(2, Version::new(1, 4, 0));
+    let json = serde_json::to_string(&version).unwrap();
+    assert_eq!(json, r#""2:1.4.0""#);
+    assert_eq!(
+        serde_json::from_str::<EpochVersion>(&json).unwrap(),
+        version
+    );
+    // The struct form written before the string form.
+    let fields = r#"{"epoch":2,"version":{"major":1,"minor":4,"patch":0}}"#;
+    assert_eq!(
+        serde_json::from_str::<EpochVersion>(fields).unwrap(),
+        version
+    );
+    assert_eq!(
+        serde_json::from_str::<EpochVersion>(r#"{"epoch":2,"version":"1.4.0"}"#).unwrap(),
+        version
+    );
+    assert_eq!(
+        serde_json::from_str::<EpochVersion>(r#"[2,[1,4,0]]"#).unwrap(),
+        version
+    );
+    assert_eq!(
+        serde_json::from_str::<EpochVersion>(r#""x:1.4.0""#)
+            .unwrap_err()
+            .to_string(),
+        "invalid version `x:1.4.0`: Epoch Parse Error at line 1 column 9"
+    );
+
+    let bytes = postcard::to_allocvec(&version).unwrap();
+    assert_eq!(bytes, [2, 1, 4, 0]);
+    assert_eq!(
+        postcard::from_bytes::<EpochVersion>(&bytes).unwrap(),
+        version
+    );
+}
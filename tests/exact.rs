@@ -0,0 +1,50 @@
+use fast_version::{Version, VersionReq, VersionReqVariant};
+
+#[test]
+fn round_trip() {
+    let values = [0, 1, 7, u64::MAX];
+    for major in values {
+        for minor in values {
+            for patch in values {
+                let version = Version::new(major, minor, patch);
+                let req = VersionReq::exact(version);
+                assert_eq!(req.as_exact(), Some(version));
+                assert_eq!(req, VersionReq::new(&VersionReqVariant::Strict(version)));
+            }
+        }
+    }
+}
+
+#[test]
+fn matches_only_the_version() {
+    let version = Version::new(1, 2, 3);
+    let req = VersionReq::exact(version);
+    assert!(req.matches(&version));
+    for other in [
+        Version::new(1, 2, 2),
+        Version::new(1, 2, 4),
+        Version::new(1, 3, 3),
+        Version::new(0, 2, 3),
+        Version::new(2, 0, 0),
+    ] {
+        assert!(!req.matches(&other), "{}", other);
+    }
+    assert_eq!(req.to_string(), "=1.2.3");
+}
+
+#[test]
+fn not_exact() {
+    assert_eq!(VersionReq::STAR.as_exact(), None);
+    assert_eq!(VersionReq::EMPTY.as_exact(), None);
+    let req = VersionReq::parse(">=1.2.3, <=1.2.4").unwrap();
+    assert_eq!(req.as_exact(), None);
+    let req = VersionReq::parse(">=1.2.3, <1.2.4").unwrap();
+    assert_eq!(req.as_exact(), Some(Version::new(1, 2, 3)));
+}
+
+#[test]
+fn const_context() {
+    const REQ: VersionReq = VersionReq::exact(Version::new(0, 1, 0));
+    const PINNED: Option<Version> = REQ.as_exact();
+    assert_eq!(PINNED, Some(Version::new(0, 1, 0)));
+}
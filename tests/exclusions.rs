@@ -0,0 +1,142 @@
+mod common;
+
+use common::req;
+use fast_version::{Version, VersionReq, VersionReqParseError, VersionReqSet};
+
+fn set(input: &str) -> VersionReqSet {
+    VersionReqSet::parse(input).unwrap()
+}
+
+#[test]
+fn exclusions_make_holes() {
+    let set = set(">=1.0, <2.0, !=1.5.0, !=1.6.2");
+    assert_eq!(
+        set.ranges(),
+        [
+            req(">=1.0.0, <=1.4.18446744073709551615"),
+            req(">=1.5.1, <=1.6.1"),
+            req(">=1.6.3, <2.0.0"),
+        ]
+    );
+    assert!(set.matches(&Version::new(1, 0, 0)));
+    assert!(!set.matches(&Version::new(1, 5, 0)));
+    assert!(set.matches(&Version::new(1, 5, 1)));
+    assert!(!set.matches(&Version::new(1, 6, 2)));
+    assert!(set.matches(&Version::new(1, 9, 9)));
+    assert!(!set.matches(&Version::new(2, 0, 0)));
+}
+
+#[test]
+fn partial_exclusion_removes_the_whole_line() {
+    let set = set(">=1, <2, !=1.5");
+    assert!(set.matches(&Version::new(1, 4, u64::MAX)));
+    assert!(!set.matches(&Version::new(1, 5, 0)));
+    assert!(!set.matches(&Version::new(1, 5, 99)));
+    assert!(set.matches(&Version::new(1, 6, 0)));
+}
+
+#[test]
+fn exclusions_at_endpoints() {
+    let set = set(">=1.0.0, <=1.2.0, !=1.0.0, !=1.2.0");
+    assert_eq!(set.ranges(), [req(">=1.0.1, <=1.1.18446744073709551615")]);
+    assert!(!set.matches(&Version::new(1, 0, 0)));
+    assert!(set.matches(&Version::new(1, 0, 1)));
+    assert!(set.matches(&Version::new(1, 1, 7)));
+    assert!(!set.matches(&Version::new(1, 2, 0)));
+}
+
+#[test]
+fn exclusions_outside_the_range() {
+    let plain = set(">=1.0, <2.0");
+    assert_eq!(set(">=1.0, <2.0, !=0.9.0, !=2.0.0, !=3"), plain);
+    assert_eq!(plain.ranges(), [req(">=1.0, <2.0")]);
+}
+
+#[test]
+fn duplicate_exclusions() {
+    assert_eq!(
+        set(">=1, <2, !=1.5.0, !=1.5.0, != 1.5.0"),
+        set(">=1, <2, !=1.5.0")
+    );
+}
+
+#[test]
+fn everything_excluded() {
+    let set = set("=1.2.3, !=1.2.3");
+    assert!(set.is_empty());
+    assert!(!set.matches(&Version::new(1, 2, 3)));
+
+    assert!(VersionReqSet::parse(">=1.2.0, <1.3.0, !=1.2")
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn without_exclusions() {
+    assert_eq!(set("*").ranges(), [VersionReq::STAR]);
+    assert_eq!(set("^1.2.3").ranges(), [req("^1.2.3")]);
+    assert_eq!(set("!=1.0.0").ranges().len(), 2);
+    // More than two comparators are fine as long as they overlap.
+    assert_eq!(set(">=1, >=1.2, <3, <2").ranges(), [req(">=1.2, <2")]);
+}
+
+#[test]
+fn reports_failing_clause() {
+    let err = VersionReqSet::parse(">=1.0, <2.0, !=1.x.0").unwrap_err();
+    assert_eq!(err.clause, 2);
+    assert!(matches!(
+        err.source,
+        VersionReqParseError::MalformedNumber { position: 15, .. }
+    ));
+
+    let err = VersionReqSet::parse("!=>1, <2").unwrap_err();
+    assert_eq!(err.clause, 0);
+    assert!(matches!(
+        err.source,
+        VersionReqParseError::UnknownOperator { position: 0 }
+    ));
+
+    let err = VersionReqSet::parse(">=1.0, ,<2").unwrap_err();
+    assert_eq!(err.clause, 1);
+
+    let err = VersionReqSet::parse(">=2, !=2.1, <1").unwrap_err();
+    assert_eq!(err.clause, 2);
+    assert!(matches!(
+        err.source,
+        VersionReqParseError::ConflictingBounds { position: 12 }
+    ));
+}
+
+#[test]
+fn prereleases_are_rejected() {
+    // Both parse as a single requirement, which can match prereleases.
+    assert!(VersionReq::parse(">=1.0.0-alpha, <1.0.0").is_ok());
+    assert!(VersionReq::parse("=1.0.0-beta").is_ok());
+
+    let err = VersionReqSet::parse(">=1.0.0-alpha, <1.0.0").unwrap_err();
+    assert_eq!(err.clause, 0);
+    assert!(matches!(
+        err.source,
+        VersionReqParseError::PrereleaseInSet { position: 7 }
+    ));
+    assert_eq!(
+        err.source.to_string(),
+        "Prerelease at position 7, version requirement sets only hold releases"
+    );
+
+    let err = VersionReqSet::parse(">=1 || =1.0.0-beta").unwrap_err();
+    assert_eq!(err.clause, 1);
+    assert!(matches!(
+        err.source,
+        VersionReqParseError::PrereleaseInSet { position: 13 }
+    ));
+
+    let err = VersionReqSet::parse(">=1, !=1.2.0-rc.1").unwrap_err();
+    assert!(matches!(
+        err.source,
+        VersionReqParseError::PrereleaseInSet { position: 12 }
+    ));
+
+    // Build metadata is ignored like in single requirements.
+    assert_eq!(set("=1.2.3+build.5"), set("=1.2.3"));
+}
@@ -0,0 +1,117 @@
+mod common;
+
+use common::req;
+use fast_version::{MatchFailure, Version, VersionDelta, VersionReq};
+
+#[test]
+fn below() {
+    let failure = req(">=2.0, <3.0")
+        .explain(&Version::new(1, 5, 0))
+        .unwrap_err();
+    assert_eq!(
+        failure,
+        MatchFailure::BelowLower {
+            bound: Version::new(2, 0, 0),
+            by: VersionDelta::new(1, -5, 0),
+        }
+    );
+    assert_eq!(
+        failure.to_string(),
+        "The version is too old, upgrade to at least 2.0.0"
+    );
+
+    let failure = req(">1.2.3").explain(&Version::new(1, 2, 3)).unwrap_err();
+    assert_eq!(
+        failure,
+        MatchFailure::BelowLower {
+            bound: Version::new(1, 2, 4),
+            by: VersionDelta::new(0, 0, 1),
+        }
+    );
+}
+
+#[test]
+fn above() {
+    let failure = req(">=2.0, <3.0")
+        .explain(&Version::new(3, 1, 0))
+        .unwrap_err();
+    assert_eq!(
+        failure,
+        MatchFailure::AboveUpper {
+            bound: Version::new(2, u64::MAX, u64::MAX),
+            by: VersionDelta::new(-1, u64::MAX as i128 - 1, u64::MAX as i128),
+        }
+    );
+    assert_eq!(
+        failure.to_string(),
+        "The version is too new, downgrade to a version below 3.0.0"
+    );
+
+    let failure = req("<=1.4.2").explain(&Version::new(1, 5, 0)).unwrap_err();
+    assert_eq!(
+        failure,
+        MatchFailure::AboveUpper {
+            bound: Version::new(1, 4, 2),
+            by: VersionDelta::new(0, -1, 2),
+        }
+    );
+    assert_eq!(
+        failure.to_string(),
+        "The version is too new, downgrade to at most 1.4.2"
+    );
+}
+
+#[test]
+fn inside() {
+    let req = req(">=2.0, <3.0");
+    for version in [
+        Version::new(2, 0, 0),
+        Version::new(2, 4, 1),
+        Version::new(2, u64::MAX, u64::MAX),
+    ] {
+        assert_eq!(req.explain(&version), Ok(()));
+    }
+    assert_eq!(VersionReq::STAR.explain(&Version::new(0, 0, 0)), Ok(()));
+}
+
+#[test]
+fn empty_requirement() {
+    let failure = VersionReq::EMPTY
+        .explain(&Version::new(1, 0, 0))
+        .unwrap_err();
+    assert_eq!(failure, MatchFailure::EmptyRequirement);
+    assert_eq!(
+        failure.to_string(),
+        "The requirement doesn't allow any version"
+    );
+}
+
+#[test]
+fn agrees_with_matches() {
+    let reqs = [
+        req("*"),
+        req("^1.2"),
+        req("~0.3.1"),
+        req(">1.0.0, <=1.0.5"),
+        req("=2.3.4"),
+        VersionReq::EMPTY,
+    ];
+    for req in reqs {
+        for major in 0..4 {
+            for minor in 0..4 {
+                for patch in 0..6 {
+                    let version = Version::new(major, minor, patch);
+                    assert_eq!(req.explain(&version).is_ok(), req.matches(&version));
+                }
+            }
+        }
+    }
+}
+
+const EXPLAINED: Result<(), MatchFailure> =
+    VersionReq::major_line(1).explain(&Version::new(0, 9, 0));
+
+#[test]
+fn is_const() {
+    assert!(matches!(EXPLAINED, Err(MatchFailure::BelowLower { .. })));
+}
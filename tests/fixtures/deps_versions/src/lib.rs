@@ -0,0 +1,13 @@
+use fast_version::{deps_versions, Version};
+
+pub static DEPS: &[(&str, Version)] = deps_versions!("fast-version-core", "quote");
+
+pub static THISERROR: &[(&str, Version)] = deps_versions!(all "thiserror");
+
+pub static MIXED: &[(&str, Version)] = deps_versions!("litrs", all "thiserror", "quote",);
+
+#[cfg(feature = "missing")]
+pub static MISSING: &[(&str, Version)] = deps_versions!("quote", "not-a-dependency");
+
+#[cfg(feature = "ambiguous")]
+pub static AMBIGUOUS: &[(&str, Version)] = deps_versions!("thiserror");
@@ -0,0 +1,43 @@
+use deps_versions_fixture::{DEPS, MIXED, THISERROR};
+use fast_version::Version;
+
+#[test]
+fn named() {
+    assert_eq!(
+        DEPS,
+        [
+            ("fast-version-core", Version::new(0, 2, 3)),
+            ("quote", Version::new(1, 0, 47)),
+        ]
+    );
+}
+
+#[test]
+fn all_versions() {
+    assert_eq!(
+        THISERROR,
+        [
+            ("thiserror", Version::new(1, 0, 69)),
+            ("thiserror", Version::new(2, 0, 21)),
+        ]
+    );
+}
+
+#[test]
+fn mixed() {
+    assert_eq!(
+        MIXED,
+        [
+            ("litrs", Version::new(0, 2, 3)),
+            ("thiserror", Version::new(1, 0, 69)),
+            ("thiserror", Version::new(2, 0, 21)),
+            ("quote", Version::new(1, 0, 47)),
+        ]
+    );
+}
+
+#[test]
+fn in_function() {
+    let deps = fast_version::deps_versions!("quote");
+    assert_eq!(deps.len(), 1);
+}
@@ -0,0 +1,249 @@
+use fast_version::{
+    BuildMetadata, Channel, FullVersion, FullVersionParseError, IdentifierError, Prerelease,
+    Version, VersionParseError, VersionReq,
+};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+fn full(input: &str) -> FullVersion {
+    FullVersion::from_str(input).unwrap()
+}
+
+fn hash(version: &FullVersion) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    version.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn parse_and_display() {
+    let version = full("1.2.3-alpha.1+build.5");
+    assert_eq!(version.version, Version::new(1, 2, 3));
+    assert_eq!(version.pre, Prerelease::new("alpha.1").unwrap());
+    assert_eq!(version.build, BuildMetadata::new("build.5").unwrap());
+    assert!(version.is_prerelease());
+
+    for input in [
+        "1.2.3",
+        "1.2.3-alpha",
+        "1.2.3+build.5",
+        "1.2.3-rc.1+build.5",
+        "1.2.3-x-y-z.--",
+        "1.2.3+001",
+        "1.2.3----RC-SNAPSHOT.12.9.1--.12+788",
+    ] {
+        assert_eq!(full(input).to_string(), input);
+    }
+    assert_eq!(full("1.2.3"), FullVersion::from(Version::new(1, 2, 3)));
+}
+
+#[test]
+fn invalid() {
+    let cases = [
+        (
+            "1.2",
+            FullVersionParseError::Version(VersionParseError::TooFewComponents { found: 2 }),
+        ),
+        (
+            "1.2.3-",
+            FullVersionParseError::Prerelease(IdentifierError::EmptyIdentifier { position: 0 }),
+        ),
+        (
+            "1.2.3+",
+            FullVersionParseError::BuildMetadata(IdentifierError::EmptyIdentifier { position: 0 }),
+        ),
+        (
+            "1.2.3-alpha..1",
+            FullVersionParseError::Prerelease(IdentifierError::EmptyIdentifier { position: 6 }),
+        ),
+        (
+            "1.2.3-alpha_1",
+            FullVersionParseError::Prerelease(IdentifierError::InvalidCharacter { position: 5 }),
+        ),
+        (
+            "1.2.3-alpha.01",
+            FullVersionParseError::Prerelease(IdentifierError::LeadingZero { position: 6 }),
+        ),
+        (
+            "1.2.3+build+5",
+            FullVersionParseError::BuildMetadata(IdentifierError::InvalidCharacter { position: 5 }),
+        ),
+        (
+            "1.2.3-0123456789abcdef0123456789abcdefg",
+            FullVersionParseError::Prerelease(IdentifierError::TooLong { len: 33 }),
+        ),
+    ];
+    for (input, expected) in cases {
+        let error = FullVersion::from_str(input).unwrap_err();
+        assert_eq!(error.to_string(), expected.to_string(), "{input}");
+    }
+    assert_eq!(
+        FullVersion::from_str("1.2.3-alpha.01")
+            .unwrap_err()
+            .to_string(),
+        "Invalid prerelease: Numeric identifier at position 6 has a leading zero"
+    );
+}
+
+#[test]
+fn capacity() {
+    let longest = "a".repeat(Prerelease::CAPACITY);
+    assert_eq!(Prerelease::new(&longest).unwrap().as_str(), longest);
+    assert_eq!(BuildMetadata::new(&longest).unwrap().as_str(), longest);
+    assert!(Prerelease::new(&format!("{longest}a")).is_err());
+}
+
+#[test]
+fn precedence_order() {
+    let ordered = [
+        "1.0.0-alpha",
+        "1.0.0-alpha.1",
+        "1.0.0-alpha.beta",
+        "1.0.0-beta",
+        "1.0.0-beta.2",
+        "1.0.0-beta.11",
+        "1.0.0-rc.1",
+        "1.0.0",
+        "1.2.3-alpha",
+        "1.2.3-alpha.1",
+        "1.2.3-beta",
+        "1.2.3",
+        "2.0.0-0",
+        "2.0.0-0.a",
+        "2.0.0-1",
+        "2.0.0-a",
+        "2.0.0",
+    ];
+    for pair in ordered.windows(2) {
+        assert!(full(pair[0]) < full(pair[1]), "{} < {}", pair[0], pair[1]);
+    }
+    let mut shuffled: Vec<FullVersion> = ordered.iter().rev().map(|input| full(input)).collect();
+    shuffled.sort();
+    let sorted: Vec<String> = shuffled.iter().map(FullVersion::to_string).collect();
+    assert_eq!(sorted, ordered);
+}
+
+#[test]
+fn build_metadata() {
+    let first = full("1.0.0+build.1");
+    let second = full("1.0.0+build.2");
+    assert_ne!(first, second);
+    assert!(first < second);
+    assert!(first.eq_precedence(&second));
+    assert_eq!(first.strip_metadata(), second.strip_metadata());
+    assert!(full("1.0.0") < first);
+    assert!(first < full("1.0.1"));
+    assert!(full("1.0.0+1") < full("1.0.0+01"));
+    assert!(full("1.0.0-rc.1+build.9") < full("1.0.0"));
+}
+
+#[test]
+fn hash_agrees_with_eq() {
+    assert_eq!(hash(&full("1.2.3-rc.1+5")), hash(&full("1.2.3-rc.1+5")));
+    assert_ne!(hash(&full("1.2.3-rc.1")), hash(&full("1.2.3-rc.2")));
+    assert_ne!(hash(&full("1.2.3+5")), hash(&full("1.2.3")));
+}
+
+#[test]
+fn matching() {
+    let req = VersionReq::parse("^1.2").unwrap();
+    assert!(req.matches_full(&full("1.4.0")));
+    assert!(req.matches_full(&full("1.4.0+build.5")));
+    assert!(!req.matches_full(&full("1.4.0-rc.1")));
+    assert!(!req.matches_full(&full("2.0.0")));
+    assert!(!VersionReq::STAR.matches_full(&full("0.1.0-alpha")));
+
+    let req = VersionReq::parse(">=1.2.0-rc.1, <2").unwrap();
+    assert!(req.matches_full(&full("1.2.0-rc.1")));
+    assert!(req.matches_full(&full("1.2.0-rc.2+build.5")));
+    assert!(!req.matches_full(&full("1.2.0-beta")));
+    assert!(!req.matches_full(&full("1.3.0-rc.1")));
+    assert!(req.matches_full(&full("1.3.0")));
+    assert!(!req.matches_full(&full("2.0.0")));
+    assert!(!req.matches(&Version::new(1, 1, 0)));
+}
+
+#[test]
+fn version_rejects_prerelease() {
+    for (input, position) in [
+        ("1.2.3-alpha.1", 5),
+        ("1.2.3+build.5", 5),
+        ("10.0.0-rc-1", 6),
+    ] {
+        match Version::from_str(input) {
+            Err(VersionParseError::Prerelease { position: found }) => {
+                assert_eq!(found, position, "{input}")
+            }
+            other => panic!("{input}: {other:?}"),
+        }
+        assert_eq!(
+            full(input).version,
+            Version::from_str(&input[..position]).unwrap()
+        );
+    }
+    // Not a version followed by identifiers.
+    assert!(matches!(
+        Version::from_str("1.2-3"),
+        Err(VersionParseError::TooFewComponents { found: 2 })
+    ));
+    assert!(matches!(
+        Version::from_str("1.2.3-é"),
+        Err(VersionParseError::PatchParseError)
+    ));
+}
+
+#[test]
+fn channel() {
+    assert_eq!(full("1.0.0").channel(), Channel::Stable);
+    assert_eq!(full("1.0.0+build").channel(), Channel::Stable);
+    assert_eq!(full("1.0.0-rc.1").channel(), Channel::Rc);
+    assert_eq!(full("1.0.0-beta.2").channel(), Channel::Beta);
+    assert_eq!(full("1.0.0-SNAPSHOT").channel(), Channel::Other);
+}
+
+#[test]
+fn tags() {
+    use fast_version::tags::{parse_full_tag, parse_tag, TagParseError};
+    assert_eq!(parse_full_tag("v1.2.3-rc.1").unwrap(), full("1.2.3-rc.1"));
+    assert_eq!(
+        parse_full_tag("refs/tags/release-1.2.3+build.5").unwrap(),
+        full("1.2.3+build.5")
+    );
+    assert_eq!(parse_full_tag("v1.2.3").unwrap(), full("1.2.3"));
+    assert!(matches!(
+        parse_full_tag("v1.2.3-rc..1"),
+        Err(TagParseError::Identifiers(
+            IdentifierError::EmptyIdentifier { position: 3 }
+        ))
+    ));
+    assert!(matches!(
+        parse_tag("v1.2.3-rc.1"),
+        Err(TagParseError::Prerelease)
+    ));
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use super::*;
+
+    #[test]
+    fn string_form() {
+        let version = full("1.2.3-alpha.1+build.5");
+        let json = serde_json::to_string(&version).unwrap();
+        assert_eq!(json, r#""1.2.3-alpha.1+build.5""#);
+        assert_eq!(serde_json::from_str::<FullVersion>(&json).unwrap(), version);
+
+        let bytes = postcard::to_allocvec(&version).unwrap();
+        assert_eq!(
+            postcard::from_bytes::<FullVersion>(&bytes).unwrap(),
+            version
+        );
+
+        let error = serde_json::from_str::<FullVersion>(r#""1.2.3-""#).unwrap_err();
+        assert!(
+            error.to_string().starts_with("Invalid prerelease"),
+            "{error}"
+        );
+    }
+}
@@ -0,0 +1,133 @@
+mod common;
+
+use common::Rng;
+use fast_version::{InvalidBounds, Version, VersionReq};
+
+const MAX: u64 = u64::MAX;
+
+#[test]
+fn range() {
+    let lower = Version::new(1, 2, 0);
+    let upper = Version::new(1, 4, 7);
+    let req = VersionReq::from_inclusive_range(lower, upper).unwrap();
+    assert_eq!(req.to_string(), ">=1.2.0, <=1.4.7");
+    assert!(req.matches(&lower));
+    assert!(req.matches(&upper));
+    assert!(req.matches(&Version::new(1, 3, 99)));
+    assert!(!req.matches(&Version::new(1, 4, 8)));
+    assert!(!req.matches(&Version::new(1, 1, MAX)));
+    assert_eq!(req.minimal(), Some(lower));
+    assert_eq!(req.maximal(), Some(upper));
+}
+
+#[test]
+fn equal_endpoints() {
+    let version = Version::new(1, 2, 3);
+    let req = VersionReq::from_inclusive_range(version, version).unwrap();
+    assert_eq!(req, VersionReq::exact(version));
+    assert_eq!(req.to_string(), "=1.2.3");
+}
+
+#[test]
+fn inverted() {
+    let lower = Version::new(2, 0, 0);
+    let upper = Version::new(1, 9, 9);
+    let error = VersionReq::from_inclusive_range(lower, upper).unwrap_err();
+    assert_eq!(error, InvalidBounds { lower, upper });
+    assert_eq!(
+        error.to_string(),
+        "The lower bound 2.0.0 is larger than the upper bound 1.9.9"
+    );
+    assert_eq!(
+        VersionReq::from_inclusive_range_unchecked(lower, upper),
+        VersionReq::EMPTY
+    );
+}
+
+#[test]
+fn full_range() {
+    let req = VersionReq::from_inclusive_range(Version::new(0, 0, 0), Version::new(MAX, MAX, MAX));
+    assert_eq!(req, Ok(VersionReq::STAR));
+}
+
+#[test]
+fn const_context() {
+    const REQ: VersionReq =
+        VersionReq::from_inclusive_range_unchecked(Version::new(1, 0, 0), Version::new(2, 0, 0));
+    const CHECKED: Result<VersionReq, InvalidBounds> =
+        VersionReq::from_inclusive_range(Version::new(1, 0, 0), Version::new(2, 0, 0));
+    assert_eq!(CHECKED, Ok(REQ));
+}
+
+#[test]
+fn range_round_trip() {
+    let lower = Version::new(1, 2, 0);
+    let upper = Version::new(1, 4, 7);
+    let req = VersionReq::from_inclusive_range(lower, upper).unwrap();
+    assert_eq!(req.range(), Some(lower..=upper));
+    let range = VersionReq::STAR.range().unwrap();
+    assert_eq!(range, Version::new(0, 0, 0)..=Version::new(MAX, MAX, MAX));
+    assert_eq!(VersionReq::EMPTY.range(), None);
+    let disjoint = VersionReq::STABLE.intersection(&VersionReq::PRE_STABLE);
+    assert_eq!(disjoint.range(), None);
+}
+
+#[test]
+fn range_crosses_components() {
+    // Checking every component against its bound separately would reject 1.9.0.
+    let req = VersionReq::parse(">=1.2.5, <2.0.3").unwrap();
+    let range = req.range().unwrap();
+    for version in [
+        Version::new(1, 9, 0),
+        Version::new(1, 2, 5),
+        Version::new(2, 0, 2),
+    ] {
+        assert!(range.contains(&version));
+        assert!(req.matches(&version));
+    }
+    for version in [Version::new(1, 2, 4), Version::new(2, 0, 3)] {
+        assert!(!range.contains(&version));
+        assert!(!req.matches(&version));
+    }
+}
+
+impl Rng {
+    fn component(&mut self) -> u64 {
+        match self.next() % 8 {
+            0 => MAX,
+            1 => MAX - 1,
+            _ => self.next() % 4,
+        }
+    }
+
+    fn version(&mut self) -> Version {
+        Version::new(self.component(), self.component(), self.component())
+    }
+
+    fn req(&mut self) -> VersionReq {
+        let (lower, upper) = (self.version(), self.version());
+        match self.next() % 4 {
+            0 => VersionReq::at_least(lower),
+            1 => VersionReq::exact(lower),
+            _ => VersionReq::from_inclusive_range(lower, upper).unwrap_or(VersionReq::EMPTY),
+        }
+    }
+}
+
+#[test]
+fn matches_agrees_with_range() {
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+    for _ in 0..2_000 {
+        let req = rng.req();
+        let range = req.range();
+        assert_eq!(range.is_none(), req.is_empty());
+        for _ in 0..50 {
+            let version = rng.version();
+            assert_eq!(
+                req.matches(&version),
+                range.as_ref().is_some_and(|range| range.contains(&version)),
+                "{req} {version}"
+            );
+        }
+    }
+}
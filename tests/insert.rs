@@ -0,0 +1,140 @@
+mod common;
+
+use common::{req, Rng};
+use fast_version::{Version, VersionReq, VersionReqSet};
+
+const MAX: u64 = u64::MAX;
+
+impl Rng {
+    fn component(&mut self) -> u64 {
+        match self.next() % 10 {
+            0 => MAX,
+            _ => self.next() % 3,
+        }
+    }
+
+    fn version(&mut self) -> Version {
+        Version::new(self.component(), self.component(), self.component())
+    }
+
+    fn range(&mut self) -> VersionReq {
+        match self.next() % 8 {
+            0 => VersionReq::exact(self.version()),
+            1 => VersionReq::EMPTY,
+            _ => VersionReq::between(self.version(), self.version()),
+        }
+    }
+}
+
+fn assert_canonical(set: &VersionReqSet) {
+    for pair in set.ranges().windows(2) {
+        let [below, above] = pair else { unreachable!() };
+        // Sorted, and something lies between neighbours.
+        assert!(below.maximal() < above.minimal(), "{set}");
+        assert_eq!(
+            below.to_req().union(&above.to_req()).ranges().len(),
+            2,
+            "{set}"
+        );
+    }
+}
+
+#[test]
+fn merges_overlapping_and_touching() {
+    let mut set = VersionReqSet::new();
+    set.insert(req(">=2, <3"));
+    set.insert(req(">=5, <6"));
+    set.insert(req("=4.0.0"));
+    assert_eq!(set.ranges().len(), 3);
+
+    // Touches 3.0.0 exactly.
+    set.insert(req(">=3.0.0, <4.0.0"));
+    assert_eq!(set.to_string(), ">=2.0.0, <=4.0.0 || >=5.0.0, <6.0.0");
+
+    // Bridges the gap.
+    set.insert(req(">=4.0.1, <5.0.0"));
+    assert_eq!(set.ranges(), [req(">=2, <6")]);
+
+    // Contained ranges change nothing.
+    set.insert(req("^2.5"));
+    assert_eq!(set.ranges(), [req(">=2, <6")]);
+
+    set.insert(req("<1"));
+    set.insert(VersionReq::EMPTY);
+    assert_eq!(set.to_string(), "<1.0.0 || >=2.0.0, <6.0.0");
+
+    set.insert(req(">=1.0.0, <2.0.0"));
+    assert_eq!(set.ranges(), [req("<6")]);
+}
+
+#[test]
+fn merges_at_the_largest_version() {
+    let mut set = VersionReqSet::new();
+    set.insert(VersionReq::exact(Version::new(MAX, MAX, MAX)));
+    set.insert(VersionReq::between(
+        Version::new(1, 0, 0),
+        Version::new(MAX, MAX, MAX - 1),
+    ));
+    assert_eq!(set.ranges(), [req(">=1")]);
+    set.insert(VersionReq::STAR);
+    assert_eq!(set.ranges(), [VersionReq::STAR]);
+}
+
+#[test]
+fn spills_and_shrinks() {
+    let mut set = VersionReqSet::new();
+    for minor in (0..40).step_by(2) {
+        set.insert(VersionReq::exact(Version::new(1, minor, 0)));
+    }
+    assert_eq!(set.ranges().len(), 20);
+    set.shrink_to_canonical();
+    assert_eq!(set.ranges().len(), 20);
+
+    // Touches the exact 1.30.0.
+    set.insert(req(">=1.0.0, <1.30.0"));
+    assert_eq!(set.ranges().len(), 5);
+    let before = set.clone();
+    set.shrink_to_canonical();
+    assert_eq!(set, before);
+    set.insert(req("=1.31.0"));
+    assert_eq!(set.ranges().len(), 6);
+    assert_canonical(&set);
+}
+
+#[test]
+fn bulk_construction() {
+    let ranges = [req(">=3"), req("^1"), req(">=1.5, <2.5"), VersionReq::EMPTY];
+    let set: VersionReqSet = ranges.into_iter().collect();
+    assert_eq!(set.to_string(), ">=1.0.0, <2.5.0 || >=3.0.0");
+
+    let mut extended = VersionReqSet::from(req("<0.5"));
+    extended.extend(ranges);
+    assert_eq!(extended.to_string(), "<0.5.0 || >=1.0.0, <2.5.0 || >=3.0.0");
+}
+
+#[test]
+fn agrees_with_a_naive_list() {
+    let mut rng = Rng(0x0dd_ba11);
+    for _ in 0..500 {
+        let mut set = VersionReqSet::new();
+        let mut naive = Vec::new();
+        for _ in 0..(rng.next() % 24) {
+            let range = rng.range();
+            set.insert(range);
+            naive.push(range);
+            assert_canonical(&set);
+        }
+        assert_eq!(set, naive.iter().copied().collect::<VersionReqSet>());
+        for _ in 0..64 {
+            let version = rng.version();
+            assert_eq!(
+                set.matches(&version),
+                naive.iter().any(|range| range.matches(&version)),
+                "{set} {version}"
+            );
+        }
+        let before = set.clone();
+        set.shrink_to_canonical();
+        assert_eq!(set, before);
+    }
+}
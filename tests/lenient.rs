@@ -0,0 +1,101 @@
+use fast_version::{LenientInfo, LenientVersion, Version, VersionParseError};
+
+fn lenient(input: &str) -> (Version, bool, Option<&str>, u8) {
+    let LenientVersion {
+        version,
+        info:
+            LenientInfo {
+                had_prefix,
+                trailing,
+                assumed_components,
+            },
+    } = LenientVersion::parse(input).unwrap();
+    (version, had_prefix, trailing, assumed_components)
+}
+
+#[test]
+fn common_shapes() {
+    assert_eq!(lenient("1.2.3"), (Version::new(1, 2, 3), false, None, 0));
+    assert_eq!(lenient("1.2"), (Version::new(1, 2, 0), false, None, 1));
+    assert_eq!(lenient("7"), (Version::new(7, 0, 0), false, None, 2));
+    assert_eq!(lenient("v1.2.3"), (Version::new(1, 2, 3), true, None, 0));
+    assert_eq!(
+        lenient("1.2.3 (build 456)"),
+        (Version::new(1, 2, 3), false, Some("(build 456)"), 0)
+    );
+    assert_eq!(
+        lenient("1.2.3-ubuntu2"),
+        (Version::new(1, 2, 3), false, Some("-ubuntu2"), 0)
+    );
+    assert_eq!(
+        lenient("1.2.3.4"),
+        (Version::new(1, 2, 3), false, Some(".4"), 0)
+    );
+    assert_eq!(
+        lenient("  1.2.  "),
+        (Version::new(1, 2, 0), false, Some("."), 1)
+    );
+}
+
+#[test]
+fn version_outputs() {
+    let corpus = [
+        ("git version 2.39.2", Version::new(2, 39, 2), None),
+        (
+            "rustc 1.75.0 (82e1608df 2023-12-21)",
+            Version::new(1, 75, 0),
+            Some("(82e1608df 2023-12-21)"),
+        ),
+        ("Python 3.11.4", Version::new(3, 11, 4), None),
+        ("python3 3.11.4", Version::new(3, 11, 4), None),
+        ("node v20.10.0", Version::new(20, 10, 0), None),
+        ("curl/8.4.0", Version::new(8, 4, 0), None),
+        (
+            "Mozilla/5.0 (X11; Linux x86_64; rv:120.0) Gecko/20100101 Firefox/120.0",
+            Version::new(5, 0, 0),
+            Some("(X11; Linux x86_64; rv:120.0) Gecko/20100101 Firefox/120.0"),
+        ),
+        (
+            "OpenSSH_9.3p1 Ubuntu-1ubuntu3",
+            Version::new(9, 3, 0),
+            Some("p1 Ubuntu-1ubuntu3"),
+        ),
+        (
+            "docker version 24.0.7, build afdd53b",
+            Version::new(24, 0, 7),
+            Some(", build afdd53b"),
+        ),
+    ];
+    for (input, version, trailing) in corpus {
+        let parsed = LenientVersion::parse(input).unwrap();
+        assert_eq!(parsed.version, version, "{}", input);
+        assert!(parsed.info.had_prefix, "{}", input);
+        assert_eq!(parsed.info.trailing, trailing, "{}", input);
+    }
+}
+
+#[test]
+fn no_version() {
+    assert!(matches!(
+        LenientVersion::parse("unknown"),
+        Err(VersionParseError::MajorNotFound)
+    ));
+    assert!(matches!(
+        LenientVersion::parse("python3"),
+        Err(VersionParseError::MajorNotFound)
+    ));
+    assert!(matches!(
+        LenientVersion::parse(""),
+        Err(VersionParseError::MajorNotFound)
+    ));
+    assert!(matches!(
+        LenientVersion::parse("1.99999999999999999999"),
+        Err(VersionParseError::ComponentOverflow { .. })
+    ));
+}
+
+#[test]
+fn into_version() {
+    let version: Version = LenientVersion::parse("v2.1").unwrap().into();
+    assert_eq!(version, Version::new(2, 1, 0));
+}
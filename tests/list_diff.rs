@@ -0,0 +1,161 @@
+use fast_version::{diff_lists, BumpKind, ListChange, ListDiff, Version};
+
+fn list(entries: &[(&'static str, (u64, u64, u64))]) -> Vec<(&'static str, Version)> {
+    entries
+        .iter()
+        .map(|&(name, (major, minor, patch))| (name, Version::new(major, minor, patch)))
+        .collect()
+}
+
+#[test]
+fn overlapping_names() {
+    let old = list(&[
+        ("tokio", (1, 37, 0)),
+        ("serde", (1, 0, 200)),
+        ("clap", (3, 2, 25)),
+        ("log", (0, 4, 21)),
+        ("rand", (0, 8, 5)),
+        ("anyhow", (1, 0, 86)),
+    ]);
+    let new = list(&[
+        ("anyhow", (1, 0, 86)),
+        ("rand", (0, 9, 0)),
+        ("log", (0, 4, 20)),
+        ("clap", (4, 5, 4)),
+        ("serde", (1, 0, 201)),
+        ("tokio", (1, 36, 9)),
+        ("csv", (1, 3, 0)),
+    ]);
+    let diff = diff_lists(&old, &new);
+    assert_eq!(
+        diff,
+        ListDiff {
+            entries: vec![
+                ("anyhow", ListChange::Unchanged(Version::new(1, 0, 86))),
+                (
+                    "clap",
+                    ListChange::Upgraded {
+                        from: Version::new(3, 2, 25),
+                        to: Version::new(4, 5, 4),
+                        kind: BumpKind::Major,
+                    }
+                ),
+                ("csv", ListChange::Added(Version::new(1, 3, 0))),
+                (
+                    "log",
+                    ListChange::Downgraded {
+                        from: Version::new(0, 4, 21),
+                        to: Version::new(0, 4, 20),
+                        kind: BumpKind::Patch,
+                    }
+                ),
+                (
+                    "rand",
+                    ListChange::Upgraded {
+                        from: Version::new(0, 8, 5),
+                        to: Version::new(0, 9, 0),
+                        kind: BumpKind::Minor,
+                    }
+                ),
+                (
+                    "serde",
+                    ListChange::Upgraded {
+                        from: Version::new(1, 0, 200),
+                        to: Version::new(1, 0, 201),
+                        kind: BumpKind::Patch,
+                    }
+                ),
+                (
+                    "tokio",
+                    ListChange::Downgraded {
+                        from: Version::new(1, 37, 0),
+                        to: Version::new(1, 36, 9),
+                        kind: BumpKind::Minor,
+                    }
+                ),
+            ]
+        }
+    );
+    assert_eq!(diff.changed().count(), 6);
+    assert!(!diff.is_unchanged());
+}
+
+#[test]
+fn disjoint_names() {
+    let old = list(&[("b", (1, 0, 0)), ("a", (2, 0, 0))]);
+    let new = list(&[("d", (0, 1, 0)), ("c", (0, 0, 1))]);
+    let diff = diff_lists(&old, &new);
+    assert_eq!(
+        diff.entries,
+        [
+            ("a", ListChange::Removed(Version::new(2, 0, 0))),
+            ("b", ListChange::Removed(Version::new(1, 0, 0))),
+            ("c", ListChange::Added(Version::new(0, 0, 1))),
+            ("d", ListChange::Added(Version::new(0, 1, 0))),
+        ]
+    );
+    assert_eq!(diff, diff_lists(&old, &new));
+}
+
+#[test]
+fn empty_and_unchanged() {
+    let empty: ListDiff<String> = diff_lists(&[], &[]);
+    assert!(empty.entries.is_empty());
+    assert!(empty.is_unchanged());
+
+    let same = list(&[("a", (1, 2, 3)), ("b", (4, 5, 6))]);
+    let diff = diff_lists(&same, &same);
+    assert!(diff.is_unchanged());
+    assert_eq!(diff.entries.len(), 2);
+
+    let added = diff_lists(&[], &same);
+    assert_eq!(added.changed().count(), 2);
+}
+
+#[test]
+fn owned_names_and_duplicates() {
+    let old = vec![
+        (String::from("serde"), Version::new(1, 0, 0)),
+        (String::from("serde"), Version::new(1, 0, 5)),
+    ];
+    let new = vec![(String::from("serde"), Version::new(1, 0, 5))];
+    let diff = diff_lists(&old, &new);
+    assert_eq!(
+        diff.entries,
+        [(
+            String::from("serde"),
+            ListChange::Unchanged(Version::new(1, 0, 5))
+        )]
+    );
+}
+
+#[test]
+fn display() {
+    let changes = [
+        (ListChange::Added(Version::new(1, 0, 0)), "added 1.0.0"),
+        (ListChange::Removed(Version::new(0, 1, 0)), "removed 0.1.0"),
+        (
+            ListChange::Upgraded {
+                from: Version::new(1, 2, 3),
+                to: Version::new(1, 3, 0),
+                kind: BumpKind::Minor,
+            },
+            "upgraded 1.2.3 -> 1.3.0 (minor)",
+        ),
+        (
+            ListChange::Downgraded {
+                from: Version::new(2, 0, 0),
+                to: Version::new(1, 9, 9),
+                kind: BumpKind::Major,
+            },
+            "downgraded 2.0.0 -> 1.9.9 (major)",
+        ),
+        (
+            ListChange::Unchanged(Version::new(3, 1, 4)),
+            "unchanged 3.1.4",
+        ),
+    ];
+    for (change, expected) in changes {
+        assert_eq!(change.to_string(), expected);
+    }
+}
@@ -0,0 +1,116 @@
+use fast_version::docker::best_version_tag;
+use fast_version::matcher::{count_matching, filter, matching_indices, max_matching, min_matching};
+use fast_version::{Version, VersionMatcher, VersionPattern, VersionReq, VersionReqSet};
+use std::str::FromStr;
+
+const VERSIONS: [Version; 6] = [
+    Version::new(1, 4, 0),
+    Version::new(0, 9, 3),
+    Version::new(2, 0, 0),
+    Version::new(1, 2, 7),
+    Version::new(3, 1, 0),
+    Version::new(1, 2, 0),
+];
+
+/// Everything the generic helpers report for one matcher.
+fn summary<M: VersionMatcher + ?Sized>(matcher: &M) -> (Option<Version>, Option<Version>, usize) {
+    (
+        max_matching(&VERSIONS, matcher).copied(),
+        min_matching(&VERSIONS, matcher).copied(),
+        count_matching(&VERSIONS, matcher),
+    )
+}
+
+#[test]
+fn version_req() {
+    let req = VersionReq::parse("^1.2").unwrap();
+    assert_eq!(
+        summary(&req),
+        (Some(Version::new(1, 4, 0)), Some(Version::new(1, 2, 0)), 3)
+    );
+    assert_eq!(
+        matching_indices(&VERSIONS, &req),
+        req.matching_indices(&VERSIONS)
+    );
+}
+
+#[test]
+fn version_req_set() {
+    let set = VersionReqSet::parse("<1 || >=3").unwrap();
+    assert_eq!(
+        summary(&set),
+        (Some(Version::new(3, 1, 0)), Some(Version::new(0, 9, 3)), 2)
+    );
+    assert_eq!(matching_indices(&VERSIONS, &set), [1, 4]);
+}
+
+#[test]
+fn version_pattern() {
+    let pattern = VersionPattern::from_str("1.2.x").unwrap();
+    assert_eq!(
+        summary(&pattern),
+        (Some(Version::new(1, 2, 7)), Some(Version::new(1, 2, 0)), 2)
+    );
+    let middle = VersionPattern::from_str("x.x.0").unwrap();
+    assert_eq!(matching_indices(&VERSIONS, &middle), [0, 2, 4, 5]);
+}
+
+#[test]
+fn closure() {
+    let odd_patch = |version: &Version| version.patch % 2 == 1;
+    assert_eq!(
+        summary(&odd_patch),
+        (Some(Version::new(1, 2, 7)), Some(Version::new(0, 9, 3)), 2)
+    );
+    assert_eq!(summary(&|_: &Version| false), (None, None, 0));
+}
+
+#[test]
+fn trait_objects() {
+    let matchers: [&dyn VersionMatcher; 3] = [
+        &VersionReq::STAR,
+        &VersionReqSet::new(),
+        &|version: &Version| version.major == 2,
+    ];
+    let counts: Vec<usize> = matchers
+        .iter()
+        .map(|matcher| count_matching(&VERSIONS, *matcher))
+        .collect();
+    assert_eq!(counts, [6, 0, 1]);
+}
+
+#[test]
+fn filter_keeps_order() {
+    let req = VersionReq::parse(">=1.2").unwrap();
+    let matching: Vec<Version> = filter(&VERSIONS, &req).copied().collect();
+    assert_eq!(
+        matching,
+        [
+            Version::new(1, 4, 0),
+            Version::new(2, 0, 0),
+            Version::new(1, 2, 7),
+            Version::new(3, 1, 0),
+            Version::new(1, 2, 0),
+        ]
+    );
+}
+
+#[test]
+fn inherent_methods_still_apply() {
+    let req = VersionReq::parse("=1.2.0").unwrap();
+    assert!(req.matches(&Version::new(1, 2, 0)));
+    assert!(VersionMatcher::matches(&req, &Version::new(1, 2, 0)));
+    assert_eq!(req.count_matching(&VERSIONS), 1);
+}
+
+#[test]
+fn docker_tags() {
+    let tags = ["latest", "1.24.0", "1.25.3-alpine", "2.0.0"];
+    let pattern = VersionPattern::from_str("1.x.x").unwrap();
+    assert_eq!(best_version_tag(&tags, &pattern), Some("1.25.3-alpine"));
+    assert_eq!(
+        best_version_tag(&tags, &|version: &Version| version.minor == 24),
+        Some("1.24.0")
+    );
+    assert_eq!(best_version_tag(&tags, &VersionReq::STAR), Some("2.0.0"));
+}
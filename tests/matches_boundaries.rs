@@ -0,0 +1,242 @@
+//! Every variant with versions right next to its bound in each component. Bounds are compared
+//! like `Ord for Version`, not component by component.
+
+use fast_version::{
+    Version, VersionReq, VersionReqVariant, VersionReqVariantLowerBound,
+    VersionReqVariantUpperBound,
+};
+
+const MAX: u64 = u64::MAX;
+
+fn v(major: u64, minor: u64, patch: u64) -> Version {
+    Version::new(major, minor, patch)
+}
+
+fn successor(version: Version) -> Version {
+    match version {
+        Version {
+            minor: MAX,
+            patch: MAX,
+            ..
+        } => v(version.major + 1, 0, 0),
+        Version { patch: MAX, .. } => v(version.major, version.minor + 1, 0),
+        _ => v(version.major, version.minor, version.patch + 1),
+    }
+}
+
+fn predecessor(version: Version) -> Version {
+    match version {
+        Version {
+            minor: 0, patch: 0, ..
+        } => v(version.major - 1, MAX, MAX),
+        Version { patch: 0, .. } => v(version.major, version.minor - 1, MAX),
+        _ => v(version.major, version.minor, version.patch - 1),
+    }
+}
+
+#[track_caller]
+fn check(variant: VersionReqVariant, matching: &[Version], not_matching: &[Version]) {
+    let req = VersionReq::new(&variant);
+    for version in matching {
+        assert!(req.matches(version), "{variant:?} should match {version}");
+    }
+    for version in not_matching {
+        assert!(
+            !req.matches(version),
+            "{variant:?} shouldn't match {version}"
+        );
+    }
+}
+
+#[test]
+fn compound_from_issue() {
+    let variant = VersionReqVariant::Compound(
+        VersionReqVariantLowerBound::PatchGreaterEqual {
+            major: 1,
+            minor: 2,
+            patch: 3,
+        },
+        VersionReqVariantUpperBound::MinorLess { major: 2, minor: 0 },
+    );
+    check(
+        variant,
+        &[
+            v(1, 2, 3),
+            v(1, 3, 0),
+            v(1, 2, MAX),
+            v(1, MAX, 0),
+            v(1, MAX, MAX),
+        ],
+        &[v(1, 1, 9), v(1, 2, 2), v(0, 9, 9), v(2, 0, 0), v(2, 0, 1)],
+    );
+}
+
+#[test]
+fn strict() {
+    check(
+        VersionReqVariant::Strict(v(1, 2, 3)),
+        &[v(1, 2, 3)],
+        &[
+            v(1, 2, 2),
+            v(1, 2, 4),
+            v(1, 1, 3),
+            v(1, 3, 3),
+            v(0, 2, 3),
+            v(2, 2, 3),
+        ],
+    );
+}
+
+#[test]
+fn greater() {
+    check(
+        VersionReqVariant::MajorGreater { major: 1 },
+        &[v(2, 0, 0), v(3, 0, 0)],
+        &[v(1, MAX, MAX), v(1, 0, 0), v(0, 9, 9)],
+    );
+    check(
+        VersionReqVariant::MinorGreater { major: 1, minor: 2 },
+        &[v(1, 3, 0), v(2, 0, 0), v(2, 1, 0)],
+        &[v(1, 2, MAX), v(1, 2, 0), v(0, 9, 9)],
+    );
+    check(
+        VersionReqVariant::PatchGreater {
+            major: 1,
+            minor: 2,
+            patch: 3,
+        },
+        &[v(1, 2, 4), v(1, 3, 0), v(2, 0, 0), v(2, 1, 2)],
+        &[v(1, 2, 3), v(1, 1, 9), v(0, 9, 9)],
+    );
+}
+
+#[test]
+fn greater_equal() {
+    check(
+        VersionReqVariant::MajorGreaterEqual { major: 1 },
+        &[v(1, 0, 0), v(1, 0, 1), v(2, 0, 0)],
+        &[v(0, MAX, MAX), v(0, 9, 9)],
+    );
+    check(
+        VersionReqVariant::MinorGreaterEqual { major: 1, minor: 2 },
+        &[v(1, 2, 0), v(1, 3, 0), v(2, 0, 0), v(2, 1, 0)],
+        &[v(1, 1, MAX), v(1, 1, 9), v(0, 9, 9)],
+    );
+    check(
+        VersionReqVariant::PatchGreaterEqual {
+            major: 1,
+            minor: 2,
+            patch: 3,
+        },
+        &[v(1, 2, 3), v(1, 3, 0), v(2, 0, 0), v(2, 1, 2)],
+        &[v(1, 2, 2), v(1, 1, 9), v(0, 9, 9)],
+    );
+}
+
+#[test]
+fn less() {
+    check(
+        VersionReqVariant::MajorLess { major: 1 },
+        &[v(0, 0, 0), v(0, MAX, MAX)],
+        &[v(1, 0, 0), v(1, 0, 1), v(2, 0, 0)],
+    );
+    check(
+        VersionReqVariant::MinorLess { major: 1, minor: 2 },
+        &[v(1, 1, MAX), v(1, 1, 9), v(0, 3, 0)],
+        &[v(1, 2, 0), v(1, 3, 0), v(2, 0, 0), v(2, 1, 0)],
+    );
+    check(
+        VersionReqVariant::PatchLess {
+            major: 1,
+            minor: 2,
+            patch: 3,
+        },
+        &[v(1, 2, 2), v(1, 1, 9), v(0, 3, 4)],
+        &[v(1, 2, 3), v(1, 3, 0), v(2, 0, 0), v(2, 1, 2)],
+    );
+}
+
+#[test]
+fn less_equal() {
+    check(
+        VersionReqVariant::MajorLessEqual { major: 1 },
+        &[v(1, MAX, MAX), v(1, 0, 0), v(0, 9, 9)],
+        &[v(2, 0, 0), v(3, 0, 0)],
+    );
+    check(
+        VersionReqVariant::MinorLessEqual { major: 1, minor: 2 },
+        &[v(1, 2, MAX), v(1, 2, 0), v(1, 1, 9), v(0, 3, 0)],
+        &[v(1, 3, 0), v(2, 0, 0), v(2, 1, 0)],
+    );
+    check(
+        VersionReqVariant::PatchLessEqual {
+            major: 1,
+            minor: 2,
+            patch: 3,
+        },
+        &[v(1, 2, 3), v(1, 1, 9), v(0, 3, 4)],
+        &[v(1, 2, 4), v(1, 3, 0), v(2, 0, 0), v(2, 1, 2)],
+    );
+}
+
+#[test]
+fn compound_bounds() {
+    use VersionReqVariantLowerBound::*;
+    use VersionReqVariantUpperBound::*;
+    let lower = [
+        (MajorGreater { major: 1 }, v(2, 0, 0)),
+        (MinorGreater { major: 1, minor: 2 }, v(1, 3, 0)),
+        (
+            PatchGreater {
+                major: 1,
+                minor: 2,
+                patch: 3,
+            },
+            v(1, 2, 4),
+        ),
+        (MajorGreaterEqual { major: 1 }, v(1, 0, 0)),
+        (MinorGreaterEqual { major: 1, minor: 2 }, v(1, 2, 0)),
+        (
+            PatchGreaterEqual {
+                major: 1,
+                minor: 2,
+                patch: 3,
+            },
+            v(1, 2, 3),
+        ),
+    ];
+    // The highest matching version of each upper bound.
+    let upper = [
+        (MajorLess { major: 4 }, v(3, MAX, MAX)),
+        (MinorLess { major: 4, minor: 5 }, v(4, 4, MAX)),
+        (
+            PatchLess {
+                major: 4,
+                minor: 5,
+                patch: 6,
+            },
+            v(4, 5, 5),
+        ),
+        (MajorLessEqual { major: 4 }, v(4, MAX, MAX)),
+        (MinorLessEqual { major: 4, minor: 5 }, v(4, 5, MAX)),
+        (
+            PatchLessEqual {
+                major: 4,
+                minor: 5,
+                patch: 6,
+            },
+            v(4, 5, 6),
+        ),
+    ];
+    for (lower, lowest) in lower {
+        for (upper, highest) in upper {
+            let req = VersionReq::new(&VersionReqVariant::Compound(lower, upper));
+            let below = predecessor(lowest);
+            let above = successor(highest);
+            assert!(req.matches(&lowest), "{req} should match {lowest}");
+            assert!(req.matches(&highest), "{req} should match {highest}");
+            assert!(!req.matches(&below), "{req} shouldn't match {below}");
+            assert!(!req.matches(&above), "{req} shouldn't match {above}");
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use fast_version::{VersionParseError, VersionReq};
+
+fn req() -> VersionReq {
+    VersionReq::parse(">=1.2.0, <2.0.0").unwrap()
+}
+
+#[test]
+fn valid() {
+    assert!(req().matches_str("1.2.0").unwrap());
+    assert!(req().matches_str("1.99.1").unwrap());
+    assert!(!req().matches_str("1.1.9").unwrap());
+    assert!(!req().matches_str("2.0.0").unwrap());
+}
+
+#[test]
+fn invalid() {
+    assert!(matches!(
+        req().matches_str("1.2"),
+        Err(VersionParseError::TooFewComponents { found: 2 })
+    ));
+    assert!(matches!(
+        req().matches_str("1.x.0"),
+        Err(VersionParseError::MinorParseError)
+    ));
+    assert!(req().matches_str("").is_err());
+    assert!(req().matches_str("1. 2.0").is_err());
+}
+
+#[test]
+fn whitespace_padded() {
+    assert!(req().matches_str(" 1.5.0").unwrap());
+    assert!(req().matches_str("1.5.0\n").unwrap());
+    assert!(!req().matches_str("\t2.5.0 ").unwrap());
+}
+
+#[test]
+fn with_default() {
+    assert!(req().matches_str_or("1.5.0", false));
+    assert!(!req().matches_str_or("2.5.0", true));
+    assert!(!req().matches_str_or("garbage", false));
+    assert!(req().matches_str_or("garbage", true));
+    assert!(VersionReq::STAR.matches_str_or(" 0.0.0 ", false));
+}
@@ -0,0 +1,180 @@
+use fast_version::{Version, VersionReq, VersionReqVariant};
+use fast_version::{VersionReqVariantLowerBound, VersionReqVariantUpperBound};
+use fast_version_core::test_util::{first_disagreement, naive_matches, SMALL_DOMAIN};
+
+/// Every one, two and three component prefix over the small domain, plus some next to it.
+fn prefixes() -> Vec<(u64, Option<u64>, Option<u64>)> {
+    let domain = || SMALL_DOMAIN.chain([5]);
+    let mut prefixes = Vec::new();
+    for major in domain() {
+        prefixes.push((major, None, None));
+        for minor in domain() {
+            prefixes.push((major, Some(minor), None));
+            for patch in domain() {
+                prefixes.push((major, Some(minor), Some(patch)));
+            }
+        }
+    }
+    prefixes
+}
+
+fn lower_bounds() -> Vec<VersionReqVariantLowerBound> {
+    use VersionReqVariantLowerBound::*;
+    let mut bounds = Vec::new();
+    for prefix in prefixes() {
+        match prefix {
+            (major, None, _) => {
+                bounds.extend([MajorGreater { major }, MajorGreaterEqual { major }])
+            }
+            (major, Some(minor), None) => bounds.extend([
+                MinorGreater { major, minor },
+                MinorGreaterEqual { major, minor },
+            ]),
+            (major, Some(minor), Some(patch)) => bounds.extend([
+                PatchGreater {
+                    major,
+                    minor,
+                    patch,
+                },
+                PatchGreaterEqual {
+                    major,
+                    minor,
+                    patch,
+                },
+            ]),
+        }
+    }
+    bounds
+}
+
+fn upper_bounds() -> Vec<VersionReqVariantUpperBound> {
+    use VersionReqVariantUpperBound::*;
+    let mut bounds = Vec::new();
+    for prefix in prefixes() {
+        match prefix {
+            (major, None, _) => bounds.extend([MajorLess { major }, MajorLessEqual { major }]),
+            (major, Some(minor), None) => {
+                bounds.extend([MinorLess { major, minor }, MinorLessEqual { major, minor }])
+            }
+            (major, Some(minor), Some(patch)) => bounds.extend([
+                PatchLess {
+                    major,
+                    minor,
+                    patch,
+                },
+                PatchLessEqual {
+                    major,
+                    minor,
+                    patch,
+                },
+            ]),
+        }
+    }
+    bounds
+}
+
+fn single_variants() -> Vec<VersionReqVariant> {
+    use VersionReqVariant::*;
+    let mut variants = Vec::new();
+    for prefix in prefixes() {
+        match prefix {
+            (major, None, _) => variants.extend([
+                MajorGreater { major },
+                MajorGreaterEqual { major },
+                MajorLess { major },
+                MajorLessEqual { major },
+            ]),
+            (major, Some(minor), None) => variants.extend([
+                MinorGreater { major, minor },
+                MinorGreaterEqual { major, minor },
+                MinorLess { major, minor },
+                MinorLessEqual { major, minor },
+            ]),
+            (major, Some(minor), Some(patch)) => variants.extend([
+                Strict(Version::new(major, minor, patch)),
+                Caret(Version::new(major, minor, patch)),
+                Tilde(Version::new(major, minor, patch)),
+                PatchGreater {
+                    major,
+                    minor,
+                    patch,
+                },
+                PatchGreaterEqual {
+                    major,
+                    minor,
+                    patch,
+                },
+                PatchLess {
+                    major,
+                    minor,
+                    patch,
+                },
+                PatchLessEqual {
+                    major,
+                    minor,
+                    patch,
+                },
+            ]),
+        }
+    }
+    variants
+}
+
+#[test]
+fn single_variants_agree() {
+    for variant in single_variants() {
+        assert_eq!(first_disagreement(&variant), None, "{variant:?}");
+    }
+}
+
+#[test]
+fn compounds_agree() {
+    let uppers = upper_bounds();
+    for lower in lower_bounds() {
+        for upper in &uppers {
+            let variant = VersionReqVariant::Compound(lower, *upper);
+            assert_eq!(first_disagreement(&variant), None, "{variant:?}");
+        }
+    }
+}
+
+#[test]
+fn naive_definitions() {
+    let version = Version::new(1, 2, 3);
+    assert!(naive_matches(
+        &VersionReqVariant::MinorGreater { major: 1, minor: 1 },
+        &version
+    ));
+    assert!(!naive_matches(
+        &VersionReqVariant::MinorGreater { major: 1, minor: 2 },
+        &version
+    ));
+    assert!(naive_matches(
+        &VersionReqVariant::MajorLessEqual { major: 1 },
+        &version
+    ));
+    assert!(!naive_matches(
+        &VersionReqVariant::MajorLess { major: 1 },
+        &version
+    ));
+    assert!(naive_matches(
+        &VersionReqVariant::Strict(version),
+        &Version::new(1, 2, 3)
+    ));
+}
+
+#[test]
+fn detects_a_broken_matcher() {
+    // `>=1.2` lowered like `>1.2` would wrongly reject 1.2.x.
+    let variant = VersionReqVariant::MinorGreaterEqual { major: 1, minor: 2 };
+    let broken = VersionReq::new(&VersionReqVariant::MinorGreater { major: 1, minor: 2 });
+    let disagreement = first_mismatch(&variant, &broken);
+    assert_eq!(disagreement, Some(Version::new(1, 2, 0)));
+}
+
+fn first_mismatch(variant: &VersionReqVariant, req: &VersionReq) -> Option<Version> {
+    SMALL_DOMAIN
+        .flat_map(|major| SMALL_DOMAIN.map(move |minor| (major, minor)))
+        .flat_map(|(major, minor)| SMALL_DOMAIN.map(move |patch| Version::new(major, minor, patch)))
+        .find(|version| naive_matches(variant, version) != req.matches(version))
+}
@@ -0,0 +1,71 @@
+use fast_version::{CompareStrError, MatchStrError, VersionParseError, VersionReqParseError};
+use std::cmp::Ordering;
+
+#[test]
+fn matches() {
+    assert!(fast_version::matches(">=1.2, <2", "1.5.0").unwrap());
+    assert!(!fast_version::matches(">=1.2, <2", "2.0.0").unwrap());
+    assert!(fast_version::matches(" ^0.3 ", " 0.3.9\n").unwrap());
+    assert!(!fast_version::matches("<0.0.0", "0.0.0").unwrap());
+}
+
+#[test]
+fn matches_invalid_req() {
+    let error = fast_version::matches(">>1", "1.0.0").unwrap_err();
+    assert!(matches!(
+        error,
+        MatchStrError::Req(VersionReqParseError::UnknownOperator { .. })
+    ));
+    assert!(error
+        .to_string()
+        .starts_with("Invalid version requirement: "));
+    // The requirement is reported even if the version is invalid too.
+    assert!(matches!(
+        fast_version::matches(">>1", "x"),
+        Err(MatchStrError::Req(_))
+    ));
+}
+
+#[test]
+fn matches_invalid_version() {
+    let error = fast_version::matches("^1", "1.x.0").unwrap_err();
+    assert!(matches!(
+        error,
+        MatchStrError::Version(VersionParseError::MinorParseError)
+    ));
+    assert_eq!(error.to_string(), "Invalid version: Minor Parse Error");
+    assert!(std::error::Error::source(&error).is_some());
+}
+
+#[test]
+fn compare() {
+    assert_eq!(
+        fast_version::compare("1.2.3", "1.10.0").unwrap(),
+        Ordering::Less
+    );
+    assert_eq!(
+        fast_version::compare("2.0.0", " 2.0.0 ").unwrap(),
+        Ordering::Equal
+    );
+    assert_eq!(
+        fast_version::compare("1.0.10", "1.0.9").unwrap(),
+        Ordering::Greater
+    );
+}
+
+#[test]
+fn compare_invalid() {
+    assert!(matches!(
+        fast_version::compare("1.2", "1.2.3"),
+        Err(CompareStrError::First(
+            VersionParseError::TooFewComponents { found: 2 }
+        ))
+    ));
+    let error = fast_version::compare("1.2.3", "").unwrap_err();
+    assert!(matches!(error, CompareStrError::Second(_)));
+    assert!(error.to_string().starts_with("Invalid second version: "));
+    assert!(matches!(
+        fast_version::compare("a", "b"),
+        Err(CompareStrError::First(_))
+    ));
+}
@@ -0,0 +1,65 @@
+use fast_version::{ComponentKind, Version, VersionParseError};
+use std::str::FromStr;
+
+fn overflow(input: &str) -> Option<(ComponentKind, usize)> {
+    match Version::from_str(input) {
+        Err(VersionParseError::ComponentOverflow {
+            component,
+            digits_len,
+        }) => Some((component, digits_len)),
+        _ => None,
+    }
+}
+
+#[test]
+fn overflow_in_each_position() {
+    assert_eq!(
+        overflow("18446744073709551616.0.0"),
+        Some((ComponentKind::Major, 20))
+    );
+    assert_eq!(
+        overflow("0.18446744073709551616.0"),
+        Some((ComponentKind::Minor, 20))
+    );
+    assert_eq!(
+        overflow("0.0.100000000000000000000"),
+        Some((ComponentKind::Patch, 21))
+    );
+}
+
+#[test]
+fn twenty_digits_still_fit() {
+    assert_eq!(
+        Version::from_str("18446744073709551615.0.10000000000000000000").unwrap(),
+        Version::new(u64::MAX, 0, 10_000_000_000_000_000_000)
+    );
+}
+
+#[test]
+fn non_digits_stay_generic() {
+    assert!(matches!(
+        Version::from_str("abc.0.0"),
+        Err(VersionParseError::MajorParseError)
+    ));
+    assert!(matches!(
+        Version::from_str("0.1x.0"),
+        Err(VersionParseError::MinorParseError)
+    ));
+    assert!(matches!(
+        Version::from_str("0.0.-1"),
+        Err(VersionParseError::PatchParseError)
+    ));
+    assert!(matches!(
+        Version::from_str("0.0."),
+        Err(VersionParseError::PatchParseError)
+    ));
+}
+
+#[test]
+fn error_message() {
+    let error = Version::from_str("18446744073709551616.0.0").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "The major component with 20 digits is too large"
+    );
+}
@@ -0,0 +1,122 @@
+use fast_version::{ComponentKind, PackedError, Version};
+
+#[test]
+fn u64_boundaries() {
+    let max = Version::new((1 << 16) - 1, (1 << 24) - 1, (1 << 24) - 1);
+    assert_eq!(max.to_packed_u64(), Ok(u64::MAX));
+    assert_eq!(Version::from_packed_u64(u64::MAX), max);
+    assert_eq!(Version::new(0, 0, 0).to_packed_u64(), Ok(0));
+    assert_eq!(Version::from_packed_u64(0), Version::new(0, 0, 0));
+    assert_eq!(Version::new(0, 0, 1).to_packed_u64(), Ok(1));
+    assert_eq!(Version::new(0, 1, 0).to_packed_u64(), Ok(1 << 24));
+    assert_eq!(Version::new(1, 0, 0).to_packed_u64(), Ok(1 << 48));
+}
+
+#[test]
+fn u128_boundaries() {
+    let max = Version::new(u32::MAX as u64, (1 << 48) - 1, (1 << 48) - 1);
+    assert_eq!(max.to_packed_u128(), Ok(u128::MAX));
+    assert_eq!(Version::from_packed_u128(u128::MAX), max);
+    let version = Version::new(1, 20240101, 3);
+    assert_eq!(
+        Version::from_packed_u128(version.to_packed_u128().unwrap()),
+        version
+    );
+}
+
+#[test]
+fn overflow() {
+    assert_eq!(
+        Version::new(1 << 16, 0, 0).to_packed_u64(),
+        Err(PackedError {
+            component: ComponentKind::Major,
+            value: 1 << 16,
+            bits: 16
+        })
+    );
+    assert_eq!(
+        Version::new(0, 1 << 24, 0)
+            .to_packed_u64()
+            .unwrap_err()
+            .component,
+        ComponentKind::Minor
+    );
+    assert_eq!(
+        Version::new(0, 0, u64::MAX).to_packed_u128(),
+        Err(PackedError {
+            component: ComponentKind::Patch,
+            value: u64::MAX,
+            bits: 48
+        })
+    );
+    assert_eq!(
+        Version::new(1 << 16, 0, 0)
+            .to_packed_u64()
+            .unwrap_err()
+            .to_string(),
+        "The major 65536 doesn't fit into 16 bits"
+    );
+}
+
+#[test]
+fn packing_preserves_order() {
+    let versions = [
+        Version::new(0, 0, 0),
+        Version::new(0, 0, 9),
+        Version::new(0, 1, 0),
+        Version::new(0, 16_777_215, 16_777_215),
+        Version::new(1, 0, 0),
+        Version::new(1, 2, 3),
+        Version::new(65_535, 0, 0),
+    ];
+    for pair in versions.windows(2) {
+        assert!(pair[0].to_packed_u64().unwrap() < pair[1].to_packed_u64().unwrap());
+        assert!(pair[0].to_packed_u128().unwrap() < pair[1].to_packed_u128().unwrap());
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use fast_version::Version;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Message {
+        #[serde(with = "fast_version::serde_helpers::version_as_packed_u64")]
+        small: Version,
+        #[serde(with = "fast_version::serde_helpers::version_as_packed_u128")]
+        large: Version,
+    }
+
+    #[test]
+    fn round_trip() {
+        let message = Message {
+            small: Version::new(1, 2, 3),
+            large: Version::new(1, 2, 3),
+        };
+        let json = serde_json::to_string(&message).unwrap();
+        assert_eq!(
+            json,
+            r#"{"small":281475010265091,"large":79228162514264900543497371651}"#
+        );
+        assert_eq!(serde_json::from_str::<Message>(&json).unwrap(), message);
+    }
+
+    #[test]
+    fn rejects_out_of_range() {
+        let message = Message {
+            small: Version::new(1 << 16, 0, 0),
+            large: Version::new(1, 2, 3),
+        };
+        let error = serde_json::to_string(&message).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "The major 65536 doesn't fit into 16 bits"
+        );
+
+        let json = r#"{"small":18446744073709551616,"large":0}"#;
+        assert!(serde_json::from_str::<Message>(json).is_err());
+        let json = r#"{"small":-1,"large":0}"#;
+        assert!(serde_json::from_str::<Message>(json).is_err());
+    }
+}
@@ -0,0 +1,85 @@
+use fast_version::{PackedKey, Version};
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
+
+const MAX_MAJOR: u64 = (1 << 32) - 1;
+const MAX_MINOR: u64 = (1 << 48) - 1;
+
+fn versions() -> [Version; 5] {
+    [
+        Version::new(0, 0, 0),
+        Version::new(1, 2, 3),
+        Version::new(1, 3, 0),
+        Version::new(7, 0, 12),
+        Version::new(MAX_MAJOR, MAX_MINOR, MAX_MINOR),
+    ]
+}
+
+fn key(version: Version) -> PackedKey {
+    PackedKey(version.to_packed_u128().unwrap())
+}
+
+#[test]
+fn hashes_agree() {
+    let state = RandomState::new();
+    for version in versions() {
+        assert_eq!(state.hash_one(key(version)), state.hash_one(version));
+        assert_eq!(key(version).version(), version);
+    }
+    assert_ne!(
+        state.hash_one(key(Version::new(1, 2, 3))),
+        state.hash_one(Version::new(1, 3, 2))
+    );
+}
+
+#[test]
+fn ordering() {
+    let versions = versions();
+    for first in versions {
+        for second in versions {
+            assert_eq!(key(first).cmp(&key(second)), first.cmp(&second));
+        }
+    }
+}
+
+#[cfg(feature = "hashbrown")]
+mod hashbrown {
+    use super::*;
+    use ::hashbrown::{HashMap, HashSet};
+
+    #[test]
+    fn version_map_by_packed_key() {
+        let map: HashMap<Version, usize> = versions()
+            .into_iter()
+            .enumerate()
+            .map(|(index, version)| (version, index))
+            .collect();
+        for (index, version) in versions().into_iter().enumerate() {
+            assert_eq!(map.get(&key(version)), Some(&index));
+        }
+        assert_eq!(map.get(&key(Version::new(1, 2, 4))), None);
+        assert!(!map.contains_key(&key(Version::new(3, 0, 0))));
+    }
+
+    #[test]
+    fn packed_map_by_version() {
+        let mut map = HashMap::new();
+        for version in versions() {
+            map.insert(key(version), version.to_string());
+        }
+        for version in versions() {
+            assert_eq!(map.get(&version), Some(&version.to_string()));
+        }
+        assert_eq!(map.get(&Version::new(2, 0, 0)), None);
+        // Too large to be packed, so never equivalent to a key.
+        assert_eq!(map.get(&Version::new(MAX_MAJOR + 1, 0, 0)), None);
+    }
+
+    #[test]
+    fn set_removal() {
+        let mut set: HashSet<Version> = versions().into_iter().collect();
+        assert!(set.remove(&key(Version::new(1, 2, 3))));
+        assert!(!set.contains(&Version::new(1, 2, 3)));
+        assert_eq!(set.len(), 4);
+    }
+}
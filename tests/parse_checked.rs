@@ -0,0 +1,123 @@
+mod common;
+
+use common::Rng;
+use fast_version::docker::classify_tag;
+use fast_version::spec::PackageSpec;
+use fast_version::{
+    DynVersion, EpochVersion, LenientVersion, Version, Version4, VersionParseError, VersionPattern,
+    VersionReq, VersionReqSet,
+};
+use std::str::FromStr;
+
+impl Rng {
+    /// Short strings mostly made of characters the parsers care about.
+    fn input(&mut self) -> String {
+        const ALPHABET: [char; 24] = [
+            '0', '1', '9', '.', '.', ',', ' ', '=', '<', '>', '^', '~', '*', 'x', '!', '|', '-',
+            '+', '@', 'v', ':', '\0', 'é', '🦀',
+        ];
+        self.string(&ALPHABET, 24)
+    }
+}
+
+/// Runs every parser of the crate on the input, the test fails if any of them panics.
+fn parse_everything(input: &str) {
+    let _ = Version::parse_checked(input);
+    let _ = Version::parse_checked_bytes(input.as_bytes());
+    let _ = Version::parse_partial(input);
+    let _ = Version4::from_str(input);
+    let _ = DynVersion::from_str(input);
+    let _ = EpochVersion::from_str(input);
+    let _ = LenientVersion::parse(input);
+    let _ = VersionPattern::from_str(input);
+    let _ = VersionReq::parse(input);
+    let _ = VersionReqSet::parse(input);
+    let _ = PackageSpec::from_str(input);
+    let _ = classify_tag(input);
+}
+
+#[test]
+fn same_as_from_str() {
+    for input in ["1.2.3", "0.0.0", "1.2", "1.2.3.4", "a.b.c", "", "1.2.-3"] {
+        assert_eq!(
+            Version::parse_checked(input).ok(),
+            Version::from_str(input).ok(),
+            "{input:?}"
+        );
+    }
+}
+
+#[test]
+fn lone_dots() {
+    for input in [".", "..", "...", "....", "1..3", ".2.3", "1.2.", "1.2.3."] {
+        assert!(Version::parse_checked(input).is_err(), "{input:?}");
+        parse_everything(input);
+    }
+}
+
+#[test]
+fn interior_nuls() {
+    for input in ["1.2.3\0", "\u{0}1.2.3", "1.\0.3", "1\0.2.3", "\0"] {
+        assert!(Version::parse_checked(input).is_err(), "{input:?}");
+        parse_everything(input);
+    }
+}
+
+#[test]
+fn huge_strings() {
+    let digits = "9".repeat(1 << 20);
+    assert!(matches!(
+        Version::parse_checked(&format!("{digits}.0.0")),
+        Err(VersionParseError::ComponentOverflow { digits_len, .. }) if digits_len == 1 << 20
+    ));
+    parse_everything(&digits);
+    parse_everything(&".".repeat(1 << 20));
+    parse_everything(&"1.".repeat(1 << 19));
+    parse_everything(&">=1, ".repeat(1 << 16));
+    parse_everything(&"=1.2.3 || ".repeat(1 << 16));
+}
+
+#[test]
+fn non_utf8_bytes() {
+    for input in [
+        &b"1.2.\xff"[..],
+        b"\xc3.2.3",
+        b"1.\xe2\x82.3",
+        b"\xed\xa0\x80",
+        b"\xff\xfe\xfd",
+    ] {
+        assert!(matches!(
+            Version::parse_checked_bytes(input),
+            Err(VersionParseError::InvalidUtf8)
+        ));
+    }
+    assert_eq!(
+        Version::parse_checked_bytes(b"10.20.30").unwrap(),
+        Version::new(10, 20, 30)
+    );
+}
+
+#[test]
+fn multi_byte_characters() {
+    for input in [
+        "é",
+        "1.é.3",
+        "🦀.2.3",
+        "1.2.3🦀",
+        ">=1.2.3é",
+        "^é",
+        "1.x.🦀",
+        "v🦀",
+    ] {
+        assert!(Version::parse_checked(input).is_err(), "{input:?}");
+        parse_everything(input);
+    }
+}
+
+#[test]
+fn random_inputs() {
+    let mut rng = Rng(0x5eed_1234_abcd_0001);
+    for _ in 0..50_000 {
+        parse_everything(&rng.input());
+    }
+}
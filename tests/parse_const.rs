@@ -0,0 +1,133 @@
+mod common;
+
+use common::Rng;
+use fast_version::{ComponentKind, Version, VersionParseError};
+use std::num::IntErrorKind;
+use std::str::FromStr;
+
+const PACKAGE_VERSION: Version = match Version::parse_const(env!("CARGO_PKG_VERSION")) {
+    Ok(version) => version,
+    Err(_) => panic!("bad version"),
+};
+
+impl Rng {
+    /// Short strings of digits, dots, signs and a few other characters.
+    fn input(&mut self) -> String {
+        const ALPHABET: [char; 12] = ['0', '1', '9', '9', '.', '.', '.', '+', '-', 'x', ' ', 'é'];
+        self.string(&ALPHABET, 28)
+    }
+}
+
+/// The parser as it was before it became const, splitting and using `u64::from_str`.
+fn reference(input: &str) -> Result<Version, VersionParseError> {
+    if let Some(position) = input.find(['-', '+']).filter(|position| *position > 0) {
+        let suffix = input[position..]
+            .bytes()
+            .all(|byte| byte.is_ascii_alphanumeric() || matches!(byte, b'.' | b'-' | b'+'));
+        if suffix && reference(&input[..position]).is_ok() {
+            return Err(VersionParseError::Prerelease { position });
+        }
+    }
+    let splits: Vec<&str> = input.split('.').collect();
+    let found = u8::try_from(splits.len()).unwrap_or(u8::MAX);
+    if found < 3 {
+        return Err(VersionParseError::TooFewComponents { found });
+    } else if found > 3 {
+        return Err(VersionParseError::TooManyComponents { found });
+    }
+    let mut components = [0; 3];
+    let kinds = [
+        ComponentKind::Major,
+        ComponentKind::Minor,
+        ComponentKind::Patch,
+    ];
+    for ((component, split), kind) in components.iter_mut().zip(splits).zip(kinds) {
+        *component = u64::from_str(split).map_err(|error| match error.kind() {
+            IntErrorKind::PosOverflow => VersionParseError::ComponentOverflow {
+                component: kind,
+                digits_len: split.len(),
+            },
+            _ => match kind {
+                ComponentKind::Major => VersionParseError::MajorParseError,
+                ComponentKind::Minor => VersionParseError::MinorParseError,
+                ComponentKind::Patch => VersionParseError::PatchParseError,
+            },
+        })?;
+    }
+    Ok(Version::new(components[0], components[1], components[2]))
+}
+
+fn assert_same(input: &str) {
+    let parsed = Version::parse_const(input);
+    let expected = reference(input);
+    assert_eq!(format!("{parsed:?}"), format!("{expected:?}"), "{input:?}");
+    assert_eq!(parsed.ok(), Version::from_str(input).ok(), "{input:?}");
+}
+
+#[test]
+fn const_context() {
+    assert_eq!(PACKAGE_VERSION.to_string(), env!("CARGO_PKG_VERSION"));
+    const { assert!(Version::parse_const("1.2").is_err()) };
+}
+
+#[test]
+fn valid() {
+    assert_eq!(
+        Version::parse_const("1.2.3").unwrap(),
+        Version::new(1, 2, 3)
+    );
+    assert_eq!(
+        Version::parse_const("18446744073709551615.0.00018446744073709551615").unwrap(),
+        Version::new(u64::MAX, 0, u64::MAX)
+    );
+    assert_eq!(
+        Version::parse_const("+1.+2.+3").unwrap(),
+        Version::new(1, 2, 3)
+    );
+}
+
+#[test]
+fn errors() {
+    let cases = [
+        ("", "TooFewComponents { found: 1 }"),
+        ("1.2", "TooFewComponents { found: 2 }"),
+        ("1.2.3.4", "TooManyComponents { found: 4 }"),
+        ("..", "MajorParseError"),
+        ("1..3", "MinorParseError"),
+        ("1.2.", "PatchParseError"),
+        ("1.2.3a", "PatchParseError"),
+        ("1.-2.3", "MinorParseError"),
+        ("1.+.3", "MinorParseError"),
+        (" 1.2.3", "MajorParseError"),
+        (
+            "18446744073709551616.0.0",
+            "ComponentOverflow { component: Major, digits_len: 20 }",
+        ),
+        (
+            "0.0.+99999999999999999999",
+            "ComponentOverflow { component: Patch, digits_len: 21 }",
+        ),
+        (
+            "0.99999999999999999999x.0",
+            "ComponentOverflow { component: Minor, digits_len: 21 }",
+        ),
+        ("0.1x99999999999999999999.0", "MinorParseError"),
+    ];
+    for (input, expected) in cases {
+        let error = Version::parse_const(input).unwrap_err();
+        assert_eq!(format!("{error:?}"), expected, "{input:?}");
+        assert_same(input);
+    }
+    assert!(matches!(
+        Version::parse_const(&".".repeat(300)),
+        Err(VersionParseError::TooManyComponents { found: u8::MAX })
+    ));
+}
+
+#[test]
+fn random_inputs() {
+    let mut rng = Rng(0x5eed_0508_c0de_0001);
+    for _ in 0..50_000 {
+        assert_same(&rng.input());
+    }
+}
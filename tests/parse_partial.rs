@@ -0,0 +1,79 @@
+use fast_version::{ComponentKind, Version, VersionParseError};
+
+fn partial(input: &str) -> (Version, &str) {
+    Version::parse_partial(input).unwrap()
+}
+
+#[test]
+fn delimiters() {
+    assert_eq!(partial("1.2.3/linux"), (Version::new(1, 2, 3), "/linux"));
+    assert_eq!(partial("1.2.3-beta"), (Version::new(1, 2, 3), "-beta"));
+    assert_eq!(
+        partial("1.2.3 (build 7)"),
+        (Version::new(1, 2, 3), " (build 7)")
+    );
+    assert_eq!(partial("10.20.30abc"), (Version::new(10, 20, 30), "abc"));
+    assert_eq!(partial("1.2.3.tar.gz"), (Version::new(1, 2, 3), ".tar.gz"));
+    assert_eq!(partial("1.2.3.4"), (Version::new(1, 2, 3), ".4"));
+    assert_eq!(partial("1.2.3."), (Version::new(1, 2, 3), "."));
+
+    let input = "name/1.2.3/linux";
+    let (_, rest) = input.split_once('/').unwrap();
+    assert_eq!(partial(rest), (Version::new(1, 2, 3), "/linux"));
+}
+
+#[test]
+fn end_of_string() {
+    assert_eq!(partial("1.2.3"), (Version::new(1, 2, 3), ""));
+    assert!(matches!(
+        Version::parse_partial(""),
+        Err(VersionParseError::MajorNotFound)
+    ));
+    assert!(matches!(
+        Version::parse_partial("1"),
+        Err(VersionParseError::MinorNotFound)
+    ));
+    assert!(matches!(
+        Version::parse_partial("1.2"),
+        Err(VersionParseError::PatchNotFound)
+    ));
+    assert!(matches!(
+        Version::parse_partial("1.2."),
+        Err(VersionParseError::PatchNotFound)
+    ));
+}
+
+#[test]
+fn no_version_at_start() {
+    assert!(Version::parse_partial("name/1.2.3").is_err());
+    assert!(Version::parse_partial(" 1.2.3").is_err());
+    assert!(Version::parse_partial("v1.2.3").is_err());
+    assert!(Version::parse_partial(".1.2.3").is_err());
+    assert!(Version::parse_partial("1..2.3").is_err());
+    assert!(Version::parse_partial("+1.2.3").is_err());
+}
+
+#[test]
+fn digit_runs() {
+    assert_eq!(
+        partial("18446744073709551615.0.0x"),
+        (Version::new(u64::MAX, 0, 0), "x")
+    );
+    assert_eq!(partial("0001.02.003"), (Version::new(1, 2, 3), ""));
+    assert!(matches!(
+        Version::parse_partial("18446744073709551616.0.0"),
+        Err(VersionParseError::ComponentOverflow {
+            component: ComponentKind::Major,
+            digits_len: 20
+        })
+    ));
+    assert!(matches!(
+        Version::parse_partial("1.2.99999999999999999999999999999999"),
+        Err(VersionParseError::ComponentOverflow {
+            component: ComponentKind::Patch,
+            digits_len: 32
+        })
+    ));
+    let long = format!("1.2.{}", "9".repeat(10_000));
+    assert!(Version::parse_partial(&long).is_err());
+}
@@ -0,0 +1,95 @@
+use fast_version::{BuildMetadata, FullVersion, Prerelease, Version, VersionReq};
+use std::cmp::Ordering;
+
+fn full(version: &str) -> FullVersion {
+    version.parse().unwrap()
+}
+
+fn versions() -> Vec<FullVersion> {
+    vec![
+        full("1.10.0"),
+        full("1.2.3+build.2"),
+        full("0.9.18446744073709551615"),
+        full("1.2.3"),
+        full("2.0.0-rc.1"),
+        full("1.2.3+build.1"),
+        full("2.0.0"),
+    ]
+}
+
+#[test]
+fn agrees_with_ord_up_to_metadata() {
+    for a in versions() {
+        for b in versions() {
+            assert_eq!(
+                a.cmp_precedence(&b),
+                a.strip_metadata().cmp(&b.strip_metadata()),
+                "{a} {b}"
+            );
+            assert_eq!(
+                a.eq_precedence(&b),
+                a.strip_metadata() == b.strip_metadata(),
+                "{a} {b}"
+            );
+            if a.cmp_precedence(&b).is_ne() {
+                assert_eq!(a.cmp_precedence(&b), a.cmp(&b), "{a} {b}");
+            }
+        }
+        assert!(a.strip_metadata().build.is_empty());
+        assert!(a.strip_metadata().eq_precedence(&a));
+    }
+}
+
+#[test]
+fn sorting() {
+    let mut by_precedence = versions();
+    by_precedence.sort_by(FullVersion::cmp_precedence);
+    assert!(by_precedence
+        .windows(2)
+        .all(|pair| pair[0].cmp_precedence(&pair[1]) != Ordering::Greater));
+    assert_eq!(by_precedence.last(), Some(&full("2.0.0")));
+}
+
+#[test]
+fn dedup_by_precedence() {
+    let mut releases = versions();
+    releases.sort();
+    releases.dedup_by(|a, b| a.eq_precedence(b));
+    assert_eq!(
+        releases,
+        [
+            full("0.9.18446744073709551615"),
+            full("1.2.3"),
+            full("1.10.0"),
+            full("2.0.0-rc.1"),
+            full("2.0.0"),
+        ]
+    );
+}
+
+#[test]
+fn matching_ignores_metadata() {
+    let req = VersionReq::parse("=1.2.3").unwrap();
+    for version in versions() {
+        assert_eq!(
+            req.matches_full(&version),
+            version.eq_precedence(&full("1.2.3")),
+            "{version}"
+        );
+        assert_eq!(
+            req.matches_full(&version),
+            req.matches_full(&version.strip_metadata())
+        );
+    }
+}
+
+#[test]
+fn const_usable() {
+    const STRIPPED: FullVersion = FullVersion::new(
+        Version::new(1, 2, 3),
+        Prerelease::EMPTY,
+        BuildMetadata::EMPTY,
+    )
+    .strip_metadata();
+    assert!(STRIPPED.eq_precedence(&full("1.2.3+build.1")));
+}
@@ -0,0 +1,90 @@
+mod common;
+
+use common::req;
+use fast_version::{Version, VersionReq, VersionReqVariant};
+
+const MAX: u64 = u64::MAX;
+
+/// `[is_exact, is_star, is_unbounded_below, is_unbounded_above]`
+fn flags(req: VersionReq) -> [bool; 4] {
+    [
+        req.is_exact(),
+        req.is_star(),
+        req.is_unbounded_below(),
+        req.is_unbounded_above(),
+    ]
+}
+
+#[test]
+fn star() {
+    assert_eq!(flags(VersionReq::STAR), [false, true, true, true]);
+    assert_eq!(flags(req(">=0.0.0")), [false, true, true, true]);
+    assert_eq!(
+        flags(req(
+            "<=18446744073709551615.18446744073709551615.18446744073709551615"
+        )),
+        [false, true, true, true]
+    );
+    assert_eq!(
+        flags(VersionReq::new(&VersionReqVariant::MajorGreaterEqual {
+            major: 0
+        })),
+        [false, true, true, true]
+    );
+}
+
+#[test]
+fn exact() {
+    assert_eq!(flags(req("=1.2.3")), [true, false, false, false]);
+    assert_eq!(flags(req("^0.0.3")), [true, false, false, false]);
+    assert_eq!(flags(req("=0.0.0")), [true, false, true, false]);
+    assert_eq!(
+        flags(VersionReq::exact(Version::new(MAX, MAX, MAX))),
+        [true, false, false, true]
+    );
+}
+
+#[test]
+fn one_sided() {
+    assert_eq!(flags(req(">=1.2.3")), [false, false, false, true]);
+    assert_eq!(flags(req(">1")), [false, false, false, true]);
+    assert_eq!(flags(req("<2")), [false, false, true, false]);
+    assert_eq!(flags(req("<=1.2")), [false, false, true, false]);
+    // The largest version is excluded, so there is an upper bound.
+    assert_eq!(
+        flags(req(
+            "<18446744073709551615.18446744073709551615.18446744073709551615"
+        )),
+        [false, false, true, false]
+    );
+}
+
+#[test]
+fn compound() {
+    assert_eq!(flags(req(">=1.2.3, <2")), [false; 4]);
+    assert_eq!(flags(req("~1.2")), [false; 4]);
+    assert_eq!(flags(req(">=0.0.0, <1")), [false, false, true, false]);
+    assert_eq!(
+        flags(req(
+            ">=1, <=18446744073709551615.18446744073709551615.18446744073709551615"
+        )),
+        [false, false, false, true]
+    );
+}
+
+#[test]
+fn empty() {
+    assert_eq!(flags(VersionReq::EMPTY), [false; 4]);
+    assert_eq!(flags(req("<0.0.0")), [false; 4]);
+}
+
+#[test]
+fn usable_in_const() {
+    const FLAGS: [bool; 4] = [
+        VersionReq::STAR.is_star(),
+        VersionReq::STAR.is_exact(),
+        VersionReq::EMPTY.is_unbounded_above(),
+        VersionReq::at_least(Version::new(1, 0, 0)).is_unbounded_above(),
+    ];
+    assert_eq!(FLAGS, [true, false, false, true]);
+}
@@ -0,0 +1,91 @@
+use fast_version::{Version, VersionReq};
+
+const MAX: u64 = u64::MAX;
+
+static STABLE: VersionReq = VersionReq::STABLE;
+const ANY_V2: VersionReq = VersionReq::major_line(2);
+
+#[test]
+fn stable() {
+    assert_eq!(VersionReq::STABLE.to_string(), ">=1.0.0");
+    assert_eq!(STABLE, VersionReq::parse(">=1").unwrap());
+    assert!(!STABLE.matches(&Version::new(0, MAX, MAX)));
+    assert!(STABLE.matches(&Version::new(1, 0, 0)));
+    assert!(STABLE.matches(&Version::new(MAX, MAX, MAX)));
+}
+
+#[test]
+fn pre_stable() {
+    assert_eq!(VersionReq::PRE_STABLE.to_string(), "<1.0.0");
+    assert_eq!(VersionReq::PRE_STABLE, VersionReq::parse("<1").unwrap());
+    assert!(VersionReq::PRE_STABLE.matches(&Version::new(0, 0, 0)));
+    assert!(VersionReq::PRE_STABLE.matches(&Version::new(0, MAX, MAX)));
+    assert!(!VersionReq::PRE_STABLE.matches(&Version::new(1, 0, 0)));
+}
+
+#[test]
+fn presets_split_the_domain() {
+    assert_eq!(
+        VersionReq::PRE_STABLE.intersection(&VersionReq::STABLE),
+        VersionReq::EMPTY
+    );
+    for version in [
+        Version::new(0, 0, 0),
+        Version::new(0, MAX, MAX),
+        Version::new(1, 0, 0),
+        Version::new(MAX, MAX, MAX),
+    ] {
+        assert_ne!(
+            VersionReq::PRE_STABLE.matches(&version),
+            VersionReq::STABLE.matches(&version)
+        );
+    }
+}
+
+#[test]
+fn major_line() {
+    assert_eq!(ANY_V2, VersionReq::parse(">=2.0.0, <3.0.0").unwrap());
+    assert_eq!(ANY_V2, VersionReq::parse("^2").unwrap());
+    assert!(!ANY_V2.matches(&Version::new(1, MAX, MAX)));
+    assert!(ANY_V2.matches(&Version::new(2, 0, 0)));
+    assert!(ANY_V2.matches(&Version::new(2, MAX, MAX)));
+    assert!(!ANY_V2.matches(&Version::new(3, 0, 0)));
+    assert_eq!(VersionReq::major_line(0), VersionReq::PRE_STABLE);
+}
+
+#[test]
+fn major_line_at_max() {
+    let last = VersionReq::major_line(MAX);
+    assert!(last.matches(&Version::new(MAX, MAX, MAX)));
+    assert!(last.matches(&Version::new(MAX, 0, 0)));
+    assert!(!last.matches(&Version::new(MAX - 1, MAX, MAX)));
+    assert!(last.is_unbounded_above());
+}
+
+#[test]
+fn in_match_guards() {
+    let channel = |version: Version| match version {
+        v if VersionReq::PRE_STABLE.matches(&v) => "experimental",
+        v if ANY_V2.matches(&v) => "lts",
+        v if STABLE.matches(&v) => "stable",
+        _ => unreachable!(),
+    };
+    assert_eq!(channel(Version::new(0, 3, 0)), "experimental");
+    assert_eq!(channel(Version::new(1, 9, 0)), "stable");
+    assert_eq!(channel(Version::new(2, 1, 0)), "lts");
+    assert_eq!(channel(Version::new(3, 0, 0)), "stable");
+}
+
+#[test]
+fn as_patterns() {
+    let name = |req: VersionReq| match req {
+        VersionReq::STABLE => "stable",
+        VersionReq::PRE_STABLE => "pre-stable",
+        ANY_V2 => "v2",
+        _ => "other",
+    };
+    assert_eq!(name(VersionReq::parse(">=1").unwrap()), "stable");
+    assert_eq!(name(VersionReq::parse("^0").unwrap()), "pre-stable");
+    assert_eq!(name(VersionReq::parse("~2").unwrap()), "v2");
+    assert_eq!(name(VersionReq::STAR), "other");
+}
@@ -0,0 +1,117 @@
+mod common;
+
+use common::req;
+use fast_version::{Version, VersionReq};
+
+const MAX: u64 = u64::MAX;
+
+#[test]
+fn exclusive_upper_bound_on_major() {
+    let below = req("<2.0.0");
+    assert!(below.allows_major(1));
+    assert!(!below.allows_major(2));
+    assert_eq!(below.major_span(), 0..=1);
+
+    let above = req("<2.0.1");
+    assert!(above.allows_major(2));
+    assert!(!above.allows_major(3));
+    assert_eq!(above.major_span(), 0..=2);
+
+    assert!(req("<=2.0.0").allows_major(2));
+    assert!(!req("<2").allows_major(2));
+}
+
+#[test]
+fn exclusive_lower_bound_on_major() {
+    let req = req(">1.18446744073709551615.18446744073709551615");
+    assert!(!req.allows_major(1));
+    assert!(req.allows_major(2));
+    assert_eq!(req.major_span(), 2..=MAX);
+
+    assert!(VersionReq::greater_than(Version::new(1, MAX, MAX - 1)).allows_major(1));
+}
+
+#[test]
+fn exclusive_upper_bound_on_minor() {
+    let req = req(">=1.2.3, <1.5.0");
+    assert!(!req.allows_minor(1, 1));
+    assert!(req.allows_minor(1, 2));
+    assert!(req.allows_minor(1, 4));
+    assert!(!req.allows_minor(1, 5));
+    assert!(!req.allows_minor(0, 3));
+    assert!(!req.allows_minor(2, 3));
+
+    assert!(VersionReq::parse("<1.5.1").unwrap().allows_minor(1, 5));
+    assert!(!VersionReq::parse("<2").unwrap().allows_minor(2, 0));
+    assert!(VersionReq::parse("<2").unwrap().allows_minor(1, MAX));
+}
+
+#[test]
+fn minor_across_majors() {
+    let req = req(">=1.8, <3.2");
+    assert!(req.allows_minor(1, MAX));
+    assert!(req.allows_minor(2, 0));
+    assert!(req.allows_minor(2, MAX));
+    assert!(req.allows_minor(3, 1));
+    assert!(!req.allows_minor(3, 2));
+    assert!(!req.allows_minor(1, 7));
+}
+
+#[test]
+fn exact_and_extremes() {
+    let exact = VersionReq::exact(Version::new(4, 5, 6));
+    assert_eq!(exact.major_span(), 4..=4);
+    assert!(exact.allows_minor(4, 5));
+    assert!(!exact.allows_minor(4, 6));
+
+    assert_eq!(VersionReq::STAR.major_span(), 0..=MAX);
+    assert!(VersionReq::STAR.allows_major(MAX));
+    assert!(VersionReq::STAR.allows_minor(MAX, MAX));
+
+    let last = VersionReq::exact(Version::new(MAX, MAX, MAX));
+    assert!(last.allows_minor(MAX, MAX));
+    assert_eq!(last.major_span(), MAX..=MAX);
+}
+
+#[test]
+fn empty_allows_nothing() {
+    let empty = VersionReq::EMPTY;
+    assert!(empty.major_span().is_empty());
+    for major in [0, 1, MAX] {
+        assert!(!empty.allows_major(major));
+        assert!(!empty.allows_minor(major, 0));
+    }
+}
+
+#[test]
+fn agrees_with_matching() {
+    let reqs = [
+        req("<2.0.0"),
+        req("<2.0.1"),
+        req(">1.2.3, <=3"),
+        req("~0.4"),
+        req("^0.0.7"),
+        VersionReq::EMPTY,
+    ];
+    for req in reqs {
+        for major in 0..5 {
+            let any_major = (0..8).any(|minor| req.allows_minor(major, minor));
+            assert_eq!(req.allows_major(major), any_major, "{req} {major}");
+            assert_eq!(
+                req.major_span().contains(&major),
+                req.allows_major(major),
+                "{req} {major}"
+            );
+            for minor in 0..8 {
+                let matched = [0, 1, 2, 3, 7, 8, MAX]
+                    .into_iter()
+                    .any(|patch| req.matches(&Version::new(major, minor, patch)));
+                assert_eq!(
+                    req.allows_minor(major, minor),
+                    matched,
+                    "{req} {major}.{minor}"
+                );
+            }
+        }
+    }
+}
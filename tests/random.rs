@@ -0,0 +1,96 @@
+#![cfg(feature = "rand")]
+
+mod common;
+
+use common::req;
+use fast_version::{Version, VersionReq, VersionReqSet};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+const MAX: u64 = u64::MAX;
+
+fn rng() -> StdRng {
+    StdRng::seed_from_u64(0x5eed)
+}
+
+#[test]
+fn empty() {
+    assert_eq!(VersionReq::EMPTY.random_matching(&mut rng()), None);
+    assert_eq!(VersionReqSet::new().random_matching(&mut rng()), None);
+}
+
+#[test]
+fn exact() {
+    let version = Version::new(1, 2, 3);
+    let req = VersionReq::exact(version);
+    assert_eq!(req.random_matching(&mut rng()), Some(version));
+    assert_eq!(
+        VersionReqSet::from(req).random_matching(&mut rng()),
+        Some(version)
+    );
+}
+
+#[test]
+fn samples_match() {
+    let mut rng = rng();
+    for input in ["*", ">=1.2.3", "<0.0.5", ">=1.2.3, <2", "^0.2", "=1.0.0"] {
+        let req = req(input);
+        let set = req.difference(&self::req(">=1.5.0, <1.6.0"));
+        for _ in 0..1_000 {
+            assert!(req.matches(&req.random_matching(&mut rng).unwrap()));
+            assert!(set.matches(&set.random_matching(&mut rng).unwrap()));
+        }
+    }
+}
+
+#[test]
+fn covers_small_range() {
+    let req = req(">=1.0.7, <=1.0.9");
+    let mut seen = [false; 3];
+    let mut rng = rng();
+    for _ in 0..100 {
+        let version = req.random_matching(&mut rng).unwrap();
+        seen[(version.patch - 7) as usize] = true;
+    }
+    assert_eq!(seen, [true; 3]);
+}
+
+#[test]
+fn crosses_component_boundaries() {
+    let req = VersionReq::between(Version::new(1, 0, MAX - 9), Version::new(1, 1, 9));
+    let mut rng = rng();
+    let below = (0..10_000)
+        .filter(|_| req.random_matching(&mut rng).unwrap().minor == 0)
+        .count();
+    assert!((4_000..6_000).contains(&below), "{}", below);
+}
+
+#[test]
+fn ranges_weighted_by_size() {
+    // 100 versions in the first and 300 in the second range.
+    let set = req(">=1.0.0, <=1.0.99").union(&req(">=2.0.0, <=2.0.299"));
+    assert_eq!(set.ranges().len(), 2);
+    let samples = 40_000;
+    let mut rng = rng();
+    let first = (0..samples)
+        .filter(|_| set.random_matching(&mut rng).unwrap().major == 1)
+        .count() as f64;
+    let second = samples as f64 - first;
+    let expected = [samples as f64 / 4.0, samples as f64 * 3.0 / 4.0];
+    let chi_squared =
+        (first - expected[0]).powi(2) / expected[0] + (second - expected[1]).powi(2) / expected[1];
+    // Critical value for one degree of freedom at p = 0.001.
+    assert!(chi_squared < 10.83, "{} {} {}", first, second, chi_squared);
+}
+
+#[test]
+fn whole_domain() {
+    let set = VersionReq::STAR.difference(&req("=1.2.3"));
+    let mut rng = rng();
+    for _ in 0..100 {
+        let version = set.random_matching(&mut rng).unwrap();
+        assert_ne!(version, Version::new(1, 2, 3));
+    }
+    let set = VersionReqSet::from(VersionReq::STAR);
+    assert!(set.random_matching(&mut rng).is_some());
+}
@@ -0,0 +1,124 @@
+use fast_version::{Version, VersionReq};
+use std::collections::{BTreeMap, BTreeSet};
+use std::ops::{Bound, RangeBounds};
+
+const MAX: u64 = u64::MAX;
+
+fn registry() -> BTreeSet<Version> {
+    [
+        (0, 1, 0),
+        (0, 9, 3),
+        (1, 0, 0),
+        (1, 2, 0),
+        (1, 2, 7),
+        (1, 9, 0),
+        (2, 0, 0),
+        (2, 0, 1),
+        (MAX, MAX, MAX),
+    ]
+    .into_iter()
+    .map(|(major, minor, patch)| Version::new(major, minor, patch))
+    .collect()
+}
+
+fn queried(set: &BTreeSet<Version>, req: &str) -> Vec<Version> {
+    let req = VersionReq::parse(req).unwrap();
+    let found: Vec<_> = set.range(req).copied().collect();
+    let filtered: Vec<_> = set.iter().filter(|v| req.matches(v)).copied().collect();
+    assert_eq!(found, filtered, "{req}");
+    found
+}
+
+#[test]
+fn bounds() {
+    let req = VersionReq::parse(">=1.2, <2").unwrap();
+    assert_eq!(req.start_bound(), Bound::Included(&Version::new(1, 2, 0)));
+    assert_eq!(req.end_bound(), Bound::Included(&Version::new(1, MAX, MAX)));
+    assert_eq!(VersionReq::STAR.start_bound(), Bound::Unbounded);
+    assert_eq!(VersionReq::STAR.end_bound(), Bound::Unbounded);
+    assert_eq!(VersionReq::STABLE.end_bound(), Bound::Unbounded);
+    assert_eq!(VersionReq::PRE_STABLE.start_bound(), Bound::Unbounded);
+}
+
+#[test]
+fn exact() {
+    let set = registry();
+    assert_eq!(queried(&set, "=1.2.7"), [Version::new(1, 2, 7)]);
+    assert!(queried(&set, "=1.2.6").is_empty());
+    assert_eq!(
+        queried(
+            &set,
+            "=18446744073709551615.18446744073709551615.18446744073709551615"
+        ),
+        [Version::new(MAX, MAX, MAX)]
+    );
+}
+
+#[test]
+fn one_sided() {
+    let set = registry();
+    assert_eq!(
+        queried(&set, ">=2"),
+        [
+            Version::new(2, 0, 0),
+            Version::new(2, 0, 1),
+            Version::new(MAX, MAX, MAX)
+        ]
+    );
+    assert_eq!(
+        queried(&set, "<1"),
+        [Version::new(0, 1, 0), Version::new(0, 9, 3)]
+    );
+    assert_eq!(queried(&set, ">1.9.0").len(), 3);
+    assert_eq!(queried(&set, "<=1.0.0").len(), 3);
+    assert_eq!(queried(&set, "*").len(), set.len());
+}
+
+#[test]
+fn compound() {
+    let set = registry();
+    assert_eq!(
+        queried(&set, "^1.2"),
+        [
+            Version::new(1, 2, 0),
+            Version::new(1, 2, 7),
+            Version::new(1, 9, 0)
+        ]
+    );
+    assert_eq!(
+        queried(&set, "~1.2"),
+        [Version::new(1, 2, 0), Version::new(1, 2, 7)]
+    );
+    assert!(queried(&set, ">1.2.0, <1.2.7").is_empty());
+    assert_eq!(queried(&set, ">=0.9.3, <=2.0.0").len(), 6);
+}
+
+#[test]
+fn empty_doesnt_panic() {
+    let set = registry();
+    let empty = VersionReq::STABLE.intersection(&VersionReq::PRE_STABLE);
+    assert_eq!(set.range(empty).count(), 0);
+    assert_eq!(set.range(VersionReq::EMPTY).count(), 0);
+    assert!(!VersionReq::EMPTY.contains(&Version::new(MAX, MAX, MAX)));
+
+    let by_ref: &VersionReq = &empty;
+    assert_eq!(set.range(by_ref).count(), 0);
+
+    let map: BTreeMap<Version, ()> = set.into_iter().map(|v| (v, ())).collect();
+    assert_eq!(map.range(VersionReq::EMPTY).count(), 0);
+}
+
+#[test]
+fn contains_agrees_with_matches() {
+    let set = registry();
+    for req in ["*", "^1.2", "<1", ">=2", "=1.2.7", "<0.0.0"] {
+        let req = VersionReq::parse(req).unwrap();
+        for version in &set {
+            assert_eq!(
+                req.contains(version),
+                req.matches(version),
+                "{req} {version}"
+            );
+        }
+    }
+}
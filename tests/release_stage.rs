@@ -0,0 +1,43 @@
+use fast_version::{ReleaseStage, Version};
+
+#[test]
+fn boundaries() {
+    let cases = [
+        (Version::new(0, 0, 0), ReleaseStage::Experimental),
+        (Version::new(0, 0, 1), ReleaseStage::Experimental),
+        (Version::new(0, 0, u64::MAX), ReleaseStage::Experimental),
+        (Version::new(0, 1, 0), ReleaseStage::Initial),
+        (Version::new(0, u64::MAX, u64::MAX), ReleaseStage::Initial),
+        (Version::new(1, 0, 0), ReleaseStage::Stable),
+        (Version::new(u64::MAX, 0, 0), ReleaseStage::Stable),
+    ];
+    for (version, stage) in cases {
+        assert_eq!(version.stage(), stage, "{}", version);
+        assert_eq!(version.is_stable(), stage == ReleaseStage::Stable);
+    }
+}
+
+#[test]
+fn is_zero() {
+    assert!(Version::new(0, 0, 0).is_zero());
+    assert!(!Version::new(0, 0, 1).is_zero());
+    assert!(!Version::new(0, 1, 0).is_zero());
+    assert!(!Version::new(1, 0, 0).is_zero());
+}
+
+#[test]
+fn const_context() {
+    const STAGE: ReleaseStage = Version::new(0, 2, 0).stage();
+    const STABLE: [bool; 2] = [
+        Version::new(1, 0, 0).is_stable(),
+        Version::new(0, 0, 0).is_zero(),
+    ];
+    assert_eq!(STAGE, ReleaseStage::Initial);
+    assert_eq!(STABLE, [true, true]);
+}
+
+#[test]
+fn ordered_by_stability() {
+    assert!(ReleaseStage::Experimental < ReleaseStage::Initial);
+    assert!(ReleaseStage::Initial < ReleaseStage::Stable);
+}
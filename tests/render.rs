@@ -0,0 +1,222 @@
+#![cfg(feature = "fancy-errors")]
+
+use fast_version::{Version, VersionReq, VersionReqSet};
+
+fn version(source: &str) -> String {
+    Version::parse_checked(source).unwrap_err().render(source)
+}
+
+fn req(source: &str) -> String {
+    VersionReq::parse(source).unwrap_err().render(source)
+}
+
+#[test]
+fn display_stays_terse() {
+    let error = Version::parse_checked("1.x.3").unwrap_err();
+    assert_eq!(error.to_string(), "Minor Parse Error");
+}
+
+#[test]
+fn invalid_digit() {
+    assert_eq!(
+        version("1.2.é"),
+        "\
+error: Patch Parse Error
+  |
+1 | 1.2.é
+  |     ^ expected a digit, found `é`
+"
+    );
+}
+
+#[test]
+fn empty_component() {
+    assert_eq!(
+        version("1..3"),
+        "\
+error: Minor Parse Error
+  |
+1 | 1..3
+  |   ^ expected a digit, found `.`
+"
+    );
+    assert_eq!(
+        version("1.2.3\n"),
+        "\
+error: Patch Parse Error
+  |
+1 | 1.2.3
+  |      ^ expected a digit, found `\\n`
+"
+    );
+}
+
+#[test]
+fn component_count() {
+    assert_eq!(
+        version("1.2"),
+        "\
+error: Expected 3 components, found only 2
+  |
+1 | 1.2
+  |    ^ expected `.`, found the end of the version
+"
+    );
+    assert_eq!(
+        version("1.2.3.4.5"),
+        "\
+error: Expected 3 components, found 5
+  |
+1 | 1.2.3.4.5
+  |      ^ expected the end of the version, found `.`
+"
+    );
+}
+
+#[test]
+fn overflow() {
+    assert_eq!(
+        version("12.3.99999999999999999999"),
+        "\
+error: The patch component with 20 digits is too large
+  |
+1 | 12.3.99999999999999999999
+  |      ^^^^^^^^^^^^^^^^^^^^ doesn't fit into a u64
+"
+    );
+}
+
+#[test]
+fn invalid_utf8_has_no_snippet() {
+    let error = Version::parse_checked_bytes(b"1.\xff.3").unwrap_err();
+    assert_eq!(
+        error.render("1.\u{fffd}.3"),
+        "error: Version is not valid UTF-8\n"
+    );
+}
+
+#[test]
+fn unknown_operator() {
+    assert_eq!(
+        req(">=1.2, =>2"),
+        "\
+error: Unknown operator at position 7
+  |
+1 | >=1.2, =>2
+  |        ^^ expected one of `=`, `>`, `>=`, `<`, `<=`, `^` or `~`
+"
+    );
+}
+
+#[test]
+fn malformed_number() {
+    assert_eq!(
+        req(">= 1.x, <2"),
+        "\
+error: Malformed version at position 3
+  |
+1 | >= 1.x, <2
+  |      ^ expected a digit, found `x`
+"
+    );
+    assert_eq!(
+        req("^1.2.3.4"),
+        "\
+error: Malformed version at position 1
+  |
+1 | ^1.2.3.4
+  |       ^ expected the end of the version, found `.`
+"
+    );
+}
+
+#[test]
+fn conflicting_bounds() {
+    assert_eq!(
+        req(">=1, >=2"),
+        "\
+error: Comparator at position 5 conflicts with the previous one
+  |
+1 | >=1, >=2
+  |      ^^^ conflicts with the previous comparator
+"
+    );
+}
+
+#[test]
+fn too_many_comparators() {
+    assert_eq!(
+        req("1, 2, 3"),
+        "\
+error: Found 3 comparators, at most 2 are supported
+  |
+1 | 1, 2, 3
+  |     ^^^ at most 2 comparators are supported
+"
+    );
+}
+
+#[test]
+fn invalid_prerelease() {
+    assert_eq!(
+        req(">=1.0.0-alpha..1"),
+        "\
+error: Invalid prerelease or build metadata at position 8
+  |
+1 | >=1.0.0-alpha..1
+  |               ^ expected an identifier
+"
+    );
+}
+
+#[test]
+fn version_with_prerelease() {
+    assert_eq!(
+        version("1.2.3-rc.1"),
+        "\
+error: Prerelease or build metadata at position 5, parse a FullVersion instead
+  |
+1 | 1.2.3-rc.1
+  |      ^^^^^ prereleases and build metadata need a full version
+"
+    );
+}
+
+#[test]
+fn prerelease_in_set() {
+    let source = ">=1 || >=1.0.0-rc.1, <2";
+    let error = VersionReqSet::parse(source).unwrap_err();
+    assert_eq!(
+        error.source.render(source),
+        "\
+error: Prerelease at position 14, version requirement sets only hold releases
+  |
+1 | >=1 || >=1.0.0-rc.1, <2
+  |               ^^^^^ prereleases need a single VersionReq
+"
+    );
+}
+
+#[test]
+fn later_line() {
+    let source = ">=1.2,\n<2.x";
+    assert_eq!(
+        req(source),
+        "\
+error: Malformed version at position 8
+  |
+2 | <2.x
+  |    ^ expected a digit, found `x`
+"
+    );
+}
+
+#[test]
+fn foreign_source_doesnt_panic() {
+    let error = VersionReq::parse("1, 2, 3, 4, 5, 6").unwrap_err();
+    assert!(error.render("").starts_with("error: "));
+    let error = VersionReq::parse(">=99999999999999999999").unwrap_err();
+    assert!(error.render("é").starts_with("error: "));
+    let error = Version::parse_checked("1.2.3.4").unwrap_err();
+    assert!(error.render("ü").starts_with("error: "));
+}
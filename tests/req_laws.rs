@@ -0,0 +1,76 @@
+#![cfg(feature = "proptest")]
+
+use fast_version::strategies::{maybe_empty, version, version_req};
+use fast_version::{ReleaseRange, VersionReq, VersionReqSet};
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn generated_requirements_are_not_empty(req in version_req()) {
+        prop_assert!(!req.is_empty());
+    }
+
+    #[test]
+    fn intersection_matches_both(lhs in maybe_empty(), rhs in maybe_empty(), version in version()) {
+        let both = lhs.matches(&version) && rhs.matches(&version);
+        prop_assert_eq!(lhs.intersection(&rhs).matches(&version), both);
+    }
+
+    #[test]
+    fn intersection_laws(a in maybe_empty(), b in maybe_empty(), c in maybe_empty()) {
+        prop_assert_eq!(a.intersection(&b), b.intersection(&a));
+        prop_assert_eq!(
+            a.intersection(&b).intersection(&c),
+            a.intersection(&b.intersection(&c))
+        );
+        prop_assert_eq!(a.intersection(&a), a);
+        prop_assert_eq!(a.intersection(&VersionReq::STAR), a);
+        prop_assert_eq!(a.intersection(&VersionReq::EMPTY), VersionReq::EMPTY);
+    }
+
+    #[test]
+    fn union_matches_either(lhs in maybe_empty(), rhs in maybe_empty(), version in version()) {
+        let either = lhs.matches(&version) || rhs.matches(&version);
+        prop_assert_eq!(lhs.union(&rhs).matches(&version), either);
+        prop_assert_eq!(lhs.union(&rhs), rhs.union(&lhs));
+    }
+
+    #[test]
+    fn difference_and_intersection_partition(lhs in maybe_empty(), rhs in maybe_empty(), version in version()) {
+        let difference = lhs.difference(&rhs).matches(&version);
+        let intersection = lhs.intersection(&rhs).matches(&version);
+        prop_assert!(!(difference && intersection));
+        prop_assert_eq!(difference || intersection, lhs.matches(&version));
+    }
+
+    #[test]
+    fn normalized_through_display(req in maybe_empty()) {
+        prop_assert_eq!(VersionReq::parse(&req.to_string()).unwrap(), req);
+        if req.is_empty() {
+            prop_assert_eq!(req, VersionReq::EMPTY);
+        }
+    }
+
+    #[test]
+    fn sets_are_normalized(ranges in prop::collection::vec(maybe_empty(), 0..6), version in version()) {
+        let set = VersionReqSet::from_unsorted(ranges.clone());
+        prop_assert_eq!(set.matches(&version), ranges.iter().any(|range| range.matches(&version)));
+        prop_assert_eq!(VersionReqSet::from_unsorted(set.ranges().iter().map(ReleaseRange::to_req).collect()), set.clone());
+        prop_assert_eq!(VersionReqSet::parse(&set.to_string()).unwrap(), set);
+    }
+}
+
+#[test]
+fn shrinks_toward_star() {
+    let mut runner = proptest::test_runner::TestRunner::deterministic();
+    let result = runner.run(&version_req(), |req| {
+        prop_assert!(req.is_empty());
+        Ok(())
+    });
+    match result {
+        Err(proptest::test_runner::TestError::Fail(_, minimal)) => {
+            assert_eq!(minimal, VersionReq::STAR)
+        }
+        other => panic!("{other:?}"),
+    }
+}
@@ -0,0 +1,116 @@
+#![cfg(feature = "rkyv")]
+
+mod common;
+
+use common::Rng;
+use fast_version::{ArchivedVersionReq, ArchivedVersionReqSet, Version, VersionReq, VersionReqSet};
+use rkyv::rancor::Error;
+use rkyv::util::AlignedVec;
+use rkyv::vec::ArchivedVec;
+
+impl Rng {
+    fn version(&mut self) -> Version {
+        Version::new(self.next() % 5, self.next() % 5, self.next() % 5)
+    }
+}
+
+/// Copies the bytes like reading them from a file would.
+fn reload(bytes: &[u8]) -> AlignedVec {
+    let mut loaded = AlignedVec::new();
+    loaded.extend_from_slice(bytes);
+    loaded
+}
+
+fn contains<T>(bytes: &[u8], value: &T) -> bool {
+    bytes
+        .as_ptr_range()
+        .contains(&(value as *const T as *const u8))
+}
+
+#[test]
+fn policy_is_queried_in_place() {
+    let mut rng = Rng(0xfeed_f00d);
+    let policy: Vec<VersionReq> = (0..4000)
+        .map(|_| VersionReq::between(rng.version(), rng.version()))
+        .collect();
+    let bytes = reload(&rkyv::to_bytes::<Error>(&policy).unwrap());
+
+    let archived = rkyv::access::<ArchivedVec<ArchivedVersionReq>, Error>(&bytes).unwrap();
+    assert_eq!(archived.len(), policy.len());
+    for (req, archived) in policy.iter().zip(archived.iter()) {
+        assert!(contains(&bytes, archived));
+        for _ in 0..4 {
+            let version = rng.version();
+            assert_eq!(archived.matches(&version), req.matches(&version), "{req}");
+        }
+        assert_eq!(archived.to_native(), *req);
+    }
+}
+
+#[test]
+fn set_is_queried_in_place() {
+    let set = VersionReqSet::parse(">=1, <2, !=1.5.0 || ^3.1 || =7.0.0").unwrap();
+    let bytes = reload(&rkyv::to_bytes::<Error>(&set).unwrap());
+
+    let archived = rkyv::access::<ArchivedVersionReqSet, Error>(&bytes).unwrap();
+    assert_eq!(archived.ranges().len(), set.ranges().len());
+    assert!(contains(&bytes, &archived.ranges()[0]));
+    for version in [
+        Version::new(1, 4, 0),
+        Version::new(1, 5, 0),
+        Version::new(3, 1, 0),
+        Version::new(3, 0, 9),
+        Version::new(7, 0, 0),
+        Version::new(7, 0, 1),
+    ] {
+        assert_eq!(
+            archived.matches(&version),
+            set.matches(&version),
+            "{version}"
+        );
+    }
+
+    let deserialized: VersionReqSet = rkyv::deserialize::<_, Error>(archived).unwrap();
+    assert_eq!(deserialized, set);
+}
+
+#[test]
+fn round_trips() {
+    let version = Version::new(1, u64::MAX, 3);
+    let bytes = rkyv::to_bytes::<Error>(&version).unwrap();
+    assert_eq!(rkyv::from_bytes::<Version, Error>(&bytes).unwrap(), version);
+
+    for req in [
+        VersionReq::STAR,
+        VersionReq::EMPTY,
+        VersionReq::parse("^1.2").unwrap(),
+        VersionReq::parse(">=1.2.3-rc.1, <1.2.3").unwrap(),
+    ] {
+        let bytes = rkyv::to_bytes::<Error>(&req).unwrap();
+        assert_eq!(rkyv::from_bytes::<VersionReq, Error>(&bytes).unwrap(), req);
+    }
+
+    let big = VersionReqSet::from_unsorted(
+        (0..20)
+            .map(|major| VersionReq::exact(Version::new(2 * major, 0, 0)))
+            .collect(),
+    );
+    let bytes = rkyv::to_bytes::<Error>(&big).unwrap();
+    assert_eq!(
+        rkyv::from_bytes::<VersionReqSet, Error>(&bytes).unwrap(),
+        big
+    );
+    assert!(rkyv::from_bytes::<VersionReqSet, Error>(
+        &rkyv::to_bytes::<Error>(&VersionReqSet::new()).unwrap()
+    )
+    .unwrap()
+    .is_empty());
+}
+
+#[test]
+fn truncated_bytes_are_rejected() {
+    let set = VersionReqSet::parse("^1 || ^3").unwrap();
+    let bytes = rkyv::to_bytes::<Error>(&set).unwrap();
+    let truncated = reload(&bytes[..bytes.len() - 4]);
+    assert!(rkyv::access::<ArchivedVersionReqSet, Error>(&truncated).is_err());
+}
@@ -0,0 +1,82 @@
+mod common;
+
+use common::req;
+use fast_version::{RuntimeReq, RuntimeReqError, Version, VersionReq, VersionReqParseError};
+use std::env;
+use std::sync::{Mutex, MutexGuard};
+
+/// The environment is process wide, tests touching it take turns.
+static ENV: Mutex<()> = Mutex::new(());
+
+fn lock_env() -> MutexGuard<'static, ()> {
+    ENV.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[test]
+fn reads_the_variable() {
+    static ACCEPTED: RuntimeReq =
+        RuntimeReq::from_env("FAST_VERSION_TEST_ACCEPTED", VersionReq::STAR);
+    let _env = lock_env();
+    env::set_var("FAST_VERSION_TEST_ACCEPTED", ">=1.4, <2");
+    assert_eq!(ACCEPTED.try_get().unwrap(), req(">=1.4, <2"));
+    assert_eq!(ACCEPTED.get(), req(">=1.4, <2"));
+    assert!(!ACCEPTED.get().matches(&Version::new(1, 3, 0)));
+    env::remove_var("FAST_VERSION_TEST_ACCEPTED");
+}
+
+#[test]
+fn cached_after_first_access() {
+    static CACHED: RuntimeReq = RuntimeReq::from_env("FAST_VERSION_TEST_CACHED", VersionReq::STAR);
+    let _env = lock_env();
+    env::set_var("FAST_VERSION_TEST_CACHED", "^1.2");
+    assert_eq!(CACHED.get(), req("^1.2"));
+
+    env::set_var("FAST_VERSION_TEST_CACHED", "^3");
+    assert_eq!(CACHED.get(), req("^1.2"));
+    env::remove_var("FAST_VERSION_TEST_CACHED");
+    assert_eq!(CACHED.try_get().unwrap(), req("^1.2"));
+}
+
+#[test]
+fn missing_falls_back() {
+    static MISSING: RuntimeReq =
+        RuntimeReq::from_env("FAST_VERSION_TEST_MISSING", VersionReq::STAR);
+    let _env = lock_env();
+    env::remove_var("FAST_VERSION_TEST_MISSING");
+    assert_eq!(MISSING.get(), VersionReq::STAR);
+    let error = MISSING.try_get().unwrap_err();
+    assert!(matches!(
+        error,
+        RuntimeReqError::Missing {
+            var: "FAST_VERSION_TEST_MISSING"
+        }
+    ));
+    assert_eq!(
+        error.to_string(),
+        "Environment variable FAST_VERSION_TEST_MISSING is not set"
+    );
+
+    // Setting it later doesn't help, the missing variable is cached as well.
+    env::set_var("FAST_VERSION_TEST_MISSING", "=1.0.0");
+    assert_eq!(MISSING.get(), VersionReq::STAR);
+    env::remove_var("FAST_VERSION_TEST_MISSING");
+}
+
+#[test]
+fn invalid_falls_back() {
+    let default = req(">=2");
+    let invalid = RuntimeReq::from_env("FAST_VERSION_TEST_INVALID", default);
+    let _env = lock_env();
+    env::set_var("FAST_VERSION_TEST_INVALID", ">=1.x");
+    assert_eq!(invalid.get(), default);
+    assert!(matches!(
+        invalid.try_get(),
+        Err(RuntimeReqError::Parse {
+            var: "FAST_VERSION_TEST_INVALID",
+            source: VersionReqParseError::MalformedNumber { .. },
+        })
+    ));
+    assert_eq!(invalid.var(), "FAST_VERSION_TEST_INVALID");
+    assert_eq!(invalid.default_req(), default);
+    env::remove_var("FAST_VERSION_TEST_INVALID");
+}
@@ -0,0 +1,85 @@
+use fast_version::{
+    Version, VersionReq, VersionReqVariant, VersionReqVariantLowerBound,
+    VersionReqVariantUpperBound,
+};
+
+fn reqs() -> Vec<VersionReq> {
+    let variants = [
+        VersionReqVariant::Strict(Version::new(1, 2, 3)),
+        VersionReqVariant::Compound(
+            VersionReqVariantLowerBound::PatchGreaterEqual {
+                major: 1,
+                minor: 2,
+                patch: 3,
+            },
+            VersionReqVariantUpperBound::MinorLess { major: 2, minor: 0 },
+        ),
+        VersionReqVariant::MajorGreater { major: 1 },
+        VersionReqVariant::MinorGreater { major: 1, minor: 2 },
+        VersionReqVariant::PatchGreater {
+            major: 1,
+            minor: 2,
+            patch: 3,
+        },
+        VersionReqVariant::MajorGreaterEqual { major: 1 },
+        VersionReqVariant::MinorGreaterEqual { major: 1, minor: 2 },
+        VersionReqVariant::PatchGreaterEqual {
+            major: 1,
+            minor: 2,
+            patch: 3,
+        },
+        VersionReqVariant::MajorLess { major: 1 },
+        VersionReqVariant::MinorLess { major: 1, minor: 2 },
+        VersionReqVariant::PatchLess {
+            major: 1,
+            minor: 2,
+            patch: 3,
+        },
+        VersionReqVariant::MajorLessEqual { major: 1 },
+        VersionReqVariant::MinorLessEqual { major: 1, minor: 2 },
+        VersionReqVariant::PatchLessEqual {
+            major: 1,
+            minor: 2,
+            patch: 3,
+        },
+    ];
+    let mut reqs: Vec<VersionReq> = variants.iter().map(VersionReq::new).collect();
+    reqs.push(VersionReq::STAR);
+    reqs
+}
+
+fn versions() -> Vec<Version> {
+    let mut versions = Vec::new();
+    for major in 0..=3 {
+        for minor in 0..=4 {
+            for patch in 0..=4 {
+                versions.push(Version::new(major, minor, patch));
+            }
+        }
+    }
+    versions.push(Version::new(u64::MAX, u64::MAX, u64::MAX));
+    versions
+}
+
+#[test]
+fn satisfies_equals_matches() {
+    for req in reqs() {
+        for version in versions() {
+            assert_eq!(version.satisfies(&req), req.matches(&version));
+        }
+    }
+}
+
+#[test]
+fn satisfies_any_equals_any_match() {
+    let reqs = reqs();
+    for version in versions() {
+        for window in reqs.windows(3) {
+            assert_eq!(
+                version.satisfies_any(window),
+                window.iter().any(|req| req.matches(&version))
+            );
+        }
+        assert!(!version.satisfies_any(&[]));
+    }
+}
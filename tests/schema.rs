@@ -0,0 +1,88 @@
+#![cfg(feature = "schemars")]
+
+use fast_version::{Version, VersionReq};
+use jsonschema::Validator;
+use serde_json::{json, Value};
+
+fn validator<T: schemars::JsonSchema>() -> Validator {
+    let schema = serde_json::to_value(schemars::schema_for!(T)).unwrap();
+    jsonschema::validator_for(&schema).unwrap()
+}
+
+#[test]
+fn serialized_requirements_are_valid() {
+    let validator = validator::<VersionReq>();
+    for input in [
+        "*",
+        "<0.0.0",
+        "=1.2.3",
+        ">=1.2.3, <2",
+        "^0.2",
+        "~1.2.3",
+        ">=18446744073709551615.0.0",
+    ] {
+        let req = VersionReq::parse(input).unwrap();
+        let value = serde_json::to_value(req).unwrap();
+        assert!(validator.is_valid(&value), "{value}");
+        assert!(validator.is_valid(&json!(input)), "{input}");
+    }
+}
+
+#[test]
+fn legacy_struct_form_is_rejected() {
+    let validator = validator::<VersionReq>();
+    let legacy = json!({
+        "major_lower": 1,
+        "minor_lower": 0,
+        "patch_lower": 0,
+        "major_higher": 2,
+        "minor_higher": 0,
+        "patch_higher": 0,
+    });
+    assert!(!validator.is_valid(&legacy));
+    // The legacy form still deserializes, it's just not advertised.
+    assert!(serde_json::from_value::<VersionReq>(legacy).is_ok());
+}
+
+#[test]
+fn pattern_rejects_other_strings() {
+    let validator = validator::<VersionReq>();
+    for value in [json!("latest"), json!("1.2.3 beta"), json!(""), json!(3)] {
+        assert!(!validator.is_valid(&value), "{value}");
+    }
+}
+
+#[test]
+fn documented_with_examples() {
+    let schema = serde_json::to_value(schemars::schema_for!(VersionReq)).unwrap();
+    assert_eq!(schema["type"], "string");
+    assert!(schema["description"]
+        .as_str()
+        .unwrap()
+        .contains("comparator"));
+    let examples = schema["examples"].as_array().unwrap();
+    assert!(examples.contains(&Value::from(">=1.2.3, <2")));
+    for example in examples {
+        assert!(VersionReq::parse(example.as_str().unwrap()).is_ok());
+    }
+}
+
+#[test]
+fn version_schema_matches_serialized_versions() {
+    let validator = validator::<Version>();
+    let value = serde_json::to_value(Version::new(1, 2, u64::MAX)).unwrap();
+    assert!(validator.is_valid(&value), "{value}");
+    assert!(!validator.is_valid(&json!({ "major": 1, "minor": 2 })));
+    // The struct form still deserializes, it's just not advertised.
+    let legacy = json!({ "major": 1, "minor": 2, "patch": 3 });
+    assert!(!validator.is_valid(&legacy));
+    assert!(serde_json::from_value::<Version>(legacy).is_ok());
+    for value in [json!("1.2"), json!("v1.2.3"), json!("1.2.3-rc.1")] {
+        assert!(!validator.is_valid(&value), "{value}");
+    }
+
+    let schema = serde_json::to_value(schemars::schema_for!(Version)).unwrap();
+    for example in schema["examples"].as_array().unwrap() {
+        assert!(example.as_str().unwrap().parse::<Version>().is_ok());
+    }
+}
@@ -0,0 +1,309 @@
+#![cfg(feature = "semver-interop")]
+
+use fast_version::semver_interop::{SemverLossError, SemverReqError};
+use fast_version::{Version, VersionReq};
+use std::str::FromStr;
+
+const CORPUS: &[&str] = &[
+    "0.0.0",
+    "0.0.1",
+    "0.1.0",
+    "0.1.0-alpha",
+    "1.0.0-alpha.1",
+    "1.0.0-0",
+    "1.0.0",
+    "1.0.0+build.1",
+    "1.2.3",
+    "1.2.3-rc.1+build",
+    "1.2.4",
+    "1.9.9",
+    "2.0.0-beta",
+    "2.0.0",
+    "2.0.0+sha.abc",
+    "3.1.4",
+    "18446744073709551615.0.0",
+    "18446744073709551615.18446744073709551615.18446744073709551615",
+    "18446744073709551615.18446744073709551615.18446744073709551615-pre",
+];
+
+const REQS: &[&str] = &[
+    "*",
+    "<0.0.0",
+    "=1.2.3",
+    "^1.2",
+    "~0.1",
+    ">=1.0.0, <2.0.0",
+    ">1.2.3",
+    "<=2",
+    ">=18446744073709551615",
+];
+
+/// The detour without the interop: going through a string, where prereleases fail to parse.
+fn converted(version: &semver::Version) -> Option<Version> {
+    if !version.pre.is_empty() {
+        return None;
+    }
+    let numeric = format!("{}.{}.{}", version.major, version.minor, version.patch);
+    Some(Version::from_str(&numeric).unwrap())
+}
+
+#[test]
+fn agrees_with_converting_first() {
+    for req in REQS {
+        let req = VersionReq::parse(req).unwrap();
+        for version in CORPUS {
+            let version = semver::Version::parse(version).unwrap();
+            let expected = converted(&version).is_some_and(|version| req.matches(&version));
+            assert_eq!(req.matches_semver(&version), expected, "{req} {version}");
+        }
+    }
+}
+
+#[test]
+fn prereleases_never_match() {
+    for version in CORPUS.iter().filter(|version| version.contains('-')) {
+        let version = semver::Version::parse(version).unwrap();
+        assert!(!VersionReq::STAR.matches_semver(&version), "{version}");
+        let exact = VersionReq::exact(Version::new(version.major, version.minor, version.patch));
+        assert!(!exact.matches_semver(&version), "{version}");
+    }
+}
+
+#[test]
+fn build_metadata_is_ignored() {
+    let req = VersionReq::parse("=1.0.0").unwrap();
+    assert!(req.matches_semver(&semver::Version::parse("1.0.0+build.1").unwrap()));
+    assert!(req.matches_semver(&semver::Version::new(1, 0, 0)));
+    assert!(!req.matches_semver(&semver::Version::new(1, 0, 1)));
+}
+
+#[test]
+fn try_from_clean() {
+    for version in CORPUS {
+        let semver = semver::Version::parse(version).unwrap();
+        if semver.pre.is_empty() && semver.build.is_empty() {
+            assert_eq!(
+                Version::try_from(&semver),
+                Ok(Version::from_str(version).unwrap())
+            );
+            assert_eq!(
+                Version::try_from(semver.clone()),
+                Version::try_from(&semver)
+            );
+        }
+    }
+}
+
+#[test]
+fn try_from_prerelease() {
+    let rc = semver::Version::parse("1.2.3-rc.1").unwrap();
+    assert_eq!(
+        Version::try_from(&rc),
+        Err(SemverLossError::Prerelease("rc.1".to_string()))
+    );
+    // The prerelease is reported even if there's build metadata too.
+    let both = semver::Version::parse("1.2.3-rc.1+build").unwrap();
+    assert_eq!(
+        Version::try_from(both),
+        Err(SemverLossError::Prerelease("rc.1".to_string()))
+    );
+}
+
+#[test]
+fn try_from_build_metadata() {
+    let build = semver::Version::parse("2.0.0+sha.abc").unwrap();
+    let error = Version::try_from(&build).unwrap_err();
+    assert_eq!(error, SemverLossError::BuildMetadata("sha.abc".to_string()));
+    assert_eq!(
+        error.to_string(),
+        "The build metadata sha.abc can't be represented"
+    );
+}
+
+#[test]
+fn from_semver_lossy() {
+    for version in CORPUS {
+        let semver = semver::Version::parse(version).unwrap();
+        let (lossy, pre, build) = Version::from_semver_lossy(&semver);
+        assert_eq!(
+            lossy,
+            Version::new(semver.major, semver.minor, semver.patch)
+        );
+        assert_eq!(pre, semver.pre);
+        assert_eq!(build, semver.build);
+        if pre.is_empty() && build.is_empty() {
+            assert_eq!(Version::try_from(&semver), Ok(lossy));
+        } else {
+            assert!(Version::try_from(&semver).is_err());
+        }
+    }
+}
+
+/// Requirements in the syntax of the semver crate, where a bare version is a caret requirement.
+const SEMVER_REQS: &[&str] = &[
+    "*",
+    "1.2.3",
+    "1.2",
+    "1",
+    "=1.2.3",
+    "=1.2",
+    "=1",
+    "^1.2.3",
+    "^0.2.3",
+    "^0.0.3",
+    "^0.0",
+    "^0",
+    "~1.2.3",
+    "~1.2",
+    "~1",
+    ">1.2.3",
+    ">1.2",
+    ">1",
+    ">=1.2",
+    "<1.2.3",
+    "<1.2",
+    "<2",
+    "<=1.2.3",
+    "<=1.2",
+    "<=1",
+    "1.2.*",
+    "1.*",
+    "<0.0.0",
+    ">=1.0.0, <2.0.0",
+    ">=2, <1",
+    ">=1, <3, ^2.1, ~2.3",
+    ">18446744073709551615",
+    "^18446744073709551615.18446744073709551615.18446744073709551615",
+    ">=1.0.0-alpha",
+    ">1.0.0-alpha.1",
+    "<2.0.0-beta",
+    "<=1.2.3-rc.1",
+    "=1.2.3-rc.1",
+    "1.2.3-beta",
+    "^1.2.3-rc.1",
+    "^0.2.3-alpha",
+    "^0.0.3-0",
+    "~1.2.3-beta",
+    ">=1.0.0-alpha, <1.0.0",
+    ">=1.0.0-alpha, <=1.0.0-rc.1",
+    ">=1.2.3-rc.1, <2.0.0-beta",
+    ">=1, <2.0.0-rc.1",
+    ">=1.2.3-alpha, <2, ~1.2.3-beta",
+    ">=18446744073709551615.18446744073709551615.18446744073709551615-pre",
+];
+
+/// Every version made of components close to the boundaries of the requirements above, with and
+/// without a prerelease.
+fn boundary_versions() -> Vec<semver::Version> {
+    const COMPONENTS: [u64; 9] = [0, 1, 2, 3, 4, 7, 8, u64::MAX - 1, u64::MAX];
+    let mut versions: Vec<_> = CORPUS
+        .iter()
+        .map(|version| semver::Version::parse(version).unwrap())
+        .collect();
+    for major in COMPONENTS {
+        for minor in COMPONENTS {
+            for patch in COMPONENTS {
+                versions.push(semver::Version::new(major, minor, patch));
+                for pre in ["0", "alpha", "alpha.1", "beta", "pre", "rc.1", "rc.2"] {
+                    let mut version = semver::Version::new(major, minor, patch);
+                    version.pre = semver::Prerelease::new(pre).unwrap();
+                    versions.push(version);
+                }
+            }
+        }
+    }
+    versions
+}
+
+#[test]
+fn req_matches_like_semver() {
+    let versions = boundary_versions();
+    for input in SEMVER_REQS {
+        let semver_req = semver::VersionReq::parse(input).unwrap();
+        let req = VersionReq::try_from(&semver_req).unwrap();
+        for version in &versions {
+            assert_eq!(
+                req.matches_semver(version),
+                semver_req.matches(version),
+                "{input} {version}"
+            );
+        }
+    }
+}
+
+#[test]
+fn req_round_trips_through_both_crates() {
+    for input in SEMVER_REQS {
+        let semver_req = semver::VersionReq::parse(input).unwrap();
+        let req = VersionReq::try_from(&semver_req).unwrap();
+        assert_eq!(VersionReq::try_from(semver_req.clone()), Ok(req), "{input}");
+        // Back through the syntax of this crate, which the semver crate reads the same way.
+        let reparsed = semver::VersionReq::parse(&req.to_string()).unwrap();
+        assert_eq!(VersionReq::try_from(&reparsed), Ok(req), "{input} {req}");
+        // Up to two comparators with an explicit operator mean the same in both crates.
+        let explicit = !input.starts_with(|c: char| c.is_ascii_digit()) && !input.contains('*');
+        if explicit && input.matches(',').count() < 2 {
+            // Except that this crate rejects conflicting bounds instead of matching nothing.
+            match VersionReq::parse(input) {
+                Ok(parsed) => assert_eq!(parsed, req, "{input}"),
+                Err(_) => assert!(req.is_empty(), "{input}"),
+            }
+        }
+    }
+}
+
+#[test]
+fn bare_versions_are_caret_requirements() {
+    for (bare, caret) in [("1.2.3", "^1.2.3"), ("1.2", "^1.2"), ("0.2.3", "^0.2.3")] {
+        let semver_req = semver::VersionReq::parse(bare).unwrap();
+        assert_eq!(
+            VersionReq::try_from(&semver_req),
+            Ok(VersionReq::parse(caret).unwrap())
+        );
+        assert_ne!(
+            VersionReq::try_from(&semver_req),
+            Ok(VersionReq::parse(bare).unwrap())
+        );
+    }
+    let star = semver::VersionReq::STAR;
+    assert!(star.comparators.is_empty());
+    assert_eq!(VersionReq::try_from(&star), Ok(VersionReq::STAR));
+}
+
+#[test]
+fn req_with_prerelease() {
+    for (input, expected) in [
+        (">=1.2.3-rc.1", ">=1.2.3-rc.1"),
+        ("<2.0.0-rc.1, >=1", ">=1, <2.0.0-rc.1"),
+        ("=0.1.0-alpha", "=0.1.0-alpha"),
+    ] {
+        let semver_req = semver::VersionReq::parse(input).unwrap();
+        assert_eq!(
+            VersionReq::try_from(&semver_req),
+            Ok(VersionReq::parse(expected).unwrap()),
+            "{input}"
+        );
+    }
+    let long = format!("~1.2.3-{}", "beta".repeat(9));
+    let error = VersionReq::try_from(&semver::VersionReq::parse(&long).unwrap()).unwrap_err();
+    assert_eq!(error, SemverReqError::Prerelease(long.clone()));
+    assert_eq!(
+        error.to_string(),
+        format!("The comparator {long} has a prerelease longer than requirements can represent")
+    );
+}
+
+#[test]
+fn version_into_semver() {
+    for version in CORPUS {
+        let semver = semver::Version::parse(version).unwrap();
+        let (lossy, _, _) = Version::from_semver_lossy(&semver);
+        let back = semver::Version::from(lossy);
+        assert_eq!(Version::try_from(&back), Ok(lossy));
+        assert!(back.pre.is_empty() && back.build.is_empty());
+        assert_eq!(back.to_string(), lossy.to_string());
+        if semver.pre.is_empty() && semver.build.is_empty() {
+            assert_eq!(back, semver);
+        }
+    }
+}
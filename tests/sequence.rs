@@ -0,0 +1,131 @@
+use fast_version::{SequenceError, SequenceSummary, Version, VersionSequence};
+
+const MAX: u64 = u64::MAX;
+
+fn versions(versions: &[(u64, u64, u64)]) -> Vec<Version> {
+    versions
+        .iter()
+        .map(|&(major, minor, patch)| Version::new(major, minor, patch))
+        .collect()
+}
+
+#[test]
+fn clean_sequence() {
+    let released = versions(&[
+        (0, 1, 0),
+        (0, 1, 1),
+        (0, 2, 0),
+        (1, 0, 0),
+        (1, 0, 1),
+        (1, 0, 2),
+        (1, 1, 0),
+        (2, 0, 0),
+    ]);
+    let summary = VersionSequence::STRICT.validate(released.clone()).unwrap();
+    assert_eq!(
+        summary,
+        SequenceSummary {
+            len: 8,
+            major: 2,
+            minor: 2,
+            patch: 3,
+            retags: 0,
+        }
+    );
+    assert_eq!(
+        VersionSequence::ALLOW_RETAGS.validate(released),
+        Ok(summary)
+    );
+}
+
+#[test]
+fn empty_and_single() {
+    assert_eq!(
+        VersionSequence::STRICT.validate([]),
+        Ok(SequenceSummary::default())
+    );
+    let summary = VersionSequence::STRICT
+        .validate([Version::new(3, 1, 4)])
+        .unwrap();
+    assert_eq!(summary.len, 1);
+    assert_eq!(summary.major + summary.minor + summary.patch, 0);
+}
+
+#[test]
+fn decreasing_pair() {
+    let released = versions(&[(1, 0, 0), (1, 0, 1), (1, 0, 0), (1, 0, 1)]);
+    let error = VersionSequence::STRICT.validate(released).unwrap_err();
+    assert_eq!(
+        error,
+        SequenceError::Decreasing {
+            index: 2,
+            previous: Version::new(1, 0, 1),
+            version: Version::new(1, 0, 0),
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "Version 1.0.0 at index 2 is lower than the previous version 1.0.1"
+    );
+    let backwards = versions(&[(2, 0, 0), (1, 9, 0)]);
+    assert!(matches!(
+        VersionSequence::ALLOW_RETAGS.validate(backwards),
+        Err(SequenceError::Decreasing { index: 1, .. })
+    ));
+}
+
+#[test]
+fn duplicates() {
+    let released = versions(&[(1, 0, 0), (1, 1, 0), (1, 1, 0), (1, 1, 1)]);
+    assert_eq!(
+        VersionSequence::STRICT.validate(released.clone()),
+        Err(SequenceError::Duplicate {
+            index: 2,
+            version: Version::new(1, 1, 0),
+        })
+    );
+    let summary = VersionSequence { allow_retags: true }
+        .validate(released)
+        .unwrap();
+    assert_eq!((summary.len, summary.retags), (4, 1));
+    assert_eq!((summary.minor, summary.patch), (1, 1));
+}
+
+#[test]
+fn gaps() {
+    for (previous, version) in [
+        ((1, 2, 3), (1, 2, 5)),
+        ((1, 2, 3), (1, 4, 0)),
+        ((1, 2, 3), (1, 3, 3)),
+        ((1, 2, 3), (3, 0, 0)),
+        ((1, 2, 3), (2, 0, 1)),
+    ] {
+        let released = versions(&[(1, 2, 2), previous, version]);
+        assert_eq!(
+            VersionSequence::STRICT.validate(released),
+            Err(SequenceError::Gap {
+                index: 2,
+                previous: Version::new(previous.0, previous.1, previous.2),
+                version: Version::new(version.0, version.1, version.2),
+            }),
+            "{previous:?} {version:?}"
+        );
+    }
+}
+
+#[test]
+fn bumps_at_max() {
+    let released = versions(&[(1, MAX, MAX), (2, 0, 0)]);
+    assert_eq!(VersionSequence::STRICT.validate(released).unwrap().major, 1);
+    let overflow = versions(&[(1, 2, MAX), (1, 3, 0)]);
+    assert_eq!(VersionSequence::STRICT.validate(overflow).unwrap().minor, 1);
+}
+
+#[test]
+fn stops_at_first_error() {
+    let released = versions(&[(1, 0, 0), (1, 0, 0), (0, 1, 0)]);
+    assert!(matches!(
+        VersionSequence::default().validate(released),
+        Err(SequenceError::Duplicate { index: 1, .. })
+    ));
+}
@@ -0,0 +1,70 @@
+#![cfg(feature = "serde")]
+
+use fast_version::Version;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Release {
+    #[serde(with = "fast_version::serde_helpers::version_with_defaults")]
+    version: Version,
+}
+
+fn parse(value: &str) -> Result<Version, String> {
+    serde_json::from_str::<Release>(&format!(r#"{{"version":{}}}"#, value))
+        .map(|release| release.version)
+        .map_err(|error| error.to_string())
+}
+
+#[test]
+fn omitted_components() {
+    assert_eq!(
+        parse(r#"{"major":2,"minor":1,"patch":5}"#),
+        Ok(Version::new(2, 1, 5))
+    );
+    assert_eq!(parse(r#"{"major":2,"minor":1}"#), Ok(Version::new(2, 1, 0)));
+    assert_eq!(parse(r#"{"major":2,"patch":5}"#), Ok(Version::new(2, 0, 5)));
+    assert_eq!(parse(r#"{"major":2}"#), Ok(Version::new(2, 0, 0)));
+    assert_eq!(parse(r#"{"patch":5,"major":2}"#), Ok(Version::new(2, 0, 5)));
+}
+
+#[test]
+fn major_missing() {
+    for value in [r#"{"minor":1,"patch":5}"#, r#"{"minor":1}"#, "{}"] {
+        assert!(parse(value)
+            .unwrap_err()
+            .starts_with("missing field `major`"));
+    }
+}
+
+#[test]
+fn unknown_and_duplicate_fields() {
+    assert!(parse(r#"{"major":2,"build":1}"#)
+        .unwrap_err()
+        .starts_with("unknown field `build`, expected one of `major`, `minor`, `patch`"));
+    assert!(parse(r#"{"major":2,"major":3}"#)
+        .unwrap_err()
+        .starts_with("duplicate field `major`"));
+    assert!(parse(r#"{"major":2,"minor":-1}"#)
+        .unwrap_err()
+        .starts_with("minor must not be negative, found -1"));
+}
+
+#[test]
+fn string_form() {
+    assert_eq!(parse(r#""2.1.5""#), Ok(Version::new(2, 1, 5)));
+    assert_eq!(parse(r#""2.1""#), Ok(Version::new(2, 1, 0)));
+    assert_eq!(parse(r#""2""#), Ok(Version::new(2, 0, 0)));
+    assert!(parse(r#""2.x""#).is_err());
+    assert!(parse(r#""""#).is_err());
+    assert!(parse("2").is_err());
+}
+
+#[test]
+fn serializes_full_version() {
+    let release = Release {
+        version: Version::new(2, 1, 0),
+    };
+    let json = serde_json::to_string(&release).unwrap();
+    assert_eq!(json, r#"{"version":"2.1.0"}"#);
+    assert_eq!(serde_json::from_str::<Release>(&json).unwrap(), release);
+}
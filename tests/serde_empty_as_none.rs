@@ -0,0 +1,119 @@
+#![cfg(feature = "serde")]
+
+use fast_version::Version;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Report {
+    #[serde(with = "fast_version::serde_helpers::option_version_empty_as_none")]
+    version: Option<Version>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct StringReport {
+    #[serde(with = "fast_version::serde_helpers::option_version_empty_as_none::empty")]
+    version: Option<Version>,
+}
+
+fn parse(value: &str) -> Result<Option<Version>, String> {
+    serde_json::from_str::<Report>(&format!(r#"{{"version":{value}}}"#))
+        .map(|report| report.version)
+        .map_err(|error| error.to_string())
+}
+
+#[test]
+fn empty_and_whitespace() {
+    assert_eq!(parse(r#""""#), Ok(None));
+    assert_eq!(parse(r#"" ""#), Ok(None));
+    assert_eq!(parse(r#""\t\n  ""#), Ok(None));
+}
+
+#[test]
+fn null() {
+    assert_eq!(parse("null"), Ok(None));
+}
+
+#[test]
+fn valid() {
+    assert_eq!(parse(r#""1.2.3""#), Ok(Some(Version::new(1, 2, 3))));
+    assert_eq!(parse(r#""0.0.0""#), Ok(Some(Version::new(0, 0, 0))));
+}
+
+#[test]
+fn invalid() {
+    assert_eq!(
+        parse(r#""1.2""#).unwrap_err(),
+        "Expected 3 components, found only 2 at line 1 column 16"
+    );
+    assert_eq!(
+        parse(r#""1.x.3""#).unwrap_err(),
+        "Minor Parse Error at line 1 column 18"
+    );
+    // No trimming around actual versions, like the regular parser.
+    assert!(parse(r#"" 1.2.3""#).is_err());
+    assert!(parse("1").is_err());
+    assert!(parse(r#"{"major":1,"minor":2,"patch":3}"#).is_err());
+}
+
+#[test]
+fn serialize() {
+    let some = Report {
+        version: Some(Version::new(1, 2, 3)),
+    };
+    let none = Report { version: None };
+    assert_eq!(
+        serde_json::to_string(&some).unwrap(),
+        r#"{"version":"1.2.3"}"#
+    );
+    assert_eq!(serde_json::to_string(&none).unwrap(), r#"{"version":null}"#);
+    for report in [some, none] {
+        let json = serde_json::to_string(&report).unwrap();
+        assert_eq!(serde_json::from_str::<Report>(&json).unwrap(), report);
+    }
+}
+
+#[test]
+fn serialize_none_as_empty() {
+    let none = StringReport { version: None };
+    assert_eq!(serde_json::to_string(&none).unwrap(), r#"{"version":""}"#);
+    assert_eq!(
+        serde_json::from_str::<StringReport>(r#"{"version":""}"#).unwrap(),
+        none
+    );
+    assert_eq!(
+        serde_json::from_str::<StringReport>(r#"{"version":null}"#).unwrap(),
+        none
+    );
+    let some = StringReport {
+        version: Some(Version::new(4, 5, 6)),
+    };
+    assert_eq!(
+        serde_json::to_string(&some).unwrap(),
+        r#"{"version":"4.5.6"}"#
+    );
+}
+
+#[test]
+fn binary_format() {
+    for report in [
+        Report {
+            version: Some(Version::new(1, 2, 3)),
+        },
+        Report { version: None },
+    ] {
+        let bytes = postcard::to_allocvec(&report).unwrap();
+        assert_eq!(postcard::from_bytes::<Report>(&bytes).unwrap(), report);
+    }
+    for report in [
+        StringReport {
+            version: Some(Version::new(1, 2, 3)),
+        },
+        StringReport { version: None },
+    ] {
+        let bytes = postcard::to_allocvec(&report).unwrap();
+        assert_eq!(
+            postcard::from_bytes::<StringReport>(&bytes).unwrap(),
+            report
+        );
+    }
+}
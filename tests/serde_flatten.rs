@@ -0,0 +1,125 @@
+#![cfg(feature = "serde")]
+
+use fast_version::serde_helpers::VersionFields;
+use fast_version::Version;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Row {
+    name: String,
+    #[serde(flatten)]
+    version: VersionFields,
+    downloads: u64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct NestedRow {
+    name: String,
+    version: VersionFields,
+    downloads: u64,
+}
+
+const CSV: &str = "\
+name,major,minor,patch,downloads
+serde,1,0,219,900
+fast-version,0,2,18446744073709551615,3
+";
+
+fn rows() -> Vec<Row> {
+    vec![
+        Row {
+            name: "serde".to_string(),
+            version: Version::new(1, 0, 219).into(),
+            downloads: 900,
+        },
+        Row {
+            name: "fast-version".to_string(),
+            version: Version::new(0, 2, u64::MAX).into(),
+            downloads: 3,
+        },
+    ]
+}
+
+#[test]
+fn conversions() {
+    let version = Version::new(1, 2, 3);
+    let fields = VersionFields::from(version);
+    assert_eq!((fields.major, fields.minor, fields.patch), (1, 2, 3));
+    let back: Version = fields.into();
+    assert_eq!(back, version);
+}
+
+#[test]
+fn csv_reads_flattened() {
+    let mut reader = csv::Reader::from_reader(CSV.as_bytes());
+    let read: Vec<Row> = reader.deserialize().collect::<Result<_, _>>().unwrap();
+    assert_eq!(read, rows());
+}
+
+#[test]
+fn csv_columns_in_any_order() {
+    let data = "patch,downloads,minor,name,major\n219,900,0,serde,1\n";
+    let mut reader = csv::Reader::from_reader(data.as_bytes());
+    let read: Row = reader.deserialize().next().unwrap().unwrap();
+    assert_eq!(read, rows().remove(0));
+}
+
+#[test]
+fn csv_rejects_bad_components() {
+    for data in [
+        "name,major,minor,patch,downloads\nserde,-1,0,0,1\n",
+        "name,major,minor,patch,downloads\nserde,1,x,0,1\n",
+        "name,major,minor,patch,downloads\nserde,1,0,18446744073709551616,1\n",
+        "name,major,minor,downloads\nserde,1,0,1\n",
+    ] {
+        let mut reader = csv::Reader::from_reader(data.as_bytes());
+        assert!(
+            reader.deserialize::<Row>().next().unwrap().is_err(),
+            "{data}"
+        );
+    }
+}
+
+#[test]
+fn csv_writes_nested_without_headers() {
+    // The csv crate can't serialize flattened fields, but writes nested structs inline as long
+    // as it doesn't derive the headers.
+    let mut writer = csv::WriterBuilder::new()
+        .has_headers(false)
+        .from_writer(vec![]);
+    writer
+        .write_record(["name", "major", "minor", "patch", "downloads"])
+        .unwrap();
+    for row in rows() {
+        let nested = NestedRow {
+            name: row.name,
+            version: row.version,
+            downloads: row.downloads,
+        };
+        writer.serialize(nested).unwrap();
+    }
+    let written = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+    assert_eq!(written, CSV);
+}
+
+#[test]
+fn json_round_trip() {
+    let row = rows().remove(0);
+    let json = serde_json::to_string(&row).unwrap();
+    assert_eq!(
+        json,
+        r#"{"name":"serde","major":1,"minor":0,"patch":219,"downloads":900}"#
+    );
+    assert_eq!(serde_json::from_str::<Row>(&json).unwrap(), row);
+}
+
+#[test]
+fn query_string_round_trip() {
+    let row = rows().remove(1);
+    let query = serde_qs::to_string(&row).unwrap();
+    assert_eq!(
+        query,
+        "name=fast-version&major=0&minor=2&patch=18446744073709551615&downloads=3"
+    );
+    assert_eq!(serde_qs::from_str::<Row>(&query).unwrap(), row);
+}
@@ -0,0 +1,85 @@
+#![cfg(feature = "serde")]
+//! The serde representations that work without `alloc`, through the `no_std` serde-json-core.
+
+use fast_version::{Version, VersionReq};
+use serde::{Deserialize, Serialize};
+
+fn to_json<T: Serialize>(value: &T) -> String {
+    let mut buffer = [0; 128];
+    let len = serde_json_core::to_slice(value, &mut buffer).unwrap();
+    String::from_utf8(buffer[..len].to_vec()).unwrap()
+}
+
+fn from_json<'a, T: Deserialize<'a>>(json: &'a str) -> T {
+    let (value, len) = serde_json_core::from_str(json).unwrap();
+    assert_eq!(len, json.len());
+    value
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct VersionString(#[serde(with = "fast_version::serde_helpers::version_as_str")] Version);
+
+#[test]
+fn version_needs_the_helper() {
+    let version = Version::new(1, 2, u64::MAX);
+    let json = to_json(&version);
+    assert_eq!(json, r#""1.2.18446744073709551615""#);
+    assert_eq!(to_json(&VersionString(version)), json);
+    assert_eq!(from_json::<VersionString>(&json), VersionString(version));
+    // The default impl also accepts the struct form, which needs `deserialize_any`.
+    assert!(serde_json_core::from_str::<Version>(&json).is_err());
+}
+
+#[test]
+fn version_rejections() {
+    for json in [
+        r#""1.2""#,
+        r#""1.2.-3""#,
+        r#"{"major":1,"minor":2,"patch":3}"#,
+        "3",
+    ] {
+        assert!(
+            serde_json_core::from_str::<VersionString>(json).is_err(),
+            "{json}"
+        );
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Helpers {
+    #[serde(with = "fast_version::serde_helpers::version_as_tuple")]
+    tuple: Version,
+    #[serde(with = "fast_version::serde_helpers::version_as_packed_u64")]
+    packed: Version,
+    #[serde(with = "fast_version::serde_helpers::version_req_as_str")]
+    req: VersionReq,
+}
+
+#[test]
+fn helpers_round_trip() {
+    for input in [">=1.2.0, <2.0.0", "*", "=1.2.3", "<0.0.0"] {
+        let helpers = Helpers {
+            tuple: Version::new(1, 2, 3),
+            packed: Version::new(1, 2, 3),
+            req: VersionReq::parse(input).unwrap(),
+        };
+        let json = to_json(&helpers);
+        assert_eq!(
+            json,
+            format!(
+                r#"{{"tuple":[1,2,3],"packed":281475010265091,"req":"{}"}}"#,
+                helpers.req
+            )
+        );
+        assert_eq!(from_json::<Helpers>(&json), helpers);
+    }
+}
+
+#[test]
+fn version_req_needs_the_helper() {
+    let req = VersionReq::parse("^1.2").unwrap();
+    let json = to_json(&req);
+    assert_eq!(json, r#"">=1.2.0, <2.0.0""#);
+    // The default impl also accepts the legacy struct form, which needs `deserialize_any`.
+    assert!(serde_json_core::from_str::<VersionReq>(&json).is_err());
+}
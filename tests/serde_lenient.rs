@@ -0,0 +1,79 @@
+#![cfg(feature = "serde")]
+
+use fast_version::Version;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Feed {
+    #[serde(with = "fast_version::serde_helpers::lenient")]
+    version: Version,
+}
+
+fn parse(value: &str) -> Result<Version, String> {
+    serde_json::from_str::<Feed>(&format!(r#"{{"version":{}}}"#, value))
+        .map(|feed| feed.version)
+        .map_err(|error| error.to_string())
+}
+
+#[test]
+fn strings() {
+    assert_eq!(parse(r#""1.2.3""#), Ok(Version::new(1, 2, 3)));
+    assert_eq!(parse(r#""v1.2.3""#), Ok(Version::new(1, 2, 3)));
+    assert_eq!(parse(r#""V1.2.3""#), Ok(Version::new(1, 2, 3)));
+    assert_eq!(parse(r#"" 1.2.3 ""#), Ok(Version::new(1, 2, 3)));
+    assert_eq!(parse(r#""\tv1.2\n""#), Ok(Version::new(1, 2, 0)));
+    assert_eq!(parse(r#""7""#), Ok(Version::new(7, 0, 0)));
+
+    assert!(parse(r#""""#).is_err());
+    assert!(parse(r#""vv1.2.3""#).is_err());
+    assert!(parse(r#""1.2.3.4""#).is_err());
+    assert!(parse(r#""1.2.x""#).unwrap_err().starts_with(
+        "invalid value: string \"1.2.x\", expected a version string, integer or float"
+    ));
+}
+
+#[test]
+fn integers() {
+    assert_eq!(parse("3"), Ok(Version::new(3, 0, 0)));
+    assert_eq!(parse("0"), Ok(Version::new(0, 0, 0)));
+    assert_eq!(
+        parse("18446744073709551615"),
+        Ok(Version::new(u64::MAX, 0, 0))
+    );
+    assert!(parse("-1").is_err());
+}
+
+#[test]
+fn floats() {
+    assert_eq!(parse("1.2"), Ok(Version::new(1, 2, 0)));
+    assert_eq!(parse("0.5"), Ok(Version::new(0, 5, 0)));
+    assert_eq!(parse("2.0"), Ok(Version::new(2, 0, 0)));
+    assert!(parse("-1.2").is_err());
+}
+
+#[test]
+fn floats_that_are_not_exact() {
+    // Trailing zeros don't survive the float.
+    assert_eq!(parse("1.10"), Ok(Version::new(1, 1, 0)));
+    // More digits than an f64 holds are rounded before the split.
+    assert_eq!(
+        parse("1.123456789012345678"),
+        Ok(Version::new(1, 1234567890123457, 0))
+    );
+}
+
+#[test]
+fn serializes_as_string() {
+    let feed = Feed {
+        version: Version::new(1, 2, 3),
+    };
+    let json = serde_json::to_string(&feed).unwrap();
+    assert_eq!(json, r#"{"version":"1.2.3"}"#);
+    assert_eq!(serde_json::from_str::<Feed>(&json).unwrap(), feed);
+}
+
+#[test]
+fn default_stays_strict() {
+    assert!(serde_json::from_str::<Version>(r#""v1.2.3""#).is_err());
+    assert!(serde_json::from_str::<Version>("1.2").is_err());
+}
@@ -0,0 +1,127 @@
+#![cfg(feature = "serde")]
+
+use fast_version::Version;
+
+fn error(json: &str) -> String {
+    serde_json::from_str::<Version>(json)
+        .unwrap_err()
+        .to_string()
+}
+
+#[test]
+fn round_trip() {
+    let version = Version::new(1, 2, 3);
+    let json = serde_json::to_string(&version).unwrap();
+    assert_eq!(json, r#""1.2.3""#);
+    assert_eq!(serde_json::from_str::<Version>(&json).unwrap(), version);
+    assert_eq!(
+        serde_json::from_str::<Version>(r#"{"major":1,"minor":2,"patch":3}"#).unwrap(),
+        version
+    );
+
+    let max = r#"{"patch":18446744073709551615,"major":0,"minor":0}"#;
+    assert_eq!(
+        serde_json::from_str::<Version>(max).unwrap(),
+        Version::new(0, 0, u64::MAX)
+    );
+}
+
+#[test]
+fn unknown_field() {
+    assert_eq!(
+        error(r#"{"mjor":1,"minor":2,"patch":3}"#),
+        "unknown field `mjor`, expected one of `major`, `minor`, `patch` at line 1 column 7"
+    );
+}
+
+#[test]
+fn missing_field() {
+    assert_eq!(
+        error(r#"{"major":1,"patch":3}"#),
+        "missing field `minor` at line 1 column 21"
+    );
+    assert_eq!(
+        error(r#"{"major":1,"minor":2}"#),
+        "missing field `patch` at line 1 column 21"
+    );
+    assert_eq!(error("{}"), "missing field `major` at line 1 column 2");
+}
+
+#[test]
+fn duplicate_field() {
+    assert_eq!(
+        error(r#"{"major":1,"major":2,"minor":2,"patch":3}"#),
+        "duplicate field `major` at line 1 column 18"
+    );
+}
+
+#[test]
+fn negative_and_float() {
+    assert_eq!(
+        error(r#"{"major":-1,"minor":2,"patch":3}"#),
+        "major must not be negative, found -1 at line 1 column 11"
+    );
+    assert_eq!(
+        error(r#"{"major":1,"minor":2.5,"patch":3}"#),
+        "minor must be an integer, found floating point 2.5 at line 1 column 22"
+    );
+    assert_eq!(
+        error(r#"{"major":1,"minor":2,"patch":"3"}"#),
+        "invalid type: string \"3\", expected a non-negative integer as patch at line 1 column 32"
+    );
+}
+
+#[test]
+fn sequence_form() {
+    assert_eq!(
+        serde_json::from_str::<Version>("[1,2,3]").unwrap(),
+        Version::new(1, 2, 3)
+    );
+    assert_eq!(
+        serde_json::from_str::<Version>("[1,2]")
+            .unwrap_err()
+            .to_string(),
+        "invalid length 2, expected a version string or a version with the fields major, minor and patch at line 1 column 5"
+    );
+}
+
+#[test]
+fn string_errors_name_the_input() {
+    assert_eq!(
+        error(r#""1.x.3""#),
+        "invalid version `1.x.3`: Minor Parse Error at line 1 column 7"
+    );
+    assert_eq!(
+        error(r#""1.2""#),
+        "invalid version `1.2`: Expected 3 components, found only 2 at line 1 column 5"
+    );
+    assert_eq!(
+        error("3"),
+        "invalid type: integer `3`, expected a version string or a version with the fields major, minor and patch at line 1 column 1"
+    );
+}
+
+#[test]
+fn binary_formats_keep_the_struct_form() {
+    let version = Version::new(1, 2, 300);
+    let bytes = postcard::to_allocvec(&version).unwrap();
+    assert_eq!(bytes, [1, 2, 0xac, 0x02]);
+    assert_eq!(postcard::from_bytes::<Version>(&bytes).unwrap(), version);
+    assert_eq!(postcard::to_allocvec(&(1u64, 2u64, 300u64)).unwrap(), bytes);
+}
+
+#[test]
+fn nested_in_other_types() {
+    let versions = vec![Version::new(1, 0, 0), Version::new(2, 3, 4)];
+    let json = serde_json::to_string(&versions).unwrap();
+    assert_eq!(json, r#"["1.0.0","2.3.4"]"#);
+    assert_eq!(
+        serde_json::from_str::<Vec<Version>>(&json).unwrap(),
+        versions
+    );
+    assert_eq!(
+        serde_json::from_str::<Vec<Version>>(r#"["1.0.0",{"major":2,"minor":3,"patch":4}]"#)
+            .unwrap(),
+        versions
+    );
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "serde")]
+
+use fast_version::Version;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Manifest {
+    #[serde(with = "fast_version::serde_helpers::version_as_tuple")]
+    version: Version,
+    #[serde(with = "fast_version::serde_helpers::version_as_tuple::option")]
+    minimum: Option<Version>,
+    #[serde(with = "fast_version::serde_helpers::version_as_tuple::vec")]
+    history: Vec<Version>,
+}
+
+fn manifest() -> Manifest {
+    Manifest {
+        version: Version::new(1, 2, 3),
+        minimum: Some(Version::new(1, 0, 0)),
+        history: vec![Version::new(0, 9, 0), Version::new(u64::MAX, 0, 1)],
+    }
+}
+
+#[test]
+fn json_round_trip() {
+    let json = serde_json::to_string(&manifest()).unwrap();
+    assert_eq!(
+        json,
+        r#"{"version":[1,2,3],"minimum":[1,0,0],"history":[[0,9,0],[18446744073709551615,0,1]]}"#
+    );
+    assert_eq!(serde_json::from_str::<Manifest>(&json).unwrap(), manifest());
+
+    let without_minimum = Manifest {
+        minimum: None,
+        history: Vec::new(),
+        ..manifest()
+    };
+    let json = serde_json::to_string(&without_minimum).unwrap();
+    assert_eq!(json, r#"{"version":[1,2,3],"minimum":null,"history":[]}"#);
+    assert_eq!(
+        serde_json::from_str::<Manifest>(&json).unwrap(),
+        without_minimum
+    );
+}
+
+#[test]
+fn cbor_round_trip() {
+    let mut bytes = Vec::new();
+    ciborium::into_writer(&manifest(), &mut bytes).unwrap();
+    let decoded: Manifest = ciborium::from_reader(bytes.as_slice()).unwrap();
+    assert_eq!(decoded, manifest());
+}
+
+#[test]
+fn wrong_lengths() {
+    let error = |version: &str| {
+        let json = format!(r#"{{"version":{},"minimum":null,"history":[]}}"#, version);
+        serde_json::from_str::<Manifest>(&json)
+            .unwrap_err()
+            .to_string()
+    };
+    assert!(error("[1,2]").starts_with("invalid length 2, expected a sequence of 3 components"));
+    assert!(error("[]").starts_with("invalid length 0, expected a sequence of 3 components"));
+    assert!(error("[1,2,3,4]").starts_with("invalid length 4, expected a sequence of 3 components"));
+    assert!(error("[1,2,3,4,5]").starts_with("invalid length 5"));
+    assert!(error("[1,-2,3]").starts_with("minor must not be negative, found -2"));
+    assert!(error(r#""1.2.3""#).starts_with("invalid type: string"));
+}
@@ -0,0 +1,115 @@
+#![cfg(feature = "serde")]
+
+use fast_version::{Version, VersionReq};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Policy {
+    exact: VersionReq,
+    range: VersionReq,
+    star: VersionReq,
+}
+
+fn policy() -> Policy {
+    Policy {
+        exact: VersionReq::exact(Version::new(1, 2, 3)),
+        range: VersionReq::parse(">=1.2.0, <2.0.0").unwrap(),
+        star: VersionReq::STAR,
+    }
+}
+
+#[test]
+fn legacy_fixture() {
+    let legacy: Policy =
+        serde_json::from_str(include_str!("fixtures/legacy_version_reqs.json")).unwrap();
+    assert_eq!(legacy, policy());
+}
+
+#[test]
+fn string_round_trip() {
+    let json = serde_json::to_string(&policy()).unwrap();
+    assert_eq!(
+        json,
+        r#"{"exact":"=1.2.3","range":">=1.2.0, <2.0.0","star":"*"}"#
+    );
+    assert_eq!(serde_json::from_str::<Policy>(&json).unwrap(), policy());
+}
+
+#[test]
+fn string_forms() {
+    let req: VersionReq = serde_json::from_str(r#""^1.2""#).unwrap();
+    assert_eq!(req, VersionReq::parse(">=1.2.0, <2.0.0").unwrap());
+    let empty: VersionReq = serde_json::from_str(r#""<0.0.0""#).unwrap();
+    assert!(empty.is_empty());
+    assert_eq!(
+        serde_json::to_string(&VersionReq::EMPTY).unwrap(),
+        r#""<0.0.0""#
+    );
+}
+
+#[test]
+fn prerelease_bounds() {
+    let req = VersionReq::parse(">=1.0.0-alpha.1, <2").unwrap();
+    let json = serde_json::to_string(&req).unwrap();
+    assert_eq!(json, r#"">=1.0.0-alpha.1, <2.0.0""#);
+    assert_eq!(serde_json::from_str::<VersionReq>(&json).unwrap(), req);
+    let mut bytes = Vec::new();
+    ciborium::into_writer(&req, &mut bytes).unwrap();
+    assert_eq!(
+        ciborium::from_reader::<VersionReq, _>(bytes.as_slice()).unwrap(),
+        req
+    );
+    let bytes = postcard::to_allocvec(&req).unwrap();
+    assert_eq!(postcard::from_bytes::<VersionReq>(&bytes).unwrap(), req);
+}
+
+#[test]
+fn invalid_inputs() {
+    let error = serde_json::from_str::<VersionReq>(r#"">=>1""#).unwrap_err();
+    assert!(error
+        .to_string()
+        .starts_with("Unknown operator at position 0"));
+    let error = serde_json::from_str::<VersionReq>(
+        r#"{"major_lower":1,"minor_lower":0,"patch_lower":0,"major_higher":2,"minor_higher":0}"#,
+    )
+    .unwrap_err();
+    assert!(error
+        .to_string()
+        .starts_with("missing field `patch_higher`"));
+    let error = serde_json::from_str::<VersionReq>(r#"{"lower":1}"#).unwrap_err();
+    assert!(error.to_string().starts_with("unknown field `lower`"));
+    assert!(serde_json::from_str::<VersionReq>("1").is_err());
+    let mut bytes = Vec::new();
+    ciborium::into_writer(
+        &ciborium::Value::Bytes(vec![2, 1, 0, 0, 2, 0, 0, 7]),
+        &mut bytes,
+    )
+    .unwrap();
+    let error = ciborium::from_reader::<VersionReq, _>(bytes.as_slice()).unwrap_err();
+    assert!(error.to_string().contains("invalid length 8"));
+    let bytes = postcard::to_allocvec(&[9u8][..]).unwrap();
+    let error = postcard::from_bytes::<VersionReq>(&bytes).unwrap_err();
+    assert!(matches!(error, postcard::Error::SerdeDeCustom));
+}
+
+#[test]
+fn legacy_inverted_bounds_are_empty() {
+    let req: VersionReq = serde_json::from_str(
+        r#"{"major_lower":2,"minor_lower":0,"patch_lower":0,"major_higher":1,"minor_higher":0,"patch_higher":0}"#,
+    )
+    .unwrap();
+    assert!(req.is_empty());
+}
+
+#[test]
+fn binary_round_trip() {
+    let mut bytes = Vec::new();
+    ciborium::into_writer(&policy(), &mut bytes).unwrap();
+    let value: ciborium::Value = ciborium::from_reader(bytes.as_slice()).unwrap();
+    let exact = value.as_map().unwrap()[0].1.as_bytes().unwrap();
+    let mut encoded = Vec::new();
+    policy().exact.encode(&mut encoded);
+    assert_eq!(exact, &encoded);
+    let decoded: Policy = ciborium::from_reader(bytes.as_slice()).unwrap();
+    assert_eq!(decoded, policy());
+}
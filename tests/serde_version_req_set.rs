@@ -0,0 +1,81 @@
+#![cfg(feature = "serde")]
+
+use fast_version::{VersionReq, VersionReqSet};
+
+fn set(input: &str) -> VersionReqSet {
+    VersionReqSet::parse(input).unwrap()
+}
+
+fn samples() -> Vec<VersionReqSet> {
+    vec![
+        VersionReqSet::new(),
+        VersionReqSet::from(VersionReq::STAR),
+        set("^1.2.3"),
+        set(">=1.2, <2 || >=3"),
+        set(">=1, <2, !=1.5.0, !=1.6.2 || =3.0.0"),
+        set("<=18446744073709551615.0.0 || >18446744073709551615.1"),
+    ]
+}
+
+#[test]
+fn json_uses_the_string() {
+    let json = serde_json::to_string(&set(">=1.2, <2 || >=3")).unwrap();
+    assert_eq!(json, r#"">=1.2.0, <2.0.0 || >=3.0.0""#);
+    assert_eq!(
+        serde_json::to_string(&VersionReqSet::new()).unwrap(),
+        r#""<0.0.0""#
+    );
+    assert_eq!(
+        serde_json::to_string(&VersionReqSet::from(VersionReq::STAR)).unwrap(),
+        r#""*""#
+    );
+}
+
+#[test]
+fn json_round_trip() {
+    for set in samples() {
+        let json = serde_json::to_string(&set).unwrap();
+        assert_eq!(
+            serde_json::from_str::<VersionReqSet>(&json).unwrap(),
+            set,
+            "{json}"
+        );
+    }
+    let parsed: VersionReqSet = serde_json::from_str(r#""<1 ||  >= 3""#).unwrap();
+    assert_eq!(parsed, set("<1 || >=3"));
+    assert!(serde_json::from_str::<VersionReqSet>(r#"">=1 || >=>2""#).is_err());
+}
+
+#[test]
+fn postcard_round_trip() {
+    for set in samples() {
+        let bytes = postcard::to_allocvec(&set).unwrap();
+        assert_eq!(bytes[0] as usize, set.ranges().len());
+        assert_eq!(postcard::from_bytes::<VersionReqSet>(&bytes).unwrap(), set);
+    }
+}
+
+#[test]
+fn postcard_merges_unsorted_ranges() {
+    let ranges = [
+        VersionReq::parse(">=3").unwrap(),
+        VersionReq::parse(">=1, <2").unwrap(),
+        VersionReq::parse(">=1.5, <2.5").unwrap(),
+        VersionReq::EMPTY,
+    ];
+    let bytes = postcard::to_allocvec(&ranges.to_vec()).unwrap();
+    assert_eq!(
+        postcard::from_bytes::<VersionReqSet>(&bytes).unwrap(),
+        set(">=1, <2.5 || >=3")
+    );
+}
+
+#[test]
+fn ciborium_round_trip() {
+    for set in samples() {
+        let mut bytes = Vec::new();
+        ciborium::into_writer(&set, &mut bytes).unwrap();
+        let decoded: VersionReqSet = ciborium::from_reader(bytes.as_slice()).unwrap();
+        assert_eq!(decoded, set);
+    }
+}
@@ -0,0 +1,37 @@
+use fast_version::Version;
+
+const VERSION: Version = Version::new(1, 4, 9);
+
+#[test]
+fn with_setters() {
+    assert_eq!(VERSION.with_major(7), Version::new(7, 4, 9));
+    assert_eq!(VERSION.with_minor(7), Version::new(1, 7, 9));
+    assert_eq!(VERSION.with_patch(7), Version::new(1, 4, 7));
+    assert_eq!(
+        VERSION.with_major(0).with_minor(0).with_patch(0),
+        Version::new(0, 0, 0)
+    );
+}
+
+#[test]
+fn truncation() {
+    assert_eq!(VERSION.truncate_to_minor(), Version::new(1, 4, 0));
+    assert_eq!(VERSION.truncate_to_major(), Version::new(1, 0, 0));
+    assert_eq!(
+        VERSION.truncate_to_minor().truncate_to_major(),
+        VERSION.truncate_to_major()
+    );
+}
+
+#[test]
+fn truncation_composes_with_bumping() {
+    let next_minor = VERSION.truncate_to_minor().with_minor(VERSION.minor + 1);
+    assert_eq!(next_minor, Version::new(1, 5, 0));
+
+    let next_major = VERSION.truncate_to_major().with_major(VERSION.major + 1);
+    assert_eq!(next_major, Version::new(2, 0, 0));
+
+    // The release line of a bumped patch stays the same.
+    let next_patch = VERSION.with_patch(VERSION.patch + 1);
+    assert_eq!(next_patch.truncate_to_minor(), VERSION.truncate_to_minor());
+}
@@ -0,0 +1,98 @@
+mod common;
+
+use common::Rng;
+use fast_version::{slice, Version};
+
+impl Rng {
+    fn version(&mut self) -> Version {
+        Version::new(self.next() % 3, self.next() % 3, self.next() % 3)
+    }
+}
+
+#[test]
+fn empty() {
+    assert_eq!(slice::max(&[]), None);
+    assert_eq!(slice::min(&[]), None);
+    assert_eq!(slice::minmax(&[]), None);
+    assert!(slice::is_sorted_ascending(&[]));
+    assert_eq!(slice::max_const([]), None);
+    assert_eq!(slice::minmax_const([]), None);
+    assert!(slice::is_sorted_ascending_const([]));
+}
+
+#[test]
+fn single() {
+    let version = Version::new(1, 2, 3);
+    assert_eq!(slice::max(&[version]), Some(&version));
+    assert_eq!(slice::min(&[version]), Some(&version));
+    assert_eq!(slice::minmax(&[version]), Some((&version, &version)));
+    assert!(slice::is_sorted_ascending(&[version]));
+    assert_eq!(slice::min_const([version]), Some(version));
+}
+
+#[test]
+fn duplicates() {
+    let versions = [
+        Version::new(1, 0, 0),
+        Version::new(2, 0, 0),
+        Version::new(0, 5, 0),
+        Version::new(2, 0, 0),
+        Version::new(0, 5, 0),
+    ];
+    // Same element as the iterator methods pick, not just an equal one.
+    assert!(std::ptr::eq(
+        slice::max(&versions).unwrap(),
+        versions.iter().max().unwrap()
+    ));
+    assert!(std::ptr::eq(slice::max(&versions).unwrap(), &versions[3]));
+    assert!(std::ptr::eq(slice::min(&versions).unwrap(), &versions[2]));
+    let (min, max) = slice::minmax(&versions).unwrap();
+    assert!(std::ptr::eq(min, &versions[2]) && std::ptr::eq(max, &versions[3]));
+    assert!(!slice::is_sorted_ascending(&versions));
+
+    let sorted = [
+        Version::new(1, 0, 0),
+        Version::new(1, 0, 0),
+        Version::new(1, 0, 1),
+    ];
+    assert!(slice::is_sorted_ascending(&sorted));
+    assert!(slice::is_sorted_ascending_const(sorted));
+}
+
+#[test]
+fn const_context() {
+    const TABLE: [Version; 4] = [
+        Version::new(0, 9, 0),
+        Version::new(1, 0, 0),
+        Version::new(1, 0, 1),
+        Version::new(1, 1, 0),
+    ];
+    const SORTED: bool = slice::is_sorted_ascending_const(TABLE);
+    const MINMAX: Option<(Version, Version)> = slice::minmax_const(TABLE);
+    const MAX: Option<Version> = slice::max_const(TABLE);
+    const _: () = assert!(SORTED);
+
+    assert_eq!(MINMAX, Some((Version::new(0, 9, 0), Version::new(1, 1, 0))));
+    assert_eq!(MAX, slice::max(&TABLE).copied());
+    assert!(!slice::is_sorted_ascending_const([
+        Version::new(1, 1, 0),
+        Version::new(1, 0, 9),
+    ]));
+}
+
+#[test]
+fn same_as_iterators() {
+    let mut rng = Rng(0x5eed_1234_abcd);
+    for len in 0..64 {
+        let mut versions: Vec<Version> = (0..len).map(|_| rng.version()).collect();
+        assert_eq!(slice::max(&versions), versions.iter().max());
+        assert_eq!(slice::min(&versions), versions.iter().min());
+        assert_eq!(
+            slice::minmax(&versions),
+            versions.iter().min().zip(versions.iter().max())
+        );
+        assert_eq!(slice::is_sorted_ascending(&versions), versions.is_sorted());
+        versions.sort();
+        assert!(slice::is_sorted_ascending(&versions));
+    }
+}
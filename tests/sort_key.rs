@@ -0,0 +1,68 @@
+mod common;
+
+use common::Rng;
+use fast_version::Version;
+
+impl Rng {
+    /// Mostly small components so that equal majors and minors are common.
+    fn component(&mut self) -> u64 {
+        match self.next() % 4 {
+            0 => self.next(),
+            1 => self.next() % 300,
+            _ => self.next() % 3,
+        }
+    }
+
+    fn version(&mut self) -> Version {
+        Version::new(self.component(), self.component(), self.component())
+    }
+}
+
+#[test]
+fn layout() {
+    let key = Version::new(1, 2, 0x0102).sort_key();
+    let mut expected = [0; 24];
+    expected[7] = 1;
+    expected[15] = 2;
+    expected[22] = 1;
+    expected[23] = 2;
+    assert_eq!(key, expected);
+    assert_eq!(
+        Version::new(u64::MAX, u64::MAX, u64::MAX).sort_key(),
+        [0xff; 24]
+    );
+}
+
+#[test]
+fn const_round_trip() {
+    const KEY: [u8; 24] = Version::new(3, 2, 1).sort_key();
+    const VERSION: Version = Version::from_sort_key(KEY);
+    assert_eq!(VERSION, Version::new(3, 2, 1));
+}
+
+#[test]
+fn ordering_law() {
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+    for _ in 0..10_000 {
+        let (lhs, rhs) = (rng.version(), rng.version());
+        assert_eq!(
+            lhs.sort_key().cmp(&rhs.sort_key()),
+            lhs.cmp(&rhs),
+            "{} {}",
+            lhs,
+            rhs
+        );
+        assert_eq!(Version::from_sort_key(lhs.sort_key()), lhs);
+    }
+}
+
+#[test]
+fn sorting_keys_sorts_versions() {
+    let mut rng = Rng(42);
+    let mut versions: Vec<Version> = (0..1_000).map(|_| rng.version()).collect();
+    let mut keys: Vec<[u8; 24]> = versions.iter().map(|version| version.sort_key()).collect();
+    versions.sort();
+    keys.sort();
+    let from_keys: Vec<Version> = keys.into_iter().map(Version::from_sort_key).collect();
+    assert_eq!(from_keys, versions);
+}
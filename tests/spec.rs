@@ -0,0 +1,87 @@
+use fast_version::spec::{PackageSpec, PackageSpecParseError};
+use fast_version::{Version, VersionReq, VersionReqParseError};
+use std::str::FromStr;
+
+fn spec(input: &str) -> PackageSpec {
+    PackageSpec::from_str(input).unwrap()
+}
+
+#[test]
+fn name_at_requirement() {
+    let spec = spec("serde@^1.0");
+    assert_eq!(spec.name, "serde");
+    assert_eq!(spec.req, VersionReq::parse(">=1.0.0, <2.0.0").unwrap());
+}
+
+#[test]
+fn name_at_version() {
+    let spec = spec("tokio@1.28.2");
+    assert_eq!(spec.name, "tokio");
+    assert!(spec.req.matches(&Version::new(1, 28, 2)));
+    assert!(!spec.req.matches(&Version::new(1, 28, 3)));
+    assert_eq!(spec.to_string(), "tokio@=1.28.2");
+}
+
+#[test]
+fn bare_name() {
+    let spec = spec("serde_json");
+    assert_eq!(spec.name, "serde_json");
+    assert_eq!(spec.req, VersionReq::STAR);
+    assert_eq!(spec.to_string(), "serde_json");
+}
+
+#[test]
+fn hyphens_and_underscores() {
+    for name in ["fast-version", "fast_version", "a-b_c-1"] {
+        assert_eq!(spec(&format!("{}@>=1", name)).name, name);
+    }
+}
+
+#[test]
+fn display_round_trip() {
+    for input in [
+        "serde@^1.0",
+        "tokio@1.28.2",
+        "fast-version",
+        "a@>=1.2, <2",
+        "b@*",
+    ] {
+        let spec = spec(input);
+        assert_eq!(self::spec(&spec.to_string()), spec, "{}", input);
+    }
+}
+
+#[test]
+fn bad_name() {
+    for (input, expected) in [("@1.0", 0), ("ser de@1.0", 3), ("serde!", 5), ("", 0)] {
+        match PackageSpec::from_str(input) {
+            Err(PackageSpecParseError::InvalidName { position }) => {
+                assert_eq!(position, expected, "{}", input)
+            }
+            other => panic!("{}: {:?}", input, other),
+        }
+    }
+}
+
+#[test]
+fn bad_requirement() {
+    assert!(matches!(
+        PackageSpec::from_str("serde@>=>1"),
+        Err(PackageSpecParseError::InvalidRequirement {
+            position: 6,
+            source: VersionReqParseError::UnknownOperator { .. }
+        })
+    ));
+    assert!(matches!(
+        PackageSpec::from_str("serde@"),
+        Err(PackageSpecParseError::InvalidRequirement { position: 6, .. })
+    ));
+}
+
+#[test]
+fn email_like_typo() {
+    assert!(matches!(
+        PackageSpec::from_str("user@example.com@1.0"),
+        Err(PackageSpecParseError::InvalidName { position: 4 })
+    ));
+}
@@ -0,0 +1,125 @@
+mod common;
+
+use common::req;
+use fast_version::{Version, VersionReq};
+
+const MAX: u64 = u64::MAX;
+
+fn split(req: &VersionReq) -> Vec<(u64, VersionReq)> {
+    req.split_by_major().collect()
+}
+
+#[test]
+fn three_majors() {
+    assert_eq!(
+        split(&req(">=1.4.2, <=3.1.7")),
+        [
+            (1, req(">=1.4.2, <2")),
+            (2, req("^2")),
+            (
+                3,
+                VersionReq::between(Version::new(3, 0, 0), Version::new(3, 1, 7))
+            ),
+        ]
+    );
+}
+
+#[test]
+fn single_major() {
+    for input in ["^1.2", ">=1.2.3, <1.2.9", "=1.2.3", "~1.7"] {
+        let req = req(input);
+        assert_eq!(split(&req), [(1, req)], "{input}");
+    }
+    assert_eq!(split(&req("<1")), [(0, req("<1"))]);
+}
+
+#[test]
+fn edges_at_major_boundaries() {
+    // The upper bound excludes 3.0.0, so major 3 isn't covered.
+    assert_eq!(split(&req(">=1, <3")), [(1, req("^1")), (2, req("^2"))]);
+    assert_eq!(
+        split(&req(">=1, <=3.0.0")),
+        [
+            (1, req("^1")),
+            (2, req("^2")),
+            (3, VersionReq::exact(Version::new(3, 0, 0)))
+        ]
+    );
+    let just_two = VersionReq::greater_than(Version::new(1, MAX, MAX))
+        .intersection(&VersionReq::less_than(Version::new(2, 0, 1)));
+    assert_eq!(
+        split(&just_two),
+        [(2, VersionReq::exact(Version::new(2, 0, 0)))]
+    );
+}
+
+#[test]
+fn empty() {
+    assert_eq!(VersionReq::EMPTY.split_by_major().next(), None);
+    assert_eq!(VersionReq::EMPTY.split_by_major_capped(0).next(), None);
+}
+
+#[test]
+fn star_with_cap() {
+    let pieces: Vec<_> = VersionReq::STAR.split_by_major_capped(2).collect();
+    assert_eq!(
+        pieces,
+        [
+            (0, req("^0")),
+            (1, req("^1")),
+            (2, req("^2")),
+            (3, req(">=3")),
+        ]
+    );
+    assert_eq!(
+        VersionReq::STAR
+            .split_by_major()
+            .take(3)
+            .collect::<Vec<_>>(),
+        pieces[..3]
+    );
+    // Majors starting above the cap end up in the open-ended entry right away.
+    assert_eq!(
+        req(">=5.1").split_by_major_capped(2).collect::<Vec<_>>(),
+        [(5, req(">=5.1"))]
+    );
+    // A cap at the last major changes nothing.
+    assert_eq!(
+        req(">=1, <3").split_by_major_capped(2).collect::<Vec<_>>(),
+        split(&req(">=1, <3"))
+    );
+}
+
+#[test]
+fn unbounded_ends_at_max_major() {
+    let req = VersionReq::at_least(Version::new(MAX - 1, 5, 0));
+    let pieces = split(&req);
+    assert_eq!(pieces.len(), 2);
+    assert_eq!(pieces[0].0, MAX - 1);
+    assert_eq!(pieces[1], (MAX, VersionReq::major_line(MAX)));
+}
+
+#[test]
+fn pieces_cover_the_requirement() {
+    let req = req(">=0.9.3, <4.2");
+    let pieces = split(&req);
+    for major in 0..6 {
+        for minor in [0, 1, 2, 3, 9, MAX] {
+            for patch in [0, 2, 3, 4, MAX] {
+                let version = Version::new(major, minor, patch);
+                let matching: Vec<_> = pieces
+                    .iter()
+                    .filter(|(_, piece)| piece.matches(&version))
+                    .collect();
+                assert_eq!(
+                    matching.len(),
+                    usize::from(req.matches(&version)),
+                    "{version}"
+                );
+                if let Some((piece_major, _)) = matching.first() {
+                    assert_eq!(*piece_major, major);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use fast_version::Version;
+use std::cmp::Ordering;
+
+#[test]
+fn equality() {
+    let version = Version::new(1, 2, 3);
+    assert_eq!(version, "1.2.3");
+    assert_eq!("1.2.3", version);
+    assert_eq!(*"1.2.3", version);
+    assert_eq!(version, *"1.2.3");
+    assert_ne!(version, "1.2.4");
+    assert_ne!("1.2.4", version);
+}
+
+#[test]
+fn ordering() {
+    let version = Version::new(1, 2, 3);
+    assert!(version >= "1.2.0");
+    assert!(version > "1.2.2");
+    assert!(version < "2.0.0");
+    assert!(version <= "1.2.3");
+    assert!("1.2.0" < version);
+    assert!("10.0.0" > version);
+    assert_eq!(version.partial_cmp(&"1.2.3"), Some(Ordering::Equal));
+    assert_eq!(
+        PartialOrd::partial_cmp(&"0.9.0", &version),
+        Some(Ordering::Less)
+    );
+}
+
+#[test]
+fn invalid_operands() {
+    let version = Version::new(1, 2, 3);
+    for invalid in ["", "1.2", "1.2.3.4", "v1.2.3", "1.2.x", "one.two.three"] {
+        assert_ne!(version, invalid);
+        assert_ne!(invalid, version);
+        assert_eq!(version.partial_cmp(&invalid), None);
+        assert_eq!(PartialOrd::partial_cmp(&invalid, &version), None);
+        let comparisons = [
+            version < invalid,
+            version > invalid,
+            version <= invalid,
+            version >= invalid,
+        ];
+        assert_eq!(comparisons, [false; 4]);
+    }
+}
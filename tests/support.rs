@@ -0,0 +1,235 @@
+use fast_version::{
+    MinSupported, ReleaseRange, SupportPolicy, SupportPolicyError, SupportStatus, SupportWindow,
+    UnsupportedVersion, Version, VersionDiff, VersionReq,
+};
+
+const MIN: MinSupported = MinSupported::new(Version::new(2, 4, 0));
+
+#[test]
+fn below_min() {
+    assert_eq!(
+        MIN.check(&Version::new(2, 3, 9)),
+        Err(UnsupportedVersion::TooOld {
+            client: Version::new(2, 3, 9),
+            min: Version::new(2, 4, 0),
+            diff: VersionDiff::Minor,
+        })
+    );
+    let error = MIN.check(&Version::new(1, 9, 0)).unwrap_err();
+    assert_eq!(error.client(), Version::new(1, 9, 0));
+    assert!(matches!(
+        error,
+        UnsupportedVersion::TooOld {
+            diff: VersionDiff::Major,
+            ..
+        }
+    ));
+    assert!(MIN.check(&Version::new(0, 0, 0)).is_err());
+}
+
+#[test]
+fn at_min() {
+    assert_eq!(MIN.check(&Version::new(2, 4, 0)), Ok(()));
+    const CHECKED: Result<(), UnsupportedVersion> = MIN.check(&Version::new(2, 4, 0));
+    assert!(CHECKED.is_ok());
+}
+
+#[test]
+fn above_min() {
+    for client in [
+        Version::new(2, 4, 1),
+        Version::new(2, 5, 0),
+        Version::new(3, 0, 0),
+        Version::new(u64::MAX, u64::MAX, u64::MAX),
+    ] {
+        assert_eq!(MIN.check(&client), Ok(()));
+        assert!(MIN.to_req().matches(&client));
+    }
+    assert!(!MIN.to_req().matches(&Version::new(2, 3, u64::MAX)));
+}
+
+#[test]
+fn rendered_errors() {
+    let patch = MinSupported::new(Version::new(2, 4, 3)).check(&Version::new(2, 4, 1));
+    assert_eq!(
+        patch.unwrap_err().to_string(),
+        "Client version 2.4.1 is older than the minimum supported version 2.4.3 (patch behind)"
+    );
+    let window = SupportWindow::new(Version::new(1, 0, 0), Version::new(1, 9, 0));
+    assert_eq!(
+        window
+            .check(&Version::new(2, 0, 0))
+            .unwrap_err()
+            .to_string(),
+        "Client version 2.0.0 is newer than the maximum supported version 1.9.0 (major ahead)"
+    );
+    assert_eq!(
+        window
+            .check(&Version::new(0, 7, 0))
+            .unwrap_err()
+            .to_string(),
+        "Client version 0.7.0 is older than the minimum supported version 1.0.0 (major behind)"
+    );
+}
+
+#[test]
+fn support_window() {
+    let window = SupportWindow::new(Version::new(1, 2, 0), Version::new(1, 5, 3));
+    assert!(window.check(&Version::new(1, 2, 0)).is_ok());
+    assert!(window.check(&Version::new(1, 5, 3)).is_ok());
+    assert_eq!(
+        window.check(&Version::new(1, 5, 4)),
+        Err(UnsupportedVersion::TooNew {
+            client: Version::new(1, 5, 4),
+            max: Version::new(1, 5, 3),
+            diff: VersionDiff::Patch,
+        })
+    );
+    assert!(window.check(&Version::new(1, 1, 9)).is_err());
+    for client in [
+        Version::new(1, 1, 9),
+        Version::new(1, 3, 0),
+        Version::new(1, 6, 0),
+    ] {
+        assert_eq!(
+            window.check(&client).is_ok(),
+            window.to_req().matches(&client)
+        );
+    }
+}
+
+#[test]
+#[should_panic]
+fn support_window_inverted() {
+    SupportWindow::new(Version::new(2, 0, 0), Version::new(1, 0, 0));
+}
+
+fn policy() -> SupportPolicy {
+    SupportPolicy {
+        supported: VersionReq::parse(">=3, <=3.4.0").unwrap(),
+        deprecated: VersionReq::parse(">=2.1, <3").unwrap(),
+        eol_below: Version::new(2, 1, 0),
+    }
+}
+
+#[test]
+fn policy_boundaries() {
+    let policy = policy();
+    assert_eq!(policy.validate(), Ok(()));
+    for (version, status) in [
+        (Version::new(0, 0, 0), SupportStatus::EndOfLife),
+        (Version::new(2, 0, u64::MAX), SupportStatus::EndOfLife),
+        (Version::new(2, 1, 0), SupportStatus::Deprecated),
+        (
+            Version::new(2, u64::MAX, u64::MAX),
+            SupportStatus::Deprecated,
+        ),
+        (Version::new(3, 0, 0), SupportStatus::Supported),
+        (Version::new(3, 4, 0), SupportStatus::Supported),
+        (Version::new(3, 4, 1), SupportStatus::Unknown),
+        (Version::new(u64::MAX, 0, 0), SupportStatus::Unknown),
+    ] {
+        assert_eq!(policy.classify(&version), status, "{version}");
+    }
+}
+
+#[test]
+fn policy_gap() {
+    let policy = SupportPolicy {
+        eol_below: Version::new(2, 0, 0),
+        ..policy()
+    };
+    assert_eq!(policy.validate(), Ok(()));
+    assert_eq!(
+        policy.classify(&Version::new(2, 0, 5)),
+        SupportStatus::Unknown
+    );
+}
+
+#[test]
+fn overlapping_policies() {
+    let overlapping = SupportPolicy {
+        deprecated: VersionReq::parse(">=2.1, <=3.0.0").unwrap(),
+        ..policy()
+    };
+    assert_eq!(
+        overlapping.validate(),
+        Err(SupportPolicyError::SupportedDeprecated {
+            overlap: ReleaseRange::of(&VersionReq::exact(Version::new(3, 0, 0))).unwrap(),
+        })
+    );
+    // Supported wins for the shared versions.
+    assert_eq!(
+        overlapping.classify(&Version::new(3, 0, 0)),
+        SupportStatus::Supported
+    );
+
+    let supported_eol = SupportPolicy {
+        eol_below: Version::new(3, 0, 1),
+        deprecated: VersionReq::EMPTY,
+        ..policy()
+    };
+    assert_eq!(
+        supported_eol.validate(),
+        Err(SupportPolicyError::SupportedEndOfLife {
+            overlap: ReleaseRange::of(&VersionReq::exact(Version::new(3, 0, 0))).unwrap(),
+        })
+    );
+    assert_eq!(
+        supported_eol.classify(&Version::new(3, 0, 0)),
+        SupportStatus::EndOfLife
+    );
+
+    let deprecated_eol = SupportPolicy {
+        eol_below: Version::new(2, 2, 0),
+        ..policy()
+    };
+    let error = deprecated_eol.validate().unwrap_err();
+    assert_eq!(
+        error,
+        SupportPolicyError::DeprecatedEndOfLife {
+            overlap: ReleaseRange::of(&VersionReq::parse(">=2.1, <2.2").unwrap()).unwrap(),
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "Deprecated versions are end of life in >=2.1.0, <2.2.0"
+    );
+}
+
+#[test]
+fn policy_display() {
+    assert_eq!(
+        policy().to_string(),
+        "supported: >=3.0.0, <=3.4.0; deprecated: >=2.1.0, <3.0.0; end of life: <2.1.0"
+    );
+    let statuses = [
+        SupportStatus::Supported,
+        SupportStatus::Deprecated,
+        SupportStatus::EndOfLife,
+        SupportStatus::Unknown,
+    ];
+    assert_eq!(
+        statuses.map(|status| status.to_string()),
+        ["supported", "deprecated", "end of life", "unknown"]
+    );
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use super::*;
+
+    #[test]
+    fn policy_config() {
+        let json = r#"{"supported":">=3.0.0, <=3.4.0","deprecated":">=2.1.0, <3.0.0","eol_below":"2.1.0"}"#;
+        assert_eq!(serde_json::to_string(&policy()).unwrap(), json);
+        assert_eq!(
+            serde_json::from_str::<SupportPolicy>(json).unwrap(),
+            policy()
+        );
+        assert_eq!(
+            serde_json::to_string(&SupportStatus::EndOfLife).unwrap(),
+            r#""EndOfLife""#
+        );
+    }
+}
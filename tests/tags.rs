@@ -0,0 +1,146 @@
+use fast_version::tags::{self, parse_tag, TagParseError};
+use fast_version::{FullVersion, Version, VersionParseError, VersionReq};
+use std::str::FromStr;
+
+#[test]
+fn prefix_styles() {
+    for tag in [
+        "1.2.3",
+        "v1.2.3",
+        "V1.2.3",
+        "release-1.2.3",
+        "release/v1.2.3",
+        "my-crate-v1.2.3",
+        "my_crate_1.2.3",
+        "@scope/pkg@1.2.3",
+        "refs/tags/v1.2.3",
+        "  v1.2.3\n",
+    ] {
+        assert_eq!(parse_tag(tag).unwrap(), Version::new(1, 2, 3), "{tag}");
+    }
+}
+
+#[test]
+fn first_parsable_position_wins() {
+    assert_eq!(parse_tag("api-2-v1.4.0").unwrap(), Version::new(1, 4, 0));
+    // The prerelease of 1.0.0, not 2.0.0.
+    assert!(matches!(
+        parse_tag("1.0.0-2.0.0"),
+        Err(TagParseError::Prerelease)
+    ));
+}
+
+#[test]
+fn rejected_tags() {
+    for tag in ["", "nightly", "docs-update", "v", "release/next"] {
+        assert!(
+            matches!(parse_tag(tag), Err(TagParseError::NoVersion)),
+            "{tag}"
+        );
+    }
+    for tag in ["v1.2.3-rc.1", "1.2.3+build.5", "crate-v0.1.0-alpha"] {
+        assert!(
+            matches!(parse_tag(tag), Err(TagParseError::Prerelease)),
+            "{tag}"
+        );
+    }
+    assert!(matches!(
+        parse_tag("v1.2"),
+        Err(TagParseError::Version(
+            VersionParseError::TooFewComponents { found: 2 }
+        ))
+    ));
+    assert!(matches!(
+        parse_tag("v1.x.0"),
+        Err(TagParseError::Version(VersionParseError::MinorParseError))
+    ));
+    assert_eq!(
+        parse_tag("v1.2.3-rc.1").unwrap_err().to_string(),
+        "Prereleases and build metadata aren't supported"
+    );
+}
+
+fn full(input: &str) -> FullVersion {
+    FullVersion::from_str(input).unwrap()
+}
+
+#[test]
+fn mixed_list() {
+    let listing = "\
+v0.9.0
+v1.0.0
+1.0.0
+release-1.0.0
+refs/tags/v1.0.0
+v1.1.0-rc.1
+v1.1.0
+my-crate-v1.1.0
+latest
+v1.10.0
+v1.2
+v2.0.0-beta.1
+v1.2.3-rc..1
+";
+    let scan = tags::collect(listing.lines());
+    assert_eq!(
+        scan.versions,
+        [
+            "2.0.0-beta.1",
+            "1.10.0",
+            "1.1.0",
+            "1.1.0-rc.1",
+            "1.0.0",
+            "0.9.0"
+        ]
+        .map(full)
+    );
+    let skipped: Vec<&str> = scan.skipped.iter().map(|(tag, _)| tag.as_str()).collect();
+    assert_eq!(skipped, ["latest", "v1.2", "v1.2.3-rc..1"]);
+    assert!(matches!(scan.skipped[0].1, TagParseError::NoVersion));
+    assert!(matches!(scan.skipped[2].1, TagParseError::Identifiers(_)));
+
+    assert_eq!(scan.latest(), Some(&full("2.0.0-beta.1")));
+    assert_eq!(scan.latest_stable(), Some(&full("1.10.0")));
+    assert_eq!(
+        scan.latest_matching(&VersionReq::parse(">=1, <1.10").unwrap()),
+        Some(&full("1.1.0"))
+    );
+    assert_eq!(
+        scan.latest_matching(&VersionReq::parse("^2").unwrap()),
+        None
+    );
+}
+
+#[test]
+fn prerelease_is_latest() {
+    let scan = tags::collect(["v1.9.0", "v2.0.0-rc.1", "v1.9.0+build.2"]);
+    assert_eq!(
+        scan.versions,
+        ["2.0.0-rc.1", "1.9.0+build.2", "1.9.0"].map(full)
+    );
+    assert!(scan.skipped.is_empty());
+    assert_eq!(scan.latest(), Some(&full("2.0.0-rc.1")));
+    assert_eq!(scan.latest_stable(), Some(&full("1.9.0+build.2")));
+    assert_eq!(
+        scan.latest_matching(&VersionReq::STAR),
+        Some(&full("1.9.0+build.2"))
+    );
+
+    let scan = tags::collect(["v2.0.0-rc.1", "v2.0.0-beta"]);
+    assert_eq!(scan.latest(), Some(&full("2.0.0-rc.1")));
+    assert_eq!(scan.latest_stable(), None);
+    assert_eq!(scan.latest_matching(&VersionReq::STAR), None);
+}
+
+#[test]
+fn empty() {
+    let scan = tags::collect([]);
+    assert!(scan.versions.is_empty() && scan.skipped.is_empty());
+    assert_eq!(scan.latest(), None);
+    assert_eq!(scan.latest_stable(), None);
+    assert_eq!(scan.latest_matching(&VersionReq::STAR), None);
+
+    let owned = [String::from("v3.0.0"), String::from("v3.0.0")];
+    let scan = tags::collect(owned.iter().map(String::as_str));
+    assert_eq!(scan.versions, [full("3.0.0")]);
+}
@@ -0,0 +1,158 @@
+mod common;
+
+use common::Rng;
+use fast_version::{
+    Version, VersionReq, VersionReqVariant, VersionReqVariantLowerBound,
+    VersionReqVariantUpperBound,
+};
+
+impl Rng {
+    /// Small components and the extremes, where the bound arithmetic is interesting.
+    fn component(&mut self) -> u64 {
+        match self.next() % 8 {
+            0 => u64::MAX,
+            1 => u64::MAX - 1,
+            _ => self.next() % 4,
+        }
+    }
+
+    fn lower(&mut self) -> VersionReqVariantLowerBound {
+        use VersionReqVariantLowerBound::*;
+        let (major, minor, patch) = (self.component(), self.component(), self.component());
+        match self.next() % 6 {
+            0 => MajorGreater { major },
+            1 => MinorGreater { major, minor },
+            2 => PatchGreater {
+                major,
+                minor,
+                patch,
+            },
+            3 => MajorGreaterEqual { major },
+            4 => MinorGreaterEqual { major, minor },
+            _ => PatchGreaterEqual {
+                major,
+                minor,
+                patch,
+            },
+        }
+    }
+
+    fn upper(&mut self) -> VersionReqVariantUpperBound {
+        use VersionReqVariantUpperBound::*;
+        let (major, minor, patch) = (self.component(), self.component(), self.component());
+        match self.next() % 6 {
+            0 => MajorLess { major },
+            1 => MinorLess { major, minor },
+            2 => PatchLess {
+                major,
+                minor,
+                patch,
+            },
+            3 => MajorLessEqual { major },
+            4 => MinorLessEqual { major, minor },
+            _ => PatchLessEqual {
+                major,
+                minor,
+                patch,
+            },
+        }
+    }
+
+    fn req(&mut self) -> VersionReq {
+        let version = Version::new(self.component(), self.component(), self.component());
+        match self.next() % 5 {
+            0 => VersionReq::new(&VersionReqVariant::Strict(version)),
+            1 => VersionReq::new(&VersionReqVariant::Compound(self.lower(), self.upper())),
+            2 => VersionReq::between(
+                version,
+                Version::new(self.component(), self.component(), self.component()),
+            ),
+            3 => VersionReq::at_least(version),
+            _ => VersionReq::at_most(version),
+        }
+    }
+}
+
+#[test]
+fn special_cases() {
+    assert!(VersionReq::STAR.to_variants().is_empty());
+    assert_eq!(
+        VersionReq::EMPTY.to_variants().as_slice(),
+        [VersionReqVariant::MajorLess { major: 0 }]
+    );
+    let version = Version::new(1, 2, 3);
+    assert_eq!(
+        VersionReq::exact(version).to_variants().as_slice(),
+        [VersionReqVariant::Strict(version)]
+    );
+}
+
+#[test]
+fn shortest_variants() {
+    let cases = [
+        (">=1.0.0", VersionReqVariant::MajorGreaterEqual { major: 1 }),
+        (
+            ">=1.2.0",
+            VersionReqVariant::MinorGreaterEqual { major: 1, minor: 2 },
+        ),
+        (
+            ">=1.2.3",
+            VersionReqVariant::PatchGreaterEqual {
+                major: 1,
+                minor: 2,
+                patch: 3,
+            },
+        ),
+        ("<2.0.0", VersionReqVariant::MajorLessEqual { major: 1 }),
+        (
+            "<1.3.0",
+            VersionReqVariant::MinorLessEqual { major: 1, minor: 2 },
+        ),
+        (
+            "<=1.2.3",
+            VersionReqVariant::PatchLessEqual {
+                major: 1,
+                minor: 2,
+                patch: 3,
+            },
+        ),
+    ];
+    for (input, variant) in cases {
+        let variants = VersionReq::parse(input).unwrap().to_variants();
+        assert_eq!(variants.as_slice(), [variant], "{}", input);
+    }
+    let variants = VersionReq::parse(">=1.2.3, <2").unwrap().to_variants();
+    assert_eq!(variants.len(), 2);
+    assert_eq!((&variants).into_iter().count(), 2);
+}
+
+#[test]
+fn round_trip() {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    for _ in 0..10_000 {
+        let req = rng.req();
+        let variants = req.to_variants();
+        assert_eq!(variants.to_req(), req, "{:?}", variants);
+        let folded = variants
+            .as_slice()
+            .iter()
+            .fold(VersionReq::STAR, |folded, variant| {
+                folded.intersection(&VersionReq::new(variant))
+            });
+        assert_eq!(folded, req);
+    }
+}
+
+#[test]
+fn intersection() {
+    let lhs = VersionReq::parse(">=1.2, <2").unwrap();
+    let rhs = VersionReq::parse(">=1.5").unwrap();
+    assert_eq!(
+        lhs.intersection(&rhs),
+        VersionReq::parse(">=1.5, <2").unwrap()
+    );
+    assert_eq!(lhs.intersection(&VersionReq::STAR), lhs);
+    assert!(lhs.intersection(&VersionReq::EMPTY).is_empty());
+    let disjoint = VersionReq::parse(">=2").unwrap();
+    assert_eq!(lhs.intersection(&disjoint), VersionReq::EMPTY);
+}
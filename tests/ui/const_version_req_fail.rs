@@ -0,0 +1,9 @@
+use fast_version::{const_version_req, VersionReq};
+
+const OPERATOR: VersionReq = const_version_req!(">=>1");
+const NUMBER: VersionReq = const_version_req!(">=1.x");
+const CONFLICT: VersionReq = const_version_req!(">=2, <1");
+const EXACT_PRERELEASE: VersionReq = const_version_req!("=1.0.0-beta");
+const PRERELEASE_BOUND: VersionReq = const_version_req!(">=1.0.0-alpha, <2");
+
+fn main() {}
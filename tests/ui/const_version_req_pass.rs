@@ -0,0 +1,19 @@
+use fast_version::{const_version_req, Version, VersionReq};
+
+const STRICT: VersionReq = const_version_req!("1.2.3");
+const SINGLE: VersionReq = const_version_req!(">1.2");
+const COMPOUND: VersionReq = const_version_req!(">=1.2.3, <2.0.0");
+const CARET: VersionReq = const_version_req!("^0.2.3");
+const STAR: VersionReq = const_version_req!("*");
+const EMPTY: VersionReq = const_version_req!("<0.0.0");
+const BUILD: VersionReq = const_version_req!("=1.2.3+build.5");
+
+fn main() {
+    assert_eq!(STRICT, VersionReq::exact(Version::new(1, 2, 3)));
+    assert_eq!(SINGLE, VersionReq::parse(">1.2").unwrap());
+    assert_eq!(COMPOUND, VersionReq::parse(">=1.2.3, <2.0.0").unwrap());
+    assert_eq!(CARET.to_string(), ">=0.2.3, <0.3.0");
+    assert_eq!(STAR, VersionReq::STAR);
+    assert_eq!(EMPTY, VersionReq::EMPTY);
+    assert_eq!(BUILD, VersionReq::exact(Version::new(1, 2, 3)));
+}
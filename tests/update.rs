@@ -0,0 +1,153 @@
+use fast_version::{RejectReason, UpdateDecision, UpdatePolicy, Version, VersionReq};
+
+const CURRENT: Version = Version::new(2, 3, 4);
+
+fn allowed() -> VersionReq {
+    VersionReq::parse(">=1, <4").unwrap()
+}
+
+fn policies() -> impl Iterator<Item = UpdatePolicy> {
+    (0..8).map(|bits| UpdatePolicy {
+        allow_downgrade: bits & 1 != 0,
+        allow_major_jump: bits & 2 != 0,
+        require_monotonic_patch: bits & 4 != 0,
+    })
+}
+
+fn evaluate(candidate: Version, policy: UpdatePolicy) -> UpdateDecision {
+    UpdateDecision::evaluate(CURRENT, candidate, &allowed(), policy)
+}
+
+fn downgrade(to: Version) -> UpdateDecision {
+    UpdateDecision::Reject(RejectReason::Downgrade { from: CURRENT, to })
+}
+
+#[test]
+fn every_policy_and_reason() {
+    for policy in policies() {
+        let expected = |candidate: Version, decision: UpdateDecision| {
+            assert_eq!(
+                evaluate(candidate, policy),
+                decision,
+                "{candidate} {policy:?}"
+            );
+        };
+        expected(CURRENT, UpdateDecision::Reject(RejectReason::SameVersion));
+        for outside in [Version::new(4, 0, 0), Version::new(0, 9, 9)] {
+            expected(
+                outside,
+                UpdateDecision::Reject(RejectReason::OutsideRequirement),
+            );
+        }
+        expected(Version::new(2, 3, 5), UpdateDecision::Accept);
+        expected(Version::new(2, 4, 0), UpdateDecision::Accept);
+        expected(Version::new(2, 99, 0), UpdateDecision::Accept);
+
+        let major = Version::new(3, 0, 0);
+        if policy.allow_major_jump {
+            expected(major, UpdateDecision::Accept);
+        } else {
+            expected(
+                major,
+                UpdateDecision::Reject(RejectReason::MajorJump { from: 2, to: 3 }),
+            );
+        }
+
+        let patch_rollback = Version::new(2, 3, 3);
+        if policy.allow_downgrade && !policy.require_monotonic_patch {
+            expected(patch_rollback, UpdateDecision::Accept);
+        } else {
+            expected(patch_rollback, downgrade(patch_rollback));
+        }
+
+        for rollback in [Version::new(2, 2, 9), Version::new(1, 9, 0)] {
+            if policy.allow_downgrade {
+                expected(rollback, UpdateDecision::Accept);
+            } else {
+                expected(rollback, downgrade(rollback));
+            }
+        }
+    }
+}
+
+#[test]
+fn presets() {
+    assert_eq!(UpdatePolicy::default(), UpdatePolicy::STRICT);
+    assert_eq!(
+        evaluate(Version::new(3, 0, 0), UpdatePolicy::STRICT),
+        UpdateDecision::Reject(RejectReason::MajorJump { from: 2, to: 3 })
+    );
+    for candidate in [
+        Version::new(3, 0, 0),
+        Version::new(2, 3, 3),
+        Version::new(1, 0, 0),
+    ] {
+        assert!(evaluate(candidate, UpdatePolicy::PERMISSIVE).is_accept());
+    }
+    assert!(!evaluate(CURRENT, UpdatePolicy::PERMISSIVE).is_accept());
+}
+
+#[test]
+fn reason_precedence() {
+    // The current version is reported as such even outside the requirement.
+    let nothing = VersionReq::EMPTY;
+    assert_eq!(
+        UpdateDecision::evaluate(CURRENT, CURRENT, &nothing, UpdatePolicy::STRICT),
+        UpdateDecision::Reject(RejectReason::SameVersion)
+    );
+    // Outside the requirement beats downgrade and major jump.
+    let only_two = VersionReq::parse("^2.3").unwrap();
+    for candidate in [Version::new(2, 2, 0), Version::new(3, 0, 0)] {
+        assert_eq!(
+            UpdateDecision::evaluate(CURRENT, candidate, &only_two, UpdatePolicy::STRICT),
+            UpdateDecision::Reject(RejectReason::OutsideRequirement)
+        );
+    }
+    // A major downgrade is a downgrade, not a jump.
+    assert_eq!(
+        evaluate(Version::new(1, 0, 0), UpdatePolicy::STRICT),
+        downgrade(Version::new(1, 0, 0))
+    );
+}
+
+#[test]
+fn const_context() {
+    const ALLOWED: VersionReq = VersionReq::STAR;
+    const DECISION: UpdateDecision = UpdateDecision::evaluate(
+        Version::new(0, 1, 0),
+        Version::new(0, 1, 1),
+        &ALLOWED,
+        UpdatePolicy::STRICT,
+    );
+    const ACCEPTED: bool = DECISION.is_accept();
+    const _: () = assert!(ACCEPTED);
+    assert_eq!(DECISION, UpdateDecision::Accept);
+}
+
+#[test]
+fn display() {
+    let reasons = [
+        (
+            RejectReason::SameVersion,
+            "the candidate is the current version",
+        ),
+        (
+            RejectReason::OutsideRequirement,
+            "the candidate doesn't match the allowed requirement",
+        ),
+        (
+            RejectReason::Downgrade {
+                from: CURRENT,
+                to: Version::new(2, 3, 3),
+            },
+            "downgrade from 2.3.4 to 2.3.3",
+        ),
+        (
+            RejectReason::MajorJump { from: 2, to: 5 },
+            "jump from major 2 to 5",
+        ),
+    ];
+    for (reason, expected) in reasons {
+        assert_eq!(reason.to_string(), expected);
+    }
+}
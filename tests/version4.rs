@@ -0,0 +1,69 @@
+use fast_version::{RevisionNotZero, Version, Version4};
+use std::str::FromStr;
+
+#[test]
+fn parse_windows_version() {
+    let version = Version4::from_str("10.0.19041.1288").unwrap();
+    assert_eq!(version, Version4::new(10, 0, 19041, 1288));
+    assert_eq!(version.to_string(), "10.0.19041.1288");
+
+    assert!(Version4::from_str("10.0.19041").is_err());
+    assert!(Version4::from_str("10.0.19041.1288.1").is_err());
+    assert!(Version4::from_str("10.0.19041.x").is_err());
+}
+
+#[test]
+fn ordering() {
+    let older = Version4::from_str("10.0.19041.1288").unwrap();
+    let newer = Version4::from_str("10.0.19041.1289").unwrap();
+    assert!(older < newer);
+    assert!(Version4::new(10, 0, 19042, 0) > newer);
+
+    assert!(older > Version::new(10, 0, 19041));
+    assert!(Version::new(10, 0, 19041) < older);
+    assert!(older < Version::new(10, 0, 19042));
+    assert_eq!(Version4::new(1, 2, 3, 0), Version::new(1, 2, 3));
+    assert_ne!(Version4::new(1, 2, 3, 1), Version::new(1, 2, 3));
+}
+
+#[test]
+fn conversions() {
+    let version = Version4::new(10, 0, 19041, 1288);
+    assert_eq!(version.truncate(), Version::new(10, 0, 19041));
+    assert!(matches!(
+        Version::try_from(version),
+        Err(RevisionNotZero { revision: 1288 })
+    ));
+    assert_eq!(
+        Version::try_from(Version4::new(10, 0, 19041, 0)).unwrap(),
+        Version::new(10, 0, 19041)
+    );
+    assert_eq!(
+        Version4::from(Version::new(1, 2, 3)),
+        Version4::new(1, 2, 3, 0)
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() {
+    let version = Version4::new(10, 0, 19041, 1288);
+    let json = serde_json::to_string(&version).unwrap();
+    assert_eq!(json, r#""10.0.19041.1288""#);
+    assert_eq!(serde_json::from_str::<Version4>(&json).unwrap(), version);
+    // The struct form written before the string form.
+    let fields = r#"{"major":10,"minor":0,"build":19041,"revision":1288}"#;
+    assert_eq!(serde_json::from_str::<Version4>(fields).unwrap(), version);
+    assert!(serde_json::from_str::<Version4>(r#"{"major":10,"minor":0}"#).is_err());
+    assert!(serde_json::from_str::<Version4>(r#""10.0.19041""#)
+        .unwrap_err()
+        .to_string()
+        .starts_with("invalid version `10.0.19041`: "));
+
+    let bytes = postcard::to_allocvec(&version).unwrap();
+    assert_eq!(
+        bytes,
+        postcard::to_allocvec(&(10u64, 0u64, 19041u64, 1288u64)).unwrap()
+    );
+    assert_eq!(postcard::from_bytes::<Version4>(&bytes).unwrap(), version);
+}
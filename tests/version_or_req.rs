@@ -0,0 +1,140 @@
+use fast_version::{Version, VersionOrReq, VersionReq, VersionReqParseError};
+use std::str::FromStr;
+
+#[test]
+fn pinned() {
+    let pinned = VersionOrReq::from_str("1.2.3").unwrap();
+    assert_eq!(pinned, VersionOrReq::Pinned(Version::new(1, 2, 3)));
+    assert!(pinned.matches(&Version::new(1, 2, 3)));
+    assert!(!pinned.matches(&Version::new(1, 2, 4)));
+    assert!(!pinned.matches(&Version::new(1, 2, 2)));
+    assert_eq!(pinned.to_req(), VersionReq::exact(Version::new(1, 2, 3)));
+    assert_eq!(
+        VersionOrReq::from_str(" 1.2.3\n").unwrap(),
+        VersionOrReq::Pinned(Version::new(1, 2, 3))
+    );
+}
+
+#[test]
+fn req() {
+    let req = VersionOrReq::from_str(">=1.2, <2").unwrap();
+    assert_eq!(
+        req,
+        VersionOrReq::Req(VersionReq::parse(">=1.2, <2").unwrap())
+    );
+    assert!(req.matches(&Version::new(1, 2, 0)));
+    assert!(req.matches(&Version::new(1, 99, 99)));
+    assert!(!req.matches(&Version::new(2, 0, 0)));
+    assert_eq!(req.to_req(), VersionReq::parse(">=1.2, <2").unwrap());
+
+    for input in ["^1.2", "~1", "*", "<3", "1.2"] {
+        assert!(
+            matches!(VersionOrReq::from_str(input), Ok(VersionOrReq::Req(_))),
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn ambiguous() {
+    // A bare version is pinned, even though it's also a valid requirement.
+    assert!(VersionReq::parse("1.2.3").is_ok());
+    assert!(matches!(
+        VersionOrReq::from_str("1.2.3"),
+        Ok(VersionOrReq::Pinned(_))
+    ));
+    let exact = VersionOrReq::from_str("=1.2.3").unwrap();
+    assert_eq!(
+        exact,
+        VersionOrReq::Req(VersionReq::exact(Version::new(1, 2, 3)))
+    );
+    assert_eq!(
+        exact.to_req(),
+        VersionOrReq::from_str("1.2.3").unwrap().to_req()
+    );
+}
+
+#[test]
+fn invalid() {
+    assert!(matches!(
+        VersionOrReq::from_str("1.x.3"),
+        Err(VersionReqParseError::MalformedNumber { .. })
+    ));
+    assert!(matches!(
+        VersionOrReq::from_str("=>1"),
+        Err(VersionReqParseError::UnknownOperator { position: 0 })
+    ));
+}
+
+#[test]
+fn display_round_trip() {
+    for input in ["1.2.3", "0.0.0", ">=1.2, <2", "^0.3", "=4.5.6", "*", "<1"] {
+        let parsed = VersionOrReq::from_str(input).unwrap();
+        let displayed = parsed.to_string();
+        assert_eq!(
+            VersionOrReq::from_str(&displayed).unwrap(),
+            parsed,
+            "{input}"
+        );
+    }
+    assert_eq!(
+        VersionOrReq::from_str("1.2.3").unwrap().to_string(),
+        "1.2.3"
+    );
+    assert_eq!(
+        VersionOrReq::from(VersionReq::exact(Version::new(1, 2, 3))).to_string(),
+        "=1.2.3"
+    );
+    assert_eq!(
+        VersionOrReq::from(Version::new(1, 2, 3)),
+        VersionOrReq::Pinned(Version::new(1, 2, 3))
+    );
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use super::*;
+    use ::serde::Deserialize;
+
+    #[derive(Deserialize, PartialEq, Debug)]
+    struct Dependency {
+        version: VersionOrReq,
+    }
+
+    #[test]
+    fn json_round_trip() {
+        for (value, json) in [
+            (VersionOrReq::Pinned(Version::new(1, 2, 3)), r#""1.2.3""#),
+            (
+                VersionOrReq::Req(VersionReq::parse(">=1.2, <2").unwrap()),
+                r#"">=1.2.0, <2.0.0""#,
+            ),
+            (
+                VersionOrReq::Req(VersionReq::exact(Version::new(1, 2, 3))),
+                r#""=1.2.3""#,
+            ),
+        ] {
+            assert_eq!(serde_json::to_string(&value).unwrap(), json);
+            assert_eq!(serde_json::from_str::<VersionOrReq>(json).unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn config() {
+        let pinned: Dependency = serde_json::from_str(r#"{"version": "1.2.3"}"#).unwrap();
+        assert_eq!(pinned.version, VersionOrReq::Pinned(Version::new(1, 2, 3)));
+        let req: Dependency = serde_json::from_str(r#"{"version": ">=1.2, <2"}"#).unwrap();
+        assert!(req.version.matches(&Version::new(1, 5, 0)));
+
+        let error = serde_json::from_str::<Dependency>(r#"{"version": "1.x"}"#).unwrap_err();
+        assert!(error.to_string().contains("at position 0"), "{error}");
+        assert!(serde_json::from_str::<Dependency>(r#"{"version": 1}"#).is_err());
+    }
+
+    #[test]
+    fn postcard_round_trip() {
+        let value = VersionOrReq::Req(VersionReq::parse("^0.3").unwrap());
+        let bytes = postcard::to_allocvec(&value).unwrap();
+        assert_eq!(postcard::from_bytes::<VersionOrReq>(&bytes).unwrap(), value);
+    }
+}
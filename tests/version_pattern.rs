@@ -0,0 +1,182 @@
+use fast_version::{
+    PatternComponent, Version, VersionPattern, VersionPatternParseError, VersionReq,
+};
+use std::str::FromStr;
+
+fn pattern(input: &str) -> VersionPattern {
+    VersionPattern::from_str(input).unwrap()
+}
+
+#[test]
+fn parse_wildcards() {
+    use PatternComponent::{Literal, Wildcard};
+    assert_eq!(
+        pattern("1.2.x"),
+        VersionPattern::new(Literal(1), Literal(2), Wildcard)
+    );
+    assert_eq!(
+        pattern("X.2.*"),
+        VersionPattern::new(Wildcard, Literal(2), Wildcard)
+    );
+    assert_eq!(pattern("1"), pattern("1.x.x"));
+    assert_eq!(pattern("1.2"), pattern("1.2.x"));
+}
+
+#[test]
+fn parse_errors() {
+    assert!(matches!(
+        VersionPattern::from_str("1.2.3.4"),
+        Err(VersionPatternParseError::FormatWrong)
+    ));
+    assert!(matches!(
+        VersionPattern::from_str("1.y.3"),
+        Err(VersionPatternParseError::ComponentParseError(_))
+    ));
+    assert!(VersionPattern::from_str("").is_err());
+}
+
+#[test]
+fn matches_every_wildcard_position() {
+    let version = Version::new(1, 2, 3);
+    let other = Version::new(4, 5, 6);
+    for mask in 0..8u8 {
+        let components: Vec<String> = [1, 2, 3]
+            .iter()
+            .enumerate()
+            .map(|(index, literal)| {
+                if mask & (1 << index) != 0 {
+                    "x".to_string()
+                } else {
+                    literal.to_string()
+                }
+            })
+            .collect();
+        let pattern = pattern(&components.join("."));
+        assert!(pattern.matches(&version), "{}", pattern);
+        assert_eq!(pattern.matches(&other), mask == 0b111, "{}", pattern);
+        assert_eq!(pattern.to_string(), components.join("."));
+    }
+}
+
+#[test]
+fn matches_middle_wildcard() {
+    let pattern = pattern("x.2.3");
+    assert!(pattern.matches(&Version::new(0, 2, 3)));
+    assert!(pattern.matches(&Version::new(u64::MAX, 2, 3)));
+    assert!(!pattern.matches(&Version::new(1, 2, 4)));
+}
+
+#[test]
+fn display_round_trip() {
+    for input in ["1.2.3", "1.2.x", "x.2.3", "1.x.3", "x.x.x"] {
+        assert_eq!(pattern(input).to_string(), input);
+        assert_eq!(pattern(&pattern(input).to_string()), pattern(input));
+    }
+    assert_eq!(pattern("1.*").to_string(), "1.x.x");
+}
+
+#[test]
+fn to_req_exact() {
+    let version = Version::new(1, 2, 3);
+    assert_eq!(pattern("1.2.3").to_req().unwrap().to_string(), "=1.2.3");
+    assert_eq!(
+        pattern("1.2.x").to_req().unwrap().to_string(),
+        ">=1.2.0, <1.3.0"
+    );
+    assert_eq!(
+        pattern("1.x.x").to_req().unwrap().to_string(),
+        ">=1.0.0, <2.0.0"
+    );
+    assert_eq!(pattern("x.x.x").to_req(), Some(VersionReq::STAR));
+    for input in ["1.2.3", "1.2.x", "1.x.x", "x.x.x"] {
+        let pattern = pattern(input);
+        let req = pattern.to_req().unwrap();
+        for candidate in [
+            version,
+            Version::new(1, 2, 0),
+            Version::new(1, 3, 0),
+            Version::new(2, 0, 0),
+            Version::new(1, u64::MAX, u64::MAX),
+        ] {
+            assert_eq!(req.matches(&candidate), pattern.matches(&candidate));
+        }
+    }
+}
+
+#[test]
+fn to_req_inexact() {
+    for input in ["x.2.3", "1.x.3", "x.x.3", "x.2.x"] {
+        assert_eq!(pattern(input).to_req(), None, "{}", input);
+    }
+}
+
+#[test]
+fn from_version() {
+    let version = Version::new(1, 2, 3);
+    assert_eq!(VersionPattern::from(version), pattern("1.2.3"));
+}
+
+#[test]
+fn to_pattern_round_trip() {
+    for input in [
+        "1.2.3",
+        "1.2.x",
+        "1.x.x",
+        "x.x.x",
+        "0.0.0",
+        "0.0.x",
+        "0.x.x",
+        "18446744073709551615.18446744073709551615.x",
+        "18446744073709551615.x.x",
+    ] {
+        let pattern = pattern(input);
+        assert_eq!(
+            pattern.to_req().unwrap().to_pattern(),
+            Some(pattern),
+            "{}",
+            input
+        );
+    }
+
+    for input in [
+        "=1.2.3",
+        ">=1.2.0, <1.3.0",
+        "~1.2",
+        "^1",
+        "^0.2",
+        "^0.0",
+        "<1.0.0",
+        "*",
+        ">=0.0.0",
+        ">=3.0.0, <=3.18446744073709551615.18446744073709551615",
+    ] {
+        let req = VersionReq::parse(input).unwrap();
+        let pattern = req.to_pattern().unwrap();
+        assert_eq!(pattern.to_req(), Some(req), "{}", input);
+    }
+}
+
+#[test]
+fn to_pattern_inexact() {
+    for input in [
+        ">=1.2.3, <1.3.0",
+        ">=1.2.0, <1.2.9",
+        ">=1.0.0, <3.0.0",
+        ">=1.2.0, <2.0.0",
+        "^1.2.3",
+        ">=1.0.0",
+        "<1.2.0",
+        // Patterns can't match prereleases.
+        ">=0.0.0-alpha",
+        ">=1.2.0-0, <1.3.0",
+        "=1.2.3-rc.1",
+    ] {
+        assert_eq!(
+            VersionReq::parse(input).unwrap().to_pattern(),
+            None,
+            "{}",
+            input
+        );
+    }
+    assert_eq!(VersionReq::EMPTY.to_pattern(), None);
+}
@@ -0,0 +1,340 @@
+mod common;
+
+use common::req;
+use fast_version::{
+    FullVersion, Version, VersionParseError, VersionReq, VersionReqParseError, VersionReqVariant,
+    VersionReqVariantLowerBound, VersionReqVariantUpperBound,
+};
+use std::str::FromStr;
+
+#[test]
+fn operators() {
+    let cases = [
+        ("*", VersionReq::STAR),
+        (
+            "1.2.3",
+            VersionReq::new(&VersionReqVariant::Strict(Version::new(1, 2, 3))),
+        ),
+        (
+            "=1.2.3",
+            VersionReq::new(&VersionReqVariant::Strict(Version::new(1, 2, 3))),
+        ),
+        (
+            ">1",
+            VersionReq::new(&VersionReqVariant::MajorGreater { major: 1 }),
+        ),
+        (
+            ">=1.2",
+            VersionReq::new(&VersionReqVariant::MinorGreaterEqual { major: 1, minor: 2 }),
+        ),
+        (
+            "<1.2.3",
+            VersionReq::new(&VersionReqVariant::PatchLess {
+                major: 1,
+                minor: 2,
+                patch: 3,
+            }),
+        ),
+        (
+            "<=1.2",
+            VersionReq::new(&VersionReqVariant::MinorLessEqual { major: 1, minor: 2 }),
+        ),
+    ];
+    for (input, expected) in cases {
+        assert_eq!(req(input), expected, "{}", input);
+    }
+}
+
+#[test]
+fn partial_equal_is_a_range() {
+    assert_eq!(req("=1.2"), req(">=1.2.0, <1.3.0"));
+    assert_eq!(req("1"), req(">=1.0.0, <2.0.0"));
+}
+
+#[test]
+fn caret_and_tilde() {
+    let cases = [
+        ("^1.2.3", ">=1.2.3, <2.0.0"),
+        ("^1.2", ">=1.2.0, <2.0.0"),
+        ("^1", ">=1.0.0, <2.0.0"),
+        ("^0.2.3", ">=0.2.3, <0.3.0"),
+        ("^0.2", ">=0.2.0, <0.3.0"),
+        ("^0.0.3", "=0.0.3"),
+        ("^0.0", "<0.1.0"),
+        ("^0", "<1.0.0"),
+        ("~1.2.3", ">=1.2.3, <1.3.0"),
+        ("~1.2", ">=1.2.0, <1.3.0"),
+        ("~1", ">=1.0.0, <2.0.0"),
+    ];
+    for (input, expected) in cases {
+        assert_eq!(req(input).to_string(), expected, "{}", input);
+    }
+}
+
+#[test]
+fn compound() {
+    let req = req(">=1.2.3, <2.0.0");
+    assert!(req.matches(&Version::new(1, 3, 0)));
+    assert!(!req.matches(&Version::new(1, 2, 2)));
+    assert!(!req.matches(&Version::new(2, 0, 0)));
+    assert_eq!(req, self::req("<2, >=1.2.3"));
+}
+
+#[test]
+fn compound_variants() {
+    use VersionReqVariantLowerBound::*;
+    use VersionReqVariantUpperBound::*;
+    let lower = [
+        (">1", MajorGreater { major: 1 }),
+        (">1.2", MinorGreater { major: 1, minor: 2 }),
+        (
+            ">1.2.3",
+            PatchGreater {
+                major: 1,
+                minor: 2,
+                patch: 3,
+            },
+        ),
+        (">=1", MajorGreaterEqual { major: 1 }),
+        (">=1.2", MinorGreaterEqual { major: 1, minor: 2 }),
+        (
+            ">=1.2.3",
+            PatchGreaterEqual {
+                major: 1,
+                minor: 2,
+                patch: 3,
+            },
+        ),
+    ];
+    let upper = [
+        ("<4", MajorLess { major: 4 }),
+        ("<4.5", MinorLess { major: 4, minor: 5 }),
+        (
+            "<4.5.6",
+            PatchLess {
+                major: 4,
+                minor: 5,
+                patch: 6,
+            },
+        ),
+        ("<=4", MajorLessEqual { major: 4 }),
+        ("<=4.5", MinorLessEqual { major: 4, minor: 5 }),
+        (
+            "<=4.5.6",
+            PatchLessEqual {
+                major: 4,
+                minor: 5,
+                patch: 6,
+            },
+        ),
+    ];
+    for (lower_input, lower) in lower {
+        for (upper_input, upper) in upper {
+            let input = format!("{lower_input}, {upper_input}");
+            let expected = VersionReq::new(&VersionReqVariant::Compound(lower, upper));
+            assert_eq!(req(&input), expected, "{input}");
+        }
+    }
+}
+
+#[test]
+fn whitespace_tolerance() {
+    let expected = req(">=1.2.3, <2.0.0");
+    for input in [
+        ">=1.2.3,<2.0.0",
+        "  >=1.2.3 ,   <2.0.0  ",
+        ">= 1.2.3, < 2.0.0",
+        "\t>=1.2.3,\n<2.0.0",
+    ] {
+        assert_eq!(req(input), expected, "{:?}", input);
+    }
+    assert_eq!(req(" * "), VersionReq::STAR);
+}
+
+#[test]
+fn display_round_trip() {
+    for input in [
+        "*",
+        "=1.2.3",
+        ">=1.2.0",
+        "<2.0.0",
+        "<=1.2.3",
+        ">=1.2.0, <2.0.0",
+        ">=1.2.0, <=1.5.7",
+        "<0.0.0",
+        "=1.0.0-rc.1",
+        ">=1.0.0-alpha",
+        ">1.0.0-alpha.1",
+        "<2.0.0-beta",
+        "<=1.0.0-rc.1",
+        ">=1.2.3-alpha.1, <2.0.0",
+        ">=1.0.0-alpha, <=1.0.0-rc.1",
+        ">=1.0.0, <2.0.0-0",
+    ] {
+        assert_eq!(req(input).to_string(), input);
+    }
+    // Caret and tilde bounds print as their range, which reads back as the same requirement.
+    for input in [
+        "^1.2.3-alpha.1",
+        "~0.1.0-beta",
+        "^0.0.3-rc",
+        ">=1.0.0-alpha, <1.0.0",
+    ] {
+        let parsed = req(input);
+        assert_eq!(req(&parsed.to_string()), parsed, "{input} {parsed}");
+    }
+}
+
+type Check = fn(&VersionReqParseError) -> bool;
+
+#[test]
+fn garbage() {
+    let cases: [(&str, Check); 11] = [
+        (">=>1", |e| {
+            matches!(e, VersionReqParseError::UnknownOperator { position: 0 })
+        }),
+        ("!=1.2.3", |e| {
+            matches!(e, VersionReqParseError::UnknownOperator { position: 0 })
+        }),
+        (">=1, =>2", |e| {
+            matches!(e, VersionReqParseError::UnknownOperator { position: 5 })
+        }),
+        ("", |e| {
+            matches!(e, VersionReqParseError::MalformedNumber { position: 0, .. })
+        }),
+        (">=1.x", |e| {
+            matches!(e, VersionReqParseError::MalformedNumber { position: 2, .. })
+        }),
+        (">=1.2.3.4", |e| {
+            matches!(e, VersionReqParseError::MalformedNumber { .. })
+        }),
+        (">=+1", |e| {
+            matches!(e, VersionReqParseError::MalformedNumber { .. })
+        }),
+        (">=1,", |e| {
+            matches!(e, VersionReqParseError::MalformedNumber { position: 4, .. })
+        }),
+        (">=1, >=2", |e| {
+            matches!(e, VersionReqParseError::ConflictingBounds { position: 5 })
+        }),
+        (">=2, <1", |e| {
+            matches!(e, VersionReqParseError::ConflictingBounds { position: 5 })
+        }),
+        (">=1, <2, <3", |e| {
+            matches!(e, VersionReqParseError::TooManyComparators { found: 3 })
+        }),
+    ];
+    for (input, check) in cases {
+        let error = VersionReq::from_str(input).unwrap_err();
+        assert!(check(&error), "{:?}: {:?}", input, error);
+    }
+}
+
+#[test]
+fn error_messages() {
+    let cases = [
+        (">=>1", "Unknown operator at position 0"),
+        (">=1.x", "Malformed version at position 2"),
+        (
+            ">=1, >=2",
+            "Comparator at position 5 conflicts with the previous one",
+        ),
+        (
+            ">=1, <2, <3",
+            "Found 3 comparators, at most 2 are supported",
+        ),
+    ];
+    for (input, expected) in cases {
+        assert_eq!(VersionReq::parse(input).unwrap_err().to_string(), expected);
+    }
+}
+
+#[test]
+fn strict_conflicts_with_bounds() {
+    assert!(matches!(
+        VersionReq::from_str("=1.2.3, <2"),
+        Err(VersionReqParseError::ConflictingBounds { position: 8 })
+    ));
+}
+
+#[test]
+fn overflow() {
+    assert!(matches!(
+        VersionReq::parse(">=18446744073709551616"),
+        Err(VersionReqParseError::MalformedNumber { position: 2, .. })
+    ));
+    assert_eq!(
+        req(">=18446744073709551615").minimal(),
+        Some(Version::new(u64::MAX, 0, 0))
+    );
+}
+
+#[test]
+fn invalid_prerelease_bounds() {
+    for (input, expected) in [
+        (">=1.0.0-alpha..1, <1.0.0", 8),
+        ("^1.0.0-", 7),
+        ("1.2.3+build.", 6),
+        (">=1, < 2.0.0-rc.01", 13),
+    ] {
+        match VersionReq::parse(input) {
+            Err(VersionReqParseError::InvalidIdentifiers { position, .. }) => {
+                assert_eq!(position, expected, "{input}")
+            }
+            other => panic!("{input}: {other:?}"),
+        }
+    }
+    // Only a version with all three components can have a prerelease.
+    assert!(matches!(
+        VersionReq::parse("1.2-beta"),
+        Err(VersionReqParseError::MalformedNumber {
+            position: 0,
+            source: VersionParseError::PatchNotFound
+        })
+    ));
+    // A sign in front of the number is no prerelease.
+    assert!(matches!(
+        VersionReq::parse(">=-1"),
+        Err(VersionReqParseError::MalformedNumber { .. })
+    ));
+}
+
+#[test]
+fn prerelease_bounds() {
+    let full = |input: &str| FullVersion::from_str(input).unwrap();
+    let alpha = req(">=1.0.0-alpha");
+    assert!(alpha.matches_full(&full("1.0.0-beta")));
+    assert!(alpha.matches_full(&full("1.0.0-alpha")));
+    assert!(alpha.matches_full(&full("1.0.0")));
+    assert!(alpha.matches_full(&full("3.1.0")));
+    assert!(!alpha.matches_full(&full("1.0.0-0")));
+    // Prereleases of other versions stay excluded, even inside the bounds.
+    assert!(!alpha.matches_full(&full("1.0.1-beta")));
+
+    let caret = req("^1.0.0");
+    assert!(!caret.matches_full(&full("1.1.0-alpha")));
+    assert!(!caret.matches_full(&full("1.0.0-rc.1")));
+    assert!(caret.matches_full(&full("1.1.0")));
+
+    let caret = req("^1.2.3-rc.1");
+    assert!(caret.matches_full(&full("1.2.3-rc.1")));
+    assert!(caret.matches_full(&full("1.2.3-rc.2")));
+    assert!(!caret.matches_full(&full("1.2.3-beta")));
+    assert!(!caret.matches_full(&full("1.3.0-rc.1")));
+    assert!(caret.matches_full(&full("1.9.0")));
+    assert!(!caret.matches_full(&full("2.0.0-alpha")));
+
+    let exact = req("=1.0.0-rc.1+build.5");
+    assert_eq!(exact, req("=1.0.0-rc.1"));
+    assert!(exact.matches_full(&full("1.0.0-rc.1+other")));
+    assert!(!exact.matches_full(&full("1.0.0")));
+    assert!(!exact.matches(&Version::new(1, 0, 0)));
+    assert!(!exact.is_empty());
+
+    // An upper bound naming a prerelease admits the earlier prereleases of that version.
+    let upper = req(">=1, <2.0.0-rc.1");
+    assert!(upper.matches_full(&full("2.0.0-beta")));
+    assert!(!upper.matches_full(&full("2.0.0-rc.1")));
+    assert!(!upper.matches_full(&full("2.0.0")));
+    assert!(upper.matches(&Version::new(1, 9, 0)));
+}
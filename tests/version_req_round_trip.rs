@@ -0,0 +1,221 @@
+mod common;
+
+use common::Rng;
+use fast_version::{
+    Version, VersionReq, VersionReqParseError, VersionReqVariant, VersionReqVariantLowerBound,
+    VersionReqVariantUpperBound,
+};
+use std::str::FromStr;
+
+const MAX: u64 = u64::MAX;
+
+impl Rng {
+    /// Mostly the extremes, where rendering needs special cases.
+    fn component(&mut self) -> u64 {
+        match self.next() % 6 {
+            0 => MAX,
+            1 => MAX - 1,
+            2 => 0,
+            3 => 1,
+            _ => self.next(),
+        }
+    }
+
+    fn version(&mut self) -> Version {
+        Version::new(self.component(), self.component(), self.component())
+    }
+
+    fn lower_bound(&mut self) -> VersionReqVariantLowerBound {
+        use VersionReqVariantLowerBound::*;
+        let (major, minor, patch) = (self.component(), self.component(), self.component());
+        match self.next() % 6 {
+            0 => MajorGreater { major },
+            1 => MinorGreater { major, minor },
+            2 => PatchGreater {
+                major,
+                minor,
+                patch,
+            },
+            3 => MajorGreaterEqual { major },
+            4 => MinorGreaterEqual { major, minor },
+            _ => PatchGreaterEqual {
+                major,
+                minor,
+                patch,
+            },
+        }
+    }
+
+    fn upper_bound(&mut self) -> VersionReqVariantUpperBound {
+        use VersionReqVariantUpperBound::*;
+        let (major, minor, patch) = (self.component(), self.component(), self.component());
+        match self.next() % 6 {
+            0 => MajorLess { major },
+            1 => MinorLess { major, minor },
+            2 => PatchLess {
+                major,
+                minor,
+                patch,
+            },
+            3 => MajorLessEqual { major },
+            4 => MinorLessEqual { major, minor },
+            _ => PatchLessEqual {
+                major,
+                minor,
+                patch,
+            },
+        }
+    }
+
+    fn req(&mut self) -> VersionReq {
+        let version = self.version();
+        let Version {
+            major,
+            minor,
+            patch,
+        } = version;
+        match self.next() % 12 {
+            0 => VersionReq::STAR,
+            1 => VersionReq::EMPTY,
+            2 => VersionReq::exact(version),
+            3 => VersionReq::between(version, self.version()),
+            4 => VersionReq::new(&VersionReqVariant::MajorGreater { major }),
+            5 => VersionReq::new(&VersionReqVariant::MinorGreater { major, minor }),
+            6 => VersionReq::new(&VersionReqVariant::MajorLess { major }),
+            7 => VersionReq::new(&VersionReqVariant::MinorLessEqual { major, minor }),
+            8 => VersionReq::greater_than(version),
+            9 => VersionReq::less_than(version),
+            10 => VersionReq::at_least(version).intersection(&VersionReq::new(
+                &VersionReqVariant::PatchLessEqual {
+                    major: self.component(),
+                    minor: self.component(),
+                    patch,
+                },
+            )),
+            _ => VersionReq::at_most(version),
+        }
+    }
+}
+
+fn round_trip(req: VersionReq) {
+    let rendered = req.to_string();
+    let parsed = VersionReq::from_str(&rendered)
+        .unwrap_or_else(|error| panic!("{:?} doesn't parse: {}", rendered, error));
+    assert_eq!(parsed, req, "{}", rendered);
+    assert_eq!(parsed.to_string(), rendered);
+}
+
+#[test]
+fn special_reqs() {
+    assert_eq!(VersionReq::STAR.to_string(), "*");
+    assert_eq!(VersionReq::EMPTY.to_string(), "<0.0.0");
+    round_trip(VersionReq::STAR);
+    round_trip(VersionReq::EMPTY);
+}
+
+#[test]
+fn extreme_bounds() {
+    let max = Version::new(MAX, MAX, MAX);
+    let zero = Version::new(0, 0, 0);
+    let reqs = [
+        VersionReq::exact(max),
+        VersionReq::exact(zero),
+        VersionReq::at_least(max),
+        VersionReq::at_most(zero),
+        VersionReq::less_than(max),
+        VersionReq::greater_than(zero),
+        VersionReq::at_most(Version::new(MAX, MAX, MAX - 1)),
+        VersionReq::at_most(Version::new(MAX - 1, MAX, MAX)),
+        VersionReq::at_most(Version::new(1, MAX, MAX)),
+        VersionReq::at_most(Version::new(1, 2, MAX)),
+        VersionReq::between(Version::new(MAX, 0, 0), Version::new(MAX, MAX, MAX - 1)),
+        VersionReq::between(Version::new(1, MAX, MAX), Version::new(2, 0, 0)),
+    ];
+    for req in reqs {
+        round_trip(req);
+    }
+    assert_eq!(
+        VersionReq::less_than(max).to_string(),
+        "<=18446744073709551615.18446744073709551615.18446744073709551614"
+    );
+    assert_eq!(
+        VersionReq::at_most(Version::new(MAX - 1, MAX, MAX)).to_string(),
+        "<18446744073709551615.0.0"
+    );
+}
+
+#[test]
+fn random_reqs() {
+    let mut rng = Rng(0xda94_2042_e4dd_58b5);
+    for _ in 0..20_000 {
+        round_trip(rng.req());
+    }
+}
+
+#[test]
+fn variant_display() {
+    let variant = VersionReqVariant::Compound(
+        VersionReqVariantLowerBound::PatchGreater {
+            major: 1,
+            minor: 2,
+            patch: 3,
+        },
+        VersionReqVariantUpperBound::MinorLessEqual { major: 1, minor: 4 },
+    );
+    assert_eq!(variant.to_string(), ">1.2.3, <=1.4");
+    assert_eq!(
+        VersionReqVariant::Strict(Version::new(1, 2, 3)).to_string(),
+        "=1.2.3"
+    );
+    assert_eq!(
+        VersionReqVariant::MajorGreaterEqual { major: 1 }.to_string(),
+        ">=1"
+    );
+    assert_eq!(
+        VersionReqVariantUpperBound::PatchLess {
+            major: 2,
+            minor: 0,
+            patch: 0
+        }
+        .to_string(),
+        "<2.0.0"
+    );
+}
+
+#[test]
+fn random_variants() {
+    let mut rng = Rng(0x8f1c_93a4_51d7_2e06);
+    for _ in 0..20_000 {
+        // Single bounds render like the variants, and are compared as compound variants with
+        // an unbounded other side.
+        let (rendered, expected) = match rng.next() % 4 {
+            0 => {
+                let variant = VersionReqVariant::Strict(rng.version());
+                (variant.to_string(), VersionReq::new(&variant))
+            }
+            1 => {
+                let variant = VersionReqVariant::Compound(rng.lower_bound(), rng.upper_bound());
+                (variant.to_string(), VersionReq::new(&variant))
+            }
+            2 => {
+                let lower = rng.lower_bound();
+                let upper = VersionReqVariantUpperBound::MajorLessEqual { major: MAX };
+                let variant = VersionReqVariant::Compound(lower, upper);
+                (lower.to_string(), VersionReq::new(&variant))
+            }
+            _ => {
+                let lower = VersionReqVariantLowerBound::MajorGreaterEqual { major: 0 };
+                let upper = rng.upper_bound();
+                let variant = VersionReqVariant::Compound(lower, upper);
+                (upper.to_string(), VersionReq::new(&variant))
+            }
+        };
+        match VersionReq::from_str(&rendered) {
+            Ok(parsed) => assert_eq!(parsed, expected, "{rendered}"),
+            Err(VersionReqParseError::ConflictingBounds { .. }) => {
+                assert!(expected.is_empty(), "{rendered}")
+            }
+            Err(error) => panic!("{rendered:?} doesn't parse: {error}"),
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use fast_version::{Version, VersionReq, VersionReqSet};
+
+fn set(input: &str) -> VersionReqSet {
+    VersionReqSet::parse(input).unwrap()
+}
+
+#[test]
+fn renders_alternatives() {
+    assert_eq!(
+        set(">=1.2, <2 || >=3").to_string(),
+        ">=1.2.0, <2.0.0 || >=3.0.0"
+    );
+    assert_eq!(
+        set(">=1, <2, !=1.5.0").to_string(),
+        ">=1.0.0, <1.5.0 || >=1.5.1, <2.0.0"
+    );
+}
+
+#[test]
+fn empty_and_single_range() {
+    assert_eq!(VersionReqSet::new().to_string(), "<0.0.0");
+    assert!(set("<0.0.0").is_empty());
+    assert!(set("<0.0.0 || <0.0.0").is_empty());
+
+    assert_eq!(VersionReqSet::from(VersionReq::STAR).to_string(), "*");
+    let single = VersionReqSet::from(VersionReq::parse("^1.2.3").unwrap());
+    assert_eq!(single.to_string(), ">=1.2.3, <2.0.0");
+    assert_eq!(
+        single.to_string(),
+        VersionReq::parse("^1.2.3").unwrap().to_string()
+    );
+}
+
+#[test]
+fn whitespace_around_alternatives() {
+    let expected = set(">=1.2, <2 || >=3");
+    for input in [
+        ">=1.2, <2||>=3",
+        ">=1.2,<2 ||>=3",
+        ">=1.2, <2||  >=3",
+        "  >=1.2 , <2   ||\t>=3  ",
+    ] {
+        assert_eq!(set(input), expected, "{input}");
+    }
+}
+
+#[test]
+fn alternatives_are_merged() {
+    assert_eq!(set(">=1, <3 || >=2, <4").ranges().len(), 1);
+    assert_eq!(set(">=2 || <2").ranges(), [VersionReq::STAR]);
+    assert_eq!(set("=1.0.0 || =1.0.1").to_string(), ">=1.0.0, <=1.0.1");
+    assert_eq!(set(">=3 || <1"), set("<1 || >=3"));
+    assert!(set("1 || 3").matches(&Version::new(3, 9, 0)));
+}
+
+#[test]
+fn round_trips() {
+    for input in [
+        "*",
+        "<0.0.0",
+        "=1.2.3",
+        ">=1.2, <2 || >=3",
+        "<1 || =2.0.0 || >3",
+        ">=1, <2, !=1.5.0, !=1.6.2 || ^4",
+        "!=18446744073709551615.18446744073709551615.18446744073709551615",
+        "!=0.0.0",
+    ] {
+        let parsed = set(input);
+        assert_eq!(set(&parsed.to_string()), parsed, "{input}");
+    }
+}
+
+#[test]
+fn clauses_count_across_alternatives() {
+    let err = VersionReqSet::parse(">=1, <2 || >=3, !=3.x").unwrap_err();
+    assert_eq!(err.clause, 3);
+    assert_eq!(VersionReqSet::parse(">=1 ||").unwrap_err().clause, 1);
+    assert_eq!(VersionReqSet::parse("| >=1").unwrap_err().clause, 0);
+}
@@ -0,0 +1,92 @@
+mod common;
+
+use common::Rng;
+use fast_version::{Version, VersionParseError, VersionStr};
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
+use std::mem::size_of;
+
+impl Rng {
+    /// A component with an occasional leading zero.
+    fn component(&mut self) -> String {
+        let value = match self.next() % 4 {
+            0 => self.next(),
+            _ => self.next() % 12,
+        };
+        match self.next() % 8 {
+            0 => format!("0{value}"),
+            _ => value.to_string(),
+        }
+    }
+}
+
+#[test]
+fn validation() {
+    assert!(VersionStr::try_new("1.2.3").is_ok());
+    assert!(matches!(
+        VersionStr::try_new("1.2"),
+        Err(VersionParseError::TooFewComponents { found: 2 })
+    ));
+    assert!(matches!(
+        VersionStr::try_new("1.x.3"),
+        Err(VersionParseError::MinorParseError)
+    ));
+    assert!(VersionStr::try_from("").is_err());
+}
+
+#[test]
+fn original_string() {
+    let version = VersionStr::try_new("01.2.003").unwrap();
+    assert_eq!(version.to_string(), "01.2.003");
+    assert_eq!(version.as_str(), "01.2.003");
+    assert_eq!(format!("{version:?}"), r#"VersionStr("01.2.003")"#);
+    assert_eq!(version.to_version(), Version::new(1, 2, 3));
+    assert_eq!(Version::from(version), Version::new(1, 2, 3));
+}
+
+#[test]
+fn equality_follows_version() {
+    let padded = VersionStr::try_new("1.02.3").unwrap();
+    let plain = VersionStr::try_new("1.2.3").unwrap();
+    assert_eq!(padded, plain);
+    assert!(padded.cmp(&plain).is_eq());
+    let state = RandomState::new();
+    assert_eq!(state.hash_one(padded), state.hash_one(plain));
+    assert_eq!(state.hash_one(plain), state.hash_one(Version::new(1, 2, 3)));
+    assert!(plain < VersionStr::try_new("1.10.0").unwrap());
+}
+
+#[test]
+fn smaller_than_version() {
+    assert_eq!(size_of::<VersionStr>(), 2 * size_of::<usize>());
+    assert!(size_of::<VersionStr>() < size_of::<Version>());
+}
+
+#[test]
+fn sorts_like_versions() {
+    let mut rng = Rng(0x7e57_ab1e_0042);
+    for len in [0, 1, 2, 10, 100, 1000] {
+        let strings: Vec<String> = (0..len)
+            .map(|_| {
+                format!(
+                    "{}.{}.{}",
+                    rng.component(),
+                    rng.component(),
+                    rng.component()
+                )
+            })
+            .collect();
+        let mut lazy: Vec<VersionStr> = strings
+            .iter()
+            .map(|string| VersionStr::try_new(string).unwrap())
+            .collect();
+        let mut parsed: Vec<Version> = strings
+            .iter()
+            .map(|string| string.parse().unwrap())
+            .collect();
+        lazy.sort();
+        parsed.sort();
+        let lazy: Vec<Version> = lazy.iter().map(VersionStr::to_version).collect();
+        assert_eq!(lazy, parsed);
+    }
+}